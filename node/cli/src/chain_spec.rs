@@ -20,6 +20,8 @@
 #![allow(missing_docs)]
 
 pub mod bootstrap;
+pub mod genesis_state;
+pub mod preload;
 
 pub use cord_primitives::{AccountId, Balance, NodeId, Signature};
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
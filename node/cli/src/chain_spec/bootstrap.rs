@@ -41,7 +41,9 @@ pub use cord_braid_runtime_constants::currency::UNITS as BRAID_UNITS;
 pub use cord_loom_runtime_constants::currency::UNITS as LOOM_UNITS;
 pub use cord_weave_runtime_constants::currency::WAY;
 
-use crate::chain_spec::{get_properties, Extensions, CORD_TELEMETRY_URL, DEFAULT_PROTOCOL_ID};
+use crate::chain_spec::{
+	get_properties, preload::GenesisPreload, Extensions, CORD_TELEMETRY_URL, DEFAULT_PROTOCOL_ID,
+};
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct ChainParams {
@@ -54,6 +56,7 @@ pub struct ChainParams {
 	pub council_members: Vec<String>,
 	pub tech_committee_members: Vec<String>,
 	pub sudo_key: String,
+	pub preload: GenesisPreload,
 }
 
 impl ChainParams {
@@ -108,7 +111,7 @@ fn weave_session_keys(
 	WeaveSessionKeys { babe, grandpa, im_online, authority_discovery }
 }
 
-fn cord_braid_custom_config_genesis(config: ChainParams) -> serde_json::Value {
+fn cord_braid_custom_config_genesis(config: ChainParams) -> Result<serde_json::Value, String> {
 	let initial_network_members: Vec<AccountId> =
 		config.network_members.iter().map(array_bytes::hex_n_into_unchecked).collect();
 
@@ -138,15 +141,16 @@ fn cord_braid_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 			.collect();
 
 	let initial_sudo_key: AccountId = array_bytes::hex_n_into_unchecked(&config.sudo_key);
-	cord_braid_custom_genesis(
+	let genesis = cord_braid_custom_genesis(
 		initial_network_members,
 		initial_well_known_nodes,
 		initial_authorities,
 		initial_sudo_key,
-	)
+	);
+	with_preload(genesis, &config.preload, cord_braid_runtime::MaxEncodedSchemaLength::get())
 }
 
-fn cord_loom_custom_config_genesis(config: ChainParams) -> serde_json::Value {
+fn cord_loom_custom_config_genesis(config: ChainParams) -> Result<serde_json::Value, String> {
 	let initial_network_members: Vec<AccountId> =
 		config.network_members.iter().map(array_bytes::hex_n_into_unchecked).collect();
 
@@ -176,15 +180,16 @@ fn cord_loom_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 			.collect();
 
 	let initial_sudo_key: AccountId = array_bytes::hex_n_into_unchecked(&config.sudo_key);
-	cord_loom_custom_genesis(
+	let genesis = cord_loom_custom_genesis(
 		initial_network_members,
 		initial_well_known_nodes,
 		initial_authorities,
 		initial_sudo_key,
-	)
+	);
+	with_preload(genesis, &config.preload, cord_loom_runtime::MaxEncodedSchemaLength::get())
 }
 
-fn cord_weave_custom_config_genesis(config: ChainParams) -> serde_json::Value {
+fn cord_weave_custom_config_genesis(config: ChainParams) -> Result<serde_json::Value, String> {
 	let initial_network_members: Vec<AccountId> =
 		config.network_members.iter().map(array_bytes::hex_n_into_unchecked).collect();
 
@@ -214,13 +219,25 @@ fn cord_weave_custom_config_genesis(config: ChainParams) -> serde_json::Value {
 			.collect();
 
 	let initial_sudo_key: AccountId = array_bytes::hex_n_into_unchecked(&config.sudo_key);
-	cord_weave_custom_genesis(
+	let genesis = cord_weave_custom_genesis(
 		initial_network_members,
 		initial_well_known_nodes,
 		initial_authorities,
 		initial_sudo_key,
-	)
+	);
+	with_preload(genesis, &config.preload, cord_weave_runtime::MaxEncodedSchemaLength::get())
 }
+
+/// Merge the genesis config patch of the preloaded entries into `genesis`.
+fn with_preload(
+	mut genesis: serde_json::Value,
+	preload: &GenesisPreload,
+	max_schema_length: u32,
+) -> Result<serde_json::Value, String> {
+	sc_chain_spec::json_merge(&mut genesis, preload.genesis_patch(max_schema_length)?);
+	Ok(genesis)
+}
+
 pub fn cord_custom_config(config: ChainParams) -> Result<CordChainSpec, String> {
 	let chain_name = String::from(config.chain_name());
 	let chain_type = config.chain_type();
@@ -236,7 +253,7 @@ pub fn cord_custom_config(config: ChainParams) -> Result<CordChainSpec, String>
 		.with_name(&chain_name)
 		.with_id("braid-cord-custom")
 		.with_chain_type(chain_type)
-		.with_genesis_config_patch(cord_braid_custom_config_genesis(config.clone()))
+		.with_genesis_config_patch(cord_braid_custom_config_genesis(config.clone())?)
 		.with_telemetry_endpoints(
 			TelemetryEndpoints::new(vec![(CORD_TELEMETRY_URL.to_string(), 0)])
 				.expect("Cord telemetry url is valid; qed"),
@@ -253,7 +270,7 @@ pub fn cord_custom_config(config: ChainParams) -> Result<CordChainSpec, String>
 		.with_name(&chain_name)
 		.with_id("loom-cord-custom")
 		.with_chain_type(chain_type)
-		.with_genesis_config_patch(cord_loom_custom_config_genesis(config.clone()))
+		.with_genesis_config_patch(cord_loom_custom_config_genesis(config.clone())?)
 		.with_telemetry_endpoints(
 			TelemetryEndpoints::new(vec![(CORD_TELEMETRY_URL.to_string(), 0)])
 				.expect("Cord telemetry url is valid; qed"),
@@ -270,7 +287,7 @@ pub fn cord_custom_config(config: ChainParams) -> Result<CordChainSpec, String>
 		.with_name(&chain_name)
 		.with_id("weave-cord-custom")
 		.with_chain_type(chain_type)
-		.with_genesis_config_patch(cord_weave_custom_config_genesis(config.clone()))
+		.with_genesis_config_patch(cord_weave_custom_config_genesis(config.clone())?)
 		.with_telemetry_endpoints(
 			TelemetryEndpoints::new(vec![(CORD_TELEMETRY_URL.to_string(), 0)])
 				.expect("Cord telemetry url is valid; qed"),
@@ -0,0 +1,515 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Decoded genesis state of a chain spec.
+//!
+//! The genesis storage is built with the runtime of the spec itself, so plain
+//! specs, raw specs and state exports of a live chain (`export-state`) are
+//! handled alike. Storage entries are decoded with the metadata of that
+//! runtime and named after their pallet and storage item, e.g.
+//! `Session.NextKeys`, which is what the sanity checks and the semantic diff
+//! work on.
+
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	fmt,
+	str::FromStr,
+};
+
+use codec::{Decode, Encode};
+use cord_node_inspect::decode::ValueDecoder;
+use frame_metadata::{
+	v15::{RuntimeMetadataV15, StorageEntryMetadata, StorageEntryType},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use sc_chain_spec::ChainSpec;
+use sc_network::PeerId;
+use scale_info::form::PortableForm;
+use serde_json::{json, Value};
+use sp_core::{
+	blake2_256,
+	storage::{well_known_keys, Storage},
+	traits::{CallContext, CodeExecutor, RuntimeCode, WrappedRuntimeCode},
+	twox_128,
+};
+use sp_state_machine::BasicExternalities;
+
+use crate::service::RuntimeExecutor;
+
+/// Session keys every authority must have.
+const SESSION_KEYS: [&str; 4] = ["babe", "grandpa", "im_online", "authority_discovery"];
+
+/// Membership pallets whose size must meet `MinAuthorities`.
+const COUNCILS: [&str; 2] = ["CouncilMembership", "TechnicalMembership"];
+
+/// A decoded storage entry.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateEntry {
+	/// `Pallet.Item` of the entry, or the raw key when it is not known to the
+	/// runtime.
+	pub item: String,
+	/// The decoded key of a map entry.
+	pub key: Option<Value>,
+	/// The decoded value.
+	pub value: Value,
+}
+
+/// The genesis state of a chain spec along with its other fields.
+#[derive(Debug, Default)]
+pub struct GenesisState {
+	/// Fields of the chain spec besides the genesis, e.g. `bootNodes`.
+	pub fields: BTreeMap<String, Value>,
+	/// The genesis config of a plain chain spec.
+	pub genesis_config: Option<Value>,
+	/// Names of the pallets of the runtime.
+	pub pallets: BTreeSet<String>,
+	/// `MinAuthorities` of the `AuthorityMembership` pallet.
+	pub min_authorities: Option<u32>,
+	/// The storage entries, by raw key.
+	pub entries: BTreeMap<Vec<u8>, StateEntry>,
+}
+
+/// A difference between two genesis states.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+	pub item: String,
+	pub key: Option<Value>,
+	pub old: Option<Value>,
+	pub new: Option<Value>,
+}
+
+impl fmt::Display for Change {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		let name = match &self.key {
+			Some(key) => format!("{}[{}]", self.item, compact(key)),
+			None => self.item.clone(),
+		};
+		match (&self.old, &self.new) {
+			(None, Some(new)) => write!(f, "+ {} = {}", name, compact(new)),
+			(Some(old), None) => write!(f, "- {} = {}", name, compact(old)),
+			(Some(old), Some(new)) => write!(f, "~ {}: {} -> {}", name, compact(old), compact(new)),
+			(None, None) => write!(f, "  {}", name),
+		}
+	}
+}
+
+impl GenesisState {
+	/// Build and decode the genesis state of a chain spec.
+	pub fn from_spec(spec: &dyn ChainSpec) -> Result<Self, String> {
+		let mut fields: BTreeMap<String, Value> = match serde_json::from_str(&spec.as_json(false)?)
+		{
+			Ok(Value::Object(fields)) => fields.into_iter().collect(),
+			Ok(_) => return Err("The chain spec is not a JSON object".into()),
+			Err(e) => return Err(format!("Invalid chain spec: {}", e)),
+		};
+		let genesis_config = fields.remove("genesis").and_then(|genesis| {
+			["/runtimeGenesis/patch", "/runtimeGenesis/config", "/runtime"]
+				.iter()
+				.find_map(|path| genesis.pointer(path).cloned())
+		});
+
+		let storage = spec.as_storage_builder().build_storage()?;
+		let code = storage
+			.top
+			.get(well_known_keys::CODE)
+			.ok_or_else(|| "The genesis state has no runtime code".to_string())?;
+		let metadata = runtime_metadata(code)?;
+		let min_authorities = metadata
+			.pallets
+			.iter()
+			.find(|pallet| pallet.name == "AuthorityMembership")
+			.and_then(|pallet| pallet.constants.iter().find(|c| c.name == "MinAuthorities"))
+			.and_then(|constant| u32::decode(&mut &constant.value[..]).ok());
+
+		Ok(Self {
+			fields,
+			genesis_config,
+			pallets: metadata.pallets.iter().map(|pallet| pallet.name.clone()).collect(),
+			min_authorities,
+			entries: StorageDecoder::new(&metadata).decode_all(&storage),
+		})
+	}
+
+	/// Sanity-check the genesis state, returning the problems found.
+	///
+	/// `min_authorities` overrides the `MinAuthorities` of the runtime.
+	pub fn check(&self, min_authorities: Option<u32>) -> Vec<String> {
+		let mut problems = Vec::new();
+		let min_authorities = min_authorities.or(self.min_authorities);
+
+		let authorities = self.accounts("AuthorityMembership.Members");
+		if authorities.is_empty() {
+			problems.push("There are no authorities".to_string());
+		}
+		if let Some(min) = min_authorities.filter(|min| authorities.len() < *min as usize) {
+			problems.push(format!(
+				"There are {} authorities, fewer than MinAuthorities ({})",
+				authorities.len(),
+				min
+			));
+		}
+
+		let session_keys: BTreeMap<&str, &Value> = self
+			.map("Session.NextKeys")
+			.filter_map(|(account, keys)| Some((account.as_str()?, keys)))
+			.collect();
+		for authority in &authorities {
+			let Some(keys) = session_keys.get(authority) else {
+				problems.push(format!("Authority {} has no session keys", authority));
+				continue;
+			};
+			let missing: Vec<&str> = SESSION_KEYS
+				.iter()
+				.copied()
+				.filter(|name| keys.get(name).map_or(true, Value::is_null))
+				.collect();
+			if !missing.is_empty() {
+				problems.push(format!(
+					"Authority {} has no {} session key",
+					authority,
+					missing.join(", ")
+				));
+			}
+		}
+
+		// Node ids that do not parse are dropped when building the genesis, so
+		// they are checked in the genesis config as well.
+		let configured_nodes = self
+			.genesis_config
+			.as_ref()
+			.and_then(|config| config.pointer("/nodeAuthorization/nodes"))
+			.and_then(Value::as_array);
+		for node in configured_nodes.into_iter().flatten() {
+			let id = node.get(0).and_then(node_id).unwrap_or_default();
+			if let Err(e) = PeerId::from_str(&id) {
+				problems.push(format!("Well-known node '{}' is not a valid PeerId: {}", id, e));
+			}
+		}
+		let well_known_nodes = self
+			.item("NodeAuthorization.WellKnownNodes")
+			.and_then(Value::as_array)
+			.cloned()
+			.unwrap_or_default();
+		if well_known_nodes.is_empty() {
+			problems.push("There are no well-known nodes".to_string());
+		}
+		for node in &well_known_nodes {
+			let bytes = node.as_str().and_then(|node| array_bytes::hex2bytes(node).ok());
+			let parsed = match bytes {
+				Some(bytes) => PeerId::from_bytes(&bytes).map(drop).map_err(|e| e.to_string()),
+				None => Err("not a hex string".to_string()),
+			};
+			if let Err(e) = parsed {
+				problems.push(format!("Well-known node {} is not a valid PeerId: {}", node, e));
+			}
+		}
+
+		let members: BTreeSet<&str> = self
+			.map("NetworkMembership.Members")
+			.filter_map(|(member, _)| member.as_str())
+			.collect();
+		for authority in &authorities {
+			if !members.contains(authority) {
+				problems.push(format!("Authority {} is not a network member", authority));
+			}
+		}
+
+		for council in COUNCILS.iter().filter(|council| self.pallets.contains(**council)) {
+			let size = self.accounts(&format!("{}.Members", council)).len();
+			if let Some(min) = min_authorities.filter(|min| size < *min as usize) {
+				problems.push(format!(
+					"{} has {} members, fewer than MinAuthorities ({})",
+					council, size, min
+				));
+			}
+		}
+
+		problems
+	}
+
+	/// The differences from this genesis state to `other`, by storage item.
+	pub fn diff(&self, other: &GenesisState) -> Vec<Change> {
+		let mut changes = Vec::new();
+
+		let names: BTreeSet<&String> = self.fields.keys().chain(other.fields.keys()).collect();
+		for name in names {
+			let (old, new) = (self.fields.get(name), other.fields.get(name));
+			if old != new {
+				changes.push(Change {
+					item: format!("spec.{}", name),
+					key: None,
+					old: old.cloned(),
+					new: new.cloned(),
+				});
+			}
+		}
+
+		let mut storage_changes = Vec::new();
+		let keys: BTreeSet<&Vec<u8>> = self.entries.keys().chain(other.entries.keys()).collect();
+		for key in keys {
+			let (old, new) = (self.entries.get(key), other.entries.get(key));
+			if old.map(|entry| &entry.value) == new.map(|entry| &entry.value) {
+				continue;
+			}
+			let Some(entry) = new.or(old) else { continue };
+			storage_changes.push(Change {
+				item: entry.item.clone(),
+				key: entry.key.clone(),
+				old: old.map(|entry| entry.value.clone()),
+				new: new.map(|entry| entry.value.clone()),
+			});
+		}
+		storage_changes.sort_by(|a, b| a.item.cmp(&b.item));
+		changes.extend(storage_changes);
+
+		changes
+	}
+
+	/// The value of a plain storage item.
+	fn item(&self, item: &str) -> Option<&Value> {
+		self.entries
+			.values()
+			.find(|entry| entry.item == item && entry.key.is_none())
+			.map(|entry| &entry.value)
+	}
+
+	/// The entries of a storage map.
+	fn map<'a>(&'a self, item: &'a str) -> impl Iterator<Item = (&'a Value, &'a Value)> + 'a {
+		self.entries
+			.values()
+			.filter(move |entry| entry.item == item)
+			.filter_map(|entry| Some((entry.key.as_ref()?, &entry.value)))
+	}
+
+	/// The accounts of a plain storage item holding a list of accounts.
+	fn accounts(&self, item: &str) -> Vec<&str> {
+		self.item(item)
+			.and_then(Value::as_array)
+			.map(|accounts| accounts.iter().filter_map(Value::as_str).collect())
+			.unwrap_or_default()
+	}
+}
+
+/// Decodes storage keys and values with the runtime metadata.
+struct StorageDecoder<'a> {
+	values: ValueDecoder<'a>,
+	items: BTreeMap<Vec<u8>, (String, &'a StorageEntryMetadata<PortableForm>)>,
+}
+
+impl<'a> StorageDecoder<'a> {
+	fn new(metadata: &'a RuntimeMetadataV15) -> Self {
+		let mut items = BTreeMap::new();
+		for pallet in &metadata.pallets {
+			let Some(storage) = &pallet.storage else { continue };
+			for entry in &storage.entries {
+				let prefix =
+					[twox_128(storage.prefix.as_bytes()), twox_128(entry.name.as_bytes())].concat();
+				items.insert(prefix, (format!("{}.{}", pallet.name, entry.name), entry));
+			}
+		}
+		Self { values: ValueDecoder::new(&metadata.types), items }
+	}
+
+	fn decode_all(&self, storage: &Storage) -> BTreeMap<Vec<u8>, StateEntry> {
+		let mut entries: BTreeMap<Vec<u8>, StateEntry> = storage
+			.top
+			.iter()
+			.map(|(key, value)| (key.clone(), self.decode(key, value)))
+			.collect();
+		for child in storage.children_default.values() {
+			let child_key = child.child_info.storage_key();
+			for (key, value) in &child.data {
+				entries.insert(
+					[well_known_keys::DEFAULT_CHILD_STORAGE_KEY_PREFIX, child_key, key].concat(),
+					StateEntry {
+						item: format!("child {}", hex_value(child_key)),
+						key: Some(hex_value(key).into()),
+						value: hex_value(value).into(),
+					},
+				);
+			}
+		}
+		entries
+	}
+
+	fn decode(&self, key: &[u8], value: &[u8]) -> StateEntry {
+		if key == well_known_keys::CODE {
+			return StateEntry {
+				item: ":code".into(),
+				key: None,
+				value: json!({ "hash": hex_value(&blake2_256(value)), "size": value.len() }),
+			};
+		}
+		let Some((item, entry)) = key.get(..32).and_then(|prefix| self.items.get(prefix)) else {
+			let item = if key.starts_with(b":") {
+				String::from_utf8_lossy(key).into_owned()
+			} else {
+				hex_value(key)
+			};
+			return StateEntry { item, key: None, value: hex_value(value).into() };
+		};
+
+		let (key, ty) = match &entry.ty {
+			StorageEntryType::Plain(ty) => (None, ty.id),
+			StorageEntryType::Map { hashers, key: key_ty, value: ty } => {
+				let key = &key[32..];
+				let decoded = self.values.decode_storage_key(hashers, key_ty.id, key);
+				(Some(decoded.unwrap_or_else(|_| hex_value(key).into())), ty.id)
+			},
+		};
+		let value = match self.values.decode(ty, &mut &value[..]) {
+			Ok(decoded) => decoded,
+			Err(e) => json!({ "raw": hex_value(value), "error": e }),
+		};
+		StateEntry { item: item.clone(), key, value }
+	}
+}
+
+/// Read the metadata of a runtime by executing its `Metadata` runtime API.
+fn runtime_metadata(code: &[u8]) -> Result<RuntimeMetadataV15, String> {
+	let runtime_code = RuntimeCode {
+		code_fetcher: &WrappedRuntimeCode(code.into()),
+		hash: blake2_256(code).to_vec(),
+		heap_pages: None,
+	};
+	let (result, _) = RuntimeExecutor::builder().build().call(
+		&mut BasicExternalities::new_empty(),
+		&runtime_code,
+		"Metadata_metadata_at_version",
+		&15u32.encode(),
+		CallContext::Offchain,
+	);
+	let raw = result.map_err(|e| format!("Failed to read the runtime metadata: {}", e))?;
+	let raw = Option::<Vec<u8>>::decode(&mut &raw[..])
+		.map_err(|e| e.to_string())?
+		.ok_or_else(|| "Metadata V15 is not available in the runtime".to_string())?;
+	match RuntimeMetadataPrefixed::decode(&mut &raw[..]).map_err(|e| e.to_string())?.1 {
+		RuntimeMetadata::V15(metadata) => Ok(metadata),
+		_ => Err("Unsupported metadata version".into()),
+	}
+}
+
+/// The UTF-8 node id of a well-known node in the genesis config.
+fn node_id(value: &Value) -> Option<String> {
+	let bytes: Option<Vec<u8>> = match value {
+		Value::Array(bytes) => bytes
+			.iter()
+			.map(|byte| byte.as_u64().and_then(|b| u8::try_from(b).ok()))
+			.collect(),
+		Value::String(text) => return Some(text.clone()),
+		_ => None,
+	};
+	String::from_utf8(bytes?).ok()
+}
+
+fn hex_value(bytes: &[u8]) -> String {
+	format!("0x{}", hex::encode(bytes))
+}
+
+fn compact(value: &Value) -> String {
+	match value {
+		Value::String(text) => text.clone(),
+		value => value.to_string(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ALICE: &str = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+	const BOB: &str = "5FHneW46xGXgs5mUiveU4sbTyGBzmstUspZC92UhjJM694ty";
+	const PEER: &str = "12D3KooWBmAwcd4PJNJvfV89HwE48nwkRmAgo8Vy3uQEyNNHBox2";
+
+	fn entry(item: &str, key: Option<Value>, value: Value) -> (Vec<u8>, StateEntry) {
+		let raw = format!("{}{}", item, key.as_ref().map(Value::to_string).unwrap_or_default());
+		(raw.into_bytes(), StateEntry { item: item.into(), key, value })
+	}
+
+	fn session_keys() -> Value {
+		json!({ "babe": "0x01", "grandpa": "0x02", "im_online": "0x03", "authority_discovery": "0x04" })
+	}
+
+	fn state() -> GenesisState {
+		let peer = PeerId::from_str(PEER).unwrap().to_bytes();
+		GenesisState {
+			pallets: ["AuthorityMembership", "CouncilMembership"].map(String::from).into(),
+			min_authorities: Some(2),
+			entries: [
+				entry("AuthorityMembership.Members", None, json!([ALICE, BOB])),
+				entry("Session.NextKeys", Some(json!(ALICE)), session_keys()),
+				entry("Session.NextKeys", Some(json!(BOB)), session_keys()),
+				entry("NodeAuthorization.WellKnownNodes", None, json!([hex_value(&peer)])),
+				entry("NetworkMembership.Members", Some(json!(ALICE)), json!({})),
+				entry("NetworkMembership.Members", Some(json!(BOB)), json!({})),
+				entry("CouncilMembership.Members", None, json!([ALICE, BOB])),
+			]
+			.into(),
+			..Default::default()
+		}
+	}
+
+	#[test]
+	fn check_passes_on_consistent_state() {
+		assert_eq!(state().check(None), Vec::<String>::new());
+	}
+
+	#[test]
+	fn check_reports_problems() {
+		let mut state = state();
+		state.entries.extend([
+			entry("Session.NextKeys", Some(json!(BOB)), json!({ "babe": "0x01", "grandpa": null })),
+			entry("NodeAuthorization.WellKnownNodes", None, json!(["0x0102"])),
+			entry("CouncilMembership.Members", None, json!([ALICE])),
+		]);
+		state.entries.retain(|_, entry| {
+			entry.key != Some(json!(BOB)) || entry.item != "NetworkMembership.Members"
+		});
+		state.genesis_config = Some(json!({ "nodeAuthorization": { "nodes": [[[0x31], ALICE]] } }));
+
+		let problems = state.check(None);
+		assert_eq!(problems.len(), 5, "{:?}", problems);
+		assert!(problems[0].contains("grandpa, im_online, authority_discovery"));
+		assert!(problems[1].starts_with("Well-known node '1'"));
+		assert!(problems[2].starts_with("Well-known node \"0x0102\""));
+		assert!(problems[3].contains(BOB));
+		assert!(problems[4].starts_with("CouncilMembership has 1 members"));
+
+		assert_eq!(state.check(Some(1)).len(), 3);
+	}
+
+	#[test]
+	fn diff_reports_changes_by_item() {
+		let old = state();
+		let mut new = state();
+		new.fields.insert("name".into(), json!("Loom"));
+		new.entries.remove(&entry("Session.NextKeys", Some(json!(BOB)), Value::Null).0);
+		new.entries.extend([entry("CouncilMembership.Members", None, json!([ALICE]))]);
+
+		let changes: Vec<String> = old.diff(&new).iter().map(ToString::to_string).collect();
+		assert_eq!(
+			changes,
+			vec![
+				"+ spec.name = Loom".to_string(),
+				format!("~ CouncilMembership.Members: [\"{ALICE}\",\"{BOB}\"] -> [\"{ALICE}\"]"),
+				format!("- Session.NextKeys[{BOB}] = {}", session_keys()),
+			]
+		);
+		assert!(old.diff(&old).is_empty());
+	}
+}
@@ -0,0 +1,410 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Genesis preloading of DIDs, chain spaces, schemas, namespaces and
+//! registries.
+//!
+//! The entries are read from the bootstrap configuration and reference each
+//! other by name:
+//!
+//! ```toml
+//! [[dids]]
+//! name = "issuer"
+//! authentication_key = "0x..."
+//! key_type = "sr25519"
+//!
+//! [[spaces]]
+//! name = "credentials"
+//! creator = "issuer"
+//! capacity = 10000
+//!
+//! [[schemas]]
+//! space = "credentials"
+//! schema = '{"title": "Degree"}'
+//!
+//! [[namespaces]]
+//! name = "records"
+//! creator = "issuer"
+//!
+//! [[registries]]
+//! name = "degrees"
+//! namespace = "records"
+//! ```
+//!
+//! Spaces, namespaces and registries are anchored with the BLAKE2-256 hash of
+//! their name, unless a `digest` is given.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use cord_primitives::{AccountId, Hash};
+use pallet_did::did_details::DidVerificationKey;
+use serde::Deserialize;
+use sp_core::{ecdsa, ed25519, sr25519};
+use sp_runtime::traits::{BlakeTwo256, Hash as _, IdentifyAccount};
+
+/// Type of the authentication key of a DID.
+#[derive(Debug, Default, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DidKeyType {
+	#[default]
+	Sr25519,
+	Ed25519,
+	Ecdsa,
+}
+
+/// A DID created at genesis. Its identifier is the account of its
+/// authentication key.
+#[derive(Debug, Deserialize, Clone)]
+pub struct DidParams {
+	pub name: String,
+	pub authentication_key: String,
+	#[serde(default)]
+	pub key_type: DidKeyType,
+}
+
+/// An approved chain space anchored at genesis by a preloaded DID.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SpaceParams {
+	pub name: String,
+	pub creator: String,
+	pub capacity: u64,
+	pub digest: Option<String>,
+}
+
+/// A schema anchored at genesis in a preloaded space, by the space creator.
+#[derive(Debug, Deserialize, Clone)]
+pub struct SchemaParams {
+	pub space: String,
+	pub schema: String,
+}
+
+/// A namespace anchored at genesis by a preloaded DID.
+#[derive(Debug, Deserialize, Clone)]
+pub struct NameSpaceParams {
+	pub name: String,
+	pub creator: String,
+	pub digest: Option<String>,
+}
+
+/// A registry anchored at genesis in a preloaded namespace, by the namespace
+/// creator.
+#[derive(Debug, Deserialize, Clone)]
+pub struct RegistryParams {
+	pub name: String,
+	pub namespace: String,
+	pub digest: Option<String>,
+}
+
+/// The entries preloaded at genesis.
+#[derive(Debug, Default, Deserialize, Clone)]
+pub struct GenesisPreload {
+	#[serde(default)]
+	pub dids: Vec<DidParams>,
+	#[serde(default)]
+	pub spaces: Vec<SpaceParams>,
+	#[serde(default)]
+	pub schemas: Vec<SchemaParams>,
+	#[serde(default)]
+	pub namespaces: Vec<NameSpaceParams>,
+	#[serde(default)]
+	pub registries: Vec<RegistryParams>,
+}
+
+impl GenesisPreload {
+	/// Validate the entries and their references, and build the genesis
+	/// config patch of the `did`, `chainSpace`, `schema`, `nameSpace` and
+	/// `registries` pallets.
+	pub fn genesis_patch(&self, max_schema_length: u32) -> Result<serde_json::Value, String> {
+		let mut dids = BTreeMap::new();
+		let mut did_keys = Vec::new();
+		for did in &self.dids {
+			let key = did.verification_key()?;
+			let account = key.clone().into_account();
+			if dids.values().any(|a| a == &account) {
+				return Err(format!("DID '{}' is defined more than once", did.name));
+			}
+			if dids.insert(did.name.as_str(), account.clone()).is_some() {
+				return Err(format!("Duplicate DID name '{}'", did.name));
+			}
+			did_keys.push((account, key));
+		}
+		let did_account = |kind: &str, name: &str, creator: &str| {
+			dids.get(creator).cloned().ok_or_else(|| {
+				format!("The creator '{}' of {} '{}' is not a preloaded DID", creator, kind, name)
+			})
+		};
+
+		let mut spaces = BTreeMap::new();
+		let mut space_entries = Vec::new();
+		for space in &self.spaces {
+			if space.capacity == 0 {
+				return Err(format!("Space '{}' must have a capacity", space.name));
+			}
+			let creator = did_account("space", &space.name, &space.creator)?;
+			let code = digest_of("space", &space.name, space.digest.as_deref())?;
+			if spaces.values().any(|anchored| anchored == &(code, creator.clone())) {
+				return Err(format!("Space '{}' is defined more than once", space.name));
+			}
+			if spaces.insert(space.name.as_str(), (code, creator.clone())).is_some() {
+				return Err(format!("Duplicate space name '{}'", space.name));
+			}
+			space_entries.push((code, creator, space.capacity));
+		}
+
+		let mut schemas = BTreeSet::new();
+		let mut schema_entries = Vec::new();
+		for schema in &self.schemas {
+			let (code, creator) = spaces
+				.get(schema.space.as_str())
+				.cloned()
+				.ok_or_else(|| format!("Schema references the unknown space '{}'", schema.space))?;
+			if schema.schema.trim().is_empty() {
+				return Err(format!("Empty schema in space '{}'", schema.space));
+			}
+			if schema.schema.len() > max_schema_length as usize {
+				return Err(format!(
+					"Schema in space '{}' exceeds {} bytes",
+					schema.space, max_schema_length
+				));
+			}
+			serde_json::from_str::<serde_json::Value>(&schema.schema)
+				.map_err(|e| format!("Invalid schema in space '{}': {}", schema.space, e))?;
+			if !schemas.insert((schema.space.as_str(), schema.schema.as_str())) {
+				return Err(format!("Schema in space '{}' is defined more than once", schema.space));
+			}
+			schema_entries.push((code, creator, schema.schema.as_bytes().to_vec()));
+		}
+
+		let mut namespaces = BTreeMap::new();
+		let mut namespace_entries = Vec::new();
+		for namespace in &self.namespaces {
+			let creator = did_account("namespace", &namespace.name, &namespace.creator)?;
+			let digest = digest_of("namespace", &namespace.name, namespace.digest.as_deref())?;
+			if namespaces.values().any(|anchored| anchored == &(digest, creator.clone())) {
+				return Err(format!("Namespace '{}' is defined more than once", namespace.name));
+			}
+			if namespaces.insert(namespace.name.as_str(), (digest, creator.clone())).is_some() {
+				return Err(format!("Duplicate namespace name '{}'", namespace.name));
+			}
+			namespace_entries.push((digest, creator));
+		}
+
+		let mut registries = BTreeSet::new();
+		let mut registry_entries = Vec::new();
+		for registry in &self.registries {
+			let (namespace, creator) =
+				namespaces.get(registry.namespace.as_str()).cloned().ok_or_else(|| {
+					format!(
+						"Registry '{}' references the unknown namespace '{}'",
+						registry.name, registry.namespace
+					)
+				})?;
+			let digest = digest_of("registry", &registry.name, registry.digest.as_deref())?;
+			if !registries.insert((digest, creator.clone())) {
+				return Err(format!("Registry '{}' is defined more than once", registry.name));
+			}
+			registry_entries.push((digest, namespace, creator));
+		}
+
+		Ok(serde_json::json!({
+			"did": { "dids": did_keys },
+			"chainSpace": { "spaces": space_entries },
+			"schema": { "schemas": schema_entries },
+			"nameSpace": { "namespaces": namespace_entries },
+			"registries": { "registries": registry_entries },
+		}))
+	}
+}
+
+impl DidParams {
+	fn verification_key(&self) -> Result<DidVerificationKey<AccountId>, String> {
+		let invalid = |e| format!("Invalid authentication key of DID '{}': {:?}", self.name, e);
+		Ok(match self.key_type {
+			DidKeyType::Sr25519 => sr25519::Public::from_raw(
+				array_bytes::hex2array::<_, 32>(&self.authentication_key).map_err(invalid)?,
+			)
+			.into(),
+			DidKeyType::Ed25519 => ed25519::Public::from_raw(
+				array_bytes::hex2array::<_, 32>(&self.authentication_key).map_err(invalid)?,
+			)
+			.into(),
+			DidKeyType::Ecdsa => ecdsa::Public::from_raw(
+				array_bytes::hex2array::<_, 33>(&self.authentication_key).map_err(invalid)?,
+			)
+			.into(),
+		})
+	}
+}
+
+/// The digest an entry is anchored with, the hash of its name by default.
+fn digest_of(kind: &str, name: &str, digest: Option<&str>) -> Result<Hash, String> {
+	match digest {
+		Some(digest) => array_bytes::hex2array::<_, 32>(digest)
+			.map(Hash::from)
+			.map_err(|e| format!("Invalid digest of {} '{}': {:?}", kind, name, e)),
+		None => Ok(BlakeTwo256::hash(name.as_bytes())),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const ALICE: &str = "0xd43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+	const BOB: &str = "0x8eaf04151687736326c9fea17e25fc5287613693c912909cb226aa4794f26a48";
+
+	fn preload(config: &str) -> GenesisPreload {
+		toml::from_str(config).unwrap()
+	}
+
+	#[test]
+	fn builds_patch_with_cross_references() {
+		let config = format!(
+			r#"
+			[[dids]]
+			name = "alice"
+			authentication_key = "{ALICE}"
+
+			[[spaces]]
+			name = "credentials"
+			creator = "alice"
+			capacity = 100
+
+			[[schemas]]
+			space = "credentials"
+			schema = '{{"title": "Degree"}}'
+
+			[[namespaces]]
+			name = "records"
+			creator = "alice"
+
+			[[registries]]
+			name = "degrees"
+			namespace = "records"
+			"#
+		);
+		let patch = preload(&config).genesis_patch(1024).unwrap();
+
+		let alice = AccountId::from(array_bytes::hex2array_unchecked::<_, 32>(ALICE));
+		let space = BlakeTwo256::hash(b"credentials");
+		assert_eq!(patch["did"]["dids"].as_array().unwrap().len(), 1);
+		assert_eq!(patch["chainSpace"]["spaces"], serde_json::json!([(space, &alice, 100)]));
+		assert_eq!(patch["schema"]["schemas"][0][0], serde_json::json!(space));
+		assert_eq!(
+			patch["registries"]["registries"],
+			serde_json::json!([(
+				BlakeTwo256::hash(b"degrees"),
+				BlakeTwo256::hash(b"records"),
+				&alice
+			)])
+		);
+	}
+
+	#[test]
+	fn rejects_unknown_references() {
+		let config = format!(
+			r#"
+			[[dids]]
+			name = "alice"
+			authentication_key = "{ALICE}"
+
+			[[spaces]]
+			name = "credentials"
+			creator = "bob"
+			capacity = 100
+			"#
+		);
+		assert!(preload(&config).genesis_patch(1024).unwrap_err().contains("'bob'"));
+
+		let config = r#"
+			[[schemas]]
+			space = "credentials"
+			schema = "{}"
+			"#;
+		assert!(preload(config).genesis_patch(1024).unwrap_err().contains("unknown space"));
+	}
+
+	#[test]
+	fn rejects_invalid_entries() {
+		let config = format!(
+			r#"
+			[[dids]]
+			name = "alice"
+			authentication_key = "{ALICE}"
+
+			[[dids]]
+			name = "bob"
+			authentication_key = "{ALICE}"
+			"#
+		);
+		assert!(preload(&config).genesis_patch(1024).is_err());
+
+		let config = format!(
+			r#"
+			[[dids]]
+			name = "bob"
+			authentication_key = "{BOB}"
+			key_type = "ecdsa"
+			"#
+		);
+		assert!(preload(&config).genesis_patch(1024).is_err());
+
+		let config = format!(
+			r#"
+			[[dids]]
+			name = "bob"
+			authentication_key = "{BOB}"
+
+			[[spaces]]
+			name = "credentials"
+			creator = "bob"
+			capacity = 100
+
+			[[schemas]]
+			space = "credentials"
+			schema = "not json"
+			"#
+		);
+		assert!(preload(&config).genesis_patch(1024).is_err());
+
+		let config = format!(
+			r#"
+			[[dids]]
+			name = "bob"
+			authentication_key = "{BOB}"
+
+			[[spaces]]
+			name = "credentials"
+			creator = "bob"
+			capacity = 100
+
+			[[schemas]]
+			space = "credentials"
+			schema = '{{"title": "Degree"}}'
+
+			[[schemas]]
+			space = "credentials"
+			schema = '{{"title": "Degree"}}'
+			"#
+		);
+		assert!(preload(&config)
+			.genesis_patch(1024)
+			.unwrap_err()
+			.contains("is defined more than once"));
+	}
+}
@@ -19,9 +19,12 @@
 //! CORD CLI library.
 
 #![allow(missing_docs)]
-use crate::command::gen_key::KeySubcommand;
+use crate::command::{
+	chain_spec_cmd::ChainSpecSubcommand, gen_key::KeySubcommand, tx::TxSubcommand,
+};
 
 use clap::Parser;
+use std::str::FromStr;
 
 use crate::chain_setup::BootstrapChainCmd;
 
@@ -38,6 +41,14 @@ pub enum Subcommand {
 	/// Bootstrap a custom configuration
 	BootstrapChain(BootstrapChainCmd),
 
+	/// Check a chain spec or compare two of them
+	#[command(subcommand)]
+	ChainSpec(ChainSpecSubcommand),
+
+	/// Build and sign transactions offline
+	#[command(subcommand)]
+	Tx(TxSubcommand),
+
 	/// Key management cli utilities
 	#[command(subcommand)]
 	Key(KeySubcommand),
@@ -114,7 +125,55 @@ pub struct Cli {
 	#[arg(long)]
 	pub no_hardware_benchmarks: bool,
 
+	/// Seal the blocks on demand instead of running BABE and GRANDPA.
+	///
+	/// `instant` seals a block for each transaction, `manual` only on
+	/// `engine_createBlock` calls, and a number of milliseconds at that
+	/// interval. Blocks sealed by the node are finalized right away. Only
+	/// available on development and local chains.
+	#[arg(long, global = true, value_name = "instant|manual|MILLIS")]
+	pub sealing: Option<Sealing>,
+
+	/// Index the CORD events of the finalized blocks in the offchain database
+	/// and serve them over the `index_*` RPC methods.
+	///
+	/// Blocks whose state is pruned can not be indexed, run with
+	/// `--state-pruning archive` to index the whole chain.
+	#[arg(long)]
+	pub indexer: bool,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub storage_monitor: sc_storage_monitor::StorageMonitorParams,
 }
+
+/// Block sealing of a development node.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Sealing {
+	/// Seal a block as soon as a transaction enters the pool.
+	Instant,
+	/// Seal blocks on `engine_createBlock` calls only.
+	Manual,
+	/// Seal a block every given number of milliseconds.
+	Interval(u64),
+}
+
+impl FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"instant" => Ok(Sealing::Instant),
+			"manual" => Ok(Sealing::Manual),
+			millis => match millis.parse::<u64>() {
+				Ok(0) => Err("the sealing interval must be greater than zero".into()),
+				Ok(millis) => Ok(Sealing::Interval(millis)),
+				Err(_) => Err(format!(
+					"`{}` is not a sealing mode, expected `instant`, `manual` or an interval \
+					 in milliseconds",
+					s
+				)),
+			},
+		}
+	}
+}
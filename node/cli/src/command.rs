@@ -19,7 +19,9 @@
 #![allow(missing_docs)]
 
 pub mod chain_setup;
+pub mod chain_spec_cmd;
 pub mod gen_key;
+pub mod tx;
 
 use crate::{
 	benchmarking::{inherent_benchmark_data, RemarkBuilder, TransferKeepAliveBuilder},
@@ -197,7 +199,10 @@ pub fn run() -> Result<()> {
 		Some(Subcommand::Inspect(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
 
-			runner.sync_run(|config| cmd.run::<Block, RuntimeApi>(config))
+			runner.sync_run(|config| {
+				set_default_ss58_version(&config.chain_spec);
+				cmd.run::<Block, RuntimeApi>(config)
+			})
 		},
 		Some(Subcommand::BuildSpec(cmd)) => {
 			let runner = cli.create_runner(cmd)?;
@@ -205,6 +210,8 @@ pub fn run() -> Result<()> {
 		},
 		Some(Subcommand::Key(cmd)) => cmd.run(&cli),
 		Some(Subcommand::BootstrapChain(cmd)) => cmd.run(),
+		Some(Subcommand::ChainSpec(cmd)) => cmd.run(&cli),
+		Some(Subcommand::Tx(cmd)) => cmd.run(),
 		Some(Subcommand::Sign(cmd)) => cmd.run(),
 		Some(Subcommand::Verify(cmd)) => cmd.run(),
 		Some(Subcommand::Vanity(cmd)) => cmd.run(),
@@ -26,6 +26,7 @@ use serde::Deserialize;
 
 use crate::chain_spec::{
 	bootstrap::{cord_custom_config, ChainParams},
+	preload::GenesisPreload,
 	ChainType,
 };
 
@@ -40,6 +41,9 @@ pub struct ChainConfigParams {
 	pub council_members: Option<Vec<String>>,
 	pub tech_committee_members: Option<Vec<String>>,
 	pub sudo_key: Option<String>,
+	/// DIDs, spaces, schemas, namespaces and registries preloaded at genesis.
+	#[serde(flatten)]
+	pub preload: GenesisPreload,
 }
 
 #[derive(Debug, Args)]
@@ -138,12 +142,11 @@ impl BootstrapChainCmd {
 			council_members: initial_council_members,
 			tech_committee_members: initial_tech_committee_members,
 			sudo_key: initial_sudo_key,
+			preload: config.preload,
 		};
 
-		let chain_spec = match cord_custom_config(chain_params) {
-			Ok(spec) => spec,
-			Err(e) => panic!("Chain spec creation failed: {}", e),
-		};
+		let chain_spec = cord_custom_config(chain_params)
+			.map_err(|e| format!("Chain spec creation failed: {}", e))?;
 
 		let json = sc_service::chain_ops::build_spec(&chain_spec, self.raw)?;
 		if std::io::stdout().write_all(json.as_bytes()).is_err() {
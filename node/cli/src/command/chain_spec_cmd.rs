@@ -0,0 +1,104 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use sc_cli::{clap, Error, SubstrateCli};
+
+use crate::chain_spec::genesis_state::GenesisState;
+
+#[derive(Debug, clap::Subcommand)]
+pub enum ChainSpecSubcommand {
+	/// Sanity-check the genesis state of a chain spec
+	Check(CheckSpecCmd),
+
+	/// Show the differences between the genesis states of two chain specs
+	Diff(DiffSpecCmd),
+}
+
+impl ChainSpecSubcommand {
+	/// Run the command
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
+		match self {
+			ChainSpecSubcommand::Check(cmd) => cmd.run(cli),
+			ChainSpecSubcommand::Diff(cmd) => cmd.run(cli),
+		}
+	}
+}
+
+/// Checks that every authority has all its session keys and is a network
+/// member, that the well-known nodes are valid PeerIds and that the councils
+/// have at least `MinAuthorities` members.
+#[derive(Debug, clap::Args)]
+pub struct CheckSpecCmd {
+	/// Chain spec file, or the id of a built-in chain.
+	#[arg(value_name = "SPEC")]
+	spec: String,
+
+	/// Minimum number of authorities and council members, `MinAuthorities`
+	/// of the runtime by default.
+	#[arg(long)]
+	min_authorities: Option<u32>,
+}
+
+impl CheckSpecCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
+		let spec = cli.load_spec(&self.spec)?;
+		super::set_default_ss58_version(&spec);
+		let state = GenesisState::from_spec(spec.as_ref())?;
+
+		let problems = state.check(self.min_authorities);
+		if problems.is_empty() {
+			println!("No problems found in {}", spec.name());
+			return Ok(());
+		}
+		for problem in &problems {
+			println!("{}", problem);
+		}
+		Err(format!("Found {} problem(s) in {}", problems.len(), spec.name()).into())
+	}
+}
+
+/// Compares the genesis states storage item by storage item. Either side can
+/// be a plain spec, a raw spec or a state export of a live chain.
+#[derive(Debug, clap::Args)]
+pub struct DiffSpecCmd {
+	/// Chain spec file or id to compare from.
+	#[arg(value_name = "OLD")]
+	old: String,
+
+	/// Chain spec file or id to compare to.
+	#[arg(value_name = "NEW")]
+	new: String,
+}
+
+impl DiffSpecCmd {
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
+		let old = cli.load_spec(&self.old)?;
+		let new = cli.load_spec(&self.new)?;
+		super::set_default_ss58_version(&old);
+
+		let changes =
+			GenesisState::from_spec(old.as_ref())?.diff(&GenesisState::from_spec(new.as_ref())?);
+		if changes.is_empty() {
+			println!("No differences");
+		}
+		for change in &changes {
+			println!("{}", change);
+		}
+		Ok(())
+	}
+}
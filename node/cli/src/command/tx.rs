@@ -0,0 +1,617 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Offline construction and signing of extrinsics.
+//!
+//! Every command prints a hex encoded call or extrinsic and never talks to a
+//! node, so the inputs a live node would provide (nonce, tx counter, genesis
+//! hash, era checkpoint) are given on the command line.
+
+use codec::{DecodeAll, Encode};
+use cord_node_inspect::timeline::IdentifierDetails;
+use cord_primitives::{AccountId, Balance, BlockNumber, Hash, Nonce};
+use identifier::{IdentifierType, Ss58Identifier};
+use pallet_did::did_details::DidAuthorizedCallOperation;
+use sc_cli::{clap, utils, with_crypto_scheme, CryptoScheme, Error};
+use sp_core::{crypto::Ss58Codec, Pair};
+use sp_runtime::{
+	generic::{Era, SignedPayload, UncheckedExtrinsic},
+	traits::{IdentifyAccount, SignedExtension},
+	MultiAddress, MultiSignature, MultiSigner,
+};
+
+/// Runs `$code` with `runtime` aliased to the runtime crate selected by
+/// `$runtime`, and `signed_extra` to its signed extension builder.
+macro_rules! with_runtime {
+	($runtime:expr, $code:expr) => {
+		match $runtime {
+			TxRuntime::Braid => {
+				#[cfg(feature = "braid-native")]
+				{
+					#[allow(unused_imports)]
+					use self::braid_signed_extra as signed_extra;
+					use cord_braid_runtime as runtime;
+					$code
+				}
+				#[cfg(not(feature = "braid-native"))]
+				{
+					Err(Error::from("`braid-native` feature not enabled"))
+				}
+			},
+			TxRuntime::Loom => {
+				#[cfg(feature = "loom-native")]
+				{
+					#[allow(unused_imports)]
+					use self::loom_signed_extra as signed_extra;
+					use cord_loom_runtime as runtime;
+					$code
+				}
+				#[cfg(not(feature = "loom-native"))]
+				{
+					Err(Error::from("`loom-native` feature not enabled"))
+				}
+			},
+			TxRuntime::Weave => {
+				#[cfg(feature = "weave-native")]
+				{
+					#[allow(unused_imports)]
+					use self::weave_signed_extra as signed_extra;
+					use cord_weave_runtime as runtime;
+					$code
+				}
+				#[cfg(not(feature = "weave-native"))]
+				{
+					Err(Error::from("`weave-native` feature not enabled"))
+				}
+			},
+		}
+	};
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum TxSubcommand {
+	/// Build a `statement::register` call
+	StatementRegister(StatementRegisterCmd),
+
+	/// Wrap a call into a DID authorised `did::submit_did_call`
+	DidCall(DidCallCmd),
+
+	/// Sign a call into an extrinsic ready for submission
+	Sign(SignTxCmd),
+}
+
+impl TxSubcommand {
+	/// Run the command
+	pub fn run(&self) -> Result<(), Error> {
+		let encoded = match self {
+			TxSubcommand::StatementRegister(cmd) => cmd.run(),
+			TxSubcommand::DidCall(cmd) => cmd.run(),
+			TxSubcommand::Sign(cmd) => cmd.run(),
+		}?;
+		println!("{}", array_bytes::bytes2hex("0x", encoded));
+		Ok(())
+	}
+}
+
+/// The runtime to build the call or extrinsic for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TxRuntime {
+	/// The braid runtime
+	Braid,
+	/// The loom runtime
+	Loom,
+	/// The weave runtime
+	Weave,
+}
+
+/// The key signing a DID operation or an extrinsic.
+#[derive(Debug, clap::Args)]
+pub struct SignerParams {
+	/// The secret key URI.
+	/// If the value is a file, the file content is used as URI.
+	/// If not given, you will be prompted for the URI.
+	#[arg(long)]
+	suri: Option<String>,
+
+	/// The crypto scheme of the key.
+	#[arg(long, value_enum, ignore_case = true, default_value_t = CryptoScheme::Sr25519)]
+	scheme: CryptoScheme,
+}
+
+impl SignerParams {
+	fn signer(&self) -> Result<Signer, Error> {
+		let suri = utils::read_uri(self.suri.as_ref())?;
+		with_crypto_scheme!(self.scheme, signer_from_suri(&suri))
+	}
+}
+
+/// An account able to sign payloads, whatever its crypto scheme.
+struct Signer {
+	account: AccountId,
+	sign: Box<dyn Fn(&[u8]) -> MultiSignature>,
+}
+
+fn signer_from_suri<P>(suri: &str) -> Result<Signer, Error>
+where
+	P: Pair + 'static,
+	MultiSignature: From<P::Signature>,
+	MultiSigner: From<P::Public>,
+{
+	let pair = utils::pair_from_suri::<P>(suri, None)?;
+	Ok(Signer {
+		account: MultiSigner::from(pair.public()).into_account(),
+		sign: Box::new(move |payload| pair.sign(payload).into()),
+	})
+}
+
+/// Builds a `statement::register` call anchoring `digest` in the space the
+/// authorization belongs to.
+#[derive(Debug, clap::Args)]
+pub struct StatementRegisterCmd {
+	/// Runtime to build the call for.
+	#[arg(long, value_enum, ignore_case = true)]
+	runtime: TxRuntime,
+
+	/// Digest of the statement, as a 32 bytes hex string.
+	#[arg(long, value_parser = parse_hash)]
+	digest: Hash,
+
+	/// Space authorization of the statement creator.
+	#[arg(long)]
+	authorization: String,
+
+	/// Schema the statement conforms to.
+	#[arg(long)]
+	schema: Option<String>,
+}
+
+impl StatementRegisterCmd {
+	fn run(&self) -> Result<Vec<u8>, Error> {
+		let authorization = parse_identifier(&self.authorization, IdentifierType::Authorization)?;
+		let schema_id = self
+			.schema
+			.as_deref()
+			.map(|schema| parse_identifier(schema, IdentifierType::Schema))
+			.transpose()?;
+
+		with_runtime!(self.runtime, {
+			let call = runtime::RuntimeCall::Statement(pallet_statement::Call::register {
+				digest: self.digest,
+				authorization,
+				schema_id,
+			});
+			Ok(call.encode())
+		})
+	}
+}
+
+/// Signs a call with the DID assertion key and wraps it into a
+/// `did::submit_did_call` call, to be signed by the submitter.
+#[derive(Debug, clap::Args)]
+pub struct DidCallCmd {
+	/// Runtime to build the call for.
+	#[arg(long, value_enum, ignore_case = true)]
+	runtime: TxRuntime,
+
+	/// Hex encoded call to authorise.
+	#[arg(long)]
+	call: String,
+
+	/// The DID, the account of the signing key by default.
+	#[arg(long, value_parser = parse_account)]
+	did: Option<AccountId>,
+
+	/// Transaction counter of the DID, one more than the last used one.
+	#[arg(long)]
+	tx_counter: u64,
+
+	/// Current block number, the operation expires `MaxBlocksTxValidity`
+	/// blocks later.
+	#[arg(long)]
+	block_number: BlockNumber,
+
+	/// Account allowed to submit the operation.
+	#[arg(long, value_parser = parse_account)]
+	submitter: AccountId,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	signer: SignerParams,
+}
+
+impl DidCallCmd {
+	fn run(&self) -> Result<Vec<u8>, Error> {
+		let call = parse_bytes(&self.call)?;
+		let signer = self.signer.signer()?;
+
+		with_runtime!(self.runtime, {
+			let operation = DidAuthorizedCallOperation {
+				did: self.did.clone().unwrap_or_else(|| signer.account.clone()),
+				tx_counter: self.tx_counter,
+				call: decode_call::<runtime::RuntimeCall>(&call)?,
+				block_number: self.block_number,
+				submitter: self.submitter.clone(),
+			};
+			let signature = (signer.sign)(&operation.encode());
+			let call = runtime::RuntimeCall::Did(pallet_did::Call::submit_did_call {
+				did_call: Box::new(operation),
+				signature: signature.into(),
+			});
+			Ok(call.encode())
+		})
+	}
+}
+
+/// Signs a call into an extrinsic. The extrinsic is immortal unless
+/// `--era-period` is given, in which case the era starts at the given block.
+#[derive(Debug, clap::Args)]
+pub struct SignTxCmd {
+	/// Runtime to build the extrinsic for.
+	#[arg(long, value_enum, ignore_case = true)]
+	runtime: TxRuntime,
+
+	/// Hex encoded call to sign.
+	#[arg(long)]
+	call: String,
+
+	/// Nonce of the signing account.
+	#[arg(long)]
+	nonce: Nonce,
+
+	/// Tip for the block author.
+	#[arg(long, default_value_t = 0)]
+	tip: Balance,
+
+	/// Genesis hash of the chain.
+	#[arg(long, value_parser = parse_hash)]
+	genesis_hash: Hash,
+
+	/// Number of blocks the extrinsic stays valid for.
+	#[arg(long, requires_all = ["block_number", "block_hash"])]
+	era_period: Option<u64>,
+
+	/// Number of the block the era starts at.
+	#[arg(long, requires = "era_period")]
+	block_number: Option<BlockNumber>,
+
+	/// Hash of the block the era starts at.
+	#[arg(long, requires = "era_period", value_parser = parse_hash)]
+	block_hash: Option<Hash>,
+
+	/// Spec version of the runtime, the one of the native runtime by default.
+	#[arg(long)]
+	spec_version: Option<u32>,
+
+	/// Transaction version of the runtime, the one of the native runtime by
+	/// default.
+	#[arg(long)]
+	transaction_version: Option<u32>,
+
+	#[allow(missing_docs)]
+	#[command(flatten)]
+	signer: SignerParams,
+}
+
+/// Inputs of the signed extensions, usually read from a live chain.
+struct ExtraParams {
+	era: Era,
+	checkpoint: Hash,
+	nonce: Nonce,
+	tip: Balance,
+	genesis_hash: Hash,
+	spec_version: Option<u32>,
+	transaction_version: Option<u32>,
+}
+
+impl SignTxCmd {
+	fn run(&self) -> Result<Vec<u8>, Error> {
+		let call = parse_bytes(&self.call)?;
+		let signer = self.signer.signer()?;
+		let (era, checkpoint) = match (self.era_period, self.block_number, self.block_hash) {
+			(Some(period), Some(number), Some(hash)) => (Era::mortal(period, number.into()), hash),
+			_ => (Era::Immortal, self.genesis_hash),
+		};
+		let params = ExtraParams {
+			era,
+			checkpoint,
+			nonce: self.nonce,
+			tip: self.tip,
+			genesis_hash: self.genesis_hash,
+			spec_version: self.spec_version,
+			transaction_version: self.transaction_version,
+		};
+
+		with_runtime!(self.runtime, {
+			let (extra, additional) = signed_extra(&params);
+			Ok(sign_call(decode_call::<runtime::RuntimeCall>(&call)?, extra, additional, &signer))
+		})
+	}
+}
+
+/// Signs `call` with the given signed extensions into an encoded extrinsic.
+fn sign_call<Call, Extra>(
+	call: Call,
+	extra: Extra,
+	additional: Extra::AdditionalSigned,
+	signer: &Signer,
+) -> Vec<u8>
+where
+	Call: Encode,
+	Extra: SignedExtension,
+{
+	let payload = SignedPayload::from_raw(call, extra, additional);
+	let signature = payload.using_encoded(|payload| (signer.sign)(payload));
+	let (call, extra, _) = payload.deconstruct();
+	UncheckedExtrinsic::<MultiAddress<AccountId, ()>, Call, MultiSignature, Extra>::new_signed(
+		call,
+		MultiAddress::Id(signer.account.clone()),
+		signature,
+		extra,
+	)
+	.encode()
+}
+
+#[cfg(feature = "braid-native")]
+fn braid_signed_extra(
+	params: &ExtraParams,
+) -> (
+	cord_braid_runtime::SignedExtra,
+	<cord_braid_runtime::SignedExtra as SignedExtension>::AdditionalSigned,
+) {
+	use cord_braid_runtime as runtime;
+
+	(
+		(
+			pallet_network_membership::CheckNetworkMembership::new(),
+			frame_system::CheckNonZeroSender::new(),
+			frame_system::CheckSpecVersion::new(),
+			frame_system::CheckTxVersion::new(),
+			frame_system::CheckGenesis::new(),
+			frame_system::CheckMortality::from(params.era),
+			frame_system::CheckNonce::from(params.nonce),
+			frame_system::CheckWeight::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::from(params.tip),
+		),
+		(
+			(),
+			(),
+			params.spec_version.unwrap_or(runtime::VERSION.spec_version),
+			params.transaction_version.unwrap_or(runtime::VERSION.transaction_version),
+			params.genesis_hash,
+			params.checkpoint,
+			(),
+			(),
+			(),
+		),
+	)
+}
+
+#[cfg(feature = "loom-native")]
+fn loom_signed_extra(
+	params: &ExtraParams,
+) -> (
+	cord_loom_runtime::SignedExtra,
+	<cord_loom_runtime::SignedExtra as SignedExtension>::AdditionalSigned,
+) {
+	use cord_loom_runtime as runtime;
+
+	(
+		(
+			pallet_network_membership::CheckNetworkMembership::new(),
+			frame_system::CheckNonZeroSender::new(),
+			frame_system::CheckSpecVersion::new(),
+			frame_system::CheckTxVersion::new(),
+			frame_system::CheckGenesis::new(),
+			frame_system::CheckMortality::from(params.era),
+			frame_system::CheckNonce::from(params.nonce),
+			frame_system::CheckWeight::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::from(params.tip),
+		),
+		(
+			(),
+			(),
+			params.spec_version.unwrap_or(runtime::VERSION.spec_version),
+			params.transaction_version.unwrap_or(runtime::VERSION.transaction_version),
+			params.genesis_hash,
+			params.checkpoint,
+			(),
+			(),
+			(),
+		),
+	)
+}
+
+/// Weave does not check the network membership of the signer.
+#[cfg(feature = "weave-native")]
+fn weave_signed_extra(
+	params: &ExtraParams,
+) -> (
+	cord_weave_runtime::SignedExtra,
+	<cord_weave_runtime::SignedExtra as SignedExtension>::AdditionalSigned,
+) {
+	use cord_weave_runtime as runtime;
+
+	(
+		(
+			frame_system::CheckNonZeroSender::new(),
+			frame_system::CheckSpecVersion::new(),
+			frame_system::CheckTxVersion::new(),
+			frame_system::CheckGenesis::new(),
+			frame_system::CheckMortality::from(params.era),
+			frame_system::CheckNonce::from(params.nonce),
+			frame_system::CheckWeight::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::from(params.tip),
+		),
+		(
+			(),
+			params.spec_version.unwrap_or(runtime::VERSION.spec_version),
+			params.transaction_version.unwrap_or(runtime::VERSION.transaction_version),
+			params.genesis_hash,
+			params.checkpoint,
+			(),
+			(),
+			(),
+		),
+	)
+}
+
+fn decode_call<Call: DecodeAll>(call: &[u8]) -> Result<Call, Error> {
+	Call::decode_all(&mut &call[..])
+		.map_err(|e| format!("Call is not valid for the runtime: {}", e).into())
+}
+
+/// Parses an identifier, checking its checksum and its type.
+fn parse_identifier(input: &str, kind: IdentifierType) -> Result<Ss58Identifier, Error> {
+	let details = IdentifierDetails::decode(input)?;
+	if !details.checksum_valid {
+		return Err(format!("Invalid checksum in identifier {}", input).into());
+	}
+	if details.kind != Some(kind.clone()) {
+		return Err(format!("{} is not a {:?} identifier", input, kind).into());
+	}
+	Ok(details.identifier)
+}
+
+fn parse_bytes(input: &str) -> Result<Vec<u8>, Error> {
+	array_bytes::hex2bytes(input).map_err(|e| format!("Invalid hex: {:?}", e).into())
+}
+
+fn parse_hash(input: &str) -> Result<Hash, String> {
+	array_bytes::hex_n_into::<_, Hash, 32>(input).map_err(|e| format!("Invalid hash: {:?}", e))
+}
+
+fn parse_account(input: &str) -> Result<AccountId, String> {
+	AccountId::from_ss58check(input).map_err(|e| format!("Invalid account: {:?}", e))
+}
+
+#[cfg(all(test, feature = "loom-native"))]
+mod tests {
+	use super::*;
+	use cord_loom_runtime as runtime;
+	use pallet_did::did_details::DidVerificationKey;
+	use sp_core::{ecdsa, ed25519, sr25519};
+	use sp_runtime::traits::Verify;
+
+	const SURI: &str = "//Alice";
+
+	fn schemes() -> Vec<(CryptoScheme, DidVerificationKey<AccountId>, AccountId)> {
+		let sr25519 = sr25519::Pair::from_string(SURI, None).unwrap().public();
+		let ed25519 = ed25519::Pair::from_string(SURI, None).unwrap().public();
+		let ecdsa = ecdsa::Pair::from_string(SURI, None).unwrap().public();
+		vec![
+			(
+				CryptoScheme::Sr25519,
+				DidVerificationKey::Sr25519(sr25519),
+				MultiSigner::from(sr25519).into_account(),
+			),
+			(
+				CryptoScheme::Ed25519,
+				DidVerificationKey::Ed25519(ed25519),
+				MultiSigner::from(ed25519).into_account(),
+			),
+			(
+				CryptoScheme::Ecdsa,
+				DidVerificationKey::Ecdsa(ecdsa),
+				MultiSigner::from(ecdsa).into_account(),
+			),
+		]
+	}
+
+	fn signer_params(scheme: CryptoScheme) -> SignerParams {
+		SignerParams { suri: Some(SURI.into()), scheme }
+	}
+
+	fn remark() -> runtime::RuntimeCall {
+		runtime::RuntimeCall::System(frame_system::Call::remark { remark: vec![1, 2, 3] })
+	}
+
+	#[test]
+	fn signed_extrinsics_verify_against_their_payload() {
+		let genesis_hash = Hash::repeat_byte(1);
+		for (scheme, _, account) in schemes() {
+			let cmd = SignTxCmd {
+				runtime: TxRuntime::Loom,
+				call: array_bytes::bytes2hex("0x", remark().encode()),
+				nonce: 7,
+				tip: 0,
+				genesis_hash,
+				era_period: None,
+				block_number: None,
+				block_hash: None,
+				spec_version: None,
+				transaction_version: None,
+				signer: signer_params(scheme),
+			};
+			let encoded = cmd.run().unwrap();
+
+			let xt = runtime::UncheckedExtrinsic::decode_all(&mut &encoded[..]).unwrap();
+			let (address, signature, extra) = xt.signature.unwrap();
+			assert_eq!(address, MultiAddress::Id(account.clone()));
+
+			let params = ExtraParams {
+				era: Era::Immortal,
+				checkpoint: genesis_hash,
+				nonce: 7,
+				tip: 0,
+				genesis_hash,
+				spec_version: None,
+				transaction_version: None,
+			};
+			let (_, additional) = loom_signed_extra(&params);
+			let payload = SignedPayload::from_raw(xt.function, extra, additional);
+			assert!(
+				payload.using_encoded(|payload| signature.verify(payload, &account)),
+				"{:?} signature does not verify",
+				scheme
+			);
+		}
+	}
+
+	#[test]
+	fn did_calls_verify_against_the_operation() {
+		let submitter = AccountId::new([2; 32]);
+		for (scheme, key, account) in schemes() {
+			let cmd = DidCallCmd {
+				runtime: TxRuntime::Loom,
+				call: array_bytes::bytes2hex("0x", remark().encode()),
+				did: None,
+				tx_counter: 1,
+				block_number: 5,
+				submitter: submitter.clone(),
+				signer: signer_params(scheme),
+			};
+			let encoded = cmd.run().unwrap();
+
+			let runtime::RuntimeCall::Did(pallet_did::Call::submit_did_call {
+				did_call,
+				signature,
+			}) = runtime::RuntimeCall::decode_all(&mut &encoded[..]).unwrap()
+			else {
+				panic!("not a DID call");
+			};
+			assert_eq!(did_call.did, account);
+			assert_eq!(did_call.submitter, submitter);
+			assert_eq!(did_call.call, remark());
+			assert!(
+				key.verify_signature(&did_call.encode(), &signature).is_ok(),
+				"{:?} signature does not verify",
+				scheme
+			);
+		}
+	}
+}
@@ -22,6 +22,7 @@
 //! the native runtimes.
 #![allow(missing_docs)]
 
+use authority_membership_runtime_api::{AuthorityHealth, OffenceReport};
 use cord_primitives::{AccountId, Balance, Block, Nonce};
 use pallet_transaction_payment::{FeeDetails, RuntimeDispatchInfo};
 pub use sp_authority_discovery::AuthorityId as AuthorityDiscoveryId;
@@ -213,6 +214,18 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl authority_membership_runtime_api::AuthorityMembershipApi<Block, AccountId> for Runtime {
+		fn authority(_: AccountId) -> Option<AuthorityHealth<AccountId>> {
+			unimplemented!()
+		}
+		fn authorities() -> Vec<AuthorityHealth<AccountId>> {
+			unimplemented!()
+		}
+		fn offence_reports(_: AccountId) -> Vec<OffenceReport<AccountId>> {
+			unimplemented!()
+		}
+	}
+
 	impl crate::fake_runtime_api::GetLastTimestamp<Block> for Runtime {
 		fn get_last_timestamp() -> u64 {
 			unimplemented!()
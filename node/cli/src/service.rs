@@ -21,7 +21,7 @@
 #![allow(missing_docs)]
 #![deny(unused_results)]
 
-use crate::cli::Cli;
+use crate::cli::{Cli, Sealing};
 
 #[cfg(feature = "full-node")]
 use {
@@ -269,6 +269,44 @@ type FullGrandpaBlockImport =
 /// The transaction pool type defintion.
 pub type TransactionPool = sc_transaction_pool::FullPool<Block, FullClient>;
 
+#[cfg(feature = "full-node")]
+type Indexer = cord_node_indexer::Indexer<<FullBackend as BackendT<Block>>::OffchainStorage>;
+
+/// Creates the indexer of the CORD events, keeping its records in the
+/// offchain database.
+#[cfg(feature = "full-node")]
+fn new_indexer(backend: &FullBackend) -> Result<Indexer, ServiceError> {
+	let storage = backend
+		.offchain_storage()
+		.ok_or_else(|| ServiceError::Other("The indexer requires the offchain database".into()))?;
+	Ok(Indexer::new(storage))
+}
+
+/// Adds the `index_*` methods of the indexer, if any, to the RPC extensions
+/// built by `rpc_builder`.
+#[cfg(feature = "full-node")]
+fn with_index_rpc(
+	rpc_builder: impl Fn(
+		cord_node_rpc::DenyUnsafe,
+		cord_node_rpc::SubscriptionTaskExecutor,
+	) -> Result<cord_node_rpc::RpcExtension, sc_service::Error>,
+	indexer: Option<Indexer>,
+) -> impl Fn(
+	cord_node_rpc::DenyUnsafe,
+	cord_node_rpc::SubscriptionTaskExecutor,
+) -> Result<cord_node_rpc::RpcExtension, sc_service::Error> {
+	use cord_node_rpc::index::{Index, IndexApiServer};
+
+	move |deny_unsafe, subscription_executor| {
+		let mut io = rpc_builder(deny_unsafe, subscription_executor.clone())?;
+		if let Some(indexer) = &indexer {
+			io.merge(Index::new(indexer.clone(), subscription_executor).into_rpc())
+				.map_err(|e| ServiceError::Application(e.into()))?;
+		}
+		Ok(io)
+	}
+}
+
 /// Creates PartialComponents for a node.
 /// Enables chain operations for cases when full node is unnecessary.
 #[cfg(feature = "full-node")]
@@ -468,6 +506,7 @@ pub struct NewFullBase {
 pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	config: Configuration,
 	disable_hardware_benchmarks: bool,
+	enable_indexer: bool,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -492,6 +531,7 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 	let enable_offchain_worker = config.offchain_worker.enabled;
+	let enable_offchain_indexing = config.offchain_worker.indexing_enabled;
 
 	let hwbench = (!disable_hardware_benchmarks)
 		.then_some(config.database.path().map(|database_path| {
@@ -511,6 +551,9 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 		other: (rpc_builder, import_setup, rpc_setup, mut telemetry),
 	} = new_partial(&config)?;
 
+	let indexer = if enable_indexer { Some(new_indexer(&backend)?) } else { None };
+	let rpc_builder = with_index_rpc(rpc_builder, indexer.clone());
+
 	let metrics = N::register_notification_metrics(
 		config.prometheus_config.as_ref().map(|cfg| &cfg.registry),
 	);
@@ -746,6 +789,22 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 		);
 	}
 
+	if let Some(indexer) = indexer {
+		task_manager.spawn_handle().spawn_blocking(
+			"cord-indexer",
+			"indexer",
+			indexer.run(client.clone()),
+		);
+	}
+
+	if let Some(storage) = backend.offchain_storage().filter(|_| enable_offchain_indexing) {
+		task_manager.spawn_handle().spawn_blocking(
+			"cord-timeline-indexer",
+			"indexer",
+			cord_node_indexer::TimelineIndexer::new(storage).run(client.clone()),
+		);
+	}
+
 	network_starter.start_network();
 	Ok(NewFullBase {
 		task_manager,
@@ -757,6 +816,239 @@ pub fn new_full_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
 	})
 }
 
+/// Creates a development service sealing its blocks on demand.
+///
+/// BABE and GRANDPA are replaced by a manual seal task, which is driven by
+/// the `engine_createBlock` and `engine_finalizeBlock` RPC calls and, with
+/// instant or interval sealing, by the transaction pool or a timer. The
+/// blocks still carry the BABE pre-digests the runtimes expect.
+#[cfg(feature = "full-node")]
+pub fn new_manual_seal_base<N: NetworkBackend<Block, <Block as BlockT>::Hash>>(
+	config: Configuration,
+	sealing: Sealing,
+	enable_indexer: bool,
+) -> Result<TaskManager, ServiceError> {
+	use sc_consensus_manual_seal::{
+		consensus::{babe::BabeConsensusDataProvider, timestamp::SlotTimestampProvider},
+		EngineCommand, ManualSealParams,
+	};
+
+	let role = config.role.clone();
+	let prometheus_registry = config.prometheus_registry().cloned();
+	let enable_offchain_worker = config.offchain_worker.enabled;
+	let enable_offchain_indexing = config.offchain_worker.indexing_enabled;
+
+	let heap_pages = config
+		.default_heap_pages
+		.map_or(DEFAULT_HEAP_ALLOC_STRATEGY, |h| HeapAllocStrategy::Static { extra_pages: h as _ });
+
+	let executor = WasmExecutor::builder()
+		.with_execution_method(config.wasm_method)
+		.with_onchain_heap_alloc_strategy(heap_pages)
+		.with_offchain_heap_alloc_strategy(heap_pages)
+		.with_max_runtime_instances(config.max_runtime_instances)
+		.with_runtime_cache_size(config.runtime_cache_size)
+		.build();
+
+	let (client, backend, keystore_container, mut task_manager) =
+		sc_service::new_full_parts::<Block, RuntimeApi, _>(&config, None, executor)?;
+	let client = Arc::new(client);
+
+	let select_chain = sc_consensus::LongestChain::new(backend.clone());
+
+	let transaction_pool = sc_transaction_pool::BasicPool::new_full(
+		config.transaction_pool.clone(),
+		config.role.is_authority().into(),
+		config.prometheus_registry(),
+		task_manager.spawn_essential_handle(),
+		client.clone(),
+	);
+
+	// The BABE block import keeps track of the epochs, for the pre-digests of
+	// the sealed blocks.
+	let babe_config = sc_consensus_babe::configuration(&*client)?;
+	let (block_import, babe_link) =
+		sc_consensus_babe::block_import(babe_config, client.clone(), client.clone())?;
+
+	let import_queue = sc_consensus_manual_seal::import_queue(
+		Box::new(block_import.clone()),
+		&task_manager.spawn_essential_handle(),
+		config.prometheus_registry(),
+	);
+
+	let net_config = sc_network::config::FullNetworkConfiguration::<_, _, N>::new(&config.network);
+	let metrics = N::register_notification_metrics(
+		config.prometheus_config.as_ref().map(|cfg| &cfg.registry),
+	);
+
+	let (network, system_rpc_tx, tx_handler_controller, network_starter, sync_service) =
+		sc_service::build_network(sc_service::BuildNetworkParams {
+			config: &config,
+			net_config,
+			client: client.clone(),
+			transaction_pool: transaction_pool.clone(),
+			spawn_handle: task_manager.spawn_handle(),
+			import_queue,
+			block_announce_validator_builder: None,
+			warp_sync_params: None,
+			block_relay: None,
+			metrics,
+		})?;
+
+	let (command_sink, rpc_commands) = futures::channel::mpsc::channel(1024);
+
+	let rpc_builder = {
+		let client = client.clone();
+		let pool = transaction_pool.clone();
+		let chain_spec = config.chain_spec.cloned_box();
+		let backend = backend.clone();
+
+		move |deny_unsafe, _| {
+			let deps = cord_node_rpc::ManualSealDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				chain_spec: chain_spec.cloned_box(),
+				deny_unsafe,
+				backend: backend.clone(),
+				command_sink: command_sink.clone(),
+			};
+
+			cord_node_rpc::create_manual_seal(deps).map_err(Into::into)
+		}
+	};
+	let indexer = if enable_indexer { Some(new_indexer(&backend)?) } else { None };
+	let rpc_builder = with_index_rpc(rpc_builder, indexer.clone());
+
+	let _rpc_handlers = sc_service::spawn_tasks(sc_service::SpawnTasksParams {
+		config,
+		backend: backend.clone(),
+		client: client.clone(),
+		keystore: keystore_container.keystore(),
+		network: network.clone(),
+		rpc_builder: Box::new(rpc_builder),
+		transaction_pool: transaction_pool.clone(),
+		task_manager: &mut task_manager,
+		system_rpc_tx,
+		tx_handler_controller,
+		sync_service,
+		telemetry: None,
+	})?;
+
+	let seal_new_block = |create_empty| EngineCommand::SealNewBlock {
+		create_empty,
+		finalize: true,
+		parent_hash: None,
+		sender: None,
+	};
+	let commands_stream: futures::stream::BoxStream<'static, EngineCommand<_>> = match sealing {
+		Sealing::Manual => rpc_commands.boxed(),
+		Sealing::Instant => futures::stream::select(
+			rpc_commands,
+			transaction_pool
+				.import_notification_stream()
+				.map(move |_| seal_new_block(false)),
+		)
+		.boxed(),
+		Sealing::Interval(millis) => futures::stream::select(
+			rpc_commands,
+			futures::stream::unfold((), move |()| async move {
+				futures_timer::Delay::new(std::time::Duration::from_millis(millis)).await;
+				Some((seal_new_block(true), ()))
+			}),
+		)
+		.boxed(),
+	};
+
+	let consensus_data_provider = BabeConsensusDataProvider::new(
+		client.clone(),
+		keystore_container.keystore(),
+		babe_link.epoch_changes().clone(),
+		babe_link.config().authorities.clone(),
+	)
+	.map_err(|e| ServiceError::Other(e.to_string()))?;
+
+	let proposer = sc_basic_authorship::ProposerFactory::new(
+		task_manager.spawn_handle(),
+		client.clone(),
+		transaction_pool.clone(),
+		prometheus_registry.as_ref(),
+		None,
+	);
+
+	let client_clone = client.clone();
+	let manual_seal = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+		block_import,
+		env: proposer,
+		client: client.clone(),
+		pool: transaction_pool.clone(),
+		commands_stream,
+		select_chain,
+		consensus_data_provider: Some(Box::new(consensus_data_provider)),
+		create_inherent_data_providers: move |parent, ()| {
+			let client_clone = client_clone.clone();
+			async move {
+				let timestamp = SlotTimestampProvider::new_babe(client_clone.clone())?;
+
+				let slot =
+					sp_consensus_babe::inherents::InherentDataProvider::new(timestamp.slot());
+
+				let storage_proof = sp_transaction_storage_proof::registration::new_data_provider(
+					&*client_clone,
+					&parent,
+				)?;
+
+				Ok((slot, timestamp, storage_proof))
+			}
+		},
+	});
+
+	task_manager.spawn_essential_handle().spawn_blocking(
+		"manual-seal",
+		Some("block-authoring"),
+		manual_seal,
+	);
+
+	if enable_offchain_worker {
+		task_manager.spawn_handle().spawn(
+			"offchain-workers-runner",
+			"offchain-work",
+			sc_offchain::OffchainWorkers::new(sc_offchain::OffchainWorkerOptions {
+				runtime_api_provider: client.clone(),
+				keystore: Some(keystore_container.keystore()),
+				offchain_db: backend.offchain_storage(),
+				transaction_pool: Some(OffchainTransactionPoolFactory::new(
+					transaction_pool.clone(),
+				)),
+				network_provider: Arc::new(network.clone()),
+				is_validator: role.is_authority(),
+				enable_http_requests: true,
+				custom_extensions: move |_| vec![],
+			})
+			.run(client.clone(), task_manager.spawn_handle())
+			.boxed(),
+		);
+	}
+
+	if let Some(indexer) = indexer {
+		task_manager.spawn_handle().spawn_blocking(
+			"cord-indexer",
+			"indexer",
+			indexer.run(client.clone()),
+		);
+	}
+
+	if let Some(storage) = backend.offchain_storage().filter(|_| enable_offchain_indexing) {
+		task_manager.spawn_handle().spawn_blocking(
+			"cord-timeline-indexer",
+			"indexer",
+			cord_node_indexer::TimelineIndexer::new(storage).run(client.clone()),
+		);
+	}
+
+	network_starter.start_network();
+	Ok(task_manager)
+}
+
 #[cfg(feature = "full-node")]
 pub trait RuntimeConfig {
 	fn new_full(&self, config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError>;
@@ -773,6 +1065,7 @@ impl RuntimeConfig for BraidRuntime {
 				let task_manager = new_full_base::<sc_network::NetworkWorker<_, _>>(
 					config,
 					cli.no_hardware_benchmarks,
+					cli.indexer,
 					|_, _| (),
 				)
 				.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -782,6 +1075,7 @@ impl RuntimeConfig for BraidRuntime {
 				let task_manager = new_full_base::<sc_network::Litep2pNetworkBackend>(
 					config,
 					cli.no_hardware_benchmarks,
+					cli.indexer,
 					|_, _| (),
 				)
 				.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -813,6 +1107,7 @@ impl RuntimeConfig for LoomRuntime {
 				let task_manager = new_full_base::<sc_network::NetworkWorker<_, _>>(
 					config,
 					cli.no_hardware_benchmarks,
+					cli.indexer,
 					|_, _| (),
 				)
 				.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -822,6 +1117,7 @@ impl RuntimeConfig for LoomRuntime {
 				let task_manager = new_full_base::<sc_network::Litep2pNetworkBackend>(
 					config,
 					cli.no_hardware_benchmarks,
+					cli.indexer,
 					|_, _| (),
 				)
 				.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -853,6 +1149,7 @@ impl RuntimeConfig for WeaveRuntime {
 				let task_manager = new_full_base::<sc_network::NetworkWorker<_, _>>(
 					config,
 					cli.no_hardware_benchmarks,
+					cli.indexer,
 					|_, _| (),
 				)
 				.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -862,6 +1159,7 @@ impl RuntimeConfig for WeaveRuntime {
 				let task_manager = new_full_base::<sc_network::Litep2pNetworkBackend>(
 					config,
 					cli.no_hardware_benchmarks,
+					cli.indexer,
 					|_, _| (),
 				)
 				.map(|NewFullBase { task_manager, .. }| task_manager)?;
@@ -895,6 +1193,26 @@ pub fn select_runtime(config: &Configuration) -> Box<dyn RuntimeConfig> {
 }
 
 pub fn new_full(config: Configuration, cli: Cli) -> Result<TaskManager, ServiceError> {
+	if let Some(sealing) = cli.sealing {
+		if !matches!(
+			config.chain_spec.chain_type(),
+			sc_service::ChainType::Development | sc_service::ChainType::Local
+		) {
+			return Err(ServiceError::Other(
+				"Sealing on demand is only available on development and local chains".into(),
+			));
+		}
+		log::info!("Sealing blocks on demand ({:?}), BABE and GRANDPA are disabled", sealing);
+		return match config.network.network_backend {
+			sc_network::config::NetworkBackendType::Libp2p => new_manual_seal_base::<
+				sc_network::NetworkWorker<_, _>,
+			>(config, sealing, cli.indexer),
+			sc_network::config::NetworkBackendType::Litep2p => new_manual_seal_base::<
+				sc_network::Litep2pNetworkBackend,
+			>(config, sealing, cli.indexer),
+		};
+	}
+
 	let runtime = select_runtime(&config);
 	runtime.new_full(config, cli)
 }
@@ -37,4 +37,15 @@ async fn inspect_works() {
 		.status()
 		.unwrap();
 	assert!(status.success());
+
+	let output = Command::new(cargo_bin("cord"))
+		.args(["inspect", "--dev", "--output", "json", "-d"])
+		.arg(base_path.path())
+		.args(["block", "1"])
+		.output()
+		.unwrap();
+	assert!(output.status.success());
+	let block: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+	assert_eq!(block["number"], 1);
+	assert_eq!(block["extrinsics"][0]["call"]["pallet"], "Timestamp");
 }
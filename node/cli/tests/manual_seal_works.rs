@@ -0,0 +1,135 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(unix)]
+use assert_cmd::cargo::cargo_bin;
+use codec::Encode;
+use cord_loom_runtime::{CheckedExtrinsic, RuntimeCall, VERSION};
+use cord_node_testing::keyring::{alice, sign, signed_extra};
+use std::{
+	process::{self, Command},
+	time::Duration,
+};
+use substrate_rpc_client::{rpc_params, ws_client, ClientT};
+use tempfile::tempdir;
+
+use cord_cli_test_utils as common;
+
+fn start_sealing_node(
+	base_path: &std::path::Path,
+	runtime: &str,
+	sealing: &str,
+) -> common::KillChildOnDrop {
+	common::KillChildOnDrop(
+		Command::new(cargo_bin("cord"))
+			.stdout(process::Stdio::piped())
+			.stderr(process::Stdio::piped())
+			.args([runtime, "--dev", "--sealing", sealing, "-d"])
+			.arg(base_path)
+			.arg("--no-hardware-benchmarks")
+			.spawn()
+			.unwrap(),
+	)
+}
+
+#[tokio::test]
+async fn interval_sealing_finalizes_blocks() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let base_path = tempdir().expect("could not create a temp dir");
+		let mut node = start_sealing_node(base_path.path(), "braid", "500");
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+
+		common::wait_n_finalized_blocks(3, &ws_url).await;
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
+
+#[tokio::test]
+async fn manual_sealing_creates_blocks_on_request() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let base_path = tempdir().expect("could not create a temp dir");
+		let mut node = start_sealing_node(base_path.path(), "braid", "manual");
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+		let rpc = ws_client(&ws_url).await.unwrap();
+
+		assert!(common::block_hash(1, &ws_url).await.is_err());
+
+		for _ in 0..3 {
+			let _: serde_json::Value = rpc
+				.request("engine_createBlock", rpc_params![true, true, None::<()>])
+				.await
+				.expect("the block is sealed");
+		}
+
+		assert!(common::block_hash(3, &ws_url).await.is_ok());
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
+
+#[tokio::test]
+async fn instant_sealing_seals_submitted_transactions() {
+	common::run_with_timeout(Duration::from_secs(60 * 10), async move {
+		let base_path = tempdir().expect("could not create a temp dir");
+		let mut node = start_sealing_node(base_path.path(), "loom", "instant");
+
+		let stderr = node.stderr.take().unwrap();
+		let ws_url = common::extract_info_from_output(stderr).0.ws_url;
+		let rpc = ws_client(&ws_url).await.unwrap();
+
+		// Nothing is sealed until a transaction is submitted.
+		assert!(common::block_hash(1, &ws_url).await.is_err());
+
+		let genesis_hash = common::block_hash(0, &ws_url).await.unwrap();
+		let xt = sign(
+			CheckedExtrinsic {
+				signed: Some((alice(), signed_extra(0, 0))),
+				function: RuntimeCall::System(frame_system::Call::remark { remark: vec![1] }),
+			},
+			VERSION.spec_version,
+			VERSION.transaction_version,
+			genesis_hash.into(),
+		);
+		let xt = sp_core::Bytes(xt.encode());
+		let _: serde_json::Value = rpc
+			.request("author_submitExtrinsic", rpc_params![xt.clone()])
+			.await
+			.expect("the transaction is accepted");
+
+		common::wait_n_finalized_blocks(1, &ws_url).await;
+
+		let block_hash = common::block_hash(1, &ws_url).await.unwrap();
+		let block: serde_json::Value =
+			rpc.request("chain_getBlock", rpc_params![block_hash]).await.unwrap();
+		let extrinsics = block["block"]["extrinsics"].as_array().unwrap();
+		assert!(extrinsics.contains(&serde_json::to_value(&xt).unwrap()));
+
+		node.assert_still_running();
+		node.stop();
+	})
+	.await;
+}
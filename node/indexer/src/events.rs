@@ -0,0 +1,246 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Decoding of the CORD events of a block into records.
+
+use crate::{EventRecord, IndexStore};
+use codec::Encode;
+use cord_node_inspect::{
+	decode::{simplify_identifiers, ValueDecoder},
+	read_events, ChainAccess, MetadataCache,
+};
+use cord_primitives::{Block, BlockNumber, Hash};
+use frame_metadata::v15::{RuntimeMetadataV15, StorageEntryType, StorageHasher};
+use serde_json::{Map, Value};
+use sp_core::{
+	blake2_128,
+	crypto::{AccountId32, Ss58Codec},
+	offchain::OffchainStorage,
+	twox_128,
+};
+use sp_runtime::traits::Header;
+use std::collections::BTreeMap;
+
+/// The pallets whose events are indexed.
+const INDEXED_PALLETS: &[&str] = &[
+	"Asset",
+	"ChainSpace",
+	"Did",
+	"DidName",
+	"Entries",
+	"NameSpace",
+	"NetworkScore",
+	"Registries",
+	"Schema",
+	"SchemaAccounts",
+	"Statement",
+];
+
+/// Where the space of an identifier is kept, by identifier type: the pallet,
+/// the map keyed by the identifier and the field of its value.
+const SPACE_SOURCES: &[(&str, (&str, &str, &str))] = &[
+	("Statement", ("Statement", "Statements", "space")),
+	("Rating", ("NetworkScore", "RatingEntries", "space")),
+	("Authorization", ("ChainSpace", "Authorizations", "space_id")),
+	("Schema", ("Schema", "Schemas", "space")),
+];
+
+/// The records of the CORD events of block `number`, numbered from
+/// `next_id`, along with the spaces found for their identifiers.
+pub(crate) fn block_records<S: OffchainStorage>(
+	chain: &dyn ChainAccess<Block>,
+	cache: &MetadataCache,
+	store: &IndexStore<S>,
+	number: BlockNumber,
+	next_id: u64,
+) -> Result<(Vec<EventRecord>, Vec<(String, String)>), String> {
+	let hash = chain
+		.hash(number)
+		.map_err(|e| e.to_string())?
+		.ok_or_else(|| format!("Block #{number} is not known"))?;
+	let header = chain
+		.header(hash)
+		.map_err(|e| e.to_string())?
+		.ok_or_else(|| format!("Header of block #{number} is not known"))?;
+	// The block is executed with the runtime of its parent.
+	let code_at = if number > 0 { *header.parent_hash() } else { hash };
+	let metadata = cache.get(chain, code_at)?;
+	let spaces = SpaceLookup { chain, metadata: &metadata, store, at: [hash, code_at] };
+
+	let mut records = Vec::new();
+	let mut found = BTreeMap::new();
+	for (event_index, event) in read_events(chain, &metadata, hash)?.into_iter().enumerate() {
+		let Some((pallet, name, fields)) = split_event(&event["event"]) else { continue };
+		if !INDEXED_PALLETS.contains(&pallet.as_str()) {
+			continue;
+		}
+
+		let mut identifiers = Vec::new();
+		let mut accounts = Vec::new();
+		collect(&fields, &mut identifiers, &mut accounts);
+
+		let space = identifiers.iter().find_map(|(identifier, kind)| {
+			if kind == "Space" {
+				return Some(identifier.clone());
+			}
+			let space = found.get(identifier).cloned().or_else(|| spaces.find(identifier, kind))?;
+			found.insert(identifier.clone(), space.clone());
+			Some(space)
+		});
+
+		records.push(EventRecord {
+			id: next_id + records.len() as u64,
+			block_number: number,
+			block_hash: hash,
+			extrinsic_index: event["phase"]["ApplyExtrinsic"].as_u64().map(|index| index as u32),
+			event_index: event_index as u32,
+			pallet,
+			event: name,
+			fields: simplify_identifiers(fields),
+			identifiers: identifiers.into_iter().map(|(identifier, _)| identifier).collect(),
+			space,
+			accounts,
+		});
+	}
+
+	let found = found.into_iter().filter(|(identifier, _)| store.space_of(identifier).is_none());
+	Ok((records, found.collect()))
+}
+
+/// The pallet, name and fields of a decoded event.
+fn split_event(event: &Value) -> Option<(String, String, Value)> {
+	let (pallet, inner) = event.as_object()?.iter().next()?;
+	match inner {
+		Value::String(name) => Some((pallet.clone(), name.clone(), Value::Null)),
+		Value::Object(map) if map.len() == 1 => {
+			let (name, fields) = map.iter().next()?;
+			Some((pallet.clone(), name.clone(), fields.clone()))
+		},
+		_ => None,
+	}
+}
+
+/// Collect the identifiers, with their type, and the accounts found in a
+/// decoded value, without duplicates.
+fn collect(value: &Value, identifiers: &mut Vec<(String, String)>, accounts: &mut Vec<String>) {
+	match value {
+		Value::Object(map) => match identifier(map) {
+			Some(found) =>
+				if !identifiers.contains(&found) {
+					identifiers.push(found);
+				},
+			None => map.values().for_each(|value| collect(value, identifiers, accounts)),
+		},
+		Value::Array(items) => items.iter().for_each(|value| collect(value, identifiers, accounts)),
+		Value::String(text) =>
+			if AccountId32::from_ss58check(text).is_ok() && !accounts.contains(text) {
+				accounts.push(text.clone());
+			},
+		_ => {},
+	}
+}
+
+/// An identifier rendered by the decoder, along with its type.
+fn identifier(map: &Map<String, Value>) -> Option<(String, String)> {
+	if map.len() != 2 {
+		return None;
+	}
+	let identifier = map.get("identifier")?.as_str()?;
+	let kind = map.get("type")?.as_str().unwrap_or_default();
+	Some((identifier.into(), kind.into()))
+}
+
+/// Finds the space of an identifier in the state.
+struct SpaceLookup<'a, S> {
+	chain: &'a dyn ChainAccess<Block>,
+	metadata: &'a RuntimeMetadataV15,
+	store: &'a IndexStore<S>,
+	/// The block, then its parent for the entries the block removed.
+	at: [Hash; 2],
+}
+
+impl<'a, S: OffchainStorage> SpaceLookup<'a, S> {
+	fn find(&self, identifier: &str, kind: &str) -> Option<String> {
+		if let Some(space) = self.store.space_of(identifier) {
+			return Some(space);
+		}
+		let (_, (pallet, item, field)) = SPACE_SOURCES.iter().find(|(k, _)| *k == kind)?;
+		let storage = self.metadata.pallets.iter().find(|p| p.name == *pallet)?.storage.as_ref()?;
+		let entry = storage.entries.iter().find(|e| e.name == *item)?;
+		let value_ty = match &entry.ty {
+			StorageEntryType::Map { hashers, value, .. }
+				if matches!(hashers.as_slice(), [StorageHasher::Blake2_128Concat]) =>
+				value.id,
+			_ => return None,
+		};
+
+		// Identifiers are encoded as a bounded vector of their bytes.
+		let encoded = identifier.as_bytes().encode();
+		let key = [
+			&twox_128(storage.prefix.as_bytes())[..],
+			&twox_128(item.as_bytes())[..],
+			&blake2_128(&encoded)[..],
+			&encoded[..],
+		]
+		.concat();
+		let raw = self.at.iter().find_map(|at| self.chain.storage(*at, &key).ok().flatten())?;
+		let value = ValueDecoder::new(&self.metadata.types).decode(value_ty, &mut &raw[..]).ok()?;
+		simplify_identifiers(value).get(field)?.as_str().map(Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+
+	#[test]
+	fn should_split_events() {
+		let event = json!({ "Statement": { "Register": { "identifier": "stmt" } } });
+		assert_eq!(
+			split_event(&event),
+			Some(("Statement".into(), "Register".into(), json!({ "identifier": "stmt" })))
+		);
+		let event = json!({ "Did": "Migrated" });
+		assert_eq!(split_event(&event), Some(("Did".into(), "Migrated".into(), Value::Null)));
+		assert_eq!(split_event(&json!("System")), None);
+	}
+
+	#[test]
+	fn should_collect_identifiers_and_accounts() {
+		let account = AccountId32::new([1; 32]).to_ss58check();
+		let fields = json!({
+			"identifier": { "identifier": "stmt", "type": "Statement" },
+			"authorization": { "identifier": "auth", "type": "Authorization" },
+			"author": account,
+			"delegates": [account, "0x0102"],
+		});
+
+		let (mut identifiers, mut accounts) = (Vec::new(), Vec::new());
+		collect(&fields, &mut identifiers, &mut accounts);
+		identifiers.sort();
+		assert_eq!(
+			identifiers,
+			vec![
+				("auth".to_string(), "Authorization".to_string()),
+				("stmt".to_string(), "Statement".to_string())
+			]
+		);
+		assert_eq!(accounts, vec![account]);
+	}
+}
@@ -0,0 +1,336 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Indexer of the CORD events of the finalized blocks.
+//!
+//! The events of the CORD pallets are decoded with the runtime metadata into
+//! [`EventRecord`]s, along with the identifiers, the space and the accounts
+//! they relate to. The records are kept in the offchain database of the node,
+//! see [`IndexStore`], and pushed to the subscribers as the blocks are
+//! finalized. `cord-node-rpc` serves them over the `index_*` methods.
+//!
+//! The [`TimelineIndexer`] keeps the complete timelines of the identifiers
+//! written to the offchain index by the runtime, for the finalized blocks.
+
+#![warn(missing_docs)]
+#![warn(unused_crate_dependencies)]
+
+mod events;
+mod store;
+mod timeline;
+
+pub use store::IndexStore;
+pub use timeline::TimelineIndexer;
+
+use cord_node_inspect::{ChainAccess, MetadataCache};
+use cord_primitives::{Block, BlockNumber, Hash};
+use futures::{
+	channel::mpsc::{channel, Receiver, Sender},
+	StreamExt,
+};
+use parking_lot::Mutex;
+use sc_client_api::BlockchainEvents;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sp_core::{
+	crypto::{AccountId32, Ss58Codec},
+	offchain::OffchainStorage,
+};
+use std::sync::Arc;
+
+/// Log target of the indexer.
+const LOG_TARGET: &str = "cord-indexer";
+
+/// Number of records queued for a subscriber. Subscribers lagging further
+/// behind are dropped.
+const SUBSCRIBER_BUFFER: usize = 1024;
+
+/// A CORD event of a finalized block.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventRecord {
+	/// Position of the record in the index.
+	pub id: u64,
+	/// Number of the block emitting the event.
+	pub block_number: BlockNumber,
+	/// Hash of the block emitting the event.
+	pub block_hash: Hash,
+	/// Index of the extrinsic emitting the event, `None` for the events of
+	/// the block initialization and finalization.
+	pub extrinsic_index: Option<u32>,
+	/// Index of the event in the block.
+	pub event_index: u32,
+	/// Name of the pallet, e.g. `Statement`.
+	pub pallet: String,
+	/// Name of the event, e.g. `Register`.
+	pub event: String,
+	/// Fields of the event, decoded with the runtime metadata.
+	pub fields: Value,
+	/// Identifiers found in the fields.
+	pub identifiers: Vec<String>,
+	/// Space the event relates to, if known.
+	pub space: Option<String>,
+	/// Accounts (DIDs) found in the fields.
+	pub accounts: Vec<String>,
+}
+
+/// Selects indexed events, all the given criteria must match.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventFilter {
+	/// Name of the pallet.
+	pub pallet: Option<String>,
+	/// Name of the event.
+	pub event: Option<String>,
+	/// Space the event relates to.
+	pub space: Option<String>,
+	/// DID found in the fields of the event.
+	pub did: Option<String>,
+	/// Identifier found in the fields of the event.
+	pub identifier: Option<String>,
+}
+
+impl EventFilter {
+	/// Whether the record matches the filter.
+	pub fn matches(&self, record: &EventRecord) -> bool {
+		self.pallet.as_ref().map_or(true, |pallet| *pallet == record.pallet) &&
+			self.event.as_ref().map_or(true, |event| *event == record.event) &&
+			self.space.as_ref().map_or(true, |space| record.space.as_ref() == Some(space)) &&
+			self.did.as_ref().map_or(true, |did| record.accounts.contains(did)) &&
+			self.identifier.as_ref().map_or(true, |id| record.identifiers.contains(id))
+	}
+
+	/// Re-encode the DID in the SS58 format of the indexed records.
+	pub fn normalize(mut self) -> Result<Self, String> {
+		if let Some(did) = &self.did {
+			let account = AccountId32::from_ss58check(did)
+				.map_err(|e| format!("Invalid DID `{did}`: {e:?}"))?;
+			self.did = Some(account.to_ss58check());
+		}
+		Ok(self)
+	}
+}
+
+/// A page of indexed events.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventPage {
+	/// The matching records, by increasing id.
+	pub records: Vec<EventRecord>,
+	/// Id to continue from for the next page, `None` once all the records
+	/// have been read.
+	pub next: Option<u64>,
+}
+
+/// Progress of the indexer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexStatus {
+	/// Last indexed block.
+	pub last_block: Option<BlockNumber>,
+	/// Number of indexed records.
+	pub records: u64,
+}
+
+/// Indexes the CORD events of the finalized blocks and hands them out to the
+/// RPC.
+#[derive(Clone)]
+pub struct Indexer<S> {
+	store: IndexStore<S>,
+	subscribers: Arc<Mutex<Vec<Sender<EventRecord>>>>,
+}
+
+impl<S: OffchainStorage + 'static> Indexer<S> {
+	/// Create an indexer keeping its records in the given offchain storage.
+	pub fn new(storage: S) -> Self {
+		Self { store: IndexStore::new(storage), subscribers: Default::default() }
+	}
+
+	/// A page of at most `limit` records matching `filter`, starting at the
+	/// record `from`.
+	pub fn events(&self, filter: &EventFilter, from: Option<u64>, limit: u32) -> EventPage {
+		self.store.events(filter, from.unwrap_or_default(), limit)
+	}
+
+	/// Progress of the indexer.
+	pub fn status(&self) -> IndexStatus {
+		IndexStatus { last_block: self.store.last_block(), records: self.store.next_id() }
+	}
+
+	/// Stream of the records of the blocks indexed from now on. The stream
+	/// ends if it is not read fast enough.
+	pub fn subscribe(&self) -> Receiver<EventRecord> {
+		let (sink, stream) = channel(SUBSCRIBER_BUFFER);
+		self.subscribers.lock().push(sink);
+		stream
+	}
+
+	/// Send the records to the subscribers, dropping those which are gone or
+	/// lagging behind.
+	fn notify(&self, records: Vec<EventRecord>) {
+		let mut subscribers = self.subscribers.lock();
+		for record in records {
+			subscribers.retain_mut(|sink| match sink.try_send(record.clone()) {
+				Ok(()) => true,
+				Err(e) => {
+					if e.is_full() {
+						log::debug!(target: LOG_TARGET, "Dropping a lagging subscriber");
+					}
+					false
+				},
+			});
+		}
+	}
+
+	/// Index the finalized blocks not indexed yet, then each block as it is
+	/// finalized.
+	pub async fn run<C>(self, client: Arc<C>)
+	where
+		C: ChainAccess<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	{
+		let mut finality = client.finality_notification_stream();
+		let mut worker =
+			Worker { cache: MetadataCache::default(), store: self.store.clone(), skipped: 0 };
+
+		self.index_up_to(&*client, &mut worker, client.info().finalized_number);
+		while let Some(notification) = finality.next().await {
+			self.index_up_to(&*client, &mut worker, notification.header.number);
+		}
+	}
+
+	fn index_up_to(
+		&self,
+		chain: &dyn ChainAccess<Block>,
+		worker: &mut Worker<S>,
+		finalized: BlockNumber,
+	) {
+		let start = worker.store.last_block().map_or(0, |last| last + 1);
+		for number in start..=finalized {
+			let next_id = worker.store.next_id();
+			let (records, spaces) = match events::block_records(
+				chain,
+				&worker.cache,
+				&worker.store,
+				number,
+				next_id,
+			) {
+				Ok(indexed) => indexed,
+				Err(e) => {
+					// The state of the older blocks is usually pruned.
+					worker.skipped += 1;
+					if worker.skipped == 1 {
+						log::warn!(
+							target: LOG_TARGET,
+							"Skipping blocks that can not be indexed, run with `--state-pruning archive` to index all of them",
+						);
+					}
+					log::debug!(target: LOG_TARGET, "Skipping block #{number}: {e}");
+					Default::default()
+				},
+			};
+
+			worker.store.insert_block(number, &records, &spaces);
+			if !records.is_empty() {
+				self.notify(records);
+			}
+		}
+	}
+}
+
+/// State of the indexing task.
+struct Worker<S> {
+	cache: MetadataCache,
+	store: IndexStore<S>,
+	/// Number of the blocks that could not be indexed.
+	skipped: u64,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use serde_json::json;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	pub(crate) fn record(id: u64, pallet: &str, event: &str) -> EventRecord {
+		EventRecord {
+			id,
+			block_number: 1,
+			block_hash: Hash::repeat_byte(1),
+			extrinsic_index: Some(1),
+			event_index: id as u32,
+			pallet: pallet.into(),
+			event: event.into(),
+			fields: json!({}),
+			identifiers: Vec::new(),
+			space: None,
+			accounts: Vec::new(),
+		}
+	}
+
+	#[test]
+	fn should_match_all_criteria() {
+		let mut statement = record(0, "Statement", "Register");
+		statement.identifiers = vec!["stmt".into()];
+		statement.space = Some("space".into());
+
+		let filter = |value: Value| serde_json::from_value::<EventFilter>(value).unwrap();
+		assert!(filter(json!({})).matches(&statement));
+		assert!(filter(json!({ "pallet": "Statement", "space": "space" })).matches(&statement));
+		assert!(filter(json!({ "identifier": "stmt" })).matches(&statement));
+		assert!(!filter(json!({ "pallet": "Statement", "event": "Revoke" })).matches(&statement));
+		assert!(!filter(json!({ "space": "other" })).matches(&statement));
+		assert!(!filter(json!({ "did": "did" })).matches(&statement));
+	}
+
+	#[test]
+	fn should_normalize_dids() {
+		let account = AccountId32::new([7; 32]);
+		let generic = account.to_ss58check_with_version(42u16.into());
+		let filter = EventFilter { did: Some(generic), ..Default::default() }.normalize().unwrap();
+		assert_eq!(filter.did, Some(account.to_ss58check()));
+
+		let invalid = EventFilter { did: Some("did".into()), ..Default::default() };
+		assert!(invalid.normalize().is_err());
+	}
+
+	#[test]
+	fn should_drop_lagging_subscribers() {
+		let indexer = Indexer::new(InMemOffchainStorage::default());
+		let mut reader = indexer.subscribe();
+		let mut lagging = indexer.subscribe();
+
+		for id in 0..SUBSCRIBER_BUFFER as u64 {
+			indexer.notify(vec![record(id, "Statement", "Register")]);
+			assert_eq!(reader.try_next().unwrap().map(|record| record.id), Some(id));
+		}
+		assert_eq!(indexer.subscribers.lock().len(), 2);
+
+		// The channel of the lagging subscriber is full by now.
+		indexer.notify((0..2).map(|id| record(id, "Statement", "Revoke")).collect());
+		assert_eq!(indexer.subscribers.lock().len(), 1);
+		assert!(reader.try_next().unwrap().is_some());
+
+		// The lagging subscriber still reads the queued records, then its stream ends.
+		let mut queued = 0;
+		while let Ok(Some(_)) = lagging.try_next() {
+			queued += 1;
+		}
+		assert!(queued >= SUBSCRIBER_BUFFER);
+		assert!(matches!(lagging.try_next(), Ok(None)));
+	}
+}
@@ -0,0 +1,272 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage of the indexed records in the offchain database.
+//!
+//! The records are stored as JSON under their id, and the ids of the records
+//! relating to a space, a DID or an identifier are appended to a list for
+//! each of them, so the records can be paged through without scanning the
+//! whole index. The counters are written last, so a block indexed again after
+//! a restart overwrites its partly written records.
+
+use crate::{EventFilter, EventPage, EventRecord};
+use codec::{Decode, Encode};
+use cord_primitives::BlockNumber;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+
+/// Key of the id of the next record.
+const NEXT_KEY: &[u8] = b"cord-index/next";
+/// Key of the number of the last indexed block.
+const LAST_KEY: &[u8] = b"cord-index/last";
+/// Prefix of the records, followed by their id.
+const RECORD_PREFIX: &[u8] = b"cord-index/record/";
+/// Prefix of the known spaces of the identifiers.
+const SPACE_PREFIX: &[u8] = b"cord-index/space/";
+
+/// Maximum number of records in a page.
+const MAX_PAGE_SIZE: u32 = 1000;
+/// Maximum number of records read for a page, so filters not backed by a
+/// list do not scan the whole index at once.
+const MAX_SCANNED: usize = 10_000;
+
+/// The lists of record ids.
+#[derive(Debug, Clone, Copy)]
+enum List {
+	Space,
+	Did,
+	Identifier,
+}
+
+impl List {
+	fn key(&self, value: &str, suffix: &[u8]) -> Vec<u8> {
+		let prefix: &[u8] = match self {
+			List::Space => b"cord-index/by/space/",
+			List::Did => b"cord-index/by/did/",
+			List::Identifier => b"cord-index/by/identifier/",
+		};
+		[prefix, value.as_bytes(), b"/", suffix].concat()
+	}
+}
+
+/// The indexed records, kept in the offchain database.
+#[derive(Clone)]
+pub struct IndexStore<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage> IndexStore<S> {
+	/// Create a store over the given offchain storage.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	/// The id of the next record, which is the number of records.
+	pub fn next_id(&self) -> u64 {
+		self.get(NEXT_KEY).unwrap_or_default()
+	}
+
+	/// The number of the last indexed block.
+	pub fn last_block(&self) -> Option<BlockNumber> {
+		self.get(LAST_KEY)
+	}
+
+	/// The record with the given id.
+	pub fn record(&self, id: u64) -> Option<EventRecord> {
+		let raw = self.storage.get(STORAGE_PREFIX, &record_key(id))?;
+		serde_json::from_slice(&raw).ok()
+	}
+
+	/// The space of an identifier, as found by the earlier blocks.
+	pub fn space_of(&self, identifier: &str) -> Option<String> {
+		let raw = self
+			.storage
+			.get(STORAGE_PREFIX, &[SPACE_PREFIX, identifier.as_bytes()].concat())?;
+		String::from_utf8(raw).ok()
+	}
+
+	/// Store the records of block `number`, numbered from [`Self::next_id`],
+	/// along with the spaces found for their identifiers.
+	pub fn insert_block(
+		&mut self,
+		number: BlockNumber,
+		records: &[EventRecord],
+		spaces: &[(String, String)],
+	) {
+		for record in records {
+			let json = serde_json::to_vec(record).expect("Records serialize to JSON; qed");
+			self.storage.set(STORAGE_PREFIX, &record_key(record.id), &json);
+			if let Some(space) = &record.space {
+				self.push(List::Space, space, record.id);
+			}
+			for account in &record.accounts {
+				self.push(List::Did, account, record.id);
+			}
+			for identifier in &record.identifiers {
+				self.push(List::Identifier, identifier, record.id);
+			}
+		}
+		for (identifier, space) in spaces {
+			let key = [SPACE_PREFIX, identifier.as_bytes()].concat();
+			self.storage.set(STORAGE_PREFIX, &key, space.as_bytes());
+		}
+
+		if let Some(last) = records.last() {
+			self.storage.set(STORAGE_PREFIX, NEXT_KEY, &(last.id + 1).encode());
+		}
+		self.storage.set(STORAGE_PREFIX, LAST_KEY, &number.encode());
+	}
+
+	/// A page of at most `limit` records matching `filter`, starting at the
+	/// record `from`.
+	pub fn events(&self, filter: &EventFilter, from: u64, limit: u32) -> EventPage {
+		let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		let list = match (&filter.identifier, &filter.did, &filter.space) {
+			(Some(identifier), _, _) => Some((List::Identifier, identifier)),
+			(None, Some(did), _) => Some((List::Did, did)),
+			(None, None, Some(space)) => Some((List::Space, space)),
+			(None, None, None) => None,
+		};
+		let ids: Box<dyn Iterator<Item = u64> + '_> = match list {
+			Some((list, value)) => {
+				let len = self.list_len(list, value);
+				let start = self.list_search(list, value, len, from);
+				Box::new((start..len).filter_map(move |n| self.list_entry(list, value, n)))
+			},
+			None => Box::new(from..self.next_id()),
+		};
+
+		let mut records = Vec::new();
+		for (scanned, id) in ids.enumerate() {
+			if records.len() == limit || scanned == MAX_SCANNED {
+				return EventPage { records, next: Some(id) };
+			}
+			if let Some(record) = self.record(id).filter(|record| filter.matches(record)) {
+				records.push(record);
+			}
+		}
+		EventPage { records, next: None }
+	}
+
+	/// Append `id` to a list, unless a block indexed again already added it.
+	fn push(&mut self, list: List, value: &str, id: u64) {
+		let len = self.list_len(list, value);
+		if len > 0 && self.list_entry(list, value, len - 1).map_or(false, |last| last >= id) {
+			return;
+		}
+		self.storage
+			.set(STORAGE_PREFIX, &list.key(value, &len.to_be_bytes()), &id.encode());
+		self.storage.set(STORAGE_PREFIX, &list.key(value, b"len"), &(len + 1).encode());
+	}
+
+	fn list_len(&self, list: List, value: &str) -> u64 {
+		self.get(&list.key(value, b"len")).unwrap_or_default()
+	}
+
+	fn list_entry(&self, list: List, value: &str, n: u64) -> Option<u64> {
+		self.get(&list.key(value, &n.to_be_bytes()))
+	}
+
+	/// Position of the first id not lower than `from` in a list of `len` ids.
+	fn list_search(&self, list: List, value: &str, len: u64, from: u64) -> u64 {
+		let (mut low, mut high) = (0, len);
+		while low < high {
+			let mid = low + (high - low) / 2;
+			if self.list_entry(list, value, mid).map_or(false, |id| id < from) {
+				low = mid + 1;
+			} else {
+				high = mid;
+			}
+		}
+		low
+	}
+
+	fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+		let raw = self.storage.get(STORAGE_PREFIX, key)?;
+		T::decode(&mut &raw[..]).ok()
+	}
+}
+
+fn record_key(id: u64) -> Vec<u8> {
+	[RECORD_PREFIX, &id.to_be_bytes()].concat()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::tests::record;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	fn store_with(records: &[EventRecord]) -> IndexStore<InMemOffchainStorage> {
+		let mut store = IndexStore::new(InMemOffchainStorage::default());
+		for (number, record) in records.iter().enumerate() {
+			store.insert_block(number as BlockNumber + 1, &[record.clone()], &[]);
+		}
+		store
+	}
+
+	#[test]
+	fn should_page_through_records() {
+		let records = (0..5).map(|id| record(id, "Statement", "Register")).collect::<Vec<_>>();
+		let store = store_with(&records);
+		assert_eq!(store.next_id(), 5);
+		assert_eq!(store.last_block(), Some(5));
+
+		let page = store.events(&EventFilter::default(), 0, 2);
+		assert_eq!(page.records, records[..2]);
+		assert_eq!(page.next, Some(2));
+
+		let page = store.events(&EventFilter::default(), 4, 2);
+		assert_eq!(page.records, records[4..]);
+		assert_eq!(page.next, None);
+	}
+
+	#[test]
+	fn should_page_through_lists() {
+		let mut records = (0..6).map(|id| record(id, "Statement", "Register")).collect::<Vec<_>>();
+		for record in records.iter_mut().filter(|r| r.id % 2 == 0) {
+			record.identifiers = vec!["stmt".into()];
+			record.space = Some("space".into());
+		}
+		let store = store_with(&records);
+
+		let filter = EventFilter { identifier: Some("stmt".into()), ..Default::default() };
+		let page = store.events(&filter, 1, 1);
+		assert_eq!(page.records, vec![records[2].clone()]);
+		assert_eq!(page.next, Some(4));
+		let page = store.events(&filter, 4, 10);
+		assert_eq!(page.records, vec![records[4].clone()]);
+		assert_eq!(page.next, None);
+
+		let filter = EventFilter { space: Some("other".into()), ..Default::default() };
+		assert!(store.events(&filter, 0, 10).records.is_empty());
+	}
+
+	#[test]
+	fn should_not_duplicate_reindexed_blocks() {
+		let mut first = record(0, "Statement", "Register");
+		first.identifiers = vec!["stmt".into()];
+		let mut store = store_with(&[first.clone()]);
+
+		// A restart before the counters were written indexes the block again.
+		store.insert_block(1, &[first.clone()], &[("stmt".into(), "space".into())]);
+		let filter = EventFilter { identifier: Some("stmt".into()), ..Default::default() };
+		assert_eq!(store.events(&filter, 0, 10).records, vec![first]);
+		assert_eq!(store.space_of("stmt"), Some("space".into()));
+		assert_eq!(store.space_of("other"), None);
+	}
+}
@@ -0,0 +1,181 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Canonicalization of the timeline entries of the offchain index.
+//!
+//! The runtime writes the timeline entries added by a block under a key of
+//! the block, see [`timeline_block_key`], on every fork it executes. Once a
+//! block is finalized, its entries are moved to the keys of their positions
+//! in their timelines, read by the `identifier_timeline` RPC method, and the
+//! entries of the blocks of the abandoned forks are removed.
+
+use codec::{Decode, Encode};
+use cord_node_inspect::ChainAccess;
+use cord_primitives::{Block, BlockNumber, Hash, Header};
+use futures::StreamExt;
+use identifier::{
+	timeline_block_key, timeline_entry_key, timeline_length_key, EventEntryOf, IdentifierOf,
+	IdentifierTypeOf,
+};
+use sc_client_api::BlockchainEvents;
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+use sp_runtime::traits::Header as _;
+use std::sync::Arc;
+
+use crate::LOG_TARGET;
+
+/// Key of the number of the last canonicalized block.
+const LAST_KEY: &[u8] = b"cord-timeline/last";
+
+/// The timeline entries added by a block, as written by the runtime.
+type BlockEntries = Vec<(IdentifierOf, IdentifierTypeOf, u64, EventEntryOf)>;
+
+/// Moves the timeline entries of the finalized blocks to the keys read by the
+/// RPC, on the nodes running with offchain indexing enabled.
+pub struct TimelineIndexer<S> {
+	storage: S,
+}
+
+impl<S: OffchainStorage + 'static> TimelineIndexer<S> {
+	/// Create an indexer of the timelines of the given offchain storage.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+
+	/// Canonicalize the finalized blocks not canonicalized yet, then each
+	/// block as it is finalized, pruning the forks it abandons.
+	pub async fn run<C>(mut self, client: Arc<C>)
+	where
+		C: ChainAccess<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	{
+		let mut finality = client.finality_notification_stream();
+
+		self.canonicalize_up_to(&*client, client.info().finalized_number);
+		while let Some(notification) = finality.next().await {
+			self.canonicalize_up_to(&*client, notification.header.number);
+			for head in notification.stale_heads.iter() {
+				self.prune_fork(&*client, *head);
+			}
+		}
+	}
+
+	fn canonicalize_up_to(&mut self, chain: &dyn ChainAccess<Block>, finalized: BlockNumber) {
+		let start = self.get::<BlockNumber>(LAST_KEY).map_or(0, |last| last + 1);
+		for number in start..=finalized {
+			match chain.hash(number).ok().flatten().and_then(|hash| header(chain, hash)) {
+				Some(header) => self.canonicalize(&header),
+				None =>
+					log::debug!(target: LOG_TARGET, "Skipping the timelines of block #{number}"),
+			}
+			self.storage.set(STORAGE_PREFIX, LAST_KEY, &number.encode());
+		}
+	}
+
+	/// Move the timeline entries of a finalized block to the keys of their
+	/// positions.
+	fn canonicalize(&mut self, header: &Header) {
+		let key = timeline_block_key(header.parent_hash(), header.digest());
+		let Some(entries) = self.get::<BlockEntries>(&key) else { return };
+
+		for (id, id_type, position, entry) in entries {
+			self.storage.set(
+				STORAGE_PREFIX,
+				&timeline_entry_key(&id, id_type, position),
+				&entry.encode(),
+			);
+			let length_key = timeline_length_key(&id, id_type);
+			let length = self.get::<u64>(&length_key).unwrap_or_default().max(position + 1);
+			self.storage.set(STORAGE_PREFIX, &length_key, &length.encode());
+		}
+		self.storage.remove(STORAGE_PREFIX, &key);
+	}
+
+	/// Remove the timeline entries of the blocks of an abandoned fork, from
+	/// its head down to the finalized chain.
+	fn prune_fork(&mut self, chain: &dyn ChainAccess<Block>, head: Hash) {
+		let mut hash = head;
+		while let Some(header) = header(chain, hash) {
+			if chain.hash(header.number).ok().flatten() == Some(hash) {
+				break;
+			}
+			self.storage
+				.remove(STORAGE_PREFIX, &timeline_block_key(header.parent_hash(), header.digest()));
+			hash = header.parent_hash;
+		}
+	}
+
+	fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+		let raw = self.storage.get(STORAGE_PREFIX, key)?;
+		T::decode(&mut &raw[..]).ok()
+	}
+}
+
+fn header(chain: &dyn ChainAccess<Block>, hash: Hash) -> Option<Header> {
+	chain.header(hash).ok().flatten()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use identifier::{CallTypeOf, IdentifierType, Ss58Identifier, Timepoint};
+	use sp_core::offchain::storage::InMemOffchainStorage;
+	use sp_runtime::{Digest, DigestItem};
+
+	fn block(slot: u8) -> Header {
+		let digest = Digest { logs: vec![DigestItem::PreRuntime(*b"BABE", vec![slot])] };
+		Header::new(2, Default::default(), Default::default(), Hash::repeat_byte(1), digest)
+	}
+
+	fn entry(height: u32) -> EventEntryOf {
+		EventEntryOf { action: CallTypeOf::Update, location: Timepoint { height, index: 0 } }
+	}
+
+	#[test]
+	fn should_canonicalize_the_entries_of_the_finalized_block() {
+		let id =
+			Ss58Identifier::create_identifier(&[2u8; 30].encode()[..], IdentifierType::Statement)
+				.unwrap();
+		let kind = IdentifierTypeOf::Statement;
+		let mut indexer = TimelineIndexer::new(InMemOffchainStorage::default());
+
+		// Two sibling blocks add a different entry at the same position.
+		let (finalized, stale) = (block(1), block(2));
+		for (header, height) in [(&finalized, 5), (&stale, 6)] {
+			let entries: BlockEntries = vec![(id.clone(), kind, 3, entry(height))];
+			indexer.storage.set(
+				STORAGE_PREFIX,
+				&timeline_block_key(header.parent_hash(), header.digest()),
+				&entries.encode(),
+			);
+		}
+
+		indexer.canonicalize(&finalized);
+		assert_eq!(indexer.get(&timeline_entry_key(&id, kind, 3)), Some(entry(5)));
+		assert_eq!(indexer.get(&timeline_length_key(&id, kind)), Some(4u64));
+		assert_eq!(
+			indexer.get::<BlockEntries>(&timeline_block_key(
+				finalized.parent_hash(),
+				finalized.digest()
+			)),
+			None
+		);
+		assert!(indexer
+			.get::<BlockEntries>(&timeline_block_key(stale.parent_hash(), stale.digest()))
+			.is_some());
+	}
+}
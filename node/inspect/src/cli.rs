@@ -18,6 +18,7 @@
 
 //! Structs to easily compose inspect sub-command for CLI.
 
+use crate::export::{ExportFormat, Registry};
 use sc_cli::{ImportParams, SharedParams};
 
 /// The `inspect` command used to print decoded chain data.
@@ -27,6 +28,10 @@ pub struct InspectCmd {
 	#[clap(subcommand)]
 	pub command: InspectSubCmd,
 
+	/// Format of the printed blocks and extrinsics.
+	#[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+	pub output: OutputFormat,
+
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub shared_params: SharedParams,
@@ -60,4 +65,56 @@ pub enum InspectSubCmd {
 		#[clap(value_name = "BLOCK:INDEX or BYTES")]
 		input: String,
 	},
+	/// Decode an identifier and print out its timeline, along with the
+	/// extrinsics and events behind each entry.
+	Identifier {
+		/// The base58 encoded identifier.
+		#[clap(value_name = "IDENTIFIER")]
+		input: String,
+
+		/// Block to read the timeline at, either a block hash (no 0x prefix)
+		/// or a number. Defaults to the best block.
+		#[clap(long, value_name = "HASH or NUMBER")]
+		at: Option<String>,
+
+		/// Rebuild the entries dropped from a truncated on-chain history by
+		/// scanning the blocks.
+		#[clap(long)]
+		rebuild: bool,
+	},
+	/// Export the registries kept in the state, decoded with the runtime
+	/// metadata.
+	Export {
+		/// Registry to export, can be repeated. All of them by default.
+		#[clap(long = "registry", value_enum)]
+		registries: Vec<Registry>,
+
+		/// Block to read the state at, either a block hash (no 0x prefix) or
+		/// a number. Defaults to the best block.
+		#[clap(long, value_name = "HASH or NUMBER")]
+		at: Option<String>,
+
+		/// Format of the exported rows. CSV takes a single registry.
+		#[clap(long, value_enum, default_value_t = ExportFormat::Jsonl)]
+		format: ExportFormat,
+
+		/// Only export the rows of the given space.
+		#[clap(long, value_name = "SPACE ID")]
+		space: Option<String>,
+
+		/// Only export the rows created by the given account.
+		#[clap(long, value_name = "SS58 ADDRESS")]
+		creator: Option<String>,
+	},
+}
+
+/// Output formats of the `inspect` command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum OutputFormat {
+	/// Readable text, decoded with the runtime metadata.
+	Text,
+	/// JSON, decoded with the runtime metadata.
+	Json,
+	/// Raw `Debug` output of the native types.
+	Debug,
 }
@@ -19,12 +19,16 @@
 //! Command ran by the CLI
 
 use crate::{
-	cli::{InspectCmd, InspectSubCmd},
-	Inspector,
+	cli::{InspectCmd, InspectSubCmd, OutputFormat},
+	export::{ExportFilter, ExportFormat, Registry},
+	CordPrinter, Inspector, PrettyPrinter,
 };
+use clap::ValueEnum;
 use sc_cli::{CliConfiguration, ImportParams, Result, SharedParams};
 use sc_service::Configuration;
+use sp_core::crypto::{AccountId32, Ss58Codec};
 use sp_runtime::traits::Block;
+use std::io::BufWriter;
 
 type HostFunctions = (sp_io::SubstrateHostFunctions,);
 
@@ -37,8 +41,21 @@ impl InspectCmd {
 	{
 		let executor = sc_service::new_wasm_executor::<HostFunctions>(&config);
 		let client = sc_service::new_full_client::<B, RA, _>(&config, None, executor)?;
-		let inspect = Inspector::<B>::new(client);
 
+		match self.output {
+			OutputFormat::Text =>
+				self.inspect(Inspector::<B, _>::with_printer(client, CordPrinter::text())),
+			OutputFormat::Json =>
+				self.inspect(Inspector::<B, _>::with_printer(client, CordPrinter::json())),
+			OutputFormat::Debug => self.inspect(Inspector::<B>::new(client)),
+		}
+	}
+
+	fn inspect<B, P>(&self, inspect: Inspector<B, P>) -> Result<()>
+	where
+		B: Block,
+		P: PrettyPrinter<B>,
+	{
 		match &self.command {
 			InspectSubCmd::Block { input } => {
 				let input = input.parse()?;
@@ -52,6 +69,45 @@ impl InspectCmd {
 				println!("{res}");
 				Ok(())
 			},
+			InspectSubCmd::Identifier { input, at, rebuild } => {
+				let at = at.as_deref().map(str::parse).transpose()?;
+				let res = inspect.identifier(input, at, *rebuild).map_err(|e| e.to_string())?;
+				println!("{res}");
+				Ok(())
+			},
+			InspectSubCmd::Export { registries, at, format, space, creator } => {
+				let registries = if registries.is_empty() {
+					Registry::value_variants().to_vec()
+				} else {
+					registries.clone()
+				};
+				if *format == ExportFormat::Csv && registries.len() != 1 {
+					return Err("CSV output takes a single --registry".into());
+				}
+				let at = at.as_deref().map(str::parse).transpose()?;
+				// Accounts are decoded with the default SS58 format of the chain.
+				let creator = creator
+					.as_deref()
+					.map(|creator| {
+						AccountId32::from_ss58check(creator)
+							.map(|account| account.to_ss58check())
+							.map_err(|e| format!("Invalid creator {creator}: {e:?}"))
+					})
+					.transpose()?;
+				let filter = ExportFilter { space: space.clone(), creator };
+
+				let out = BufWriter::new(std::io::stdout().lock());
+				let written = inspect
+					.export(&registries, at, &filter, *format, out)
+					.map_err(|e| e.to_string())?;
+				for (registry, rows) in written {
+					match rows {
+						Some(rows) => eprintln!("Exported {rows} {}", registry.name()),
+						None => eprintln!("Skipped {}, not in the runtime", registry.name()),
+					}
+				}
+				Ok(())
+			},
 		}
 	}
 }
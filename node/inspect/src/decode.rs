@@ -0,0 +1,368 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Metadata driven decoding of SCALE values into JSON.
+//!
+//! Values are decoded against the type registry of the runtime metadata, so
+//! no native runtime types are needed. A few CORD types get a readable form:
+//! `Ss58Identifier`s are rendered with their `IdentifierType`, accounts as
+//! SS58 addresses and byte arrays as hex strings.
+
+use codec::{Compact, Decode, Input};
+use frame_metadata::v15::StorageHasher;
+use identifier::{CordIdentifierType, Ss58Identifier};
+use scale_info::{form::PortableForm, Field, PortableRegistry, TypeDef, TypeDefPrimitive};
+use serde_json::{json, Map, Value};
+use sp_core::crypto::{AccountId32, Ss58Codec};
+
+/// Maximum nesting of values, guards against malformed input.
+const MAX_DEPTH: usize = 64;
+
+/// Decodes SCALE encoded values of the types in a `PortableRegistry`.
+pub struct ValueDecoder<'a> {
+	registry: &'a PortableRegistry,
+}
+
+impl<'a> ValueDecoder<'a> {
+	/// Create a decoder over the given type registry.
+	pub fn new(registry: &'a PortableRegistry) -> Self {
+		Self { registry }
+	}
+
+	/// Decode a value of type `ty` from `input`.
+	pub fn decode(&self, ty: u32, input: &mut &[u8]) -> Result<Value, String> {
+		self.decode_at(ty, input, 0)
+	}
+
+	/// Decode the key of a storage map entry of key type `ty`, given without
+	/// its pallet and item prefix. Keys under hashers that do not concatenate
+	/// them are rendered as the hex of their hash.
+	pub fn decode_storage_key(
+		&self,
+		hashers: &[StorageHasher],
+		ty: u32,
+		key: &[u8],
+	) -> Result<Value, String> {
+		let types = match hashers.len() {
+			1 => vec![ty],
+			_ => match self.registry.resolve(ty).map(|info| &info.type_def) {
+				Some(TypeDef::Tuple(tuple)) => tuple.fields.iter().map(|field| field.id).collect(),
+				_ => return Err(format!("Type {ty} is not a tuple of {} keys", hashers.len())),
+			},
+		};
+
+		let mut input = key;
+		let mut parts = Vec::new();
+		for (hasher, ty) in hashers.iter().zip(types) {
+			let (hash_len, concat) = match hasher {
+				StorageHasher::Blake2_128 | StorageHasher::Twox128 => (16, false),
+				StorageHasher::Blake2_256 | StorageHasher::Twox256 => (32, false),
+				StorageHasher::Blake2_128Concat => (16, true),
+				StorageHasher::Twox64Concat => (8, true),
+				StorageHasher::Identity => (0, true),
+			};
+			if input.len() < hash_len {
+				return Err("Storage key is too short".into());
+			}
+			let (hash, rest) = input.split_at(hash_len);
+			input = rest;
+			if concat {
+				parts.push(self.decode(ty, &mut input)?);
+			} else {
+				parts.push(Value::String(format!("0x{}", hex::encode(hash))));
+			}
+		}
+
+		Ok(match parts.len() {
+			1 => parts.remove(0),
+			_ => Value::Array(parts),
+		})
+	}
+
+	fn decode_at(&self, ty: u32, input: &mut &[u8], depth: usize) -> Result<Value, String> {
+		if depth > MAX_DEPTH {
+			return Err("Value nested too deeply".into());
+		}
+		let depth = depth + 1;
+		let info = self.registry.resolve(ty).ok_or_else(|| format!("Unknown type id {ty}"))?;
+
+		match info.path.segments.last().map(String::as_str) {
+			Some("Ss58Identifier") => {
+				let id = Ss58Identifier::decode(input).map_err(|e| e.to_string())?;
+				return Ok(identifier_value(&id));
+			},
+			Some("AccountId32") => {
+				let raw = <[u8; 32]>::decode(input).map_err(|e| e.to_string())?;
+				return Ok(Value::String(AccountId32::from(raw).to_ss58check()));
+			},
+			_ => {},
+		}
+
+		match &info.type_def {
+			TypeDef::Composite(composite) => self.decode_fields(&composite.fields, input, depth),
+			TypeDef::Variant(variant) => {
+				let index = u8::decode(input).map_err(|e| e.to_string())?;
+				let variant = variant
+					.variants
+					.iter()
+					.find(|v| v.index == index)
+					.ok_or_else(|| format!("Unknown variant index {index} of type {ty}"))?;
+				match (info.path.segments.last().map(String::as_str), variant.name.as_str()) {
+					(Some("Option"), "None") => return Ok(Value::Null),
+					(Some("Option"), "Some") =>
+						return self.decode_fields(&variant.fields, input, depth),
+					_ => {},
+				}
+				if variant.fields.is_empty() {
+					Ok(Value::String(variant.name.clone()))
+				} else {
+					let fields = self.decode_fields(&variant.fields, input, depth)?;
+					Ok(Value::Object(Map::from_iter([(variant.name.clone(), fields)])))
+				}
+			},
+			TypeDef::Sequence(seq) => {
+				let len = Compact::<u32>::decode(input).map_err(|e| e.to_string())?.0 as usize;
+				self.decode_items(seq.type_param.id, len, input, depth)
+			},
+			TypeDef::Array(array) =>
+				self.decode_items(array.type_param.id, array.len as usize, input, depth),
+			TypeDef::Tuple(tuple) => {
+				let items = tuple
+					.fields
+					.iter()
+					.map(|f| self.decode_at(f.id, input, depth))
+					.collect::<Result<Vec<_>, _>>()?;
+				Ok(if items.is_empty() { Value::Null } else { Value::Array(items) })
+			},
+			TypeDef::Primitive(primitive) => decode_primitive(primitive, input),
+			TypeDef::Compact(compact) => self.decode_compact(compact.type_param.id, input),
+			TypeDef::BitSequence(_) => Err(format!("Bit sequences are not supported (type {ty})")),
+		}
+	}
+
+	fn decode_fields(
+		&self,
+		fields: &[Field<PortableForm>],
+		input: &mut &[u8],
+		depth: usize,
+	) -> Result<Value, String> {
+		match fields {
+			[] => Ok(Value::Null),
+			[field] if field.name.is_none() => self.decode_at(field.ty.id, input, depth),
+			_ if fields.iter().all(|f| f.name.is_some()) => {
+				let mut map = Map::new();
+				for field in fields {
+					let value = self.decode_at(field.ty.id, input, depth)?;
+					map.insert(field.name.clone().unwrap_or_default(), value);
+				}
+				Ok(Value::Object(map))
+			},
+			_ => fields
+				.iter()
+				.map(|f| self.decode_at(f.ty.id, input, depth))
+				.collect::<Result<Vec<_>, _>>()
+				.map(Value::Array),
+		}
+	}
+
+	fn decode_items(
+		&self,
+		ty: u32,
+		len: usize,
+		input: &mut &[u8],
+		depth: usize,
+	) -> Result<Value, String> {
+		if self.is_byte(ty) {
+			let mut bytes = vec![0u8; len];
+			input.read(&mut bytes).map_err(|e| e.to_string())?;
+			return Ok(Value::String(format!("0x{}", hex::encode(bytes))));
+		}
+		if len > input.len() {
+			return Err(format!("Sequence length {len} exceeds the remaining input"));
+		}
+		(0..len)
+			.map(|_| self.decode_at(ty, input, depth))
+			.collect::<Result<Vec<_>, _>>()
+			.map(Value::Array)
+	}
+
+	fn decode_compact(&self, ty: u32, input: &mut &[u8]) -> Result<Value, String> {
+		// Compact wrappers (e.g. `Compact<Perbill>`) resolve to their inner primitive.
+		let mut ty = ty;
+		let primitive = loop {
+			let info = self.registry.resolve(ty).ok_or_else(|| format!("Unknown type id {ty}"))?;
+			match &info.type_def {
+				TypeDef::Primitive(primitive) => break primitive,
+				TypeDef::Composite(composite) if composite.fields.len() == 1 =>
+					ty = composite.fields[0].ty.id,
+				_ => return Err(format!("Type {ty} can not be compact encoded")),
+			}
+		};
+		let err = |e: codec::Error| e.to_string();
+		Ok(match primitive {
+			TypeDefPrimitive::U8 => json!(Compact::<u8>::decode(input).map_err(err)?.0),
+			TypeDefPrimitive::U16 => json!(Compact::<u16>::decode(input).map_err(err)?.0),
+			TypeDefPrimitive::U32 => json!(Compact::<u32>::decode(input).map_err(err)?.0),
+			TypeDefPrimitive::U64 => json!(Compact::<u64>::decode(input).map_err(err)?.0),
+			TypeDefPrimitive::U128 => u128_value(Compact::<u128>::decode(input).map_err(err)?.0),
+			_ => return Err(format!("Type {ty} can not be compact encoded")),
+		})
+	}
+
+	fn is_byte(&self, ty: u32) -> bool {
+		self.registry
+			.resolve(ty)
+			.map_or(false, |t| matches!(t.type_def, TypeDef::Primitive(TypeDefPrimitive::U8)))
+	}
+}
+
+/// Render an identifier along with its `IdentifierType`.
+pub fn identifier_value(id: &Ss58Identifier) -> Value {
+	let text = String::from_utf8_lossy(id.inner()).into_owned();
+	match id.get_type() {
+		Ok(kind) => json!({ "identifier": text, "type": format!("{:?}", kind) }),
+		Err(_) => json!({ "identifier": text, "type": Value::Null }),
+	}
+}
+
+/// Replace the identifiers rendered by [`identifier_value`] with their text,
+/// for output that does not need the identifier type.
+pub fn simplify_identifiers(value: Value) -> Value {
+	match value {
+		Value::Object(mut map) => {
+			if map.len() == 2 &&
+				map.contains_key("type") &&
+				map.get("identifier").map_or(false, Value::is_string)
+			{
+				return map.remove("identifier").unwrap_or_default();
+			}
+			Value::Object(map.into_iter().map(|(k, v)| (k, simplify_identifiers(v))).collect())
+		},
+		Value::Array(items) => Value::Array(items.into_iter().map(simplify_identifiers).collect()),
+		value => value,
+	}
+}
+
+fn u128_value(value: u128) -> Value {
+	u64::try_from(value).map_or_else(|_| Value::String(value.to_string()), Value::from)
+}
+
+fn decode_primitive(primitive: &TypeDefPrimitive, input: &mut &[u8]) -> Result<Value, String> {
+	let err = |e: codec::Error| e.to_string();
+	Ok(match primitive {
+		TypeDefPrimitive::Bool => json!(bool::decode(input).map_err(err)?),
+		TypeDefPrimitive::Char => {
+			let c = u32::decode(input).map_err(err)?;
+			json!(char::from_u32(c).ok_or("Invalid char")?)
+		},
+		TypeDefPrimitive::Str => json!(String::decode(input).map_err(err)?),
+		TypeDefPrimitive::U8 => json!(u8::decode(input).map_err(err)?),
+		TypeDefPrimitive::U16 => json!(u16::decode(input).map_err(err)?),
+		TypeDefPrimitive::U32 => json!(u32::decode(input).map_err(err)?),
+		TypeDefPrimitive::U64 => json!(u64::decode(input).map_err(err)?),
+		TypeDefPrimitive::U128 => u128_value(u128::decode(input).map_err(err)?),
+		TypeDefPrimitive::I8 => json!(i8::decode(input).map_err(err)?),
+		TypeDefPrimitive::I16 => json!(i16::decode(input).map_err(err)?),
+		TypeDefPrimitive::I32 => json!(i32::decode(input).map_err(err)?),
+		TypeDefPrimitive::I64 => json!(i64::decode(input).map_err(err)?),
+		TypeDefPrimitive::I128 => {
+			let value = i128::decode(input).map_err(err)?;
+			i64::try_from(value).map_or_else(|_| Value::String(value.to_string()), Value::from)
+		},
+		TypeDefPrimitive::U256 | TypeDefPrimitive::I256 => {
+			let raw = <[u8; 32]>::decode(input).map_err(err)?;
+			Value::String(format!("0x{}", hex::encode(raw)))
+		},
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use identifier::{IdentifierCreator, IdentifierType};
+	use scale_info::{MetaType, Registry, TypeInfo};
+
+	#[derive(Encode, TypeInfo)]
+	enum Call {
+		Register { digest: sp_core::H256, space_id: Ss58Identifier, note: Option<Vec<u8>> },
+		Noop,
+	}
+
+	fn registry_for<T: TypeInfo + 'static>() -> (PortableRegistry, u32) {
+		let mut registry = Registry::new();
+		let id = registry.register_type(&MetaType::new::<T>()).id;
+		(registry.into(), id)
+	}
+
+	#[test]
+	fn should_decode_calls_with_identifiers() {
+		let (registry, ty) = registry_for::<Call>();
+		let space_id =
+			Ss58Identifier::create_identifier(&[1u8; 32], IdentifierType::Space).unwrap();
+		let call = Call::Register {
+			digest: sp_core::H256::repeat_byte(0xab),
+			space_id: space_id.clone(),
+			note: Some(vec![1, 2]),
+		};
+		let encoded = call.encode();
+
+		let value = ValueDecoder::new(&registry).decode(ty, &mut &encoded[..]).unwrap();
+
+		assert_eq!(
+			value,
+			json!({ "Register": {
+				"digest": format!("0x{}", "ab".repeat(32)),
+				"space_id": {
+					"identifier": String::from_utf8(space_id.inner().to_vec()).unwrap(),
+					"type": "Space",
+				},
+				"note": "0x0102",
+			}})
+		);
+		let encoded = Call::Noop.encode();
+		let value = ValueDecoder::new(&registry).decode(ty, &mut &encoded[..]).unwrap();
+		assert_eq!(value, json!("Noop"));
+	}
+
+	#[test]
+	fn should_decode_storage_keys() {
+		let (registry, ty) = registry_for::<(u32, Vec<u8>)>();
+		let (first, second) = (7u32.encode(), vec![1u8, 2].encode());
+		let key =
+			[&sp_core::twox_64(&first)[..], &first, &sp_core::blake2_128(&second)[..]].concat();
+		let decoder = ValueDecoder::new(&registry);
+
+		let value = decoder
+			.decode_storage_key(&[StorageHasher::Twox64Concat, StorageHasher::Blake2_128], ty, &key)
+			.unwrap();
+
+		assert_eq!(value, json!([7, format!("0x{}", hex::encode(sp_core::blake2_128(&second)))]));
+		assert!(decoder
+			.decode_storage_key(&[StorageHasher::Twox64Concat], ty, &key[..4])
+			.is_err());
+	}
+
+	#[test]
+	fn should_reject_truncated_input() {
+		let (registry, ty) = registry_for::<Vec<u32>>();
+		let encoded = vec![1u32, 2, 3].encode();
+
+		assert!(ValueDecoder::new(&registry).decode(ty, &mut &encoded[..6]).is_err());
+	}
+}
@@ -0,0 +1,465 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Export of the CORD registries at a block.
+//!
+//! Each registry is read from its main storage map, one row per entry, and
+//! joined with the related maps (delegates of a space, revocation status of a
+//! statement, ...). Keys and values are decoded with the runtime metadata, so
+//! the export follows the runtime the state was written by.
+
+use crate::{
+	decode::{simplify_identifiers, ValueDecoder},
+	ChainAccess, Error,
+};
+use frame_metadata::v15::{RuntimeMetadataV15, StorageEntryType, StorageHasher};
+use serde_json::{json, Map, Value};
+use sp_core::{twox_128, twox_64};
+use sp_runtime::traits::Block;
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	io::Write,
+};
+
+/// The registries that can be exported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Registry {
+	/// Chain spaces, with their delegates.
+	Spaces,
+	/// Schemas.
+	Schemas,
+	/// Statements, with their creator and revocation status.
+	Statements,
+	/// DIDs, with their keys and names.
+	Dids,
+	/// Assets, with their distribution.
+	Assets,
+	/// Rating entries, with the aggregated score of their entity.
+	Ratings,
+}
+
+impl Registry {
+	/// Name of the registry in the exported rows.
+	pub fn name(&self) -> &'static str {
+		match self {
+			Registry::Spaces => "spaces",
+			Registry::Schemas => "schemas",
+			Registry::Statements => "statements",
+			Registry::Dids => "dids",
+			Registry::Assets => "assets",
+			Registry::Ratings => "ratings",
+		}
+	}
+
+	/// Pallet and storage map the rows are read from, and the column of the
+	/// map key.
+	fn source(&self) -> (&'static str, &'static str, &'static str) {
+		match self {
+			Registry::Spaces => ("ChainSpace", "Spaces", "space_id"),
+			Registry::Schemas => ("Schema", "Schemas", "schema_id"),
+			Registry::Statements => ("Statement", "Statements", "statement_id"),
+			Registry::Dids => ("Did", "Did", "did"),
+			Registry::Assets => ("Asset", "Assets", "asset_id"),
+			Registry::Ratings => ("NetworkScore", "RatingEntries", "rating_id"),
+		}
+	}
+
+	fn space_column(&self) -> Option<&'static str> {
+		match self {
+			Registry::Spaces => Some("space_id"),
+			Registry::Schemas | Registry::Statements | Registry::Ratings => Some("space"),
+			Registry::Dids | Registry::Assets => None,
+		}
+	}
+
+	fn creator_column(&self) -> Option<&'static str> {
+		match self {
+			Registry::Spaces | Registry::Schemas | Registry::Statements => Some("creator"),
+			Registry::Dids => Some("did"),
+			Registry::Assets => Some("asset_issuer"),
+			Registry::Ratings => Some("creator_id"),
+		}
+	}
+}
+
+/// Output formats of the export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+	/// One JSON object per line.
+	Jsonl,
+	/// Comma separated values, with nested values flattened into
+	/// `parent.child` columns. The rows are written once all of them are
+	/// read, with the columns found in any of them.
+	Csv,
+}
+
+/// Restricts the export to the rows of a space or of a creator.
+///
+/// Registries without a space, or without a creator, are left out when
+/// filtering on it.
+#[derive(Debug, Clone, Default)]
+pub struct ExportFilter {
+	/// Identifier of the space.
+	pub space: Option<String>,
+	/// SS58 address of the creator.
+	pub creator: Option<String>,
+}
+
+impl ExportFilter {
+	fn matches(&self, registry: Registry, row: &Map<String, Value>) -> bool {
+		let column_is = |column: Option<&str>, wanted: &Option<String>| match wanted {
+			None => true,
+			Some(wanted) =>
+				column.and_then(|c| row.get(c)).and_then(Value::as_str) == Some(wanted.as_str()),
+		};
+		column_is(registry.space_column(), &self.space) &&
+			column_is(registry.creator_column(), &self.creator)
+	}
+}
+
+/// A storage map of the runtime.
+struct StorageMap<'a> {
+	prefix: Vec<u8>,
+	hashers: &'a [StorageHasher],
+	key_ty: u32,
+	value_ty: u32,
+}
+
+/// Reads the registries from the state at a block.
+pub(crate) struct Exporter<'a, TBlock: Block> {
+	chain: &'a dyn ChainAccess<TBlock>,
+	metadata: &'a RuntimeMetadataV15,
+	values: ValueDecoder<'a>,
+	at: TBlock::Hash,
+}
+
+impl<'a, TBlock: Block> Exporter<'a, TBlock> {
+	pub(crate) fn new(
+		chain: &'a dyn ChainAccess<TBlock>,
+		metadata: &'a RuntimeMetadataV15,
+		at: TBlock::Hash,
+	) -> Self {
+		Self { chain, metadata, values: ValueDecoder::new(&metadata.types), at }
+	}
+
+	/// Write the rows of `registry` matching `filter`. Returns the number of
+	/// rows written, or `None` if the registry is not in the runtime.
+	pub(crate) fn export<W: Write>(
+		&self,
+		registry: Registry,
+		filter: &ExportFilter,
+		out: &mut RowWriter<W>,
+	) -> Result<Option<usize>, Error> {
+		let (pallet, item, id_column) = registry.source();
+		let Some(map) = self.map(pallet, item) else { return Ok(None) };
+		let aggregates = match registry {
+			Registry::Ratings => self.aggregates()?,
+			_ => BTreeMap::new(),
+		};
+
+		let mut written = 0;
+		for entry in self.entries(&map, &[])? {
+			let (key, id, value) = entry?;
+			let mut row = Map::new();
+			row.insert("registry".into(), registry.name().into());
+			row.insert(id_column.into(), id);
+			match value {
+				Value::Object(fields) => row.extend(fields),
+				value => {
+					row.insert("value".into(), value);
+				},
+			}
+			self.join(registry, &key, &mut row, &aggregates)?;
+			if filter.matches(registry, &row) {
+				out.write(row)?;
+				written += 1;
+			}
+		}
+		Ok(Some(written))
+	}
+
+	/// Add the details kept outside of the main map of the registry to a row.
+	/// `key` is the hashed key of the row in the main map.
+	fn join(
+		&self,
+		registry: Registry,
+		key: &[u8],
+		row: &mut Map<String, Value>,
+		aggregates: &BTreeMap<String, Value>,
+	) -> Result<(), Error> {
+		match registry {
+			Registry::Spaces => {
+				let delegates = self.get("ChainSpace", "Delegates", key)?;
+				row.insert("delegates".into(), delegates.unwrap_or_else(|| json!([])));
+			},
+			Registry::Schemas => {},
+			Registry::Statements => {
+				// Entries and revocations are double maps keyed by the statement
+				// id, then by the digest.
+				let id = key.get(16..).unwrap_or_default();
+				let prefix = [&twox_64(id)[..], id].concat();
+				let digest = row.get("digest").cloned().unwrap_or_default();
+				let creator = self.find("Statement", "Entries", &prefix, &digest)?;
+				let status = self.find("Statement", "RevocationList", &prefix, &digest)?;
+				row.insert("creator".into(), creator.unwrap_or_default());
+				row.insert(
+					"revoked".into(),
+					status.and_then(|s| s.get("revoked").cloned()).unwrap_or(Value::Bool(false)),
+				);
+			},
+			Registry::Dids => {
+				let name = self.get("DidName", "Names", key)?;
+				row.insert("name".into(), name.map(name_value).unwrap_or_default());
+			},
+			Registry::Assets => {
+				let distribution = self.get("Asset", "Distribution", key)?;
+				row.insert("distribution".into(), distribution.unwrap_or_else(|| json!([])));
+			},
+			Registry::Ratings => {
+				let entry = row.get("entry");
+				let aggregate = json!([
+					entry.and_then(|e| e.get("entity_id")),
+					entry.and_then(|e| e.get("rating_type")),
+				]);
+				let aggregate = aggregates.get(&aggregate.to_string()).cloned();
+				row.insert("aggregate".into(), aggregate.unwrap_or_default());
+			},
+		}
+		Ok(())
+	}
+
+	/// The aggregated scores, by their `[entity, rating type]` key.
+	fn aggregates(&self) -> Result<BTreeMap<String, Value>, Error> {
+		let Some(map) = self.map("NetworkScore", "AggregateScores") else {
+			return Ok(BTreeMap::new());
+		};
+		self.entries(&map, &[])?
+			.map(|entry| entry.map(|(_, key, value)| (key.to_string(), value)))
+			.collect()
+	}
+
+	fn map(&self, pallet: &str, item: &str) -> Option<StorageMap<'a>> {
+		let storage = self.metadata.pallets.iter().find(|p| p.name == pallet)?.storage.as_ref()?;
+		let entry = storage.entries.iter().find(|e| e.name == item)?;
+		match &entry.ty {
+			StorageEntryType::Map { hashers, key, value } => Some(StorageMap {
+				prefix: [twox_128(storage.prefix.as_bytes()), twox_128(item.as_bytes())].concat(),
+				hashers,
+				key_ty: key.id,
+				value_ty: value.id,
+			}),
+			StorageEntryType::Plain(_) => None,
+		}
+	}
+
+	/// The entries of `map` whose hashed key starts with `key_prefix`, along
+	/// with their hashed key.
+	fn entries<'b>(
+		&'b self,
+		map: &'b StorageMap<'a>,
+		key_prefix: &[u8],
+	) -> Result<impl Iterator<Item = Result<(Vec<u8>, Value, Value), Error>> + 'b, Error> {
+		let pairs = self.chain.storage_pairs(self.at, &[&map.prefix[..], key_prefix].concat())?;
+		Ok(pairs.map(move |(key, value)| {
+			let key = key[map.prefix.len()..].to_vec();
+			let decoded = self
+				.values
+				.decode_storage_key(map.hashers, map.key_ty, &key)
+				.map_err(Error::NotFound)?;
+			let value =
+				self.values.decode(map.value_ty, &mut &value[..]).map_err(Error::NotFound)?;
+			Ok((key, simplify_identifiers(decoded), simplify_identifiers(value)))
+		}))
+	}
+
+	/// The value of `pallet.item` under the given hashed key.
+	fn get(&self, pallet: &str, item: &str, key: &[u8]) -> Result<Option<Value>, Error> {
+		let Some(map) = self.map(pallet, item) else { return Ok(None) };
+		let Some(raw) = self.chain.storage(self.at, &[&map.prefix[..], key].concat())? else {
+			return Ok(None);
+		};
+		let value = self.values.decode(map.value_ty, &mut &raw[..]).map_err(Error::NotFound)?;
+		Ok(Some(simplify_identifiers(value)))
+	}
+
+	/// The value of the entry of the double map `pallet.item` under the hashed
+	/// first key `prefix`, whose second key is `second`.
+	fn find(
+		&self,
+		pallet: &str,
+		item: &str,
+		prefix: &[u8],
+		second: &Value,
+	) -> Result<Option<Value>, Error> {
+		let Some(map) = self.map(pallet, item) else { return Ok(None) };
+		for entry in self.entries(&map, prefix)? {
+			let (_, key, value) = entry?;
+			if key.get(1) == Some(second) {
+				return Ok(Some(value));
+			}
+		}
+		Ok(None)
+	}
+}
+
+/// Writes the exported rows as JSON lines or CSV.
+pub(crate) struct RowWriter<W: Write> {
+	out: W,
+	format: ExportFormat,
+	/// CSV rows, kept until all of them are known, as the columns are those of
+	/// all the rows.
+	rows: Vec<Map<String, Value>>,
+}
+
+impl<W: Write> RowWriter<W> {
+	pub(crate) fn new(out: W, format: ExportFormat) -> Self {
+		Self { out, format, rows: Vec::new() }
+	}
+
+	fn write(&mut self, row: Map<String, Value>) -> Result<(), Error> {
+		match self.format {
+			ExportFormat::Jsonl => writeln!(self.out, "{}", Value::Object(row))?,
+			ExportFormat::Csv => self.rows.push(flatten(row)),
+		}
+		Ok(())
+	}
+
+	pub(crate) fn finish(mut self) -> Result<(), Error> {
+		if !self.rows.is_empty() {
+			let columns: BTreeSet<&String> = self.rows.iter().flat_map(|row| row.keys()).collect();
+			let header: Vec<_> = columns.iter().map(|c| csv_cell(c)).collect();
+			writeln!(self.out, "{}", header.join(","))?;
+			for row in &self.rows {
+				let cells: Vec<_> = columns
+					.iter()
+					.map(|column| match row.get(*column) {
+						None | Some(Value::Null) => String::new(),
+						Some(Value::String(text)) => csv_cell(text),
+						Some(value) => csv_cell(&value.to_string()),
+					})
+					.collect();
+				writeln!(self.out, "{}", cells.join(","))?;
+			}
+		}
+		self.out.flush()?;
+		Ok(())
+	}
+}
+
+/// DID names are ASCII, show them as text rather than hex.
+fn name_value(value: Value) -> Value {
+	let text = value
+		.as_str()
+		.and_then(|name| name.strip_prefix("0x"))
+		.and_then(|name| hex::decode(name).ok())
+		.and_then(|name| String::from_utf8(name).ok());
+	text.map_or(value, Value::String)
+}
+
+/// Flatten nested objects into `parent.child` columns.
+fn flatten(row: Map<String, Value>) -> Map<String, Value> {
+	let mut flat = Map::new();
+	for (column, value) in row {
+		match value {
+			Value::Object(fields) =>
+				for (field, value) in flatten(fields) {
+					flat.insert(format!("{column}.{field}"), value);
+				},
+			value => {
+				flat.insert(column, value);
+			},
+		}
+	}
+	flat
+}
+
+fn csv_cell(text: &str) -> String {
+	if text.contains(&[',', '"', '\n', '\r'][..]) {
+		format!("\"{}\"", text.replace('"', "\"\""))
+	} else {
+		text.to_string()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn row(value: Value) -> Map<String, Value> {
+		match value {
+			Value::Object(map) => map,
+			_ => unreachable!(),
+		}
+	}
+
+	#[test]
+	fn should_simplify_identifiers() {
+		let value = json!({
+			"space": { "identifier": "s3abc", "type": "Space" },
+			"delegates": [{ "identifier": "a3xyz", "type": null }],
+			"details": { "identifier": "s3abc", "type": "Space", "extra": 1 },
+		});
+
+		assert_eq!(
+			simplify_identifiers(value),
+			json!({
+				"space": "s3abc",
+				"delegates": ["a3xyz"],
+				"details": { "identifier": "s3abc", "type": "Space", "extra": 1 },
+			})
+		);
+	}
+
+	#[test]
+	fn should_filter_on_space_and_creator() {
+		let statement = row(json!({ "space": "s3abc", "creator": "5Grw" }));
+		let by_space = ExportFilter { space: Some("s3abc".into()), creator: None };
+		let by_creator = ExportFilter { space: None, creator: Some("5Fhe".into()) };
+
+		assert!(ExportFilter::default().matches(Registry::Statements, &statement));
+		assert!(by_space.matches(Registry::Statements, &statement));
+		assert!(!by_creator.matches(Registry::Statements, &statement));
+		assert!(!by_space.matches(Registry::Dids, &row(json!({ "did": "5Grw" }))));
+	}
+
+	#[test]
+	fn should_write_csv_rows() {
+		let mut out = Vec::new();
+		let mut writer = RowWriter::new(&mut out, ExportFormat::Csv);
+		writer
+			.write(row(json!({ "entry": { "note": "x,\"y\"" }, "id": "a", "keys": [1, 2] })))
+			.unwrap();
+		writer
+			.write(row(json!({ "entry": { "note": null }, "id": "b", "keys": [] })))
+			.unwrap();
+		writer.write(row(json!({ "entry": { "tag": 1 }, "id": "c" }))).unwrap();
+		writer.finish().unwrap();
+
+		// The columns are those of all the rows, not only of the first one.
+		assert_eq!(
+			String::from_utf8(out).unwrap(),
+			"entry.note,entry.tag,id,keys\n\"x,\"\"y\"\"\",,a,\"[1,2]\"\n,,b,[]\n,1,c,\n"
+		);
+	}
+
+	#[test]
+	fn should_show_did_names_as_text() {
+		assert_eq!(name_value(json!(format!("0x{}", hex::encode("alice")))), json!("alice"));
+		assert_eq!(name_value(json!("0xff")), json!("0xff"));
+	}
+}
@@ -20,23 +20,36 @@
 //! info about blocks and extrinsics.
 //!
 //! The blocks and extrinsics can either be retrieved from the database
-//! (on-chain), or a raw SCALE-encoding can be provided.
+//! (on-chain), or a raw SCALE-encoding can be provided. Besides the raw
+//! `Debug` output, the [`CordPrinter`] decodes them with the runtime metadata
+//! into readable text or JSON.
+//!
+//! The registries kept in the state can also be exported as JSON lines or CSV,
+//! see [`export`].
 
 #![warn(missing_docs)]
 #![warn(unused_crate_dependencies)]
 
 pub mod cli;
 pub mod command;
+pub mod decode;
+pub mod export;
+mod printer;
+pub mod timeline;
+
+pub use printer::{read_events, CordPrinter, MetadataCache};
 
 use codec::{Decode, Encode};
-use sc_client_api::BlockBackend;
+use export::{ExportFilter, ExportFormat, Registry};
+use sc_client_api::{BlockBackend, CallExecutor, StorageProvider};
 use sp_blockchain::HeaderBackend;
-use sp_core::hexdisplay::HexDisplay;
+use sp_core::{hexdisplay::HexDisplay, storage::StorageKey, traits::CallContext};
 use sp_runtime::{
 	generic::BlockId,
 	traits::{Block, Hash, HashingFor, NumberFor},
 };
-use std::{fmt, fmt::Debug, marker::PhantomData, str::FromStr};
+use std::{fmt, fmt::Debug, io::Write, marker::PhantomData, str::FromStr};
+use timeline::{IdentifierDetails, IdentifierReport};
 
 /// A helper type for a generic block input.
 pub type BlockAddressFor<TBlock> =
@@ -45,24 +58,53 @@ pub type BlockAddressFor<TBlock> =
 /// A Pretty formatter implementation.
 pub trait PrettyPrinter<TBlock: Block> {
 	/// Nicely format block.
-	fn fmt_block(&self, fmt: &mut fmt::Formatter, block: &TBlock) -> fmt::Result;
-	/// Nicely format extrinsic.
-	fn fmt_extrinsic(&self, fmt: &mut fmt::Formatter, extrinsic: &TBlock::Extrinsic)
-		-> fmt::Result;
+	fn fmt_block(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		block: &TBlock,
+	) -> fmt::Result;
+	/// Nicely format extrinsic, optionally located at the given index of a
+	/// block.
+	fn fmt_extrinsic(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		extrinsic: &TBlock::Extrinsic,
+		location: Option<(&TBlock::Header, usize)>,
+	) -> fmt::Result;
+	/// Nicely format a decoded identifier with its timelines.
+	fn fmt_identifier(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		report: &IdentifierReport<TBlock::Hash>,
+	) -> fmt::Result;
 }
 
 /// Default dummy debug printer.
 #[derive(Default)]
 pub struct DebugPrinter;
 impl<TBlock: Block> PrettyPrinter<TBlock> for DebugPrinter {
-	fn fmt_block(&self, fmt: &mut fmt::Formatter, block: &TBlock) -> fmt::Result {
+	fn fmt_block(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		block: &TBlock,
+	) -> fmt::Result {
 		writeln!(fmt, "Header:")?;
 		writeln!(fmt, "{:?}", block.header())?;
 		writeln!(fmt, "Block bytes: {:?}", HexDisplay::from(&block.encode()))?;
 		writeln!(fmt, "Extrinsics ({})", block.extrinsics().len())?;
 		for (idx, ex) in block.extrinsics().iter().enumerate() {
 			writeln!(fmt, "- {}:", idx)?;
-			<DebugPrinter as PrettyPrinter<TBlock>>::fmt_extrinsic(self, fmt, ex)?;
+			<DebugPrinter as PrettyPrinter<TBlock>>::fmt_extrinsic(
+				self,
+				fmt,
+				chain,
+				ex,
+				Some((block.header(), idx)),
+			)?;
 		}
 		Ok(())
 	}
@@ -70,12 +112,23 @@ impl<TBlock: Block> PrettyPrinter<TBlock> for DebugPrinter {
 	fn fmt_extrinsic(
 		&self,
 		fmt: &mut fmt::Formatter,
+		_chain: &dyn ChainAccess<TBlock>,
 		extrinsic: &TBlock::Extrinsic,
+		_location: Option<(&TBlock::Header, usize)>,
 	) -> fmt::Result {
 		writeln!(fmt, " {:#?}", extrinsic)?;
 		writeln!(fmt, " Bytes: {:?}", HexDisplay::from(&extrinsic.encode()))?;
 		Ok(())
 	}
+
+	fn fmt_identifier(
+		&self,
+		fmt: &mut fmt::Formatter,
+		_chain: &dyn ChainAccess<TBlock>,
+		report: &IdentifierReport<TBlock::Hash>,
+	) -> fmt::Result {
+		writeln!(fmt, "{:#?}", report)
+	}
 }
 
 /// Aggregated error for `Inspector` operations.
@@ -90,15 +143,69 @@ pub enum Error {
 	/// Given block has not been found.
 	#[error("{0}")]
 	NotFound(String),
+	/// Could not write the output.
+	#[error(transparent)]
+	Io(#[from] std::io::Error),
+}
+
+/// A helper trait to read the runtime state at a block.
+pub trait StateAccess<TBlock: Block> {
+	/// SCALE encoded runtime metadata (V15) at the given block.
+	fn metadata(&self, at: TBlock::Hash) -> Result<Vec<u8>, Error>;
+	/// Raw storage value under `key` at the given block.
+	fn storage(&self, at: TBlock::Hash, key: &[u8]) -> Result<Option<Vec<u8>>, Error>;
+	/// Raw storage keys and values under `prefix` at the given block.
+	fn storage_pairs<'a>(
+		&'a self,
+		at: TBlock::Hash,
+		prefix: &[u8],
+	) -> Result<Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a>, Error>;
 }
 
-/// A helper trait to access block headers and bodies.
-pub trait ChainAccess<TBlock: Block>: HeaderBackend<TBlock> + BlockBackend<TBlock> {}
+impl<B, E, TBlock, RA> StateAccess<TBlock> for sc_service::client::Client<B, E, TBlock, RA>
+where
+	B: sc_client_api::Backend<TBlock>,
+	E: CallExecutor<TBlock>,
+	TBlock: Block,
+{
+	fn metadata(&self, at: TBlock::Hash) -> Result<Vec<u8>, Error> {
+		let result = self.executor().call(
+			at,
+			"Metadata_metadata_at_version",
+			&15u32.encode(),
+			CallContext::Offchain,
+		)?;
+		Option::<Vec<u8>>::decode(&mut &result[..])?
+			.ok_or_else(|| Error::NotFound(format!("Metadata V15 is not available at {:?}", at)))
+	}
+
+	fn storage(&self, at: TBlock::Hash, key: &[u8]) -> Result<Option<Vec<u8>>, Error> {
+		Ok(StorageProvider::storage(self, at, &StorageKey(key.to_vec()))?.map(|data| data.0))
+	}
+
+	fn storage_pairs<'a>(
+		&'a self,
+		at: TBlock::Hash,
+		prefix: &[u8],
+	) -> Result<Box<dyn Iterator<Item = (Vec<u8>, Vec<u8>)> + 'a>, Error> {
+		let pairs =
+			StorageProvider::storage_pairs(self, at, Some(&StorageKey(prefix.to_vec())), None)?;
+		Ok(Box::new(pairs.map(|(key, data)| (key.0, data.0))))
+	}
+}
+
+/// A helper trait to access block headers, bodies and state.
+pub trait ChainAccess<TBlock: Block>:
+	HeaderBackend<TBlock> + BlockBackend<TBlock> + StateAccess<TBlock>
+{
+}
 
 impl<T, TBlock> ChainAccess<TBlock> for T
 where
 	TBlock: Block,
-	T: sp_blockchain::HeaderBackend<TBlock> + sc_client_api::BlockBackend<TBlock>,
+	T: sp_blockchain::HeaderBackend<TBlock>
+		+ sc_client_api::BlockBackend<TBlock>
+		+ StateAccess<TBlock>,
 {
 }
 
@@ -125,15 +232,15 @@ impl<TBlock: Block, TPrinter: PrettyPrinter<TBlock>> Inspector<TBlock, TPrinter>
 
 	/// Get a pretty-printed block.
 	pub fn block(&self, input: BlockAddressFor<TBlock>) -> Result<String, Error> {
-		struct BlockPrinter<'a, A, B>(A, &'a B);
+		struct BlockPrinter<'a, A: Block, B>(A, &'a B, &'a dyn ChainAccess<A>);
 		impl<'a, A: Block, B: PrettyPrinter<A>> fmt::Display for BlockPrinter<'a, A, B> {
 			fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-				self.1.fmt_block(fmt, &self.0)
+				self.1.fmt_block(fmt, self.2, &self.0)
 			}
 		}
 
 		let block = self.get_block(input)?;
-		Ok(format!("{}", BlockPrinter(block, &self.printer)))
+		Ok(format!("{}", BlockPrinter(block, &self.printer, &*self.chain)))
 	}
 
 	fn get_block(&self, input: BlockAddressFor<TBlock>) -> Result<TBlock, Error> {
@@ -169,27 +276,113 @@ impl<TBlock: Block, TPrinter: PrettyPrinter<TBlock>> Inspector<TBlock, TPrinter>
 		&self,
 		input: ExtrinsicAddress<<HashingFor<TBlock> as Hash>::Output, NumberFor<TBlock>>,
 	) -> Result<String, Error> {
-		struct ExtrinsicPrinter<'a, A: Block, B>(A::Extrinsic, &'a B);
+		struct ExtrinsicPrinter<'a, A: Block, B>(
+			A::Extrinsic,
+			Option<(A::Header, usize)>,
+			&'a B,
+			&'a dyn ChainAccess<A>,
+		);
 		impl<'a, A: Block, B: PrettyPrinter<A>> fmt::Display for ExtrinsicPrinter<'a, A, B> {
 			fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
-				self.1.fmt_extrinsic(fmt, &self.0)
+				let location = self.1.as_ref().map(|(header, index)| (header, *index));
+				self.2.fmt_extrinsic(fmt, self.3, &self.0, location)
 			}
 		}
 
-		let ext = match input {
+		let (ext, location) = match input {
 			ExtrinsicAddress::Block(block, index) => {
 				let block = self.get_block(block)?;
-				block.extrinsics().get(index).cloned().ok_or_else(|| {
+				let ext = block.extrinsics().get(index).cloned().ok_or_else(|| {
 					Error::NotFound(format!(
 						"Could not find extrinsic {} in block {:?}",
 						index, block
 					))
-				})?
+				})?;
+				(ext, Some((block.header().clone(), index)))
 			},
-			ExtrinsicAddress::Bytes(bytes) => TBlock::Extrinsic::decode(&mut &*bytes)?,
+			ExtrinsicAddress::Bytes(bytes) => (TBlock::Extrinsic::decode(&mut &*bytes)?, None),
 		};
 
-		Ok(format!("{}", ExtrinsicPrinter(ext, &self.printer)))
+		Ok(format!("{}", ExtrinsicPrinter(ext, location, &self.printer, &*self.chain)))
+	}
+
+	/// Get a pretty-printed identifier along with its timelines at the given
+	/// block, or the best block.
+	///
+	/// With `rebuild`, the entries dropped from a truncated on-chain history
+	/// are recovered by scanning the blocks.
+	pub fn identifier(
+		&self,
+		input: &str,
+		at: Option<BlockAddressFor<TBlock>>,
+		rebuild: bool,
+	) -> Result<String, Error> {
+		struct IdentifierPrinter<'a, A: Block, B>(
+			IdentifierReport<A::Hash>,
+			&'a B,
+			&'a dyn ChainAccess<A>,
+		);
+		impl<'a, A: Block, B: PrettyPrinter<A>> fmt::Display for IdentifierPrinter<'a, A, B> {
+			fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+				self.1.fmt_identifier(fmt, self.2, &self.0)
+			}
+		}
+
+		let details = IdentifierDetails::decode(input).map_err(Error::NotFound)?;
+		let at = self.state_at(at)?;
+
+		let cache = MetadataCache::default();
+		let mut timelines =
+			timeline::read_timelines(&*self.chain, &cache, &details.identifier, at)?;
+		if rebuild {
+			for history in timelines.iter_mut().filter(|t| t.truncated) {
+				history.recovered = Some(timeline::recover_timeline(
+					&*self.chain,
+					&cache,
+					&details.identifier,
+					history,
+				)?);
+			}
+		}
+
+		let report = IdentifierReport { details, at, timelines };
+		Ok(format!("{}", IdentifierPrinter(report, &self.printer, &*self.chain)))
+	}
+
+	/// Export the given registries at the given block, or the best block, to
+	/// `out`. Returns the number of rows written for each registry, `None` for
+	/// the registries the runtime does not have.
+	pub fn export(
+		&self,
+		registries: &[Registry],
+		at: Option<BlockAddressFor<TBlock>>,
+		filter: &ExportFilter,
+		format: ExportFormat,
+		out: impl Write,
+	) -> Result<Vec<(Registry, Option<usize>)>, Error> {
+		let at = self.state_at(at)?;
+		let metadata = MetadataCache::default().get(&*self.chain, at).map_err(Error::NotFound)?;
+		let exporter = export::Exporter::new(&*self.chain, &metadata, at);
+
+		let mut writer = export::RowWriter::new(out, format);
+		let mut written = Vec::new();
+		for registry in registries {
+			written.push((*registry, exporter.export(*registry, filter, &mut writer)?));
+		}
+		writer.finish()?;
+		Ok(written)
+	}
+
+	/// The hash of the block to read the state at, the best block by default.
+	fn state_at(&self, at: Option<BlockAddressFor<TBlock>>) -> Result<TBlock::Hash, Error> {
+		Ok(match at {
+			None => self.chain.info().best_hash,
+			Some(BlockAddress::Hash(hash)) => hash,
+			Some(BlockAddress::Number(number)) =>
+				self.chain.expect_block_hash_from_id(&BlockId::number(number))?,
+			Some(BlockAddress::Bytes(_)) =>
+				return Err(Error::NotFound("State can only be read at a known block".into())),
+		})
 	}
 }
 
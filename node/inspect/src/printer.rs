@@ -0,0 +1,610 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! A CORD aware pretty printer.
+//!
+//! Extrinsics and events are decoded with the metadata of the runtime the
+//! block was executed with. DID authorised calls are unwrapped, so the inner
+//! call is shown together with the signer's DID, and each extrinsic is
+//! listed with the events it emitted.
+
+use crate::{
+	decode::ValueDecoder,
+	timeline::{block_at, IdentifierReport},
+	ChainAccess, PrettyPrinter,
+};
+use codec::{Decode, Encode};
+use frame_metadata::{
+	v15::{RuntimeMetadataV15, StorageEntryType},
+	RuntimeMetadata, RuntimeMetadataPrefixed,
+};
+use serde_json::{json, Map, Value};
+use sp_runtime::traits::{Block, Hash, HashingFor, Header, UniqueSaturatedInto};
+use std::{cell::RefCell, fmt, sync::Arc};
+
+/// The only extrinsic format version the printer understands.
+const EXTRINSIC_FORMAT_VERSION: u8 = 4;
+
+/// Pretty printer decoding CORD blocks and extrinsics with the runtime
+/// metadata, either as readable text or as JSON.
+#[derive(Default)]
+pub struct CordPrinter {
+	json: bool,
+	metadata: MetadataCache,
+}
+
+impl CordPrinter {
+	/// Printer producing readable text.
+	pub fn text() -> Self {
+		Self { json: false, metadata: Default::default() }
+	}
+
+	/// Printer producing JSON.
+	pub fn json() -> Self {
+		Self { json: true, metadata: Default::default() }
+	}
+
+	fn write(&self, fmt: &mut fmt::Formatter, value: Result<Value, String>) -> fmt::Result {
+		match (self.json, value) {
+			(true, Ok(value)) => {
+				writeln!(fmt, "{}", serde_json::to_string_pretty(&value).map_err(|_| fmt::Error)?)
+			},
+			(true, Err(e)) => writeln!(fmt, "{}", json!({ "error": e })),
+			(false, Ok(value)) => write_text(fmt, &value),
+			(false, Err(e)) => writeln!(fmt, "Could not decode: {e}"),
+		}
+	}
+}
+
+impl<TBlock: Block> PrettyPrinter<TBlock> for CordPrinter {
+	fn fmt_block(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		block: &TBlock,
+	) -> fmt::Result {
+		self.write(fmt, block_value(chain, &self.metadata, block))
+	}
+
+	fn fmt_extrinsic(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		extrinsic: &TBlock::Extrinsic,
+		location: Option<(&TBlock::Header, usize)>,
+	) -> fmt::Result {
+		self.write(fmt, extrinsic_value::<TBlock>(chain, &self.metadata, extrinsic, location))
+	}
+
+	fn fmt_identifier(
+		&self,
+		fmt: &mut fmt::Formatter,
+		chain: &dyn ChainAccess<TBlock>,
+		report: &IdentifierReport<TBlock::Hash>,
+	) -> fmt::Result {
+		self.write(fmt, Ok(identifier_report_value(chain, &self.metadata, report)))
+	}
+}
+
+/// Keeps the decoded metadata of the last used runtime, so scanning a range
+/// of blocks does not decode it again for each block.
+#[derive(Default)]
+pub struct MetadataCache {
+	last: RefCell<Option<(Option<Vec<u8>>, Arc<RuntimeMetadataV15>)>>,
+}
+
+impl MetadataCache {
+	/// Metadata of the runtime at the given block.
+	pub fn get<TBlock: Block>(
+		&self,
+		chain: &dyn ChainAccess<TBlock>,
+		at: TBlock::Hash,
+	) -> Result<Arc<RuntimeMetadataV15>, String> {
+		let key = [sp_core::twox_128(b"System"), sp_core::twox_128(b"LastRuntimeUpgrade")].concat();
+		let upgrade = chain.storage(at, &key).map_err(|e| e.to_string())?;
+		if let Some((last_upgrade, metadata)) = &*self.last.borrow() {
+			if *last_upgrade == upgrade {
+				return Ok(metadata.clone());
+			}
+		}
+
+		let raw = chain.metadata(at).map_err(|e| e.to_string())?;
+		let metadata =
+			match RuntimeMetadataPrefixed::decode(&mut &raw[..]).map_err(|e| e.to_string())?.1 {
+				RuntimeMetadata::V15(metadata) => Arc::new(metadata),
+				_ => return Err("Unsupported metadata version".into()),
+			};
+		*self.last.borrow_mut() = Some((upgrade, metadata.clone()));
+		Ok(metadata)
+	}
+}
+
+/// Runtime metadata along with the events of a block.
+pub(crate) struct BlockContext {
+	metadata: Arc<RuntimeMetadataV15>,
+	events: Vec<Value>,
+}
+
+impl BlockContext {
+	pub(crate) fn new<TBlock: Block>(
+		chain: &dyn ChainAccess<TBlock>,
+		cache: &MetadataCache,
+		header: Option<&TBlock::Header>,
+	) -> Result<Self, String> {
+		// The block is executed with the runtime of its parent.
+		let code_at = match header {
+			Some(header) if block_number::<TBlock>(header) > 0 => *header.parent_hash(),
+			Some(header) => header.hash(),
+			None => chain.info().best_hash,
+		};
+		let metadata = cache.get(chain, code_at)?;
+		let events = match header {
+			Some(header) => read_events(chain, &metadata, header.hash())?,
+			None => Vec::new(),
+		};
+		Ok(Self { metadata, events })
+	}
+
+	/// Events emitted while applying the extrinsic at `index`.
+	fn extrinsic_events(&self, index: usize) -> Vec<Value> {
+		self.events
+			.iter()
+			.filter(|record| record["phase"]["ApplyExtrinsic"] == json!(index))
+			.map(|record| call_value(record["event"].clone()))
+			.collect()
+	}
+
+	/// Events emitted on initialization and finalization of the block.
+	fn block_events(&self) -> Vec<Value> {
+		self.events
+			.iter()
+			.filter(|record| record["phase"].get("ApplyExtrinsic").is_none())
+			.map(|record| {
+				let mut event = call_value(record["event"].clone());
+				event["phase"] = record["phase"].clone();
+				event
+			})
+			.collect()
+	}
+}
+
+fn block_number<TBlock: Block>(header: &TBlock::Header) -> u64 {
+	(*header.number()).unique_saturated_into()
+}
+
+/// The decoded `System::Events` records of the block `at`, each with its
+/// `phase`, `event` and `topics`.
+pub fn read_events<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	metadata: &RuntimeMetadataV15,
+	at: TBlock::Hash,
+) -> Result<Vec<Value>, String> {
+	let Some(ty) = metadata
+		.pallets
+		.iter()
+		.find(|p| p.name == "System")
+		.and_then(|p| p.storage.as_ref())
+		.and_then(|s| s.entries.iter().find(|e| e.name == "Events"))
+		.and_then(|e| match &e.ty {
+			StorageEntryType::Plain(ty) => Some(ty.id),
+			_ => None,
+		})
+	else {
+		return Ok(Vec::new());
+	};
+
+	let key = [sp_core::twox_128(b"System"), sp_core::twox_128(b"Events")].concat();
+	// Blocks given as raw bytes are usually not part of the chain.
+	let Some(raw) = chain.storage(at, &key).ok().flatten() else { return Ok(Vec::new()) };
+
+	match ValueDecoder::new(&metadata.types).decode(ty, &mut &raw[..])? {
+		Value::Array(records) => Ok(records),
+		_ => Err("Unexpected events layout".into()),
+	}
+}
+
+fn block_value<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	cache: &MetadataCache,
+	block: &TBlock,
+) -> Result<Value, String> {
+	let header = block.header();
+	let context = BlockContext::new(chain, cache, Some(header))?;
+	let extrinsics = block
+		.extrinsics()
+		.iter()
+		.enumerate()
+		.map(|(index, ext)| {
+			decode_extrinsic::<TBlock>(&context, ext, Some(index))
+				.unwrap_or_else(|e| json!({ "index": index, "error": e }))
+		})
+		.collect::<Vec<_>>();
+
+	Ok(json!({
+		"number": block_number::<TBlock>(header),
+		"hash": format!("{:?}", header.hash()),
+		"parent_hash": format!("{:?}", header.parent_hash()),
+		"state_root": format!("{:?}", header.state_root()),
+		"extrinsics_root": format!("{:?}", header.extrinsics_root()),
+		"extrinsics": extrinsics,
+		"events": context.block_events(),
+	}))
+}
+
+fn extrinsic_value<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	cache: &MetadataCache,
+	extrinsic: &TBlock::Extrinsic,
+	location: Option<(&TBlock::Header, usize)>,
+) -> Result<Value, String> {
+	let context = BlockContext::new(chain, cache, location.map(|(header, _)| header))?;
+	decode_extrinsic::<TBlock>(&context, extrinsic, location.map(|(_, index)| index))
+}
+
+fn identifier_report_value<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	cache: &MetadataCache,
+	report: &IdentifierReport<TBlock::Hash>,
+) -> Value {
+	let details = &report.details;
+	let resolve = |height: u32, index: u32| -> Value {
+		let extrinsic =
+			block_at(chain, height)
+				.map_err(|e| e.to_string())
+				.and_then(|(header, extrinsics)| {
+					let context = BlockContext::new(chain, cache, Some(&header))?;
+					let ext = extrinsics
+						.get(index as usize)
+						.ok_or_else(|| format!("Block #{height} has no extrinsic {index}"))?;
+					decode_extrinsic::<TBlock>(&context, ext, Some(index as usize))
+				});
+		extrinsic.unwrap_or_else(|e| json!({ "index": index, "error": e }))
+	};
+
+	let timelines = report
+		.timelines
+		.iter()
+		.map(|timeline| {
+			let entries = timeline
+				.entries
+				.iter()
+				.map(|entry| {
+					json!({
+						"action": format!("{:?}", entry.action),
+						"height": entry.location.height,
+						"index": entry.location.index,
+						"extrinsic": resolve(entry.location.height, entry.location.index),
+					})
+				})
+				.collect::<Vec<_>>();
+			let mut value = json!({
+				"kind": format!("{:?}", timeline.kind),
+				"truncated": timeline.truncated,
+				"entries": entries,
+			});
+			if let Some(recovered) = &timeline.recovered {
+				value["recovered"] = recovered
+					.iter()
+					.map(|location| {
+						json!({
+							"height": location.height,
+							"index": location.index,
+							"extrinsic": resolve(location.height, location.index),
+						})
+					})
+					.collect();
+			}
+			value
+		})
+		.collect::<Vec<_>>();
+
+	json!({
+		"identifier": String::from_utf8_lossy(details.identifier.inner()),
+		"prefix": details.prefix,
+		"type": details.kind.as_ref().map(|kind| format!("{:?}", kind)),
+		"payload": format!("0x{}", hex::encode(&details.payload)),
+		"checksum": format!("0x{}", hex::encode(details.checksum)),
+		"checksum_valid": details.checksum_valid,
+		"at": format!("{:?}", report.at),
+		"timelines": timelines,
+	})
+}
+
+pub(crate) fn decode_extrinsic<TBlock: Block>(
+	context: &BlockContext,
+	extrinsic: &TBlock::Extrinsic,
+	index: Option<usize>,
+) -> Result<Value, String> {
+	let metadata = &context.metadata;
+	let decoder = ValueDecoder::new(&metadata.types);
+	let encoded = extrinsic.encode();
+	let bytes = Vec::<u8>::decode(&mut &encoded[..]).map_err(|e| e.to_string())?;
+	let input = &mut &bytes[..];
+
+	let version = u8::decode(input).map_err(|e| e.to_string())?;
+	if version & 0b0111_1111 != EXTRINSIC_FORMAT_VERSION {
+		return Err(format!("Unsupported extrinsic version {}", version & 0b0111_1111));
+	}
+
+	let mut value = Map::new();
+	if let Some(index) = index {
+		value.insert("index".into(), json!(index));
+	}
+	value.insert(
+		"hash".into(),
+		json!(format!("{:?}", <HashingFor<TBlock> as Hash>::hash(&encoded))),
+	);
+	value.insert("signed".into(), json!(version & 0b1000_0000 != 0));
+	if version & 0b1000_0000 != 0 {
+		let address = decoder.decode(metadata.extrinsic.address_ty.id, input)?;
+		let signature = decoder.decode(metadata.extrinsic.signature_ty.id, input)?;
+		let extra = decoder.decode(metadata.extrinsic.extra_ty.id, input)?;
+		// `MultiAddress::Id` is by far the most common address kind.
+		let signer = match address {
+			Value::Object(mut address) if address.len() == 1 && address.contains_key("Id") =>
+				address.remove("Id").unwrap_or_default(),
+			address => address,
+		};
+		value.insert("signer".into(), signer);
+		value.insert("signature".into(), signature);
+		value.insert("extra".into(), extra);
+	}
+
+	let call = call_value(decoder.decode(metadata.extrinsic.call_ty.id, input)?);
+	if !input.is_empty() {
+		return Err(format!("{} bytes left after decoding the call", input.len()));
+	}
+	match did_authorized_call(&call) {
+		Some((did, authorization, inner)) => {
+			value.insert("did".into(), did);
+			value.insert("call".into(), inner);
+			value.insert("did_authorization".into(), authorization);
+		},
+		None => {
+			value.insert("call".into(), call);
+		},
+	}
+	if let Some(index) = index {
+		value.insert("events".into(), json!(context.extrinsic_events(index)));
+	}
+	Ok(Value::Object(value))
+}
+
+/// Flatten a decoded `RuntimeCall` or `RuntimeEvent` into its pallet, name and
+/// arguments.
+fn call_value(value: Value) -> Value {
+	fn single(value: Value) -> Option<(String, Value)> {
+		match value {
+			Value::Object(map) if map.len() == 1 => map.into_iter().next(),
+			Value::String(name) => Some((name, Value::Null)),
+			_ => None,
+		}
+	}
+
+	match single(value.clone()).and_then(|(pallet, inner)| Some((pallet, single(inner)?))) {
+		Some((pallet, (name, args))) => json!({ "pallet": pallet, "name": name, "args": args }),
+		None => json!({ "pallet": Value::Null, "name": Value::Null, "args": value }),
+	}
+}
+
+/// Unwrap a `Did::submit_did_call`, returning the DID, the authorization
+/// details and the inner call.
+fn did_authorized_call(call: &Value) -> Option<(Value, Value, Value)> {
+	if call["pallet"] != "Did" || call["name"] != "submit_did_call" {
+		return None;
+	}
+	let mut operation = call["args"]["did_call"].as_object()?.clone();
+	let did = operation.remove("did")?;
+	let inner = call_value(operation.remove("call")?);
+	operation.insert("signature".into(), call["args"]["signature"].clone());
+	Some((did, Value::Object(operation), inner))
+}
+
+fn write_text(fmt: &mut fmt::Formatter, value: &Value) -> fmt::Result {
+	if value.get("extrinsics").is_some() {
+		writeln!(fmt, "Block #{} ({})", value["number"], inline(&value["hash"]))?;
+		for field in ["parent_hash", "state_root", "extrinsics_root"] {
+			writeln!(fmt, "  {}: {}", field, inline(&value[field]))?;
+		}
+		let extrinsics = value["extrinsics"].as_array().cloned().unwrap_or_default();
+		writeln!(fmt, "Extrinsics ({})", extrinsics.len())?;
+		for extrinsic in &extrinsics {
+			write_extrinsic(fmt, extrinsic, 0)?;
+		}
+		let events = value["events"].as_array().cloned().unwrap_or_default();
+		if !events.is_empty() {
+			writeln!(fmt, "Block events ({})", events.len())?;
+			for event in &events {
+				writeln!(fmt, "- {} [{}]", title(event), inline(&event["phase"]))?;
+				write_value(fmt, &event["args"], 4)?;
+			}
+		}
+		Ok(())
+	} else if value.get("timelines").is_some() {
+		write_identifier(fmt, value)
+	} else {
+		write_extrinsic(fmt, value, 0)
+	}
+}
+
+fn write_identifier(fmt: &mut fmt::Formatter, value: &Value) -> fmt::Result {
+	writeln!(fmt, "Identifier {}", inline(&value["identifier"]))?;
+	writeln!(fmt, "  prefix: {} ({})", value["prefix"], inline(&value["type"]))?;
+	writeln!(fmt, "  payload: {}", inline(&value["payload"]))?;
+	let validity = if value["checksum_valid"] == true { "valid" } else { "invalid" };
+	writeln!(fmt, "  checksum: {} ({validity})", inline(&value["checksum"]))?;
+	writeln!(fmt, "  at: {}", inline(&value["at"]))?;
+
+	let timelines = value["timelines"].as_array().cloned().unwrap_or_default();
+	if timelines.is_empty() {
+		writeln!(fmt, "No timeline found")?;
+	}
+	for timeline in &timelines {
+		let entries = timeline["entries"].as_array().cloned().unwrap_or_default();
+		let truncated = if timeline["truncated"] == true { ", truncated" } else { "" };
+		writeln!(
+			fmt,
+			"Timeline {} ({} entries{truncated})",
+			inline(&timeline["kind"]),
+			entries.len()
+		)?;
+		for entry in &entries {
+			writeln!(
+				fmt,
+				"- {} at #{}:{}",
+				inline(&entry["action"]),
+				entry["height"],
+				entry["index"]
+			)?;
+			write_extrinsic(fmt, &entry["extrinsic"], 2)?;
+		}
+		if let Some(recovered) = timeline["recovered"].as_array() {
+			writeln!(fmt, "Recovered from blocks ({})", recovered.len())?;
+			for entry in recovered {
+				writeln!(fmt, "- #{}:{}", entry["height"], entry["index"])?;
+				write_extrinsic(fmt, &entry["extrinsic"], 2)?;
+			}
+		}
+	}
+	Ok(())
+}
+
+fn write_extrinsic(fmt: &mut fmt::Formatter, ext: &Value, indent: usize) -> fmt::Result {
+	let pad = " ".repeat(indent);
+	let index = ext.get("index").map(|i| format!("{i}: ")).unwrap_or_default();
+	if let Some(error) = ext.get("error") {
+		return writeln!(fmt, "{pad}- {index}could not decode: {}", inline(error));
+	}
+	let origin = match (ext.get("signer"), ext.get("did")) {
+		(Some(signer), Some(did)) => format!("signed by {}, DID {}", inline(signer), inline(did)),
+		(Some(signer), None) => format!("signed by {}", inline(signer)),
+		_ => "unsigned".into(),
+	};
+	writeln!(fmt, "{pad}- {index}{} ({origin})", title(&ext["call"]))?;
+	writeln!(fmt, "{pad}    hash: {}", inline(&ext["hash"]))?;
+	write_value(fmt, &ext["call"]["args"], indent + 4)?;
+	if let Some(authorization) = ext.get("did_authorization") {
+		writeln!(fmt, "{pad}    did authorization:")?;
+		write_value(fmt, authorization, indent + 6)?;
+	}
+	if let Some(events) = ext["events"].as_array() {
+		writeln!(fmt, "{pad}    events ({}):", events.len())?;
+		for event in events {
+			writeln!(fmt, "{pad}    - {}", title(event))?;
+			write_value(fmt, &event["args"], indent + 8)?;
+		}
+	}
+	Ok(())
+}
+
+fn title(call: &Value) -> String {
+	match (call["pallet"].as_str(), call["name"].as_str()) {
+		(Some(pallet), Some(name)) => format!("{pallet}.{name}"),
+		_ => "<unknown>".into(),
+	}
+}
+
+/// Single line rendering of scalars, identifiers and empty collections.
+fn inline(value: &Value) -> String {
+	match value {
+		Value::Null => "-".into(),
+		Value::String(s) => s.clone(),
+		Value::Object(map) if map.is_empty() => "{}".into(),
+		Value::Array(items) if items.is_empty() => "[]".into(),
+		Value::Object(map) if map.len() == 2 && map.contains_key("identifier") => {
+			match &map["type"] {
+				Value::String(kind) => format!("{} ({kind})", inline(&map["identifier"])),
+				_ => inline(&map["identifier"]),
+			}
+		},
+		Value::Object(map) if map.len() == 1 => {
+			let (key, inner) = map.iter().next().expect("map has one entry; qed");
+			if is_inline(inner) {
+				format!("{key}({})", inline(inner))
+			} else {
+				value.to_string()
+			}
+		},
+		other => other.to_string(),
+	}
+}
+
+fn is_inline(value: &Value) -> bool {
+	match value {
+		Value::Object(map) =>
+			map.is_empty() ||
+				(map.len() == 2 && map.contains_key("identifier")) ||
+				(map.len() == 1 && map.values().all(is_inline)),
+		Value::Array(items) => items.is_empty(),
+		_ => true,
+	}
+}
+
+fn write_value(fmt: &mut fmt::Formatter, value: &Value, indent: usize) -> fmt::Result {
+	let pad = " ".repeat(indent);
+	match value {
+		Value::Null => Ok(()),
+		Value::Object(map) if !is_inline(value) => map.iter().try_for_each(|(key, inner)| {
+			if is_inline(inner) {
+				writeln!(fmt, "{pad}{key}: {}", inline(inner))
+			} else {
+				writeln!(fmt, "{pad}{key}:")?;
+				write_value(fmt, inner, indent + 2)
+			}
+		}),
+		Value::Array(items) if !items.is_empty() => items.iter().try_for_each(|item| {
+			if is_inline(item) {
+				writeln!(fmt, "{pad}- {}", inline(item))
+			} else {
+				writeln!(fmt, "{pad}-")?;
+				write_value(fmt, item, indent + 2)
+			}
+		}),
+		other => writeln!(fmt, "{pad}{}", inline(other)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_unwrap_did_authorized_calls() {
+		let call = call_value(json!({ "Did": { "submit_did_call": {
+			"did_call": {
+				"did": "3x9did",
+				"tx_counter": 3,
+				"call": { "Statement": { "register": { "digest": "0x01" } } },
+				"block_number": 10,
+				"submitter": "3xsubmitter",
+			},
+			"signature": { "Sr25519": "0x02" },
+		}}}));
+
+		let (did, authorization, inner) = did_authorized_call(&call).unwrap();
+
+		assert_eq!(did, json!("3x9did"));
+		assert_eq!(
+			inner,
+			json!({ "pallet": "Statement", "name": "register", "args": { "digest": "0x01" } })
+		);
+		assert_eq!(authorization["tx_counter"], json!(3));
+		assert_eq!(authorization["signature"], json!({ "Sr25519": "0x02" }));
+		assert!(did_authorized_call(&call_value(json!({ "System": "remark" }))).is_none());
+	}
+}
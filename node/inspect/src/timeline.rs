@@ -0,0 +1,273 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Decoding of `Ss58Identifier`s and their on-chain timelines.
+
+use crate::{
+	printer::{decode_extrinsic, BlockContext, MetadataCache},
+	ChainAccess, Error,
+};
+use codec::{Decode, Encode};
+use frame_metadata::v15::RuntimeMetadataV15;
+use identifier::{
+	CordIdentifierType, EventEntryOf, IdentifierType, IdentifierTypeOf, Ss58Identifier, Timepoint,
+};
+use serde_json::Value;
+use sp_runtime::traits::Block;
+
+/// All the timelines an identifier may have an entry in.
+const TIMELINE_KINDS: [IdentifierTypeOf; 15] = [
+	IdentifierTypeOf::Asset,
+	IdentifierTypeOf::Auth,
+	IdentifierTypeOf::ChainSpace,
+	IdentifierTypeOf::Did,
+	IdentifierTypeOf::Rating,
+	IdentifierTypeOf::Registry,
+	IdentifierTypeOf::Statement,
+	IdentifierTypeOf::Schema,
+	IdentifierTypeOf::Template,
+	IdentifierTypeOf::Registries,
+	IdentifierTypeOf::Entries,
+	IdentifierTypeOf::RegistryAuthorization,
+	IdentifierTypeOf::SchemaAccounts,
+	IdentifierTypeOf::NameSpace,
+	IdentifierTypeOf::NameSpaceAuthorization,
+];
+
+/// The parts of a decoded `Ss58Identifier`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifierDetails {
+	/// The identifier as given.
+	pub identifier: Ss58Identifier,
+	/// The identifier prefix, encoding the identifier type.
+	pub prefix: u16,
+	/// The identifier type, if the prefix is a known one.
+	pub kind: Option<IdentifierType>,
+	/// The digest the identifier was created from.
+	pub payload: Vec<u8>,
+	/// The checksum at the end of the identifier.
+	pub checksum: [u8; 2],
+	/// Whether the checksum matches the prefix and payload.
+	pub checksum_valid: bool,
+}
+
+impl IdentifierDetails {
+	/// Decode the given base58 identifier.
+	pub fn decode(input: &str) -> Result<Self, String> {
+		let identifier = Ss58Identifier::decode(&mut &input.as_bytes().encode()[..])
+			.map_err(|_| "Identifier is too long".to_string())?;
+		let prefix = identifier
+			.get_identifier_type()
+			.map_err(|e| format!("Invalid identifier: {:?}", e))?;
+		let data = bs58::decode(input).into_vec().map_err(|e| e.to_string())?;
+		let prefix_len = if prefix < 64 { 1 } else { 2 };
+		if data.len() < prefix_len + 2 {
+			return Err("Invalid identifier: too short".into());
+		}
+
+		let (body, checksum) = data.split_at(data.len() - 2);
+		let checksum = [checksum[0], checksum[1]];
+		let checksum_valid = Ss58Identifier::ss58hash(body).as_bytes()[0..2] == checksum;
+		Ok(Self {
+			kind: identifier.get_type().ok(),
+			identifier,
+			prefix,
+			payload: body[prefix_len..].to_vec(),
+			checksum,
+			checksum_valid,
+		})
+	}
+}
+
+/// The entries of an identifier in one of its timelines.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Timeline {
+	/// The kind of timeline.
+	pub kind: IdentifierTypeOf,
+	/// The entries kept on chain.
+	pub entries: Vec<EventEntryOf>,
+	/// Whether older entries have been dropped from the on-chain history.
+	pub truncated: bool,
+	/// Locations of extrinsics touching the identifier in the dropped part of
+	/// the history, if it was rebuilt from the blocks.
+	pub recovered: Option<Vec<Timepoint>>,
+}
+
+/// A decoded identifier along with its timelines at a block.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifierReport<Hash> {
+	/// The decoded identifier.
+	pub details: IdentifierDetails,
+	/// The block the timelines were read at.
+	pub at: Hash,
+	/// All the timelines the identifier has entries in.
+	pub timelines: Vec<Timeline>,
+}
+
+/// Read the timelines of `identifier` at the given block.
+pub(crate) fn read_timelines<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	cache: &MetadataCache,
+	identifier: &Ss58Identifier,
+	at: TBlock::Hash,
+) -> Result<Vec<Timeline>, Error> {
+	// Without the metadata the timelines are still readable, only their
+	// truncation can not be detected.
+	let max_history = cache.get(chain, at).ok().and_then(|metadata| max_events_history(&metadata));
+
+	let mut timelines = Vec::new();
+	for kind in TIMELINE_KINDS {
+		if let Some(raw) = chain.storage(at, &timeline_key(identifier, kind))? {
+			let entries = Vec::<EventEntryOf>::decode(&mut &raw[..])?;
+			let truncated = max_history.map_or(false, |max| entries.len() >= max as usize);
+			timelines.push(Timeline { kind, entries, truncated, recovered: None });
+		}
+	}
+	Ok(timelines)
+}
+
+/// Find the extrinsics touching `identifier` in the part of `timeline` that
+/// was dropped from the on-chain history.
+///
+/// The first entry is always kept on chain, the dropped ones are those
+/// between it and the second entry.
+pub(crate) fn recover_timeline<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	cache: &MetadataCache,
+	identifier: &Ss58Identifier,
+	timeline: &Timeline,
+) -> Result<Vec<Timepoint>, Error> {
+	let (Some(first), Some(second)) = (timeline.entries.first(), timeline.entries.get(1)) else {
+		return Ok(Vec::new());
+	};
+	let needle = String::from_utf8_lossy(identifier.inner()).into_owned();
+
+	let mut recovered = Vec::new();
+	for height in first.location.height..=second.location.height {
+		let (header, extrinsics) = block_at(chain, height)?;
+		let context = BlockContext::new(chain, cache, Some(&header)).map_err(Error::NotFound)?;
+		for (index, ext) in extrinsics.iter().enumerate() {
+			let location = Timepoint { height, index: index as u32 };
+			let key = (height, location.index);
+			if key <= (first.location.height, first.location.index) ||
+				key >= (second.location.height, second.location.index)
+			{
+				continue;
+			}
+			let value =
+				decode_extrinsic::<TBlock>(&context, ext, Some(index)).map_err(Error::NotFound)?;
+			if mentions(&value, &needle) {
+				recovered.push(location);
+			}
+		}
+	}
+	Ok(recovered)
+}
+
+/// The header and extrinsics of the block at `height`.
+pub(crate) fn block_at<TBlock: Block>(
+	chain: &dyn ChainAccess<TBlock>,
+	height: u32,
+) -> Result<(TBlock::Header, Vec<TBlock::Extrinsic>), Error> {
+	let not_found = || Error::NotFound(format!("Could not find block #{}", height));
+	let hash = chain.hash(height.into())?.ok_or_else(not_found)?;
+	let header = chain.header(hash)?.ok_or_else(not_found)?;
+	let body = chain.block_body(hash)?.ok_or_else(not_found)?;
+	Ok((header, body))
+}
+
+/// Storage key of the `Identifier::Identifiers` timeline of `kind`.
+fn timeline_key(identifier: &Ss58Identifier, kind: IdentifierTypeOf) -> Vec<u8> {
+	let identifier = identifier.encode();
+	let kind = kind.encode();
+	[
+		&sp_core::twox_128(b"Identifier")[..],
+		&sp_core::twox_128(b"Identifiers")[..],
+		&sp_core::blake2_128(&identifier)[..],
+		&identifier[..],
+		&sp_core::twox_64(&kind)[..],
+		&kind[..],
+	]
+	.concat()
+}
+
+/// The `MaxEventsHistory` constant of the identifier pallet.
+fn max_events_history(metadata: &RuntimeMetadataV15) -> Option<u32> {
+	let constant = metadata
+		.pallets
+		.iter()
+		.find(|p| p.name == "Identifier")?
+		.constants
+		.iter()
+		.find(|c| c.name == "MaxEventsHistory")?;
+	u32::decode(&mut &constant.value[..]).ok()
+}
+
+/// Whether the decoded value refers to the identifier anywhere.
+fn mentions(value: &Value, identifier: &str) -> bool {
+	match value {
+		Value::String(s) => s == identifier,
+		Value::Array(items) => items.iter().any(|v| mentions(v, identifier)),
+		Value::Object(map) => map.values().any(|v| mentions(v, identifier)),
+		_ => false,
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use identifier::IdentifierCreator;
+	use serde_json::json;
+
+	#[test]
+	fn should_decode_identifier_parts() {
+		let digest = [7u8; 32];
+		let id = Ss58Identifier::create_identifier(&digest, IdentifierType::Statement).unwrap();
+		let text = String::from_utf8(id.inner().to_vec()).unwrap();
+
+		let details = IdentifierDetails::decode(&text).unwrap();
+
+		assert_eq!(details.identifier, id);
+		assert_eq!(details.prefix, 8902);
+		assert_eq!(details.kind, Some(IdentifierType::Statement));
+		assert_eq!(details.payload, digest.to_vec());
+		assert!(details.checksum_valid);
+	}
+
+	#[test]
+	fn should_detect_bad_checksums() {
+		let id = Ss58Identifier::create_identifier(&[7u8; 32], IdentifierType::Space).unwrap();
+		let mut data = bs58::decode(id.inner()).into_vec().unwrap();
+		let last = data.len() - 1;
+		data[last] ^= 0xff;
+
+		let details = IdentifierDetails::decode(&bs58::encode(data).into_string()).unwrap();
+
+		assert_eq!(details.kind, Some(IdentifierType::Space));
+		assert!(!details.checksum_valid);
+		assert!(IdentifierDetails::decode("not-base58!").is_err());
+	}
+
+	#[test]
+	fn should_find_identifier_mentions() {
+		let value = json!({ "args": { "space_id": { "identifier": "s3abc", "type": "Space" } } });
+
+		assert!(mentions(&value, "s3abc"));
+		assert!(!mentions(&value, "s3abd"));
+	}
+}
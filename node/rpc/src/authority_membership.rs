@@ -0,0 +1,117 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the health of the authorities.
+
+use std::{marker::PhantomData, sync::Arc};
+
+pub use authority_membership_runtime_api::{
+	AuthorityHealth, AuthorityMembershipApi as AuthorityMembershipRuntimeApi, OffenceReport,
+};
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+/// Error code of a failed runtime call.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait AuthorityMembershipApi<BlockHash, ValidatorId> {
+	/// Returns the membership state, the pending changes, the blacklist
+	/// status and the recent offences of an authority.
+	#[method(name = "authorityMembership_authority")]
+	fn authority(
+		&self,
+		authority: ValidatorId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Option<AuthorityHealth<ValidatorId>>>;
+
+	/// Returns the health of the members, the current validators and the
+	/// blacklisted authorities.
+	#[method(name = "authorityMembership_authorities")]
+	fn authorities(&self, at: Option<BlockHash>) -> RpcResult<Vec<AuthorityHealth<ValidatorId>>>;
+
+	/// Returns the offence reports against an authority, including the
+	/// reporters and the time slots.
+	#[method(name = "authorityMembership_offenceReports")]
+	fn offence_reports(
+		&self,
+		authority: ValidatorId,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<OffenceReport<ValidatorId>>>;
+}
+
+/// Provides RPC methods to query the health of the authorities.
+pub struct AuthorityMembership<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> AuthorityMembership<C, Block> {
+	/// Creates a new instance of the AuthorityMembership RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block, ValidatorId> AuthorityMembershipApiServer<<Block as BlockT>::Hash, ValidatorId>
+	for AuthorityMembership<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: AuthorityMembershipRuntimeApi<Block, ValidatorId>,
+	ValidatorId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn authority(
+		&self,
+		authority: ValidatorId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Option<AuthorityHealth<ValidatorId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().authority(at, authority).map_err(runtime_error)
+	}
+
+	fn authorities(&self, at: Option<Block::Hash>) -> RpcResult<Vec<AuthorityHealth<ValidatorId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().authorities(at).map_err(runtime_error)
+	}
+
+	fn offence_reports(
+		&self,
+		authority: ValidatorId,
+		at: Option<Block::Hash>,
+	) -> RpcResult<Vec<OffenceReport<ValidatorId>>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		self.client.runtime_api().offence_reports(at, authority).map_err(runtime_error)
+	}
+}
+
+fn runtime_error(err: impl std::fmt::Debug) -> ErrorObjectOwned {
+	ErrorObject::owned(
+		RUNTIME_ERROR,
+		"Unable to query the authorities.",
+		Some(format!("{:?}", err)),
+	)
+}
@@ -0,0 +1,119 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the CORD events indexed by the node.
+
+pub use cord_node_indexer::{EventFilter, EventPage, EventRecord, IndexStatus, Indexer};
+use futures::{future, FutureExt, StreamExt};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+	PendingSubscriptionSink,
+};
+use sc_rpc::{utils::pipe_from_stream, SubscriptionTaskExecutor};
+use sp_core::offchain::OffchainStorage;
+
+/// Error code of an invalid filter.
+const INVALID_FILTER: i32 = 1;
+
+/// Number of records in a page when no limit is given.
+const DEFAULT_PAGE_SIZE: u32 = 100;
+
+#[rpc(client, server)]
+pub trait IndexApi {
+	/// Returns a page of the indexed events matching the filter, starting at
+	/// the record `from`. The page tells where the next one starts.
+	#[method(name = "index_events")]
+	fn events(
+		&self,
+		filter: EventFilter,
+		from: Option<u64>,
+		limit: Option<u32>,
+	) -> RpcResult<EventPage>;
+
+	/// Returns the last indexed block and the number of indexed events.
+	#[method(name = "index_status")]
+	fn status(&self) -> RpcResult<IndexStatus>;
+
+	/// Subscribes to the events matching the filter, as their blocks are
+	/// finalized and indexed.
+	#[subscription(
+		name = "index_subscribeEvents" => "index_event",
+		unsubscribe = "index_unsubscribeEvents",
+		item = EventRecord
+	)]
+	fn subscribe_events(&self, filter: EventFilter);
+}
+
+/// Provides RPC methods to query the indexed CORD events.
+pub struct Index<S> {
+	indexer: Indexer<S>,
+	executor: SubscriptionTaskExecutor,
+}
+
+impl<S> Index<S> {
+	/// Creates a new instance of the Index RPC handler.
+	pub fn new(indexer: Indexer<S>, executor: SubscriptionTaskExecutor) -> Self {
+		Self { indexer, executor }
+	}
+}
+
+impl<S: OffchainStorage + 'static> IndexApiServer for Index<S> {
+	fn events(
+		&self,
+		filter: EventFilter,
+		from: Option<u64>,
+		limit: Option<u32>,
+	) -> RpcResult<EventPage> {
+		let filter = filter.normalize().map_err(invalid_filter)?;
+		Ok(self.indexer.events(&filter, from, limit.unwrap_or(DEFAULT_PAGE_SIZE)))
+	}
+
+	fn status(&self) -> RpcResult<IndexStatus> {
+		Ok(self.indexer.status())
+	}
+
+	fn subscribe_events(&self, pending: PendingSubscriptionSink, filter: EventFilter) {
+		let filter = match filter.normalize() {
+			Ok(filter) => filter,
+			Err(e) => {
+				self.executor.spawn(
+					"cord-rpc-index-subscription",
+					Some("rpc"),
+					pending.reject(invalid_filter(e)).boxed(),
+				);
+				return;
+			},
+		};
+
+		let stream = self
+			.indexer
+			.subscribe()
+			.filter(move |record| future::ready(filter.matches(record)));
+		self.executor.spawn(
+			"cord-rpc-index-subscription",
+			Some("rpc"),
+			pipe_from_stream(pending, stream).boxed(),
+		);
+	}
+}
+
+fn invalid_filter(err: String) -> ErrorObjectOwned {
+	ErrorObject::owned(INVALID_FILTER, "Invalid event filter.", Some(err))
+}
@@ -20,11 +20,16 @@
 
 use std::sync::Arc;
 
+pub mod authority_membership;
+pub mod index;
+pub mod timeline;
+
 use cord_primitives::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
 use jsonrpsee::RpcModule;
 use sc_client_api::AuxStore;
 use sc_consensus_babe::BabeWorkerHandle;
 use sc_consensus_grandpa::FinalityProofProvider;
+use sc_consensus_manual_seal::EngineCommand;
 pub use sc_rpc::SubscriptionTaskExecutor;
 pub use sc_rpc_api::DenyUnsafe;
 use sc_transaction_pool_api::TransactionPool;
@@ -80,6 +85,22 @@ pub struct FullDeps<C, P, SC, B> {
 	pub backend: Arc<B>,
 }
 
+/// Dependencies of a development node sealing its blocks on demand.
+pub struct ManualSealDeps<C, P, B> {
+	/// The client instance to use.
+	pub client: Arc<C>,
+	/// Transaction pool instance.
+	pub pool: Arc<P>,
+	/// A copy of the chain spec.
+	pub chain_spec: Box<dyn sc_chain_spec::ChainSpec>,
+	/// Whether to deny unsafe calls
+	pub deny_unsafe: DenyUnsafe,
+	/// The backend used by the node.
+	pub backend: Arc<B>,
+	/// Channel the `engine_*` calls are sent to the sealing task through.
+	pub command_sink: futures::channel::mpsc::Sender<EngineCommand<Hash>>,
+}
+
 /// Instantiate all Full RPC extensions.
 pub fn create_full<C, P, SC, B>(
 	FullDeps {
@@ -106,11 +127,13 @@ where
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BabeApi<Block>,
 	C::Api: BlockBuilder<Block>,
+	C::Api: authority_membership::AuthorityMembershipRuntimeApi<Block, AccountId>,
 	P: TransactionPool + 'static,
 	SC: SelectChain<Block> + 'static,
 	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
 	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
 {
+	use authority_membership::{AuthorityMembership, AuthorityMembershipApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use sc_consensus_babe_rpc::{Babe, BabeApiServer};
 	use sc_consensus_grandpa_rpc::{Grandpa, GrandpaApiServer};
@@ -119,6 +142,7 @@ where
 	use sc_sync_state_rpc::{SyncState, SyncStateApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+	use timeline::{IdentifierApiServer, IdentifierTimeline};
 
 	let mut io = RpcModule::new(());
 
@@ -138,6 +162,7 @@ where
 
 	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
 	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(AuthorityMembership::new(client.clone()).into_rpc())?;
 
 	io.merge(
 		Babe::new(client.clone(), babe_worker_handle.clone(), keystore, select_chain, deny_unsafe)
@@ -158,6 +183,67 @@ where
 		SyncState::new(chain_spec, client.clone(), shared_authority_set, babe_worker_handle)?
 			.into_rpc(),
 	)?;
+	if let Some(storage) = backend.offchain_storage() {
+		io.merge(IdentifierTimeline::new(storage).into_rpc())?;
+	}
+	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
+	io.merge(Dev::new(client, deny_unsafe).into_rpc())?;
+
+	Ok(io)
+}
+
+/// Instantiate the RPC extensions of a development node sealing its blocks
+/// on demand.
+///
+/// The BABE and GRANDPA extensions are replaced by `engine_createBlock` and
+/// `engine_finalizeBlock`, as no consensus is running.
+pub fn create_manual_seal<C, P, B>(
+	ManualSealDeps { client, pool, chain_spec, deny_unsafe, backend, command_sink }: ManualSealDeps<
+		C,
+		P,
+		B,
+	>,
+) -> Result<RpcExtension, Box<dyn std::error::Error + Send + Sync>>
+where
+	C: ProvideRuntimeApi<Block>
+		+ sc_client_api::BlockBackend<Block>
+		+ HeaderBackend<Block>
+		+ HeaderMetadata<Block, Error = BlockChainError>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
+	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
+	C::Api: BlockBuilder<Block>,
+	C::Api: authority_membership::AuthorityMembershipRuntimeApi<Block, AccountId>,
+	P: TransactionPool + 'static,
+	B: sc_client_api::Backend<Block> + Send + Sync + 'static,
+	B::State: sc_client_api::backend::StateBackend<sp_runtime::traits::HashingFor<Block>>,
+{
+	use authority_membership::{AuthorityMembership, AuthorityMembershipApiServer};
+	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
+	use sc_consensus_manual_seal::rpc::{ManualSeal, ManualSealApiServer};
+	use sc_rpc::dev::{Dev, DevApiServer};
+	use sc_rpc_spec_v2::chain_spec::{ChainSpec, ChainSpecApiServer};
+	use substrate_frame_rpc_system::{System, SystemApiServer};
+	use substrate_state_trie_migration_rpc::{StateMigration, StateMigrationApiServer};
+	use timeline::{IdentifierApiServer, IdentifierTimeline};
+
+	let mut io = RpcModule::new(());
+
+	let chain_name = chain_spec.name().to_string();
+	let genesis_hash = client.block_hash(0).ok().flatten().expect("Genesis block exists; qed");
+	let properties = chain_spec.properties();
+	io.merge(ChainSpec::new(chain_name, genesis_hash, properties).into_rpc())?;
+
+	io.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
+	io.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	io.merge(AuthorityMembership::new(client.clone()).into_rpc())?;
+	io.merge(ManualSeal::new(command_sink).into_rpc())?;
+
+	if let Some(storage) = backend.offchain_storage() {
+		io.merge(IdentifierTimeline::new(storage).into_rpc())?;
+	}
 	io.merge(StateMigration::new(client.clone(), backend, deny_unsafe).into_rpc())?;
 	io.merge(Dev::new(client, deny_unsafe).into_rpc())?;
 
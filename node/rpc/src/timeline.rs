@@ -0,0 +1,134 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! RPC interface for the complete timelines of the identifiers.
+//!
+//! The on-chain timelines only keep their latest entries, the runtime writes
+//! every entry to the offchain index as well. The complete timelines are
+//! available on the nodes running with `--enable-offchain-indexing true`,
+//! from the block they started indexing at, up to the last finalized block.
+
+use codec::{Decode, Encode};
+use identifier::{
+	timeline_entry_key, timeline_length_key, EventEntryOf, IdentifierTypeOf, Ss58Identifier,
+};
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{Deserialize, Serialize};
+use sp_core::offchain::{OffchainStorage, STORAGE_PREFIX};
+
+/// Error code of an invalid identifier.
+const INVALID_IDENTIFIER: i32 = 1;
+
+/// Number of entries in a page when no limit is given.
+const DEFAULT_PAGE_SIZE: u32 = 100;
+/// Maximum number of entries in a page.
+const MAX_PAGE_SIZE: u32 = 1000;
+
+/// An entry of a timeline, along with its position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineEntry {
+	/// Position of the entry in the timeline.
+	pub position: u64,
+	/// The entry.
+	#[serde(flatten)]
+	pub entry: EventEntryOf,
+}
+
+/// A page of a timeline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelinePage {
+	/// The indexed entries of the page. Entries added before the node indexed
+	/// the timeline are missing.
+	pub entries: Vec<TimelineEntry>,
+	/// Number of entries of the timeline, as of the last finalized block.
+	pub total: u64,
+	/// Position to continue from for the next page, `None` on the last page.
+	pub next: Option<u64>,
+}
+
+#[rpc(client, server)]
+pub trait IdentifierApi {
+	/// Returns a page of the complete `kind` timeline of an identifier,
+	/// starting at the entry `from`, including the entries dropped from the
+	/// on-chain history.
+	#[method(name = "identifier_timeline")]
+	fn timeline(
+		&self,
+		identifier: String,
+		kind: IdentifierTypeOf,
+		from: Option<u64>,
+		limit: Option<u32>,
+	) -> RpcResult<TimelinePage>;
+}
+
+/// Provides RPC methods to read the complete timelines of the identifiers.
+pub struct IdentifierTimeline<S> {
+	storage: S,
+}
+
+impl<S> IdentifierTimeline<S> {
+	/// Creates a new instance of the IdentifierTimeline RPC handler.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S: OffchainStorage + 'static> IdentifierApiServer for IdentifierTimeline<S> {
+	fn timeline(
+		&self,
+		identifier: String,
+		kind: IdentifierTypeOf,
+		from: Option<u64>,
+		limit: Option<u32>,
+	) -> RpcResult<TimelinePage> {
+		let id = Ss58Identifier::decode(&mut &identifier.as_bytes().encode()[..])
+			.ok()
+			.filter(|id| id.get_identifier_type().is_ok())
+			.ok_or_else(|| invalid_identifier(identifier))?;
+
+		let total = self.get::<u64>(&timeline_length_key(&id, kind)).unwrap_or_default();
+		let from = from.unwrap_or_default();
+		let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+		let end = total.min(from.saturating_add(limit.into()));
+		let entries = (from..end)
+			.filter_map(|position| {
+				let entry = self.get::<EventEntryOf>(&timeline_entry_key(&id, kind, position))?;
+				Some(TimelineEntry { position, entry })
+			})
+			.collect();
+
+		Ok(TimelinePage { entries, total, next: (end < total).then_some(end) })
+	}
+}
+
+impl<S: OffchainStorage> IdentifierTimeline<S> {
+	fn get<T: Decode>(&self, key: &[u8]) -> Option<T> {
+		let raw = self.storage.get(STORAGE_PREFIX, key)?;
+		T::decode(&mut &raw[..]).ok()
+	}
+}
+
+fn invalid_identifier(identifier: String) -> ErrorObjectOwned {
+	ErrorObject::owned(INVALID_IDENTIFIER, "Invalid identifier.", Some(identifier))
+}
@@ -30,6 +30,7 @@ use std::{
 
 use crate::{
 	client::{Backend, Client},
+	genesis::space_authorization,
 	keyring::*,
 };
 use codec::{Decode, Encode};
@@ -38,8 +39,10 @@ use cord_loom_runtime::{
 	UncheckedExtrinsic,
 };
 use cord_loom_runtime_constants::currency::UNITS;
-use cord_primitives::Block;
+use cord_primitives::{Block, Hash};
 use futures::executor;
+use pallet_did::did_details::{DidAuthorizedCallOperation, DidSignature, DidVerificationKey};
+use pallet_network_score::{RatingInputEntry, RatingTypeOf};
 use sc_block_builder::BlockBuilderBuilder;
 use sc_client_api::{execution_extensions::ExecutionExtensions, UsageProvider};
 use sc_client_db::PruningMode;
@@ -80,8 +83,26 @@ impl BenchPair {
 			Self::Ed25519(pair) => pair.sign(payload).into(),
 		}
 	}
+
+	fn did_sign(&self, payload: &[u8]) -> DidSignature {
+		match self {
+			Self::Sr25519(pair) => pair.sign(payload).into(),
+			Self::Ed25519(pair) => pair.sign(payload).into(),
+		}
+	}
+
+	fn did_key(&self) -> DidVerificationKey<AccountId> {
+		match self {
+			Self::Sr25519(pair) => pair.public().into(),
+			Self::Ed25519(pair) => pair.public().into(),
+		}
+	}
 }
 
+/// Number of digests in the statement batches of
+/// [`BlockType::StatementBatches`].
+const STATEMENT_BATCH_SIZE: usize = 100;
+
 /// Drop system cache.
 ///
 /// Will panic if cache drop is impossbile.
@@ -183,6 +204,9 @@ impl Clone for BenchDb {
 }
 
 /// Type of block for generation
+///
+/// There is no block type for `asset::issue`, as the CORD asset pallet is not
+/// part of the braid, loom or weave runtimes.
 #[derive(Debug, PartialEq, Clone, Copy)]
 pub enum BlockType {
 	/// Bunch of random transfers.
@@ -191,6 +215,12 @@ pub enum BlockType {
 	RandomTransfersReaping,
 	/// Bunch of "no-op" calls.
 	Noop,
+	/// Bunch of statements registered through DID calls.
+	Statements,
+	/// Bunch of statement batches registered through DID calls.
+	StatementBatches,
+	/// Bunch of ratings registered through DID calls.
+	Ratings,
 }
 
 impl BlockType {
@@ -292,11 +322,12 @@ impl<'a> Iterator for BlockContentIterator<'a> {
 			"random-user//{}",
 			self.iteration
 		));
+		let digest = |n: usize| Hash::from(blake2_256(&(self.iteration, n).encode()));
 
 		let signed = self.keyring.sign(
 			CheckedExtrinsic {
 				signed: Some((
-					sender,
+					sender.clone(),
 					signed_extra(0, cord_loom_runtime::ExistentialDeposit::get() + 1),
 				)),
 				function: match self.content.block_type {
@@ -315,6 +346,38 @@ impl<'a> Iterator for BlockContentIterator<'a> {
 					},
 					BlockType::Noop =>
 						RuntimeCall::System(SystemCall::remark { remark: Vec::new() }),
+					BlockType::Statements => self.keyring.did_call(
+						&sender,
+						RuntimeCall::Statement(pallet_statement::Call::register {
+							digest: digest(0),
+							authorization: space_authorization(&sender),
+							schema_id: None,
+						}),
+					),
+					BlockType::StatementBatches => self.keyring.did_call(
+						&sender,
+						RuntimeCall::Statement(pallet_statement::Call::register_batch {
+							digests: (0..STATEMENT_BATCH_SIZE).map(digest).collect(),
+							authorization: space_authorization(&sender),
+							schema_id: None,
+						}),
+					),
+					BlockType::Ratings => self.keyring.did_call(
+						&sender,
+						RuntimeCall::NetworkScore(pallet_network_score::Call::register_rating {
+							entry: RatingInputEntry {
+								entity_id: bounded(format!("entity//{}", self.iteration)),
+								provider_id: bounded("provider".into()),
+								count_of_txn: 1,
+								total_encoded_rating: 5,
+								rating_type: RatingTypeOf::Overall,
+								provider_did: sender.clone(),
+							},
+							digest: digest(0),
+							message_id: bounded(format!("message//{}", self.iteration)),
+							authorization: space_authorization(&sender),
+						}),
+					),
 				},
 			},
 			self.runtime_version.spec_version,
@@ -589,8 +652,32 @@ impl BenchKeyring {
 		}
 	}
 
+	/// Wrap a call into a DID call of the DID of the given account, submitted
+	/// by that account.
+	///
+	/// Like the transactions of the keyring, the DID call is only valid as
+	/// the first one of the DID after genesis.
+	pub fn did_call(&self, did: &AccountId, call: RuntimeCall) -> RuntimeCall {
+		let operation = DidAuthorizedCallOperation {
+			did: did.clone(),
+			tx_counter: 1,
+			call,
+			block_number: 0,
+			submitter: did.clone(),
+		};
+		let key = self.accounts.get(did).expect("Account id not found in keyring");
+		let signature = key.did_sign(&operation.encode());
+		RuntimeCall::Did(pallet_did::Call::submit_did_call {
+			did_call: Box::new(operation),
+			signature,
+		})
+	}
+
 	/// Generate genesis with accounts from this keyring endowed with some
 	/// balance and cord_loom_runtime code blob.
+	///
+	/// Each account is a network member and has a DID which created an
+	/// approved space.
 	pub fn as_storage_builder(&self) -> &dyn sp_runtime::BuildStorage {
 		self
 	}
@@ -602,7 +689,12 @@ impl sp_runtime::BuildStorage for BenchKeyring {
 			sp_core::storage::well_known_keys::CODE.to_vec(),
 			cord_loom_runtime::wasm_binary_unwrap().into(),
 		);
-		crate::genesis::config_endowed(self.collect_account_ids()).assimilate_storage(storage)
+		let dids = self
+			.accounts
+			.iter()
+			.map(|(account, pair)| (account.clone(), pair.did_key()))
+			.collect();
+		crate::genesis::config_with_dids(dids).assimilate_storage(storage)
 	}
 }
 
@@ -642,6 +734,10 @@ where
 	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
+fn bounded<S: sp_core::Get<u32>>(value: String) -> sp_runtime::BoundedVec<u8, S> {
+	value.into_bytes().try_into().expect("Value fits its bound")
+}
+
 impl BenchContext {
 	/// Import some block.
 	pub fn import_block(&mut self, block: Block) {
@@ -19,15 +19,22 @@
 //! Genesis Configuration.
 
 use crate::keyring::*;
+use codec::Encode;
 use cord_loom_runtime::{
-	AccountId, AssetsConfig, AuthorityMembershipConfig, BalancesConfig, IndicesConfig,
+	AccountId, AssetsConfig, AuthorityMembershipConfig, BalancesConfig, ChainSpace, IndicesConfig,
 	NetworkMembershipConfig, NetworkParametersConfig, NodeAuthorizationConfig,
-	RuntimeGenesisConfig, SessionConfig,
+	RuntimeGenesisConfig, SessionConfig, Ss58Identifier,
 };
 use cord_loom_runtime_constants::currency::*;
+use cord_primitives::Hash;
+use pallet_did::did_details::DidVerificationKey;
+use sp_core::blake2_256;
 use sp_keyring::{Ed25519Keyring, Sr25519Keyring};
 use sp_std::collections::btree_map::BTreeMap;
 
+/// Transaction capacity of the spaces anchored for the DIDs at genesis.
+pub const SPACE_CAPACITY: u64 = 1_000_000_000;
+
 /// Create genesis runtime configuration for tests.
 pub fn config() -> RuntimeGenesisConfig {
 	config_endowed(Default::default())
@@ -86,3 +93,36 @@ pub fn config_endowed(extra_endowed: Vec<AccountId>) -> RuntimeGenesisConfig {
 		..Default::default()
 	}
 }
+
+/// Create genesis runtime configuration for tests with some extra DIDs.
+///
+/// The account of each DID is endowed and a network member, and the DID is
+/// the creator of an approved space, see [`space_authorization`].
+pub fn config_with_dids(
+	dids: Vec<(AccountId, DidVerificationKey<AccountId>)>,
+) -> RuntimeGenesisConfig {
+	let accounts: Vec<AccountId> = dids.iter().map(|(account, _)| account.clone()).collect();
+
+	let mut config = config_endowed(accounts.clone());
+	config
+		.network_membership
+		.members
+		.extend(accounts.iter().map(|account| (account.clone(), false)));
+	config.chain_space.spaces = accounts
+		.iter()
+		.map(|account| (space_code(account), account.clone(), SPACE_CAPACITY))
+		.collect();
+	config.did.dids = dids;
+	config
+}
+
+/// Code of the space anchored at genesis for a DID.
+pub fn space_code(did: &AccountId) -> Hash {
+	blake2_256(&(b"space", did).encode()).into()
+}
+
+/// Authorization of a DID in the space anchored for it at genesis.
+pub fn space_authorization(did: &AccountId) -> Ss58Identifier {
+	let space_id = ChainSpace::space_id(&space_code(did), did).expect("Valid space id");
+	ChainSpace::authorization_id(&space_id, did, did).expect("Valid authorization id")
+}
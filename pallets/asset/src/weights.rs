@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -66,6 +70,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
@@ -74,8 +79,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6667`
 		// Minimum execution time: 37_750_000 picoseconds.
 		Weight::from_parts(38_870_000, 6667)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -87,6 +92,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
@@ -97,8 +103,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6667`
 		// Minimum execution time: 53_110_000 picoseconds.
 		Weight::from_parts(54_730_000, 6667)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -106,14 +112,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
 		// Minimum execution time: 30_110_000 picoseconds.
 		Weight::from_parts(30_910_000, 6799)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -121,14 +128,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
 		// Minimum execution time: 29_790_000 picoseconds.
 		Weight::from_parts(30_700_000, 6799)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 }
 
@@ -142,6 +150,7 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
@@ -150,8 +159,8 @@ impl WeightInfo for () {
 		//  Estimated: `6667`
 		// Minimum execution time: 37_750_000 picoseconds.
 		Weight::from_parts(38_870_000, 6667)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -163,6 +172,7 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
@@ -173,8 +183,8 @@ impl WeightInfo for () {
 		//  Estimated: `6667`
 		// Minimum execution time: 53_110_000 picoseconds.
 		Weight::from_parts(54_730_000, 6667)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -182,14 +192,15 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
 		// Minimum execution time: 30_110_000 picoseconds.
 		Weight::from_parts(30_910_000, 6799)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3202), added: 5677, mode: `MaxEncodedLen`)
@@ -197,13 +208,14 @@ impl WeightInfo for () {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3334), added: 5809, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `864`
 		//  Estimated: `6799`
 		// Minimum execution time: 29_790_000 picoseconds.
 		Weight::from_parts(30_700_000, 6799)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 }
@@ -7,6 +7,7 @@ use frame_benchmarking::{account, benchmarks};
 use frame_support::sp_runtime::traits::Hash;
 use frame_system::RawOrigin;
 use identifier::{IdentifierType, Ss58Identifier};
+use sp_std::{vec, vec::Vec};
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
@@ -419,6 +420,131 @@ benchmarks! {
 			 assert_last_event::<T>(Event::Create { space: subspace_id, creator: did, authorization: authorization_id }.into());
 		 }
 
+		 set_identity_policy {
+			 let registrar: T::AccountId = account("registrar", 0, SEED);
+			 let policy = Some(IdentityPolicy { registrar, enforce_on_transactions: true, auto_suspend: true });
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), policy.clone())
+		 verify {
+			 assert_last_event::<T>(Event::IdentityPolicySet { policy }.into());
+		 }
+
+		 suspend_unverified {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let registrar: T::AccountId = account("registrar", 0, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let capacity = 5u64;
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did);
+
+			 Pallet::<T>::create(origin, space_digest )?;
+			 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), capacity )?;
+			 SpaceIdentityPolicy::<T>::put(IdentityPolicy { registrar, enforce_on_transactions: true, auto_suspend: true });
+
+		 }: _(RawOrigin::Signed(caller), space_id.clone())
+		 verify {
+			 assert_last_event::<T>(Event::IdentitySuspend { space: space_id }.into());
+		 }
+
+		 set_capacity_tier {
+			 let name: TierNameOf<T> = vec![b't'; T::MaxTierNameLength::get() as usize].try_into().unwrap();
+			 let tier = Some(CapacityTier { txn_capacity: 100u64, reset_period: 10u32.into() });
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), name.clone(), tier.clone())
+		 verify {
+			 assert_last_event::<T>(Event::CapacityTierSet { name, tier }.into());
+		 }
+
+		 bulk_approve {
+			 let n in 1 .. T::MaxSpacesPerBatch::get();
+
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let name: TierNameOf<T> = vec![b't'; T::MaxTierNameLength::get() as usize].try_into().unwrap();
+			 CapacityTiers::<T>::insert(&name, CapacityTier { txn_capacity: 100u64, reset_period: 10u32.into() });
+
+			 let mut space_ids = Vec::new();
+			 for i in 0 .. n {
+				 let space_digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+				 let id_digest = <T as frame_system::Config>::Hashing::hash(
+					 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+				 );
+				 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+				 Pallet::<T>::create(origin, space_digest )?;
+				 space_ids.push(generate_space_id::<T>(&id_digest));
+			 }
+			 let last = space_ids.last().unwrap().clone();
+			 let space_ids: BoundedVec<_, _> = space_ids.try_into().unwrap();
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), space_ids, name.clone())
+		 verify {
+			 assert_last_event::<T>(Event::CapacityTierAssign { space: last, tier: Some(name) }.into());
+		 }
+
+		 assign_capacity_tier {
+			 let n in 1 .. T::MaxSpacesPerBatch::get();
+
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let name: TierNameOf<T> = vec![b't'; T::MaxTierNameLength::get() as usize].try_into().unwrap();
+			 CapacityTiers::<T>::insert(&name, CapacityTier { txn_capacity: 100u64, reset_period: 10u32.into() });
+
+			 let mut space_ids = Vec::new();
+			 for i in 0 .. n {
+				 let space_digest = <T as frame_system::Config>::Hashing::hash(&i.encode()[..]);
+				 let id_digest = <T as frame_system::Config>::Hashing::hash(
+					 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+				 );
+				 let space_id = generate_space_id::<T>(&id_digest);
+				 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller.clone(), did.clone());
+				 Pallet::<T>::create(origin, space_digest )?;
+				 Pallet::<T>::approve(RawOrigin::Root.into(), space_id.clone(), 5u64 )?;
+				 space_ids.push(space_id);
+			 }
+			 let last = space_ids.last().unwrap().clone();
+			 let space_ids: BoundedVec<_, _> = space_ids.try_into().unwrap();
+
+		 }: _<T::RuntimeOrigin>(RawOrigin::Root.into(), space_ids, Some(name.clone()))
+		 verify {
+			 assert_last_event::<T>(Event::CapacityTierAssign { space: last, tier: Some(name) }.into());
+		 }
+
+		 reset_capacity_tier {
+			 let caller: T::AccountId = account("caller", 0, SEED);
+			 let did: T::SpaceCreatorId = account("did", 0, SEED);
+			 let space = [2u8; 256].to_vec();
+			 let name: TierNameOf<T> = vec![b't'; T::MaxTierNameLength::get() as usize].try_into().unwrap();
+			 CapacityTiers::<T>::insert(&name, CapacityTier { txn_capacity: 100u64, reset_period: 10u32.into() });
+
+			 let space_digest = <T as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+			 let id_digest = <T as frame_system::Config>::Hashing::hash(
+				 &[&space_digest.encode()[..], &did.encode()[..]].concat()[..],
+			 );
+			 let space_id: SpaceIdOf = generate_space_id::<T>(&id_digest);
+
+			 let origin =  <T as Config>::EnsureOrigin::generate_origin(caller, did);
+
+			 Pallet::<T>::create(origin, space_digest )?;
+			 Pallet::<T>::bulk_approve(RawOrigin::Root.into(), vec![space_id.clone()].try_into().unwrap(), name)?;
+			 Pallet::<T>::increment_usage(&space_id)?;
+
+			 let at = SpaceTiers::<T>::get(&space_id).and_then(|assigned| assigned.next_reset).unwrap();
+			 frame_system::Pallet::<T>::set_block_number(at);
+
+		 }: {
+			 Pallet::<T>::reset_capacity_tiers(at);
+		 }
+		 verify {
+			 assert_eq!(Spaces::<T>::get(&space_id).unwrap().txn_count, 0);
+		 }
+
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
 
 }
@@ -46,6 +46,19 @@
 //!   permissions.
 //! - `add_audit_delegate`: Adds an audit delegate to a space, granting them audit permissions.
 //! - `remove_delegate`: Removes a delegate from a space, revoking their permissions.
+//! - `set_identity_policy`: Requires the creators of the spaces to hold a registrar judgement.
+//! - `suspend_unverified`: Suspends a space whose creator lost the required judgement.
+//! - `set_capacity_tier`: Sets a named capacity tier, with a default capacity and reset period.
+//! - `bulk_approve`: Approves a batch of spaces, assigning them a capacity tier.
+//! - `assign_capacity_tier`: Assigns a capacity tier to a batch of approved spaces.
+//!
+//! ## Capacity Tiers
+//!
+//! Instead of an absolute capacity per space, governance can define capacity
+//! tiers and assign them to the spaces. The usage of the spaces of a tier
+//! with a reset period is reset automatically at the start of the block in
+//! which the period elapses. When too many resets fall in the same block,
+//! they are spread over the following blocks.
 //!
 //! ## Permissions
 //!
@@ -97,15 +110,17 @@ pub mod benchmarking;
 #[cfg(test)]
 mod tests;
 
-use frame_support::{ensure, storage::types::StorageMap, BoundedVec};
+use frame_support::{ensure, storage::types::StorageMap, traits::Get, weights::Weight, BoundedVec};
 pub mod types;
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
 use codec::Encode;
+use frame_system::pallet_prelude::BlockNumberFor;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_runtime::traits::{Convert, Hash, One, Saturating, UniqueSaturatedInto, Zero};
+use sp_std::vec::Vec;
 
 /// Type of a CORD account.
 pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
@@ -122,10 +137,24 @@ pub type SpaceDetailsOf<T> = SpaceDetails<SpaceCodeOf<T>, SpaceCreatorOf<T>, Sta
 
 pub type SpaceAuthorizationOf<T> = SpaceAuthorization<SpaceIdOf, SpaceCreatorOf<T>, Permissions>;
 
+/// Type of the identity policy of the spaces.
+pub type IdentityPolicyOf<T> = IdentityPolicy<AccountIdOf<T>>;
+
+/// Name of a capacity tier.
+pub type TierNameOf<T> = BoundedVec<u8, <T as Config>::MaxTierNameLength>;
+/// Type of a capacity tier.
+pub type CapacityTierOf<T> = CapacityTier<BlockNumberFor<T>>;
+/// Type of the capacity tier assigned to a space.
+pub type SpaceCapacityTierOf<T> = SpaceCapacityTier<TierNameOf<T>, BlockNumberFor<T>>;
+
+/// The number of consecutive blocks searched for room to schedule the usage
+/// reset of a space, once its reset block is full.
+const CAPACITY_RESET_WINDOW: u32 = 10;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	pub use cord_primitives::{IsPermissioned, StatusOf};
+	pub use cord_primitives::{IdentityVerifier, IsPermissioned, NetworkParameters, StatusOf};
 	use cord_utilities::traits::CallSources;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
@@ -142,13 +171,34 @@ pub mod pallet {
 			Success = <Self as Config>::OriginSuccess,
 		>;
 		type OriginSuccess: CallSources<AccountIdOf<Self>, SpaceCreatorOf<Self>>;
-		type SpaceCreatorId: Parameter + MaxEncodedLen;
+		type SpaceCreatorId: Parameter + MaxEncodedLen + MaybeSerializeDeserialize;
 		type ChainSpaceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 		type NetworkPermission: IsPermissioned;
+		/// Network parameters which can be changed by governance.
+		type NetworkParameters: NetworkParameters<BlockNumberFor<Self>>;
+		/// Judgements of the identity registrars, checked by the identity
+		/// policy.
+		type IdentityVerifier: IdentityVerifier<AccountIdOf<Self>>;
+		/// The account linked to a space creator, holding its identity.
+		type CreatorAccount: Convert<Self::SpaceCreatorId, Option<AccountIdOf<Self>>>;
 
 		#[pallet::constant]
 		type MaxSpaceDelegates: Get<u32>;
 
+		/// The maximum length of the name of a capacity tier.
+		#[pallet::constant]
+		type MaxTierNameLength: Get<u32>;
+
+		/// The maximum number of spaces approved or assigned a tier in a
+		/// single call.
+		#[pallet::constant]
+		type MaxSpacesPerBatch: Get<u32>;
+
+		/// The maximum number of spaces whose usage is reset in a single
+		/// block.
+		#[pallet::constant]
+		type MaxCapacityResetsPerBlock: Get<u32>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -158,7 +208,11 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::reset_capacity_tiers(n)
+		}
+	}
 
 	/// Space information stored on chain.
 	/// It maps from an identifier to its details.
@@ -183,6 +237,33 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The identity policy of the spaces, if any.
+	#[pallet::storage]
+	pub type SpaceIdentityPolicy<T> = StorageValue<_, IdentityPolicyOf<T>, OptionQuery>;
+
+	/// Capacity tiers stored on chain.
+	/// It maps from a tier name to its details.
+	#[pallet::storage]
+	pub type CapacityTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, TierNameOf<T>, CapacityTierOf<T>, OptionQuery>;
+
+	/// Capacity tiers assigned to the spaces.
+	/// It maps from a space identifier to its tier and next reset.
+	#[pallet::storage]
+	pub type SpaceTiers<T: Config> =
+		StorageMap<_, Blake2_128Concat, SpaceIdOf, SpaceCapacityTierOf<T>, OptionQuery>;
+
+	/// Scheduled usage resets of the spaces.
+	/// It maps from a block number to the spaces reset in that block.
+	#[pallet::storage]
+	pub(super) type CapacityResets<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<SpaceIdOf, T::MaxCapacityResetsPerBlock>,
+		ValueQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -223,6 +304,24 @@ pub mod pallet {
 		/// A chain space usage has been reset.
 		/// \[space identifier \]
 		ResetUsage { space: SpaceIdOf },
+		/// The identity policy has been set or cleared.
+		/// \[policy\]
+		IdentityPolicySet { policy: Option<IdentityPolicyOf<T>> },
+		/// A space has been suspended as its creator lost the judgement
+		/// required by the identity policy.
+		/// \[space identifier\]
+		IdentitySuspend { space: SpaceIdOf },
+		/// A capacity tier has been set or removed.
+		/// \[tier name, tier\]
+		CapacityTierSet { name: TierNameOf<T>, tier: Option<CapacityTierOf<T>> },
+		/// A capacity tier has been assigned to or removed from a space.
+		/// \[space identifier, tier name\]
+		CapacityTierAssign { space: SpaceIdOf, tier: Option<TierNameOf<T>> },
+		/// The next usage reset of a space could not be scheduled, as the
+		/// blocks of the reset window were full. The tier needs to be
+		/// assigned again.
+		/// \[space identifier\]
+		CapacityResetUnscheduled { space: SpaceIdOf },
 	}
 
 	#[pallet::error]
@@ -266,6 +365,37 @@ pub mod pallet {
 		CapacityValueMissing,
 		/// Type capacity overflow
 		TypeCapacityOverflow,
+		/// The creator does not hold the judgement required by the identity
+		/// policy
+		CreatorIdentityNotVerified,
+		/// The creator holds the judgement required by the identity policy
+		CreatorIdentityVerified,
+		/// The identity policy does not allow the automatic suspension
+		AutoSuspendDisabled,
+		/// Capacity tier not found
+		CapacityTierNotFound,
+		/// Too many usage resets scheduled in the same block
+		TooManyCapacityResets,
+		/// Capacity tiers must have a non-zero capacity
+		ZeroTierCapacity,
+	}
+
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Spaces anchored and approved at genesis, with their code, creator
+		/// and transaction capacity.
+		pub spaces: Vec<(SpaceCodeOf<T>, SpaceCreatorOf<T>, u64)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (space_code, creator, txn_capacity) in &self.spaces {
+				Pallet::<T>::anchor_space(*space_code, creator.clone(), true, *txn_capacity)
+					.expect("Genesis space is already anchored");
+			}
+		}
 	}
 
 	#[pallet::call]
@@ -510,68 +640,16 @@ pub mod pallet {
 		pub fn create(origin: OriginFor<T>, space_code: SpaceCodeOf<T>) -> DispatchResult {
 			let creator = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
 
-			// Id Digest = concat (H(<scale_encoded_registry_input>,
-			// <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&space_code.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier =
-				Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::Space)
-					.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(!<Spaces<T>>::contains_key(&identifier), Error::<T>::SpaceAlreadyAnchored);
-
-			// Construct the authorization_id from the provided parameters.
-			// Id Digest = concat (H(<scale_encoded_space_identifier>,
-			// <scale_encoded_creator_identifier> ))
-			let auth_id_digest = T::Hashing::hash(
-				&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let authorization_id = Ss58Identifier::create_identifier(
-				&auth_id_digest.encode(),
-				IdentifierType::Authorization,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			let mut delegates: BoundedVec<SpaceCreatorOf<T>, T::MaxSpaceDelegates> =
-				BoundedVec::default();
-			delegates
-				.try_push(creator.clone())
-				.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
-
-			Delegates::<T>::insert(&identifier, delegates);
-
-			Authorizations::<T>::insert(
-				&authorization_id,
-				SpaceAuthorizationOf::<T> {
-					space_id: identifier.clone(),
-					delegate: creator.clone(),
-					permissions: Permissions::all(),
-					delegator: creator.clone(),
-				},
-			);
-
 			let approved = !T::NetworkPermission::is_permissioned();
+			// Spaces approved on creation get the network default capacity.
+			let txn_capacity = if approved {
+				T::NetworkParameters::default_space_capacity().unwrap_or_default()
+			} else {
+				0
+			};
 
-			<Spaces<T>>::insert(
-				&identifier,
-				SpaceDetailsOf::<T> {
-					code: space_code,
-					creator: creator.clone(),
-					txn_capacity: 0,
-					txn_reserve: 0,
-					txn_count: 0,
-					approved,
-					archive: false,
-					parent: identifier.clone(),
-				},
-			);
-
-			Self::update_activity(&identifier, IdentifierTypeOf::ChainSpace, CallTypeOf::Genesis)
-				.map_err(Error::<T>::from)?;
+			let (identifier, authorization_id) =
+				Self::anchor_space(space_code, creator.clone(), approved, txn_capacity)?;
 
 			Self::deposit_event(Event::Create {
 				space: identifier,
@@ -630,6 +708,10 @@ pub mod pallet {
 			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
 			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
 			ensure!(!space_details.approved, Error::<T>::SpaceAlreadyApproved);
+			ensure!(
+				Self::satisfies_identity_policy(&space_details.creator),
+				Error::<T>::CreatorIdentityNotVerified
+			);
 
 			<Spaces<T>>::insert(
 				&space_id,
@@ -794,39 +876,7 @@ pub mod pallet {
 			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
 			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
-			// Ensure the new capacity is greater than the current usage
-			ensure!(
-				new_txn_capacity >= (space_details.txn_count + space_details.txn_reserve),
-				Error::<T>::CapacityLessThanUsage
-			);
-
-			if space_id.clone() != space_details.parent.clone() {
-				let parent_details = Spaces::<T>::get(&space_details.parent.clone())
-					.ok_or(Error::<T>::SpaceNotFound)?;
-
-				// Ensure the new capacity is greater than the current usage
-				ensure!(
-					(parent_details.txn_capacity >=
-						(parent_details.txn_count +
-							parent_details.txn_reserve +
-							new_txn_capacity - space_details.txn_capacity)),
-					Error::<T>::CapacityLessThanUsage
-				);
-
-				<Spaces<T>>::insert(
-					&space_details.parent.clone(),
-					SpaceDetailsOf::<T> {
-						txn_reserve: parent_details.txn_reserve - space_details.txn_capacity +
-							new_txn_capacity,
-						..parent_details.clone()
-					},
-				);
-			}
-
-			<Spaces<T>>::insert(
-				&space_id,
-				SpaceDetailsOf::<T> { txn_capacity: new_txn_capacity, ..space_details },
-			);
+			Self::apply_transaction_capacity(&space_id, space_details, new_txn_capacity)?;
 
 			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Capacity)
 				.map_err(Error::<T>::from)?;
@@ -943,6 +993,10 @@ pub mod pallet {
 			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
 			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
 			ensure!(!space_details.approved, Error::<T>::SpaceAlreadyApproved);
+			ensure!(
+				Self::satisfies_identity_policy(&space_details.creator),
+				Error::<T>::CreatorIdentityNotVerified
+			);
 
 			<Spaces<T>>::insert(&space_id, SpaceDetailsOf::<T> { approved: true, ..space_details });
 
@@ -1009,6 +1063,10 @@ pub mod pallet {
 			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
 			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 			ensure!(space_details.creator == creator.clone(), Error::<T>::UnauthorizedOperation);
+			ensure!(
+				Self::satisfies_identity_policy(&creator),
+				Error::<T>::CreatorIdentityNotVerified
+			);
 
 			// Check if the network is permissioned
 			let is_permissioned = T::NetworkPermission::is_permissioned();
@@ -1186,10 +1244,358 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Sets or clears the identity policy of the spaces.
+		///
+		/// With a policy in place, the account linked to the creator of a
+		/// space must hold a `KnownGood` or `Reasonable` judgement from the
+		/// configured registrar for the space to be approved or restored.
+		/// The policy can also require the judgement to transact in an
+		/// approved space, and allow the suspension of the spaces whose
+		/// creator lost it.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be a council or root origin.
+		/// - `policy`: The new identity policy, or `None` to clear it.
+		///
+		/// # Events
+		/// - `IdentityPolicySet`: Emitted when the policy is set or cleared.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_identity_policy())]
+		pub fn set_identity_policy(
+			origin: OriginFor<T>,
+			policy: Option<IdentityPolicyOf<T>>,
+		) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			SpaceIdentityPolicy::<T>::set(policy.clone());
+
+			Self::deposit_event(Event::IdentityPolicySet { policy });
+
+			Ok(())
+		}
+
+		/// Suspends an approved space whose creator no longer holds the
+		/// judgement required by the identity policy.
+		///
+		/// Any signed origin, e.g. a service watching the judgements, can
+		/// perform this action when the policy allows the automatic
+		/// suspension. The approval of the space is revoked, and can be
+		/// restored by the council once the judgement is back.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be signed.
+		/// - `space_id`: The identifier of the space to be suspended.
+		///
+		/// # Errors
+		/// - `AutoSuspendDisabled`: If there is no policy or it does not allow the suspension.
+		/// - `SpaceNotFound`: If the specified space ID does not correspond to an existing space.
+		/// - `ArchivedSpace`: If the space is archived.
+		/// - `SpaceNotApproved`: If the space is not approved.
+		/// - `CreatorIdentityVerified`: If the creator still holds the judgement.
+		///
+		/// # Events
+		/// - `IdentitySuspend`: Emitted when the space is suspended.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::suspend_unverified())]
+		pub fn suspend_unverified(origin: OriginFor<T>, space_id: SpaceIdOf) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			ensure!(
+				SpaceIdentityPolicy::<T>::get().map_or(false, |policy| policy.auto_suspend),
+				Error::<T>::AutoSuspendDisabled
+			);
+
+			let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+			ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
+			ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
+			ensure!(
+				!Self::satisfies_identity_policy(&space_details.creator),
+				Error::<T>::CreatorIdentityVerified
+			);
+
+			<Spaces<T>>::insert(
+				&space_id,
+				SpaceDetailsOf::<T> { approved: false, ..space_details },
+			);
+
+			Self::update_activity(&space_id, IdentifierTypeOf::ChainSpace, CallTypeOf::Revoke)
+				.map_err(Error::<T>::from)?;
+
+			Self::deposit_event(Event::IdentitySuspend { space: space_id });
+
+			Ok(())
+		}
+
+		/// Sets or removes a capacity tier.
+		///
+		/// A tier gives the spaces it is assigned to a default transaction
+		/// capacity and, with a non-zero reset period, resets their usage
+		/// every period. Updating a tier does not change the capacity of the
+		/// spaces already assigned to it, which applies on their next
+		/// assignment; the new reset period applies from their next reset.
+		/// The spaces of a removed tier are released from it on their next
+		/// reset.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be a council or root origin.
+		/// - `name`: The name of the tier.
+		/// - `tier`: The new details of the tier, or `None` to remove it.
+		///
+		/// # Errors
+		/// - `ZeroTierCapacity`: If the capacity of the tier is zero.
+		/// - `CapacityTierNotFound`: If the tier to remove does not exist.
+		///
+		/// # Events
+		/// - `CapacityTierSet`: Emitted when the tier is set or removed.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_capacity_tier())]
+		pub fn set_capacity_tier(
+			origin: OriginFor<T>,
+			name: TierNameOf<T>,
+			tier: Option<CapacityTierOf<T>>,
+		) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			match tier {
+				Some(ref details) => {
+					ensure!(details.txn_capacity > 0, Error::<T>::ZeroTierCapacity);
+					CapacityTiers::<T>::insert(&name, details);
+				},
+				None => {
+					ensure!(
+						CapacityTiers::<T>::contains_key(&name),
+						Error::<T>::CapacityTierNotFound
+					);
+					CapacityTiers::<T>::remove(&name);
+				},
+			}
+
+			Self::deposit_event(Event::CapacityTierSet { name, tier });
+
+			Ok(())
+		}
+
+		/// Approves a batch of spaces, assigning them a capacity tier.
+		///
+		/// Each space is approved as with `approve`, with the capacity of the
+		/// tier, and its usage reset is scheduled when the tier has a reset
+		/// period. The call fails as a whole if any of the spaces cannot be
+		/// approved.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be a council or root origin.
+		/// - `space_ids`: The identifiers of the spaces to be approved.
+		/// - `tier`: The name of the tier assigned to the spaces.
+		///
+		/// # Errors
+		/// - `CapacityTierNotFound`: If the tier does not exist.
+		/// - `SpaceNotFound`: If one of the space IDs does not correspond to an existing space.
+		/// - `ArchivedSpace`: If one of the spaces is archived.
+		/// - `SpaceAlreadyApproved`: If one of the spaces has already been approved.
+		/// - `CreatorIdentityNotVerified`: If the creator of one of the spaces does not hold the
+		///   judgement required by the identity policy.
+		/// - `TooManyCapacityResets`: If the usage resets cannot be scheduled.
+		///
+		/// # Events
+		/// - `Approve` and `CapacityTierAssign`: Emitted for each approved space.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::bulk_approve(space_ids.len() as u32))]
+		pub fn bulk_approve(
+			origin: OriginFor<T>,
+			space_ids: BoundedVec<SpaceIdOf, T::MaxSpacesPerBatch>,
+			tier: TierNameOf<T>,
+		) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			let details = CapacityTiers::<T>::get(&tier).ok_or(Error::<T>::CapacityTierNotFound)?;
+
+			for space_id in space_ids {
+				let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+				ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
+				ensure!(!space_details.approved, Error::<T>::SpaceAlreadyApproved);
+				ensure!(
+					Self::satisfies_identity_policy(&space_details.creator),
+					Error::<T>::CreatorIdentityNotVerified
+				);
+
+				Self::set_space_tier(
+					&space_id,
+					SpaceDetailsOf::<T> { approved: true, ..space_details },
+					Some((tier.clone(), details.clone())),
+				)?;
+
+				Self::update_activity(
+					&space_id,
+					IdentifierTypeOf::ChainSpace,
+					CallTypeOf::Approved,
+				)
+				.map_err(Error::<T>::from)?;
+
+				Self::deposit_event(Event::Approve { space: space_id.clone() });
+				Self::deposit_event(Event::CapacityTierAssign {
+					space: space_id,
+					tier: Some(tier.clone()),
+				});
+			}
+
+			Ok(())
+		}
+
+		/// Assigns a capacity tier to a batch of approved spaces, or releases
+		/// them from their tier.
+		///
+		/// With a tier, the capacity of the tier is applied to each space and
+		/// its usage reset is rescheduled from now. Without one, the spaces
+		/// keep their capacity and are no longer reset. The call fails as a
+		/// whole if the tier cannot be assigned to any of the spaces.
+		///
+		/// # Parameters
+		/// - `origin`: The origin of the transaction, which must be a council or root origin.
+		/// - `space_ids`: The identifiers of the spaces.
+		/// - `tier`: The name of the tier assigned to the spaces, or `None` to release them.
+		///
+		/// # Errors
+		/// - `CapacityTierNotFound`: If the tier does not exist.
+		/// - `SpaceNotFound`: If one of the space IDs does not correspond to an existing space.
+		/// - `ArchivedSpace`: If one of the spaces is archived.
+		/// - `SpaceNotApproved`: If one of the spaces is not approved.
+		/// - `CapacityLessThanUsage`: If the capacity of the tier is less than the usage of one of
+		///   the spaces.
+		/// - `TooManyCapacityResets`: If the usage resets cannot be scheduled.
+		///
+		/// # Events
+		/// - `CapacityTierAssign`: Emitted for each space.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::assign_capacity_tier(space_ids.len() as u32))]
+		pub fn assign_capacity_tier(
+			origin: OriginFor<T>,
+			space_ids: BoundedVec<SpaceIdOf, T::MaxSpacesPerBatch>,
+			tier: Option<TierNameOf<T>>,
+		) -> DispatchResult {
+			T::ChainSpaceOrigin::ensure_origin(origin)?;
+
+			let details = tier
+				.clone()
+				.map(|name| {
+					CapacityTiers::<T>::get(&name)
+						.map(|details| (name, details))
+						.ok_or(Error::<T>::CapacityTierNotFound)
+				})
+				.transpose()?;
+
+			for space_id in space_ids {
+				let space_details = Spaces::<T>::get(&space_id).ok_or(Error::<T>::SpaceNotFound)?;
+				ensure!(!space_details.archive, Error::<T>::ArchivedSpace);
+				ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
+
+				Self::set_space_tier(&space_id, space_details, details.clone())?;
+
+				Self::update_activity(
+					&space_id,
+					IdentifierTypeOf::ChainSpace,
+					CallTypeOf::Capacity,
+				)
+				.map_err(Error::<T>::from)?;
+
+				Self::deposit_event(Event::CapacityTierAssign {
+					space: space_id,
+					tier: tier.clone(),
+				});
+			}
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Computes the identifier of the space anchored by `creator` with the
+	/// given space code.
+	pub fn space_id(
+		space_code: &SpaceCodeOf<T>,
+		creator: &SpaceCreatorOf<T>,
+	) -> Result<SpaceIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_registry_input>,
+		// <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&space_code.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::Space)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)
+	}
+
+	/// Computes the identifier of the authorization of `delegate` in a space,
+	/// granted by `delegator`. The creator of a space is its own delegator.
+	pub fn authorization_id(
+		space_id: &SpaceIdOf,
+		delegate: &SpaceCreatorOf<T>,
+		delegator: &SpaceCreatorOf<T>,
+	) -> Result<AuthorizationIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_space_identifier>,
+		// <scale_encoded_delegate_identifier>, <scale_encoded_delegator_identifier>))
+		let id_digest = T::Hashing::hash(
+			&[&space_id.encode()[..], &delegate.encode()[..], &delegator.encode()[..]].concat()[..],
+		);
+
+		Ss58Identifier::create_identifier(&id_digest.encode(), IdentifierType::Authorization)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)
+	}
+
+	/// Anchors a new space with the creator as its first delegate.
+	///
+	/// Used by `create` and by the genesis build, which anchors the spaces
+	/// of the chain specification as approved with their capacity.
+	pub(crate) fn anchor_space(
+		space_code: SpaceCodeOf<T>,
+		creator: SpaceCreatorOf<T>,
+		approved: bool,
+		txn_capacity: u64,
+	) -> Result<(SpaceIdOf, AuthorizationIdOf), Error<T>> {
+		let identifier = Self::space_id(&space_code, &creator)?;
+
+		ensure!(!<Spaces<T>>::contains_key(&identifier), Error::<T>::SpaceAlreadyAnchored);
+
+		let authorization_id = Self::authorization_id(&identifier, &creator, &creator)?;
+
+		let mut delegates: BoundedVec<SpaceCreatorOf<T>, T::MaxSpaceDelegates> =
+			BoundedVec::default();
+		delegates
+			.try_push(creator.clone())
+			.map_err(|_| Error::<T>::SpaceDelegatesLimitExceeded)?;
+
+		Delegates::<T>::insert(&identifier, delegates);
+
+		Authorizations::<T>::insert(
+			&authorization_id,
+			SpaceAuthorizationOf::<T> {
+				space_id: identifier.clone(),
+				delegate: creator.clone(),
+				permissions: Permissions::all(),
+				delegator: creator.clone(),
+			},
+		);
+
+		<Spaces<T>>::insert(
+			&identifier,
+			SpaceDetailsOf::<T> {
+				code: space_code,
+				creator: creator.clone(),
+				txn_capacity,
+				txn_reserve: 0,
+				txn_count: 0,
+				approved,
+				archive: false,
+				parent: identifier.clone(),
+			},
+		);
+
+		Self::update_activity(&identifier, IdentifierTypeOf::ChainSpace, CallTypeOf::Genesis)?;
+
+		Ok((identifier, authorization_id))
+	}
+
 	/// Adds a delegate to a space with specified permissions.
 	///
 	/// This function will add a new delegate to a space, given the space's ID,
@@ -1203,15 +1609,7 @@ impl<T: Config> Pallet<T> {
 		creator: SpaceCreatorOf<T>,
 		permissions: Permissions,
 	) -> Result<(), Error<T>> {
-		// Id Digest = concat (H(<scale_encoded_space_identifier>,
-		// <scale_encoded_creator_identifier>, <scale_encoded_delegate_identifier>))
-		let id_digest = T::Hashing::hash(
-			&[&space_id.encode()[..], &delegate.encode()[..], &creator.encode()[..]].concat()[..],
-		);
-
-		let delegate_authorization_id =
-			Ss58Identifier::create_identifier(&id_digest.encode(), IdentifierType::Authorization)
-				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+		let delegate_authorization_id = Self::authorization_id(&space_id, &delegate, &creator)?;
 
 		ensure!(
 			!Authorizations::<T>::contains_key(&delegate_authorization_id),
@@ -1369,6 +1767,155 @@ impl<T: Config> Pallet<T> {
 		Ok(d.space_id)
 	}
 
+	/// Sets the transaction capacity of a space.
+	///
+	/// The new capacity must cover the current usage and reserve of the
+	/// space. For a subspace, the capacity is drawn from the reserve of its
+	/// parent, which must have room for the change.
+	fn apply_transaction_capacity(
+		space_id: &SpaceIdOf,
+		space_details: SpaceDetailsOf<T>,
+		new_txn_capacity: u64,
+	) -> Result<(), Error<T>> {
+		// Ensure the new capacity is greater than the current usage
+		ensure!(
+			new_txn_capacity >= (space_details.txn_count + space_details.txn_reserve),
+			Error::<T>::CapacityLessThanUsage
+		);
+
+		if space_id.clone() != space_details.parent.clone() {
+			let parent_details =
+				Spaces::<T>::get(&space_details.parent.clone()).ok_or(Error::<T>::SpaceNotFound)?;
+
+			// Ensure the new capacity is greater than the current usage
+			ensure!(
+				(parent_details.txn_capacity >=
+					(parent_details.txn_count + parent_details.txn_reserve + new_txn_capacity -
+						space_details.txn_capacity)),
+				Error::<T>::CapacityLessThanUsage
+			);
+
+			<Spaces<T>>::insert(
+				&space_details.parent.clone(),
+				SpaceDetailsOf::<T> {
+					txn_reserve: parent_details.txn_reserve - space_details.txn_capacity +
+						new_txn_capacity,
+					..parent_details.clone()
+				},
+			);
+		}
+
+		<Spaces<T>>::insert(
+			space_id,
+			SpaceDetailsOf::<T> { txn_capacity: new_txn_capacity, ..space_details },
+		);
+
+		Ok(())
+	}
+
+	/// Assigns a capacity tier to a space, or removes its tier.
+	///
+	/// Any scheduled usage reset of the space is dropped. With a tier, the
+	/// capacity of the tier is applied to the space and its next usage reset
+	/// is scheduled, if the tier has a reset period.
+	fn set_space_tier(
+		space_id: &SpaceIdOf,
+		space_details: SpaceDetailsOf<T>,
+		tier: Option<(TierNameOf<T>, CapacityTierOf<T>)>,
+	) -> Result<(), Error<T>> {
+		if let Some(SpaceCapacityTier { next_reset: Some(at), .. }) =
+			SpaceTiers::<T>::take(space_id)
+		{
+			CapacityResets::<T>::mutate(at, |resets| resets.retain(|s| s != space_id));
+		}
+
+		if let Some((name, tier)) = tier {
+			Self::apply_transaction_capacity(space_id, space_details, tier.txn_capacity)?;
+			let next_reset = Self::schedule_capacity_reset(space_id, tier.reset_period)?;
+			SpaceTiers::<T>::insert(space_id, SpaceCapacityTier { tier: name, next_reset });
+		}
+
+		Ok(())
+	}
+
+	/// Schedules the next usage reset of a space, a reset period from now.
+	/// Nothing is scheduled for a zero period.
+	///
+	/// Once the reset block is full, the reset is scheduled in the first of
+	/// the following `CAPACITY_RESET_WINDOW` blocks with room left, so that
+	/// the spaces of a large batch are spread over consecutive blocks.
+	fn schedule_capacity_reset(
+		space_id: &SpaceIdOf,
+		reset_period: BlockNumberFor<T>,
+	) -> Result<Option<BlockNumberFor<T>>, Error<T>> {
+		if reset_period.is_zero() {
+			return Ok(None);
+		}
+
+		let mut at = frame_system::Pallet::<T>::block_number().saturating_add(reset_period);
+		for _ in 0..CAPACITY_RESET_WINDOW {
+			if CapacityResets::<T>::try_mutate(at, |resets| resets.try_push(space_id.clone()))
+				.is_ok()
+			{
+				return Ok(Some(at));
+			}
+			at = at.saturating_add(One::one());
+		}
+
+		Err(Error::<T>::TooManyCapacityResets)
+	}
+
+	/// Resets the usage of the spaces scheduled for the block, and
+	/// schedules their next reset.
+	///
+	/// Spaces which have been archived keep their schedule, but their usage
+	/// is left untouched. Spaces whose tier has been removed are released
+	/// from it.
+	pub(crate) fn reset_capacity_tiers(n: BlockNumberFor<T>) -> Weight {
+		let mut total_weight = T::DbWeight::get().reads_writes(1, 1);
+
+		for space_id in CapacityResets::<T>::take(n) {
+			total_weight =
+				total_weight.saturating_add(<T as Config>::WeightInfo::reset_capacity_tier());
+
+			let Some(assigned) = SpaceTiers::<T>::get(&space_id) else { continue };
+			let Some(tier) = CapacityTiers::<T>::get(&assigned.tier) else {
+				SpaceTiers::<T>::remove(&space_id);
+				Self::deposit_event(Event::CapacityTierAssign { space: space_id, tier: None });
+				continue;
+			};
+
+			if let Some(space_details) = Spaces::<T>::get(&space_id) {
+				if !space_details.archive {
+					<Spaces<T>>::insert(
+						&space_id,
+						SpaceDetailsOf::<T> { txn_count: 0, ..space_details },
+					);
+					let _ = Self::update_activity(
+						&space_id,
+						IdentifierTypeOf::ChainSpace,
+						CallTypeOf::Usage,
+					);
+					Self::deposit_event(Event::ResetUsage { space: space_id.clone() });
+				}
+			}
+
+			match Self::schedule_capacity_reset(&space_id, tier.reset_period) {
+				Ok(next_reset) =>
+					SpaceTiers::<T>::insert(&space_id, SpaceCapacityTier { next_reset, ..assigned }),
+				Err(_) => {
+					SpaceTiers::<T>::insert(
+						&space_id,
+						SpaceCapacityTier { next_reset: None, ..assigned },
+					);
+					Self::deposit_event(Event::CapacityResetUnscheduled { space: space_id });
+				},
+			}
+		}
+
+		total_weight
+	}
+
 	/// Validates that a space is eligible for a new transaction.
 	///
 	/// This function ensures that a space is not archived, is approved, and has
@@ -1384,6 +1931,9 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for transactions.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
+		// Ensure the creator still holds the judgement, if required.
+		Self::ensure_identity_policy_for_transaction(&space_details.creator)?;
+
 		// Ensure the space has not exceeded its capacity limit.
 		if space_details.txn_capacity == 0 || space_details.txn_count < space_details.txn_capacity {
 			Ok(())
@@ -1392,6 +1942,29 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Checks whether the account linked to a space creator holds the
+	/// judgement required by the identity policy. Always true without a
+	/// policy.
+	pub fn satisfies_identity_policy(creator: &SpaceCreatorOf<T>) -> bool {
+		SpaceIdentityPolicy::<T>::get().map_or(true, |policy| {
+			T::CreatorAccount::convert(creator.clone()).map_or(false, |account| {
+				T::IdentityVerifier::has_judgement_from(&account, &policy.registrar)
+			})
+		})
+	}
+
+	/// Ensures that the creator of a space holds the judgement required by
+	/// the identity policy, when the policy is enforced on transactions.
+	fn ensure_identity_policy_for_transaction(creator: &SpaceCreatorOf<T>) -> Result<(), Error<T>> {
+		if SpaceIdentityPolicy::<T>::get().map_or(false, |policy| policy.enforce_on_transactions) {
+			ensure!(
+				Self::satisfies_identity_policy(creator),
+				Error::<T>::CreatorIdentityNotVerified
+			);
+		}
+		Ok(())
+	}
+
 	/// Validates a space for restore transactions.
 	///
 	/// This function checks that the specified space is approved and has not
@@ -1435,6 +2008,9 @@ impl<T: Config> Pallet<T> {
 		// Ensure the space is approved for adding new entries.
 		ensure!(space_details.approved, Error::<T>::SpaceNotApproved);
 
+		// Ensure the creator still holds the judgement, if required.
+		Self::ensure_identity_policy_for_transaction(&space_details.creator)?;
+
 		// Calculate the new usage to check against the capacity.
 		let new_usage = space_details
 			.txn_count
@@ -19,11 +19,11 @@
 use crate as pallet_chain_space;
 use cord_utilities::mock::{mock_origin, SubjectId};
 use frame_support::{derive_impl, parameter_types};
-use pallet_chain_space::IsPermissioned;
+use pallet_chain_space::{IdentityVerifier, IsPermissioned};
 
 use frame_system::EnsureRoot;
 use sp_runtime::{
-	traits::{IdentifyAccount, IdentityLookup, Verify},
+	traits::{Convert, IdentifyAccount, IdentityLookup, Verify},
 	BuildStorage, MultiSignature,
 };
 
@@ -64,6 +64,9 @@ impl mock_origin::Config for Test {
 parameter_types! {
 	#[derive(Debug, Clone)]
 	pub const MaxSpaceDelegates: u32 = 5u32;
+	pub const MaxTierNameLength: u32 = 16u32;
+	pub const MaxSpacesPerBatch: u32 = 5u32;
+	pub const MaxCapacityResetsPerBlock: u32 = 2u32;
 }
 
 pub struct NetworkPermission;
@@ -73,14 +76,43 @@ impl IsPermissioned for NetworkPermission {
 	}
 }
 
+parameter_types! {
+	/// The positive judgements, as (account, registrar) pairs.
+	pub static Judgements: Vec<(AccountId, AccountId)> = vec![];
+}
+
+pub struct TestIdentityVerifier;
+impl IdentityVerifier<AccountId> for TestIdentityVerifier {
+	fn has_verified_identity(who: &AccountId) -> bool {
+		Judgements::get().iter().any(|(account, _)| account == who)
+	}
+
+	fn has_judgement_from(who: &AccountId, registrar: &AccountId) -> bool {
+		Judgements::get().contains(&(who.clone(), registrar.clone()))
+	}
+}
+
+pub struct SubjectAccount;
+impl Convert<SubjectId, Option<AccountId>> for SubjectAccount {
+	fn convert(subject: SubjectId) -> Option<AccountId> {
+		Some(subject.0)
+	}
+}
+
 impl pallet_chain_space::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type EnsureOrigin = mock_origin::EnsureDoubleOrigin<AccountId, SubjectId>;
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxTierNameLength = MaxTierNameLength;
+	type MaxSpacesPerBatch = MaxSpacesPerBatch;
+	type MaxCapacityResetsPerBlock = MaxCapacityResetsPerBlock;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type NetworkParameters = ();
+	type IdentityVerifier = TestIdentityVerifier;
+	type CreatorAccount = SubjectAccount;
 	type WeightInfo = ();
 }
 
@@ -2,9 +2,9 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use frame_support::{assert_err, assert_ok, error::BadOrigin, traits::Hooks};
 use frame_system::RawOrigin;
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{traits::Hash, AccountId32, BuildStorage};
 use sp_std::prelude::*;
 
 pub fn generate_space_id<T: Config>(digest: &SpaceCodeOf<T>) -> SpaceIdOf {
@@ -1248,3 +1248,331 @@ fn add_delegator_should_fail_for_space_creator_delegating_themselves() {
 		);
 	});
 }
+
+//TEST FUNCTIONS FOR THE IDENTITY POLICY
+
+const REGISTRAR: AccountId = AccountId::new([9u8; 32]);
+
+fn set_identity_policy(enforce_on_transactions: bool, auto_suspend: bool) {
+	assert_ok!(Space::set_identity_policy(
+		RawOrigin::Root.into(),
+		Some(IdentityPolicy { registrar: REGISTRAR, enforce_on_transactions, auto_suspend }),
+	));
+}
+
+fn policy_space_ids() -> (SpaceCodeOf<Test>, SpaceIdOf, AuthorizationIdOf) {
+	let creator = DID_00;
+	let space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	(space_digest, space_id, authorization_id)
+}
+
+#[test]
+fn approve_should_require_creator_judgement() {
+	let (space_digest, space_id, _) = policy_space_ids();
+
+	new_test_ext().execute_with(|| {
+		set_identity_policy(false, false);
+		assert_ok!(Space::create(DoubleOrigin(ACCOUNT_00, DID_00).into(), space_digest));
+
+		assert_err!(
+			Space::approve(RawOrigin::Root.into(), space_id.clone(), 3u64),
+			Error::<Test>::CreatorIdentityNotVerified
+		);
+
+		// A judgement from another registrar is not enough.
+		Judgements::set(vec![(ACCOUNT_00, AccountId::new([8u8; 32]))]);
+		assert_err!(
+			Space::approve(RawOrigin::Root.into(), space_id.clone(), 3u64),
+			Error::<Test>::CreatorIdentityNotVerified
+		);
+
+		Judgements::set(vec![(ACCOUNT_00, REGISTRAR)]);
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id, 3u64));
+	});
+}
+
+#[test]
+fn transactions_should_require_creator_judgement_when_enforced() {
+	let (space_digest, space_id, authorization_id) = policy_space_ids();
+
+	new_test_ext().execute_with(|| {
+		set_identity_policy(true, false);
+		Judgements::set(vec![(ACCOUNT_00, REGISTRAR)]);
+		assert_ok!(Space::create(DoubleOrigin(ACCOUNT_00, DID_00).into(), space_digest));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), 5u64));
+
+		Judgements::set(vec![]);
+		assert_err!(
+			Space::add_delegate(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				space_id.clone(),
+				DID_01,
+				authorization_id.clone(),
+			),
+			Error::<Test>::CreatorIdentityNotVerified
+		);
+
+		// Without enforcement, the approved space can still be used.
+		set_identity_policy(false, false);
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			space_id,
+			DID_01,
+			authorization_id,
+		));
+	});
+}
+
+#[test]
+fn suspend_unverified_should_revoke_the_approval() {
+	let (space_digest, space_id, _) = policy_space_ids();
+
+	new_test_ext().execute_with(|| {
+		set_identity_policy(false, false);
+		Judgements::set(vec![(ACCOUNT_00, REGISTRAR)]);
+		assert_ok!(Space::create(DoubleOrigin(ACCOUNT_00, DID_00).into(), space_digest));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_id.clone(), 3u64));
+
+		assert_err!(
+			Space::suspend_unverified(RuntimeOrigin::signed(REGISTRAR), space_id.clone()),
+			Error::<Test>::AutoSuspendDisabled
+		);
+
+		set_identity_policy(false, true);
+		assert_err!(
+			Space::suspend_unverified(RuntimeOrigin::signed(REGISTRAR), space_id.clone()),
+			Error::<Test>::CreatorIdentityVerified
+		);
+
+		Judgements::set(vec![]);
+		assert_ok!(Space::suspend_unverified(RuntimeOrigin::signed(REGISTRAR), space_id.clone()));
+		assert!(!Spaces::<Test>::get(&space_id).unwrap().approved);
+		assert_eq!(
+			space_events_since_last_call().last(),
+			Some(&Event::IdentitySuspend { space: space_id.clone() })
+		);
+
+		// The approval can only be restored once the judgement is back.
+		assert_err!(
+			Space::approval_restore(RawOrigin::Root.into(), space_id.clone()),
+			Error::<Test>::CreatorIdentityNotVerified
+		);
+		Judgements::set(vec![(ACCOUNT_00, REGISTRAR)]);
+		assert_ok!(Space::approval_restore(RawOrigin::Root.into(), space_id));
+	});
+}
+
+fn tier_space_ids(seed: u8) -> (SpaceCodeOf<Test>, SpaceIdOf) {
+	let space = [seed; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&space.encode()[..]);
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &DID_00.encode()[..]].concat()[..],
+	);
+
+	(space_digest, generate_space_id::<Test>(&id_digest))
+}
+
+fn set_capacity_tier(name: &[u8], txn_capacity: u64, reset_period: u64) -> TierNameOf<Test> {
+	let name: TierNameOf<Test> = name.to_vec().try_into().unwrap();
+	assert_ok!(Space::set_capacity_tier(
+		RawOrigin::Root.into(),
+		name.clone(),
+		Some(CapacityTier { txn_capacity, reset_period }),
+	));
+	name
+}
+
+#[test]
+fn bulk_approve_should_assign_the_tier_and_reset_usage() {
+	let (digest_a, space_a) = tier_space_ids(2u8);
+	let (digest_b, space_b) = tier_space_ids(3u8);
+
+	new_test_ext().execute_with(|| {
+		let tier = set_capacity_tier(b"basic", 5, 10);
+		assert_err!(
+			Space::set_capacity_tier(
+				RawOrigin::Root.into(),
+				tier.clone(),
+				Some(CapacityTier { txn_capacity: 0, reset_period: 10 }),
+			),
+			Error::<Test>::ZeroTierCapacity
+		);
+		assert_ok!(Space::create(DoubleOrigin(ACCOUNT_00, DID_00).into(), digest_a));
+		assert_ok!(Space::create(DoubleOrigin(ACCOUNT_00, DID_00).into(), digest_b));
+
+		let space_ids: BoundedVec<_, _> =
+			vec![space_a.clone(), space_b.clone()].try_into().unwrap();
+		assert_err!(
+			Space::bulk_approve(
+				RawOrigin::Root.into(),
+				space_ids.clone(),
+				b"unknown".to_vec().try_into().unwrap(),
+			),
+			Error::<Test>::CapacityTierNotFound
+		);
+		assert_err!(
+			Space::bulk_approve(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				space_ids.clone(),
+				tier.clone()
+			),
+			BadOrigin
+		);
+		assert_ok!(Space::bulk_approve(RawOrigin::Root.into(), space_ids, tier.clone()));
+
+		for space_id in [&space_a, &space_b] {
+			let details = Spaces::<Test>::get(space_id).unwrap();
+			assert!(details.approved);
+			assert_eq!(details.txn_capacity, 5);
+			assert_eq!(
+				SpaceTiers::<Test>::get(space_id),
+				Some(SpaceCapacityTier { tier: tier.clone(), next_reset: Some(11) })
+			);
+		}
+		assert_eq!(CapacityResets::<Test>::get(11).len(), 2);
+
+		assert_ok!(Space::increment_usage_entries(&space_a, 5));
+		assert_err!(
+			Space::validate_space_for_transaction(&space_a),
+			Error::<Test>::CapacityLimitExceeded
+		);
+
+		System::set_block_number(11);
+		Space::on_initialize(11);
+
+		assert_eq!(Spaces::<Test>::get(&space_a).unwrap().txn_count, 0);
+		assert_ok!(Space::validate_space_for_transaction(&space_a));
+		assert!(CapacityResets::<Test>::get(11).is_empty());
+		assert_eq!(CapacityResets::<Test>::get(21).len(), 2);
+		assert_eq!(SpaceTiers::<Test>::get(&space_a).unwrap().next_reset, Some(21));
+
+		// The spaces of a removed tier are released on their next reset.
+		assert_ok!(Space::set_capacity_tier(RawOrigin::Root.into(), tier.clone(), None));
+		System::set_block_number(21);
+		Space::on_initialize(21);
+		assert_eq!(SpaceTiers::<Test>::get(&space_a), None);
+		assert!(CapacityResets::<Test>::get(31).is_empty());
+		assert_eq!(
+			space_events_since_last_call().last(),
+			Some(&Event::CapacityTierAssign { space: space_b, tier: None })
+		);
+	});
+}
+
+#[test]
+fn assign_capacity_tier_should_reschedule_and_release_spaces() {
+	let (digest_a, space_a) = tier_space_ids(2u8);
+	let (digest_b, space_b) = tier_space_ids(3u8);
+	let (digest_c, space_c) = tier_space_ids(4u8);
+
+	new_test_ext().execute_with(|| {
+		let basic = set_capacity_tier(b"basic", 5, 10);
+		let large = set_capacity_tier(b"large", 50, 0);
+		for digest in [digest_a, digest_b, digest_c] {
+			assert_ok!(Space::create(DoubleOrigin(ACCOUNT_00, DID_00).into(), digest));
+		}
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_a.clone(), 3u64));
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_b.clone(), 3u64));
+
+		// Only approved spaces can be assigned a tier.
+		assert_err!(
+			Space::assign_capacity_tier(
+				RawOrigin::Root.into(),
+				vec![space_a.clone(), space_c.clone()].try_into().unwrap(),
+				Some(basic.clone()),
+			),
+			Error::<Test>::SpaceNotApproved
+		);
+
+		assert_ok!(Space::assign_capacity_tier(
+			RawOrigin::Root.into(),
+			vec![space_a.clone(), space_b.clone()].try_into().unwrap(),
+			Some(basic.clone()),
+		));
+		assert_eq!(Spaces::<Test>::get(&space_a).unwrap().txn_capacity, 5);
+		assert_eq!(CapacityResets::<Test>::get(11).len(), 2);
+
+		// Resets beyond the limit of a block are scheduled in the next one.
+		assert_ok!(Space::approve(RawOrigin::Root.into(), space_c.clone(), 3u64));
+		assert_ok!(Space::assign_capacity_tier(
+			RawOrigin::Root.into(),
+			vec![space_c.clone()].try_into().unwrap(),
+			Some(basic),
+		));
+		assert_eq!(CapacityResets::<Test>::get(12).into_inner(), vec![space_c.clone()]);
+		assert_eq!(SpaceTiers::<Test>::get(&space_c).unwrap().next_reset, Some(12));
+
+		// A tier without reset period drops the scheduled reset.
+		assert_ok!(Space::increment_usage_entries(&space_a, 4));
+		assert_ok!(Space::assign_capacity_tier(
+			RawOrigin::Root.into(),
+			vec![space_a.clone()].try_into().unwrap(),
+			Some(large.clone()),
+		));
+		assert_eq!(Spaces::<Test>::get(&space_a).unwrap().txn_capacity, 50);
+		assert_eq!(
+			SpaceTiers::<Test>::get(&space_a),
+			Some(SpaceCapacityTier { tier: large, next_reset: None })
+		);
+		assert_eq!(CapacityResets::<Test>::get(11).into_inner(), vec![space_b.clone()]);
+
+		// Released spaces keep their capacity.
+		assert_ok!(Space::assign_capacity_tier(
+			RawOrigin::Root.into(),
+			vec![space_b.clone()].try_into().unwrap(),
+			None,
+		));
+		assert_eq!(SpaceTiers::<Test>::get(&space_b), None);
+		assert_eq!(Spaces::<Test>::get(&space_b).unwrap().txn_capacity, 5);
+		assert!(CapacityResets::<Test>::get(11).is_empty());
+
+		System::set_block_number(11);
+		Space::on_initialize(11);
+		assert_eq!(Spaces::<Test>::get(&space_a).unwrap().txn_count, 4);
+	});
+}
+
+#[test]
+fn genesis_build_should_anchor_approved_spaces() {
+	let space_code = <Test as frame_system::Config>::Hashing::hash(b"genesis-space");
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_code.encode()[..], &DID_00.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_id.encode()[..], &DID_00.encode()[..], &DID_00.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	GenesisConfig::<Test> { spaces: vec![(space_code, DID_00, 10)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		assert_eq!(Space::space_id(&space_code, &DID_00), Ok(space_id.clone()));
+		let space = Spaces::<Test>::get(&space_id).unwrap();
+		assert!(space.approved);
+		assert_eq!(space.creator, DID_00);
+		assert_eq!(space.txn_capacity, 10);
+
+		// The space is usable from the first block.
+		System::set_block_number(1);
+		assert_ok!(Space::add_delegate(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			space_id,
+			DID_01,
+			authorization_id,
+		));
+	});
+}
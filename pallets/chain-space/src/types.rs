@@ -108,3 +108,52 @@ pub struct SpaceAuthorization<SpaceIdOf, SpaceCreatorOf, Permissions> {
 	pub permissions: Permissions,
 	pub delegator: SpaceCreatorOf,
 }
+
+/// Identity policy of the chain spaces.
+///
+/// When set, the account linked to the creator of a space must hold a
+/// `KnownGood` or `Reasonable` judgement from the registrar for the space to
+/// be approved.
+///
+/// ## Fields
+///
+/// - `registrar`: The registrar whose judgement is required.
+/// - `enforce_on_transactions`: Whether the judgement is also required to transact in an approved
+///   space, e.g. to issue statements.
+/// - `auto_suspend`: Whether anyone can suspend an approved space whose creator no longer holds the
+///   judgement.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct IdentityPolicy<AccountId> {
+	pub registrar: AccountId,
+	pub enforce_on_transactions: bool,
+	pub auto_suspend: bool,
+}
+
+/// Capacity tier of the chain spaces.
+///
+/// A named policy which governance assigns to the spaces, in place of an
+/// absolute capacity per space.
+///
+/// ## Fields
+///
+/// - `txn_capacity`: The transaction capacity given to the spaces of the tier. It must be non-zero,
+///   as tiers cannot grant unlimited capacity.
+/// - `reset_period`: The number of blocks after which the usage of the spaces of the tier is reset.
+///   A value of zero disables the periodic reset.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct CapacityTier<BlockNumber> {
+	pub txn_capacity: u64,
+	pub reset_period: BlockNumber,
+}
+
+/// Capacity tier assigned to a space.
+///
+/// ## Fields
+///
+/// - `tier`: The name of the assigned tier.
+/// - `next_reset`: The block at which the usage of the space is next reset, if any.
+#[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+pub struct SpaceCapacityTier<TierName, BlockNumber> {
+	pub tier: TierName,
+	pub next_reset: Option<BlockNumber>,
+}
@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -62,6 +66,12 @@ pub trait WeightInfo {
 	fn approval_revoke() -> Weight;
 	fn approval_restore() -> Weight;
 	fn subspace_create() -> Weight;
+	fn set_identity_policy() -> Weight;
+	fn suspend_unverified() -> Weight;
+	fn set_capacity_tier() -> Weight;
+	fn bulk_approve(n: u32, ) -> Weight;
+	fn assign_capacity_tier(n: u32, ) -> Weight;
+	fn reset_capacity_tier() -> Weight;
 }
 
 /// Weights for `pallet_chain_space` using the CORD node and recommended hardware.
@@ -75,14 +85,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_470_000 picoseconds.
 		Weight::from_parts(41_550_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -92,14 +103,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_851_000 picoseconds.
 		Weight::from_parts(41_909_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -109,14 +121,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_730_000 picoseconds.
 		Weight::from_parts(41_720_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -126,19 +139,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1061`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_860_000 picoseconds.
 		Weight::from_parts(41_970_000, 323533)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -149,21 +164,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3671`
 		// Minimum execution time: 27_280_000 picoseconds.
 		Weight::from_parts(27_960_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `494`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_331_000 picoseconds.
 		Weight::from_parts(23_009_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -171,14 +187,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `715`
 		//  Estimated: `3671`
 		// Minimum execution time: 31_040_000 picoseconds.
 		Weight::from_parts(31_750_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -186,71 +203,77 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `724`
 		//  Estimated: `3671`
 		// Minimum execution time: 30_580_000 picoseconds.
 		Weight::from_parts(31_880_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_570_000 picoseconds.
 		Weight::from_parts(23_300_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_330_000 picoseconds.
 		Weight::from_parts(23_040_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_000_000 picoseconds.
 		Weight::from_parts(22_650_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `512`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_150_000 picoseconds.
 		Weight::from_parts(22_570_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -261,7 +284,87 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6352`
 		// Minimum execution time: 36_090_000 picoseconds.
 		Weight::from_parts(36_930_000, 6352)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:0 w:1)
+	/// Proof: `ChainSpace::SpaceIdentityPolicy` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	fn set_identity_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_450_000 picoseconds.
+		Weight::from_parts(6_820_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:1 w:0)
+	/// Proof: `ChainSpace::SpaceIdentityPolicy` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	fn suspend_unverified() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912`
+		//  Estimated: `5731`
+		// Minimum execution time: 29_870_000 picoseconds.
+		Weight::from_parts(30_940_000, 5731)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:1)
+	fn set_capacity_tier() -> Weight {
+		Weight::from_parts(10_340_000, 3526)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:50 w:50)
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:1 w:0)
+	/// Storage: `ChainSpace::SpaceTiers` (r:50 w:50)
+	/// Storage: `ChainSpace::CapacityResets` (r:50 w:50)
+	/// Storage: `Identifier::Identifiers` (r:50 w:50)
+	/// Storage: `Identifier::TimelineLength` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn bulk_approve(n: u32, ) -> Weight {
+		Weight::from_parts(23_518_402, 8486)
+			.saturating_add(Weight::from_parts(19_794_221, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(n.into()))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:50 w:50)
+	/// Storage: `ChainSpace::SpaceTiers` (r:50 w:50)
+	/// Storage: `ChainSpace::CapacityResets` (r:50 w:50)
+	/// Storage: `Identifier::Identifiers` (r:50 w:50)
+	/// Storage: `Identifier::TimelineLength` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn assign_capacity_tier(n: u32, ) -> Weight {
+		Weight::from_parts(21_904_117, 8486)
+			.saturating_add(Weight::from_parts(18_632_905, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(n.into()))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::SpaceTiers` (r:1 w:1)
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Storage: `ChainSpace::CapacityResets` (r:1 w:1)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	fn reset_capacity_tier() -> Weight {
+		Weight::from_parts(28_150_000, 8486)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 }
@@ -276,14 +379,15 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_470_000 picoseconds.
 		Weight::from_parts(41_550_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -293,14 +397,15 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_851_000 picoseconds.
 		Weight::from_parts(41_909_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -310,14 +415,15 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_730_000 picoseconds.
 		Weight::from_parts(41_720_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -327,19 +433,21 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1061`
 		//  Estimated: `323533`
 		// Minimum execution time: 40_860_000 picoseconds.
 		Weight::from_parts(41_970_000, 323533)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -350,21 +458,22 @@ impl WeightInfo for () {
 		//  Estimated: `3671`
 		// Minimum execution time: 27_280_000 picoseconds.
 		Weight::from_parts(27_960_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `494`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_331_000 picoseconds.
 		Weight::from_parts(23_009_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -372,14 +481,15 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `715`
 		//  Estimated: `3671`
 		// Minimum execution time: 31_040_000 picoseconds.
 		Weight::from_parts(31_750_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -387,71 +497,77 @@ impl WeightInfo for () {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `724`
 		//  Estimated: `3671`
 		// Minimum execution time: 30_580_000 picoseconds.
 		Weight::from_parts(31_880_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_570_000 picoseconds.
 		Weight::from_parts(23_300_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_330_000 picoseconds.
 		Weight::from_parts(23_040_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_000_000 picoseconds.
 		Weight::from_parts(22_650_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `512`
 		//  Estimated: `3671`
 		// Minimum execution time: 22_150_000 picoseconds.
 		Weight::from_parts(22_570_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -462,7 +578,87 @@ impl WeightInfo for () {
 		//  Estimated: `6352`
 		// Minimum execution time: 36_090_000 picoseconds.
 		Weight::from_parts(36_930_000, 6352)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:0 w:1)
+	/// Proof: `ChainSpace::SpaceIdentityPolicy` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	fn set_identity_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_450_000 picoseconds.
+		Weight::from_parts(6_820_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:1 w:0)
+	/// Proof: `ChainSpace::SpaceIdentityPolicy` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	fn suspend_unverified() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912`
+		//  Estimated: `5731`
+		// Minimum execution time: 29_870_000 picoseconds.
+		Weight::from_parts(30_940_000, 5731)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:1)
+	fn set_capacity_tier() -> Weight {
+		Weight::from_parts(10_340_000, 3526)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:50 w:50)
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:1 w:0)
+	/// Storage: `ChainSpace::SpaceTiers` (r:50 w:50)
+	/// Storage: `ChainSpace::CapacityResets` (r:50 w:50)
+	/// Storage: `Identifier::Identifiers` (r:50 w:50)
+	/// Storage: `Identifier::TimelineLength` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn bulk_approve(n: u32, ) -> Weight {
+		Weight::from_parts(23_518_402, 8486)
+			.saturating_add(Weight::from_parts(19_794_221, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(n.into()))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:50 w:50)
+	/// Storage: `ChainSpace::SpaceTiers` (r:50 w:50)
+	/// Storage: `ChainSpace::CapacityResets` (r:50 w:50)
+	/// Storage: `Identifier::Identifiers` (r:50 w:50)
+	/// Storage: `Identifier::TimelineLength` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn assign_capacity_tier(n: u32, ) -> Weight {
+		Weight::from_parts(21_904_117, 8486)
+			.saturating_add(Weight::from_parts(18_632_905, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((5_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((5_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(n.into()))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::SpaceTiers` (r:1 w:1)
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Storage: `ChainSpace::CapacityResets` (r:1 w:1)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	fn reset_capacity_tier() -> Weight {
+		Weight::from_parts(28_150_000, 8486)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
 			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 }
@@ -0,0 +1,62 @@
+// CORD Blockchain – https://dhiway.network
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	set_network_permissioned {
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	}: _<T::RuntimeOrigin>(origin, false)
+	verify {
+		assert_last_event::<T>(Event::NetworkPermissionedSet { permissioned: false }.into());
+	}
+
+	set_parameter {
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let old = NetworkParameterOf::<T>::DefaultSpaceCapacity(0);
+		Parameters::<T>::insert(old.key(), old.clone());
+		let new = NetworkParameterOf::<T>::DefaultSpaceCapacity(1);
+	}: _<T::RuntimeOrigin>(origin, new.clone())
+	verify {
+		assert_last_event::<T>(Event::ParameterSet { old: Some(old), new }.into());
+	}
+
+	clear_parameter {
+		let origin = T::ConfigOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let parameter = NetworkParameterOf::<T>::DefaultSpaceCapacity(0);
+		let key = parameter.key();
+		Parameters::<T>::insert(key, parameter);
+	}: _<T::RuntimeOrigin>(origin, key)
+	verify {
+		assert_last_event::<T>(Event::ParameterCleared { key }.into());
+	}
+
+	impl_benchmark_test_suite! (
+		Pallet,
+		crate::mock::new_test_ext(),
+		crate::mock::Test
+	)
+}
@@ -15,24 +15,58 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-//! # Network Membership Manager
+//! # Network Configuration
+//!
+//! Stores the network parameters which can be changed by governance without
+//! a runtime upgrade. Parameters are typed, checked against the bounds
+//! configured by the runtime and read by the other pallets through the
+//! `cord_primitives::NetworkParameters` trait. Parameters which have not been
+//! set on chain fall back to the compile-time defaults of the reading pallet.
 #![warn(unused_extern_crates)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub mod types;
+
+pub use crate::types::*;
 pub use pallet::*;
+pub use weights::WeightInfo;
 
-// use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
 
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
-	use frame_support::{pallet_prelude::*, traits::StorageVersion};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Contains, StorageVersion},
+	};
+	use frame_system::pallet_prelude::*;
 	use sp_std::marker::PhantomData;
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
+	pub type NetworkParameterOf<T> = NetworkParameter<BlockNumberFor<T>>;
+
 	#[pallet::config]
-	pub trait Config: frame_system::Config {}
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+		/// The origin which can change the network parameters.
+		type ConfigOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// The accepted values of each network parameter.
+		type ParameterBounds: Contains<NetworkParameterOf<Self>>;
+		type WeightInfo: WeightInfo;
+	}
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -42,6 +76,31 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type NetworkPermissioned<T> = StorageValue<_, bool, ValueQuery>;
 
+	/// Network parameters set on chain.
+	/// It maps from a parameter key to the parameter value.
+	#[pallet::storage]
+	pub type Parameters<T> =
+		StorageMap<_, Blake2_128Concat, NetworkParameterKey, NetworkParameterOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The network type has been changed.
+		NetworkPermissionedSet { permissioned: bool },
+		/// A network parameter has been set.
+		ParameterSet { old: Option<NetworkParameterOf<T>>, new: NetworkParameterOf<T> },
+		/// A network parameter has been cleared and falls back to its default.
+		ParameterCleared { key: NetworkParameterKey },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The parameter value is outside of the configured bounds.
+		ParameterOutOfBounds,
+		/// The parameter has not been set.
+		ParameterNotSet,
+	}
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -55,6 +114,63 @@ pub mod pallet {
 			NetworkPermissioned::<T>::put(&self.permissioned);
 		}
 	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Switch the network between permissioned and permissionless.
+		/// Only the config origin can perform this action.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_network_permissioned())]
+		pub fn set_network_permissioned(
+			origin: OriginFor<T>,
+			permissioned: bool,
+		) -> DispatchResult {
+			T::ConfigOrigin::ensure_origin(origin)?;
+
+			NetworkPermissioned::<T>::put(permissioned);
+
+			Self::deposit_event(Event::NetworkPermissionedSet { permissioned });
+
+			Ok(())
+		}
+
+		/// Set a network parameter. Only the config origin can perform this
+		/// action.
+		///
+		/// The value must be accepted by the configured parameter bounds.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_parameter())]
+		pub fn set_parameter(
+			origin: OriginFor<T>,
+			parameter: NetworkParameterOf<T>,
+		) -> DispatchResult {
+			T::ConfigOrigin::ensure_origin(origin)?;
+
+			ensure!(T::ParameterBounds::contains(&parameter), Error::<T>::ParameterOutOfBounds);
+
+			let old =
+				Parameters::<T>::mutate(parameter.key(), |value| value.replace(parameter.clone()));
+
+			Self::deposit_event(Event::ParameterSet { old, new: parameter });
+
+			Ok(())
+		}
+
+		/// Clear a network parameter, so that readers fall back to their
+		/// compile-time default. Only the config origin can perform this
+		/// action.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::clear_parameter())]
+		pub fn clear_parameter(origin: OriginFor<T>, key: NetworkParameterKey) -> DispatchResult {
+			T::ConfigOrigin::ensure_origin(origin)?;
+
+			ensure!(Parameters::<T>::take(key).is_some(), Error::<T>::ParameterNotSet);
+
+			Self::deposit_event(Event::ParameterCleared { key });
+
+			Ok(())
+		}
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -69,3 +185,26 @@ impl<T: Config> cord_primitives::IsPermissioned for Pallet<T> {
 		Self::is_permissioned()
 	}
 }
+
+impl<T: Config> cord_primitives::NetworkParameters<BlockNumberFor<T>> for Pallet<T> {
+	fn default_space_capacity() -> Option<u64> {
+		match Parameters::<T>::get(NetworkParameterKey::DefaultSpaceCapacity) {
+			Some(NetworkParameter::DefaultSpaceCapacity(capacity)) => Some(capacity),
+			_ => None,
+		}
+	}
+
+	fn max_digests_per_batch() -> Option<u16> {
+		match Parameters::<T>::get(NetworkParameterKey::MaxDigestsPerBatch) {
+			Some(NetworkParameter::MaxDigestsPerBatch(max_digests)) => Some(max_digests),
+			_ => None,
+		}
+	}
+
+	fn membership_period() -> Option<BlockNumberFor<T>> {
+		match Parameters::<T>::get(NetworkParameterKey::MembershipPeriod) {
+			Some(NetworkParameter::MembershipPeriod(period)) => Some(period),
+			_ => None,
+		}
+	}
+}
@@ -0,0 +1,76 @@
+// CORD Blockchain – https://dhiway.network
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_config, NetworkParameter, NetworkParameterOf};
+use frame_support::{derive_impl, parameter_types, traits::Contains};
+use frame_system::EnsureRoot;
+use sp_runtime::{
+	traits::{IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, MultiSignature,
+};
+
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		NetworkParameters: pallet_config,
+	}
+);
+
+parameter_types! {
+	pub const SS58Prefix: u8 = 29;
+}
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+pub struct TestParameterBounds;
+impl Contains<NetworkParameterOf<Test>> for TestParameterBounds {
+	fn contains(parameter: &NetworkParameterOf<Test>) -> bool {
+		match parameter {
+			NetworkParameter::DefaultSpaceCapacity(capacity) => *capacity <= 1_000,
+			NetworkParameter::MaxDigestsPerBatch(max_digests) => (1..=100).contains(max_digests),
+			NetworkParameter::MembershipPeriod(period) => (10..=1_000).contains(period),
+		}
+	}
+}
+
+impl pallet_config::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type ConfigOrigin = EnsureRoot<AccountId>;
+	type ParameterBounds = TestParameterBounds;
+	type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_config::GenesisConfig::<Test> { permissioned: true, _marker: Default::default() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
@@ -0,0 +1,127 @@
+// CORD Blockchain – https://dhiway.network
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::mock::*;
+use cord_primitives::NetworkParameters as _;
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::DispatchError;
+
+#[test]
+fn set_network_permissioned_works() {
+	new_test_ext().execute_with(|| {
+		assert!(Pallet::<Test>::is_permissioned());
+
+		assert_ok!(NetworkParameters::set_network_permissioned(RuntimeOrigin::root(), false));
+		assert!(!Pallet::<Test>::is_permissioned());
+		System::assert_last_event(Event::NetworkPermissionedSet { permissioned: false }.into());
+	});
+}
+
+#[test]
+fn set_network_permissioned_requires_config_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			NetworkParameters::set_network_permissioned(
+				RuntimeOrigin::signed(AccountId::new([1u8; 32])),
+				false
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_parameter_works() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Pallet::<Test>::max_digests_per_batch(), None);
+
+		assert_ok!(NetworkParameters::set_parameter(
+			RuntimeOrigin::root(),
+			NetworkParameter::MaxDigestsPerBatch(50)
+		));
+		assert_eq!(Pallet::<Test>::max_digests_per_batch(), Some(50));
+		System::assert_last_event(
+			Event::ParameterSet { old: None, new: NetworkParameter::MaxDigestsPerBatch(50) }.into(),
+		);
+
+		assert_ok!(NetworkParameters::set_parameter(
+			RuntimeOrigin::root(),
+			NetworkParameter::MaxDigestsPerBatch(20)
+		));
+		assert_eq!(Pallet::<Test>::max_digests_per_batch(), Some(20));
+		System::assert_last_event(
+			Event::ParameterSet {
+				old: Some(NetworkParameter::MaxDigestsPerBatch(50)),
+				new: NetworkParameter::MaxDigestsPerBatch(20),
+			}
+			.into(),
+		);
+
+		// Other parameters are not affected.
+		assert_eq!(Pallet::<Test>::default_space_capacity(), None);
+		assert_eq!(Pallet::<Test>::membership_period(), None);
+	});
+}
+
+#[test]
+fn set_parameter_out_of_bounds_fails() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			NetworkParameters::set_parameter(
+				RuntimeOrigin::root(),
+				NetworkParameter::MaxDigestsPerBatch(0)
+			),
+			Error::<Test>::ParameterOutOfBounds
+		);
+		assert_noop!(
+			NetworkParameters::set_parameter(
+				RuntimeOrigin::root(),
+				NetworkParameter::MembershipPeriod(5)
+			),
+			Error::<Test>::ParameterOutOfBounds
+		);
+	});
+}
+
+#[test]
+fn clear_parameter_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			NetworkParameters::clear_parameter(
+				RuntimeOrigin::root(),
+				NetworkParameterKey::DefaultSpaceCapacity
+			),
+			Error::<Test>::ParameterNotSet
+		);
+
+		assert_ok!(NetworkParameters::set_parameter(
+			RuntimeOrigin::root(),
+			NetworkParameter::DefaultSpaceCapacity(100)
+		));
+		assert_eq!(Pallet::<Test>::default_space_capacity(), Some(100));
+
+		assert_ok!(NetworkParameters::clear_parameter(
+			RuntimeOrigin::root(),
+			NetworkParameterKey::DefaultSpaceCapacity
+		));
+		assert_eq!(Pallet::<Test>::default_space_capacity(), None);
+		System::assert_last_event(
+			Event::ParameterCleared { key: NetworkParameterKey::DefaultSpaceCapacity }.into(),
+		);
+	});
+}
@@ -0,0 +1,53 @@
+// CORD Blockchain – https://dhiway.network
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with this program. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// Keys of the network parameters managed by the pallet.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum NetworkParameterKey {
+	/// Transaction capacity assigned to chain spaces approved on creation.
+	DefaultSpaceCapacity,
+	/// Max number of digests accepted in a statement batch.
+	MaxDigestsPerBatch,
+	/// Life span of an expiring network membership, in blocks.
+	MembershipPeriod,
+}
+
+/// A network parameter together with its typed value.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum NetworkParameter<BlockNumber> {
+	/// Transaction capacity assigned to chain spaces approved on creation.
+	DefaultSpaceCapacity(u64),
+	/// Max number of digests accepted in a statement batch.
+	MaxDigestsPerBatch(u16),
+	/// Life span of an expiring network membership, in blocks.
+	MembershipPeriod(BlockNumber),
+}
+
+impl<BlockNumber> NetworkParameter<BlockNumber> {
+	/// Return the key the parameter is stored under.
+	pub fn key(&self) -> NetworkParameterKey {
+		match self {
+			Self::DefaultSpaceCapacity(_) => NetworkParameterKey::DefaultSpaceCapacity,
+			Self::MaxDigestsPerBatch(_) => NetworkParameterKey::MaxDigestsPerBatch,
+			Self::MembershipPeriod(_) => NetworkParameterKey::MembershipPeriod,
+		}
+	}
+}
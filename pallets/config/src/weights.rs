@@ -0,0 +1,128 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `pallet_config`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-05-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_config
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/config/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_config`.
+pub trait WeightInfo {
+	fn set_network_permissioned() -> Weight;
+	fn set_parameter() -> Weight;
+	fn clear_parameter() -> Weight;
+}
+
+/// Weights for `pallet_config` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `NetworkParameters::NetworkPermissioned` (r:0 w:1)
+	/// Proof: `NetworkParameters::NetworkPermissioned` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_network_permissioned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_210_000 picoseconds.
+		Weight::from_parts(5_480_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkParameters::Parameters` (r:1 w:1)
+	/// Proof: `NetworkParameters::Parameters` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_parameter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3490`
+		// Minimum execution time: 9_770_000 picoseconds.
+		Weight::from_parts(10_160_000, 3490)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkParameters::Parameters` (r:1 w:1)
+	/// Proof: `NetworkParameters::Parameters` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn clear_parameter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64`
+		//  Estimated: `3490`
+		// Minimum execution time: 10_840_000 picoseconds.
+		Weight::from_parts(11_290_000, 3490)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `NetworkParameters::NetworkPermissioned` (r:0 w:1)
+	/// Proof: `NetworkParameters::NetworkPermissioned` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_network_permissioned() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_210_000 picoseconds.
+		Weight::from_parts(5_480_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkParameters::Parameters` (r:1 w:1)
+	/// Proof: `NetworkParameters::Parameters` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_parameter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `6`
+		//  Estimated: `3490`
+		// Minimum execution time: 9_770_000 picoseconds.
+		Weight::from_parts(10_160_000, 3490)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkParameters::Parameters` (r:1 w:1)
+	/// Proof: `NetworkParameters::Parameters` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn clear_parameter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `64`
+		//  Estimated: `3490`
+		// Minimum execution time: 10_840_000 picoseconds.
+		Weight::from_parts(11_290_000, 3490)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+}
@@ -25,13 +25,32 @@ use frame_support::{
 	pallet_prelude::EnsureOrigin, sp_runtime::SaturatedConversion, traits::Get, BoundedVec,
 };
 use frame_system::RawOrigin;
-use sp_runtime::app_crypto::sr25519;
+use sp_runtime::{
+	app_crypto::sr25519,
+	traits::{Saturating, Zero},
+};
 use sp_std::{vec, vec::Vec};
 
+use cord_primitives::UsernameRegistry;
 use cord_utilities::traits::GenerateBenchmarkOrigin;
 
 const CALLER_SEED: u32 = 0;
 const OWNER_SEED: u32 = 1;
+const RECIPIENT_SEED: u32 = 2;
+
+/// Generate a sub-name `b….b@aaa.cord` of the given length, one level below
+/// the top-level name `aaa@cord`.
+fn generate_did_sub_name_input(length: usize) -> (Vec<u8>, Vec<u8>) {
+	let root = b"aaa".to_vec();
+	let label_length = length.saturating_sub(root.len() + 6);
+	let mut sub_name = vec![b'b'; label_length];
+	sub_name.push(b'@');
+	sub_name.extend(root.iter());
+	sub_name.extend(b".cord".iter());
+	let mut root_name = root;
+	root_name.extend(b"@cord".iter());
+	(root_name, sub_name)
+}
 
 fn generate_did_name_input(length: usize) -> Vec<u8> {
 	let max_length = length.saturating_sub(10_usize);
@@ -115,6 +134,193 @@ benchmarks! {
 		assert!(Banned::<T>::get(&did_name).is_none());
 	}
 
+	renew {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+		frame_system::Pallet::<T>::set_block_number(T::RegistrationPeriod::get());
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		let ownership = Owner::<T>::get(&did_name).expect("Name should be registered.");
+		assert_eq!(ownership.registered_at, T::RegistrationPeriod::get());
+	}
+
+	transfer {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let recipient: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin, recipient.clone())
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert_eq!(PendingTransfers::<T>::get(&did_name), Some(recipient));
+	}
+
+	cancel_transfer {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let recipient: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+		Pallet::<T>::transfer(origin.clone(), recipient).expect("Should request the transfer.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(PendingTransfers::<T>::get(&did_name).is_none());
+	}
+
+	accept_transfer {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let recipient: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let owner_origin = T::EnsureOrigin::generate_origin(caller.clone(), owner.clone());
+		let recipient_origin = T::EnsureOrigin::generate_origin(caller, recipient.clone());
+
+		Pallet::<T>::register(owner_origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+		Pallet::<T>::transfer(owner_origin, recipient.clone()).expect("Should request the transfer.");
+	}: _<T::RuntimeOrigin>(recipient_origin, did_name_input_clone)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(Names::<T>::get(&owner).is_none());
+		assert_eq!(Names::<T>::get(&recipient), Some(did_name.clone()));
+		assert_eq!(Owner::<T>::get(&did_name).map(|o| o.owner), Some(recipient));
+	}
+
+	expire {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MaxNameLength::get().saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
+
+		Pallet::<T>::register(origin, did_name_input.clone()).expect("Should register the did name.");
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		let release_at = Owner::<T>::get(&did_name)
+			.and_then(|ownership| Pallet::<T>::expires_at(&ownership))
+			.map(|expires_at| expires_at.saturating_add(T::GracePeriod::get()))
+			.unwrap_or_default();
+	}: {
+		Pallet::<T>::expire_names(release_at);
+	}
+	verify {
+		if !T::RegistrationPeriod::get().is_zero() {
+			assert!(Names::<T>::get(&owner).is_none());
+			assert!(Owner::<T>::get(&did_name).is_none());
+		}
+	}
+
+	issue_sub_name {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MinNameLength::get().saturating_add(T::MaxSubNameLabelLength::get()).saturating_add(6));
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let recipient: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let (root_input, sub_name_input) = generate_did_sub_name_input(n.saturated_into());
+		let root_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(root_input).expect("BoundedVec creation should not fail.");
+		let sub_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(sub_name_input).expect("BoundedVec creation should not fail.");
+		let sub_name_input_clone = sub_name_input.clone();
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), root_input).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin, sub_name_input_clone, recipient.clone())
+	verify {
+		let sub_name = DidNameOf::<T>::try_from(sub_name_input.to_vec()).unwrap();
+		assert_eq!(Pallet::<T>::resolve_owner(&sub_name), Some(recipient));
+	}
+
+	revoke_sub_name {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MinNameLength::get().saturating_add(T::MaxSubNameLabelLength::get()).saturating_add(6));
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let recipient: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let (root_input, sub_name_input) = generate_did_sub_name_input(n.saturated_into());
+		let root_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(root_input).expect("BoundedVec creation should not fail.");
+		let sub_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(sub_name_input).expect("BoundedVec creation should not fail.");
+		let sub_name_input_clone = sub_name_input.clone();
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), root_input).expect("Should register the did name.");
+		Pallet::<T>::issue_sub_name(origin.clone(), sub_name_input.clone(), recipient).expect("Should issue the sub-name.");
+	}: _<T::RuntimeOrigin>(origin, sub_name_input_clone)
+	verify {
+		let sub_name = DidNameOf::<T>::try_from(sub_name_input.to_vec()).unwrap();
+		assert!(SubNameOwner::<T>::get(&sub_name).is_none());
+	}
+
+	add_sub_name_delegate {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let delegate: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone, delegate.clone())
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(SubNameDelegates::<T>::get(&did_name).contains(&delegate));
+	}
+
+	remove_sub_name_delegate {
+		let n in (T::MinNameLength::get().saturating_add(10)) .. (T::MaxNameLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let delegate: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(n.saturated_into())).expect("BoundedVec creation should not fail.");
+		let did_name_input_clone = did_name_input.clone();
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+		Pallet::<T>::add_sub_name_delegate(origin.clone(), did_name_input.clone(), delegate.clone()).expect("Should add the delegate.");
+	}: _<T::RuntimeOrigin>(origin, did_name_input_clone, delegate.clone())
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(!SubNameDelegates::<T>::get(&did_name).contains(&delegate));
+	}
+
+	project_username {
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let did_name_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(generate_did_name_input(T::MinNameLength::get().saturating_add(10).saturated_into())).expect("BoundedVec creation should not fail.");
+		let origin = T::EnsureOrigin::generate_origin(caller, owner);
+
+		Pallet::<T>::register(origin.clone(), did_name_input.clone()).expect("Should register the did name.");
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		let did_name = DidNameOf::<T>::try_from(did_name_input.to_vec()).unwrap();
+		assert!(ProjectedNames::<T>::contains_key(&did_name));
+	}
+
+	issue_authority_name {
+		let n in 1 .. (T::MaxSubNameLabelLength::get());
+		let caller: AccountIdOf<T> = account("caller", 0, CALLER_SEED);
+		let owner: DidNameOwnerOf<T> = account("owner", 0, OWNER_SEED);
+		let recipient: DidNameOwnerOf<T> = account("recipient", 0, RECIPIENT_SEED);
+		let root_input: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(b"aaa@cord".to_vec()).expect("BoundedVec creation should not fail.");
+		let label: BoundedVec<u8, T::MaxNameLength> = BoundedVec::try_from(vec![b'b'; n.saturated_into()]).expect("BoundedVec creation should not fail.");
+		let label_clone = label.clone();
+		let origin = T::EnsureOrigin::generate_origin(caller, owner.clone());
+
+		Pallet::<T>::register(origin.clone(), root_input).expect("Should register the did name.");
+		T::UsernameRegistry::add_authority(&owner, b"aaa".to_vec(), 1);
+	}: _<T::RuntimeOrigin>(origin, label_clone, recipient.clone())
+	verify {
+		let sub_name = DidNameOf::<T>::try_from(crate::did_name::sub_name_of(&label, b"aaa")).unwrap();
+		assert_eq!(Pallet::<T>::resolve_owner(&sub_name), Some(recipient));
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
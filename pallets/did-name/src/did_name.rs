@@ -27,12 +27,32 @@ use scale_info::TypeInfo;
 use sp_runtime::RuntimeDebug;
 
 const NAME_SEPARATOR: u8 = b'@';
+const LABEL_SEPARATOR: u8 = b'.';
 const NETWORK_SUFFIX: &[u8] = b"cord";
+const SUB_NAME_NETWORK_SUFFIX: &[u8] = b".cord";
+
+/// Access to the position of a name in the DID name hierarchy.
+///
+/// Top-level names have the `prefix@cord` form. Sub-names are issued under a
+/// top-level name and have the `label.….label@prefix.cord` form, where each
+/// label adds a level to the hierarchy.
+pub trait DidNameHierarchy: Sized {
+	/// Return the name one level up the hierarchy, or `None` for a top-level
+	/// name.
+	fn parent(&self) -> Option<Self>;
+}
+
+/// Compose the `label@root.cord` sub-name directly below the `root@cord`
+/// top-level name.
+pub fn sub_name_of(label: &[u8], root: &[u8]) -> Vec<u8> {
+	[label, &[NAME_SEPARATOR][..], root, SUB_NAME_NETWORK_SUFFIX].concat()
+}
 
 /// A DID name.
 ///
 /// It is bounded in size (inclusive range [MinLength, MaxLength]) and can only
-/// contain a subset of ASCII characters.
+/// contain a subset of ASCII characters. Sub-names are additionally bounded
+/// in depth (`MaxSubNameDepth`) and label size (`MaxSubNameLabelLength`).
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 #[scale_info(skip_type_params(T, MinLength, MaxLength))]
 #[codec(mel_bound())]
@@ -71,16 +91,13 @@ impl<T: Config> TryFrom<Vec<u8>> for AsciiDidName<T> {
 		let (prefix, suffix) = (split.next(), split.next());
 
 		if let (Some(prefix), Some(suffix)) = (prefix, suffix) {
-			ensure!(matches!(suffix, NETWORK_SUFFIX), Self::Error::InvalidSuffix);
-			ensure!(
-				prefix.len() >= T::MinNameLength::get().saturated_into(),
-				Self::Error::NameTooShort
-			);
-			ensure!(
-				prefix.len() <= T::MaxPrefixLength::get().saturated_into(),
-				Self::Error::NameExceedsMaxLength
-			);
-			ensure!(is_valid_did_name_prefix(prefix), Self::Error::InvalidFormat);
+			if let Some(root) = suffix.strip_suffix(SUB_NAME_NETWORK_SUFFIX) {
+				ensure_valid_did_name_prefix::<T>(root)?;
+				ensure_valid_sub_name_labels::<T>(prefix)?;
+			} else {
+				ensure!(matches!(suffix, NETWORK_SUFFIX), Self::Error::InvalidSuffix);
+				ensure_valid_did_name_prefix::<T>(prefix)?;
+			}
 		} else {
 			Err(Self::Error::InvalidFormat)?
 		}
@@ -89,6 +106,43 @@ impl<T: Config> TryFrom<Vec<u8>> for AsciiDidName<T> {
 	}
 }
 
+/// Verify that a given slice can be used as the prefix of a top-level name.
+fn ensure_valid_did_name_prefix<T: Config>(prefix: &[u8]) -> Result<(), Error<T>> {
+	ensure!(prefix.len() >= T::MinNameLength::get().saturated_into(), Error::<T>::NameTooShort);
+	ensure!(
+		prefix.len() <= T::MaxPrefixLength::get().saturated_into(),
+		Error::<T>::NameExceedsMaxLength
+	);
+	ensure!(is_valid_did_name_prefix(prefix), Error::<T>::InvalidFormat);
+
+	Ok(())
+}
+
+/// Verify that a given slice can be used as the labels of a sub-name.
+/// Specifically:
+/// - The number of labels does not exceed the max sub-name depth
+/// - Each label is non-empty and does not exceed the max label length
+/// - Each label is a valid name prefix without periods
+fn ensure_valid_sub_name_labels<T: Config>(labels: &[u8]) -> Result<(), Error<T>> {
+	let mut depth: u32 = 0;
+	for label in labels.split(|c| *c == LABEL_SEPARATOR) {
+		depth = depth.saturating_add(1);
+		ensure!(depth <= T::MaxSubNameDepth::get(), Error::<T>::SubNameTooDeep);
+		ensure!(!label.is_empty(), Error::<T>::InvalidFormat);
+		ensure!(
+			label.len() <= T::MaxSubNameLabelLength::get().saturated_into(),
+			Error::<T>::SubNameLabelTooLong
+		);
+		ensure!(
+			label[0].is_ascii_lowercase() &&
+				label.iter().all(|c| matches!(c, b'a'..=b'z' | b'0'..=b'9')),
+			Error::<T>::InvalidFormat
+		);
+	}
+
+	Ok(())
+}
+
 /// Verify that a given slice can be used as a name prefix.
 fn is_valid_did_name_prefix(input: &[u8]) -> bool {
 	// Check prefix is empty or not
@@ -130,6 +184,24 @@ fn is_valid_did_name_prefix(input: &[u8]) -> bool {
 	is_valid
 }
 
+impl<T: Config> DidNameHierarchy for AsciiDidName<T> {
+	/// The parent of `l1.l2@root.cord` is `l2@root.cord`, and the parent of
+	/// `l1@root.cord` is `root@cord`.
+	fn parent(&self) -> Option<Self> {
+		let mut split = self.0.splitn(2, |c| *c == NAME_SEPARATOR);
+		let (labels, suffix) = (split.next()?, split.next()?);
+		let root = suffix.strip_suffix(SUB_NAME_NETWORK_SUFFIX)?;
+
+		let parent = match labels.iter().position(|c| *c == LABEL_SEPARATOR) {
+			Some(index) => [&labels[index + 1..], &[NAME_SEPARATOR][..], suffix].concat(),
+			None => [root, &[NAME_SEPARATOR][..], NETWORK_SUFFIX].concat(),
+		};
+
+		// The parent is always shorter than the name itself.
+		Some(Self(BoundedVec::truncate_from(parent), PhantomData))
+	}
+}
+
 // FIXME: did not find a way to automatically implement this.
 impl<T: Config> PartialEq for AsciiDidName<T> {
 	fn eq(&self, other: &Self) -> bool {
@@ -157,7 +229,11 @@ pub struct DidNameOwnership<Owner, BlockNumber> {
 mod tests {
 	use sp_runtime::SaturatedConversion;
 
-	use crate::{did_name::AsciiDidName, mock::Test, Config};
+	use crate::{
+		did_name::{AsciiDidName, DidNameHierarchy},
+		mock::Test,
+		Config,
+	};
 
 	const MIN_LENGTH: u32 = <Test as Config>::MinNameLength::get();
 	const MAX_LENGTH: u32 = <Test as Config>::MaxNameLength::get();
@@ -201,4 +277,52 @@ mod tests {
 			assert!(AsciiDidName::<Test>::try_from(invalid).is_err());
 		}
 	}
+
+	#[test]
+	fn valid_did_sub_name_inputs() {
+		let valid_inputs = vec![
+			b"alice@acme.cord".to_vec(),
+			b"alice.hr@acme.cord".to_vec(),
+			b"a1.b2.c3@acme.org.cord".to_vec(),
+		];
+
+		let invalid_inputs = vec![
+			// Empty label
+			b"@acme.cord".to_vec(),
+			b"alice..hr@acme.cord".to_vec(),
+			b".hr@acme.cord".to_vec(),
+			// Label must start with a letter
+			b"1alice@acme.cord".to_vec(),
+			// Label exceeds max label length
+			b"abcdefghijklmnopq@acme.cord".to_vec(),
+			// Exceeds max depth
+			b"a.b.c.d@acme.cord".to_vec(),
+			// Invalid root prefix
+			b"alice@.cord".to_vec(),
+			b"alice@ac.cord".to_vec(),
+			b"alice@acme..cord".to_vec(),
+			// Invalid network suffix
+			b"alice@acme.web".to_vec(),
+		];
+
+		for valid in valid_inputs {
+			assert!(AsciiDidName::<Test>::try_from(valid).is_ok());
+		}
+
+		for invalid in invalid_inputs {
+			assert!(AsciiDidName::<Test>::try_from(invalid).is_err());
+		}
+	}
+
+	#[test]
+	fn did_sub_name_parents() {
+		let name = |input: &[u8]| AsciiDidName::<Test>::try_from(input.to_vec()).unwrap();
+
+		assert!(name(b"acme@cord").parent().is_none());
+		assert!(name(b"acme.org@cord").parent().is_none());
+		assert_eq!(name(b"hr@acme.cord").parent(), Some(name(b"acme@cord")));
+		assert_eq!(name(b"alice.hr@acme.cord").parent(), Some(name(b"hr@acme.cord")));
+		assert_eq!(name(b"a.b.c@acme.org.cord").parent(), Some(name(b"b.c@acme.org.cord")));
+		assert_eq!(name(b"c@acme.org.cord").parent(), Some(name(b"acme.org@cord")));
+	}
 }
@@ -20,11 +20,30 @@
 
 //! # Pallet storing unique nickname <-> DID links for user-friendly DID
 //! nicknames.
+//!
+//! Names can optionally be registered for a limited period
+//! (`RegistrationPeriod`). Once the period has elapsed the name enters a
+//! grace period (`GracePeriod`) during which the owner can still renew it.
+//! Names which are not renewed before the end of the grace period are
+//! released by the pallet hooks. Owners can hand a name over to another DID
+//! with a two-step transfer which the recipient must accept.
+//!
+//! The owner of a top-level name (`acme@cord`) can issue sub-names under it
+//! (`hr@acme.cord`, `alice.hr@acme.cord`) to other DIDs. Owners of a name, and
+//! the delegates they appoint, can issue and revoke sub-names anywhere below
+//! that name. A sub-name only resolves while every name above it does.
+//!
+//! Names are linked with the usernames of the identity pallet: an owner can
+//! project its top-level name as the primary username of its identity, and a
+//! username authority owning (or delegated on) the `suffix@cord` name can
+//! mint `label@suffix.cord` sub-names against its username allocation.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod did_name;
 
+pub mod migrations;
+
 pub mod weights;
 
 #[cfg(any(feature = "mock", test))]
@@ -42,24 +61,29 @@ pub use crate::{pallet::*, weights::WeightInfo};
 pub mod pallet {
 	use codec::FullCodec;
 	use frame_support::{
-		pallet_prelude::*, sp_runtime::SaturatedConversion, traits::StorageVersion,
+		pallet_prelude::*,
+		sp_runtime::{traits::Zero, SaturatedConversion},
+		traits::StorageVersion,
 		Blake2_128Concat,
 	};
 	use frame_system::pallet_prelude::*;
 	use sp_std::{fmt::Debug, vec::Vec};
 
+	use cord_primitives::UsernameRegistry;
 	use cord_utilities::traits::CallSources;
 
 	use super::WeightInfo;
-	use crate::did_name::DidNameOwnership;
+	use crate::did_name::{sub_name_of, DidNameHierarchy, DidNameOwnership};
 
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	pub type DidNameOwnerOf<T> = <T as Config>::DidNameOwner;
 	pub type DidNameInput<T> = BoundedVec<u8, <T as Config>::MaxNameLength>;
 	pub type DidNameOf<T> = <T as Config>::DidName;
 	pub type DidNameOwnershipOf<T> = DidNameOwnership<DidNameOwnerOf<T>, BlockNumberFor<T>>;
+	pub type SubNameDelegatesOf<T> =
+		BoundedVec<DidNameOwnerOf<T>, <T as Config>::MaxSubNameDelegates>;
 
 	#[pallet::pallet]
 	#[pallet::storage_version(STORAGE_VERSION)]
@@ -79,6 +103,44 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type Banned<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, ()>;
 
+	/// Map of name -> recipient of a pending transfer.
+	///
+	/// If a name key is present, the current owner has offered the name to
+	/// the recipient, who has not accepted it yet.
+	#[pallet::storage]
+	pub type PendingTransfers<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, DidNameOwnerOf<T>>;
+
+	/// Map of block number -> names to be released at that block.
+	///
+	/// A name is scheduled for release at the end of its grace period.
+	#[pallet::storage]
+	pub type NamesExpiresOn<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		BlockNumberFor<T>,
+		BoundedVec<DidNameOf<T>, T::MaxNameExpiriesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Map of sub-name -> ownership details.
+	#[pallet::storage]
+	pub type SubNameOwner<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, DidNameOwnershipOf<T>>;
+
+	/// Map of name -> number of sub-names issued directly under it.
+	#[pallet::storage]
+	pub type SubNameCount<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, u32, ValueQuery>;
+
+	/// Map of name -> DIDs allowed to issue and revoke sub-names under it on
+	/// behalf of the owner.
+	#[pallet::storage]
+	pub type SubNameDelegates<T> =
+		StorageMap<_, Blake2_128Concat, DidNameOf<T>, SubNameDelegatesOf<T>, ValueQuery>;
+
+	/// Map of name -> () for the top-level names projected as the primary
+	/// username of their owner.
+	#[pallet::storage]
+	pub type ProjectedNames<T> = StorageMap<_, Blake2_128Concat, DidNameOf<T>, ()>;
+
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type BanOrigin: EnsureOrigin<Self::RuntimeOrigin>;
@@ -97,6 +159,8 @@ pub mod pallet {
 			+ Clone
 			+ TypeInfo
 			+ TryFrom<Vec<u8>, Error = Error<Self>>
+			+ Into<Vec<u8>>
+			+ DidNameHierarchy
 			+ MaxEncodedLen;
 		/// The type of a name owner.
 		type DidNameOwner: Parameter + MaxEncodedLen;
@@ -109,6 +173,30 @@ pub mod pallet {
 		/// The max encoded length of a prefix.
 		#[pallet::constant]
 		type MaxPrefixLength: Get<u32>;
+		/// The number of blocks a name registration (or renewal) is valid
+		/// for. A value of zero disables name expiry.
+		#[pallet::constant]
+		type RegistrationPeriod: Get<BlockNumberFor<Self>>;
+		/// The number of blocks after the registration period during which
+		/// an expired name can still be renewed by its owner.
+		#[pallet::constant]
+		type GracePeriod: Get<BlockNumberFor<Self>>;
+		/// The max number of names that can be scheduled for release at the
+		/// same block.
+		#[pallet::constant]
+		type MaxNameExpiriesPerBlock: Get<u32>;
+		/// The max number of levels below a top-level name.
+		#[pallet::constant]
+		type MaxSubNameDepth: Get<u32>;
+		/// The max encoded length of each level of a sub-name.
+		#[pallet::constant]
+		type MaxSubNameLabelLength: Get<u32>;
+		/// The max number of sub-name delegates per name.
+		#[pallet::constant]
+		type MaxSubNameDelegates: Get<u32>;
+		/// The usernames names can be projected as, and the username
+		/// authorities allowed to mint names.
+		type UsernameRegistry: UsernameRegistry<Self::DidNameOwner>;
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -124,6 +212,36 @@ pub mod pallet {
 		DidNameBanned { name: DidNameOf<T> },
 		/// A name has been unbanned.
 		DidNameUnbanned { name: DidNameOf<T> },
+		/// A name has been renewed.
+		DidNameRenewed { owner: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A name has not been renewed in time and has been released.
+		DidNameExpired { owner: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A name transfer has been offered to a recipient.
+		DidNameTransferRequested {
+			owner: DidNameOwnerOf<T>,
+			recipient: DidNameOwnerOf<T>,
+			name: DidNameOf<T>,
+		},
+		/// A pending name transfer has been cancelled by the owner.
+		DidNameTransferCancelled { owner: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A name has been transferred to a new owner.
+		DidNameTransferred { from: DidNameOwnerOf<T>, to: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A sub-name has been issued.
+		SubNameIssued { issuer: DidNameOwnerOf<T>, owner: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A sub-name has been revoked.
+		SubNameRevoked { revoker: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A sub-name delegate has been added to a name.
+		SubNameDelegateAdded { name: DidNameOf<T>, delegate: DidNameOwnerOf<T> },
+		/// A sub-name delegate has been removed from a name.
+		SubNameDelegateRemoved { name: DidNameOf<T>, delegate: DidNameOwnerOf<T> },
+		/// A name has been projected as the primary username of its owner.
+		DidNameProjected { owner: DidNameOwnerOf<T>, name: DidNameOf<T> },
+		/// A username authority has minted a name under its suffix.
+		AuthorityNameIssued {
+			authority: DidNameOwnerOf<T>,
+			owner: DidNameOwnerOf<T>,
+			name: DidNameOf<T>,
+		},
 	}
 
 	#[pallet::error]
@@ -161,10 +279,51 @@ pub mod pallet {
 		SuffixTooLong,
 		/// A name that contains not allowed characters is being claimed.
 		InvalidFormat,
+		/// The registration period of the name has elapsed.
+		NameExpired,
+		/// Names do not expire, so they cannot be renewed.
+		RenewalNotEnabled,
+		/// Max number of name expiries exceeded for the block.
+		MaxNameExpiriesExceededForTheBlock,
+		/// There is no pending transfer for the specified name.
+		TransferNotFound,
+		/// A transfer for the specified name is already pending.
+		TransferAlreadyRequested,
+		/// A name cannot be transferred to its current owner.
+		TransferToSelf,
+		/// A sub-name has more levels than allowed.
+		SubNameTooDeep,
+		/// A level of a sub-name is too long.
+		SubNameLabelTooLong,
+		/// The operation requires a top-level name.
+		NotATopLevelName,
+		/// The operation requires a sub-name.
+		NotASubName,
+		/// The name above the specified sub-name does not exist or does not
+		/// resolve.
+		ParentNotFound,
+		/// The name still has sub-names issued under it.
+		SubNamesExist,
+		/// The DID is already a sub-name delegate for the name.
+		DelegateAlreadyExists,
+		/// The DID is not a sub-name delegate for the name.
+		DelegateNotFound,
+		/// Max number of sub-name delegates exceeded for the name.
+		MaxSubNameDelegatesExceeded,
+		/// The name is already projected as a username.
+		AlreadyProjected,
 	}
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			if n > BlockNumberFor::<T>::zero() {
+				Self::expire_names(n)
+			} else {
+				Weight::zero()
+			}
+		}
+	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
@@ -180,7 +339,7 @@ pub mod pallet {
 
 			let decoded_name = Self::check_claiming_preconditions(name, &owner)?;
 
-			Self::register_name(decoded_name.clone(), owner.clone());
+			Self::register_name(decoded_name.clone(), owner.clone())?;
 			Self::deposit_event(Event::<T>::DidNameRegistered { owner, name: decoded_name });
 
 			Ok(())
@@ -204,7 +363,7 @@ pub mod pallet {
 
 		/// Ban a name.
 		///
-		/// A banned name cannot be registered by anyone.
+		/// A banned name cannot be registered or issued as a sub-name by anyone.
 		///
 		/// The origin must be the ban origin.
 		#[pallet::call_index(3)]
@@ -215,7 +374,12 @@ pub mod pallet {
 			let (decoded_name, is_claimed) = Self::check_banning_preconditions(name)?;
 
 			if is_claimed {
-				Self::unregister_name(&decoded_name);
+				match decoded_name.parent() {
+					None => {
+						Self::unregister_name(&decoded_name);
+					},
+					Some(parent) => Self::revoke_name(&decoded_name, &parent),
+				}
 			}
 
 			Self::ban_name(&decoded_name);
@@ -241,14 +405,243 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Renew the name owned by the origin.
+		///
+		/// The registration period restarts from the current block. A name
+		/// can be renewed at any time before the end of its grace period.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::renew())]
+		pub fn renew(origin: OriginFor<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (owned_name, ownership) = Self::check_renewing_preconditions(&owner)?;
+
+			Self::renew_name(&owned_name, ownership)?;
+			Self::deposit_event(Event::<T>::DidNameRenewed { owner, name: owned_name });
+
+			Ok(())
+		}
+
+		/// Offer the name owned by the origin to another DID.
+		///
+		/// The ownership does not change until the recipient accepts the
+		/// transfer with `accept_transfer`. Expired names cannot be
+		/// transferred.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::transfer())]
+		pub fn transfer(origin: OriginFor<T>, recipient: DidNameOwnerOf<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let owned_name = Self::check_transferring_preconditions(&owner, &recipient)?;
+
+			PendingTransfers::<T>::insert(&owned_name, recipient.clone());
+			Self::deposit_event(Event::<T>::DidNameTransferRequested {
+				owner,
+				recipient,
+				name: owned_name,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel a pending transfer of the name owned by the origin.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_transfer())]
+		pub fn cancel_transfer(origin: OriginFor<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let owned_name = Names::<T>::get(&owner).ok_or(Error::<T>::OwnerNotFound)?;
+			ensure!(
+				PendingTransfers::<T>::take(&owned_name).is_some(),
+				Error::<T>::TransferNotFound
+			);
+
+			Self::deposit_event(Event::<T>::DidNameTransferCancelled { owner, name: owned_name });
+
+			Ok(())
+		}
+
+		/// Accept a pending transfer of the specified name.
+		///
+		/// The origin must be the recipient of the transfer and must not own
+		/// another name. The ownership is moved atomically and the
+		/// registration period of the name is left unchanged.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::accept_transfer(name.len().saturated_into()))]
+		pub fn accept_transfer(origin: OriginFor<T>, name: DidNameInput<T>) -> DispatchResult {
+			let recipient = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let decoded_name = Self::check_accepting_preconditions(name, &recipient)?;
+
+			let previous_owner = Self::transfer_name(&decoded_name, recipient.clone());
+			Self::deposit_event(Event::<T>::DidNameTransferred {
+				from: previous_owner,
+				to: recipient,
+				name: decoded_name,
+			});
+
+			Ok(())
+		}
+
+		/// Issue the specified sub-name to the provided owner.
+		///
+		/// The origin must own, or be a sub-name delegate of, a name above
+		/// the sub-name. Every name above the sub-name must resolve.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_sub_name(name.len().saturated_into()))]
+		pub fn issue_sub_name(
+			origin: OriginFor<T>,
+			name: DidNameInput<T>,
+			owner: DidNameOwnerOf<T>,
+		) -> DispatchResult {
+			let issuer = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (decoded_name, parent) = Self::check_issuing_preconditions(name, &issuer)?;
+
+			Self::issue_name(decoded_name.clone(), &parent, owner.clone());
+			Self::deposit_event(Event::<T>::SubNameIssued { issuer, owner, name: decoded_name });
+
+			Ok(())
+		}
+
+		/// Revoke the specified sub-name.
+		///
+		/// The origin must own the sub-name, or own or be a sub-name delegate
+		/// of a name above it. Sub-names which no longer resolve because a
+		/// name above them was removed can be revoked by any DID. Sub-names
+		/// must be revoked bottom-up.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::revoke_sub_name(name.len().saturated_into()))]
+		pub fn revoke_sub_name(origin: OriginFor<T>, name: DidNameInput<T>) -> DispatchResult {
+			let revoker = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let (decoded_name, parent) = Self::check_revoking_preconditions(name, &revoker)?;
+
+			Self::revoke_name(&decoded_name, &parent);
+			Self::deposit_event(Event::<T>::SubNameRevoked { revoker, name: decoded_name });
+
+			Ok(())
+		}
+
+		/// Allow a DID to issue and revoke sub-names below the specified
+		/// name.
+		///
+		/// The origin must own the specified name.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_sub_name_delegate(name.len().saturated_into()))]
+		pub fn add_sub_name_delegate(
+			origin: OriginFor<T>,
+			name: DidNameInput<T>,
+			delegate: DidNameOwnerOf<T>,
+		) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let decoded_name = Self::check_delegating_preconditions(name, &owner)?;
+
+			SubNameDelegates::<T>::try_mutate(&decoded_name, |delegates| {
+				ensure!(!delegates.contains(&delegate), Error::<T>::DelegateAlreadyExists);
+				delegates
+					.try_push(delegate.clone())
+					.map_err(|_| Error::<T>::MaxSubNameDelegatesExceeded)
+			})?;
+			Self::deposit_event(Event::<T>::SubNameDelegateAdded { name: decoded_name, delegate });
+
+			Ok(())
+		}
+
+		/// Remove a sub-name delegate from the specified name.
+		///
+		/// The origin must own the specified name.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_sub_name_delegate(name.len().saturated_into()))]
+		pub fn remove_sub_name_delegate(
+			origin: OriginFor<T>,
+			name: DidNameInput<T>,
+			delegate: DidNameOwnerOf<T>,
+		) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let decoded_name = Self::check_delegating_preconditions(name, &owner)?;
+
+			SubNameDelegates::<T>::try_mutate_exists(&decoded_name, |maybe_delegates| {
+				let delegates = maybe_delegates.as_mut().ok_or(Error::<T>::DelegateNotFound)?;
+				let index = delegates
+					.iter()
+					.position(|d| d == &delegate)
+					.ok_or(Error::<T>::DelegateNotFound)?;
+				delegates.swap_remove(index);
+				if delegates.is_empty() {
+					*maybe_delegates = None;
+				}
+				Ok::<(), Error<T>>(())
+			})?;
+			Self::deposit_event(Event::<T>::SubNameDelegateRemoved {
+				name: decoded_name,
+				delegate,
+			});
+
+			Ok(())
+		}
+
+		/// Project the name owned by the origin as the primary username of
+		/// its identity.
+		///
+		/// The name must not have expired. The projection is removed when the
+		/// name is released, transferred or expires.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::project_username())]
+		pub fn project_username(origin: OriginFor<T>) -> DispatchResult {
+			let owner = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let owned_name = Self::check_projecting_preconditions(&owner)?;
+
+			T::UsernameRegistry::set_primary_username(&owner, owned_name.clone().into())?;
+			ProjectedNames::<T>::insert(&owned_name, ());
+			Self::deposit_event(Event::<T>::DidNameProjected { owner, name: owned_name });
+
+			Ok(())
+		}
+
+		/// Issue the `label@suffix.cord` sub-name to the provided owner,
+		/// where `suffix` is the suffix of the username authority.
+		///
+		/// The origin must be a username authority with an allocation left,
+		/// and must own, or be a sub-name delegate of, the `suffix@cord`
+		/// name. One allocation of the authority is consumed.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::issue_authority_name(label.len().saturated_into()))]
+		pub fn issue_authority_name(
+			origin: OriginFor<T>,
+			label: DidNameInput<T>,
+			owner: DidNameOwnerOf<T>,
+		) -> DispatchResult {
+			let authority = <T as Config>::EnsureOrigin::ensure_origin(origin)?.subject();
+
+			let suffix = T::UsernameRegistry::use_authority_allocation(&authority)?;
+			let name = DidNameInput::<T>::try_from(sub_name_of(&label, &suffix))
+				.map_err(|_| Error::<T>::NameExceedsMaxLength)?;
+			let (decoded_name, parent) = Self::check_issuing_preconditions(name, &authority)?;
+
+			Self::issue_name(decoded_name.clone(), &parent, owner.clone());
+			Self::deposit_event(Event::<T>::AuthorityNameIssued {
+				authority,
+				owner,
+				name: decoded_name,
+			});
+
+			Ok(())
+		}
 	}
 
 	impl<T: Config> Pallet<T> {
 		/// Verify that the claiming preconditions are verified. Specifically:
 		/// - The name input data can be decoded as a valid name
+		/// - The name is a top-level name
 		/// - The name does not already exist
 		/// - The owner does not already own a name
 		/// - The name has not been banned
+		/// - No sub-names of a previous owner are left under the name
 		fn check_claiming_preconditions(
 			name_input: DidNameInput<T>,
 			owner: &DidNameOwnerOf<T>,
@@ -256,34 +649,43 @@ pub mod pallet {
 			let name =
 				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
 
+			ensure!(name.parent().is_none(), Error::<T>::NotATopLevelName);
 			ensure!(!Names::<T>::contains_key(owner), Error::<T>::OwnerAlreadyExists);
 			ensure!(!Owner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
 			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::Banned);
+			ensure!(SubNameCount::<T>::get(&name) == 0, Error::<T>::SubNamesExist);
 
 			Ok(name)
 		}
 
-		/// Assign a name to the provided owner reserving the deposit from
-		/// the provided account. This function must be called after
+		/// Assign a name to the provided owner and schedule its release if
+		/// names expire. This function must be called after
 		/// `check_claiming_preconditions` as it does not verify all the
 		/// preconditions again.
-		pub(crate) fn register_name(name: DidNameOf<T>, owner: DidNameOwnerOf<T>) {
+		pub(crate) fn register_name(
+			name: DidNameOf<T>,
+			owner: DidNameOwnerOf<T>,
+		) -> Result<(), DispatchError> {
 			let block_number = frame_system::Pallet::<T>::block_number();
+			let ownership = DidNameOwnershipOf::<T> { owner, registered_at: block_number };
 
-			Names::<T>::insert(&owner, name.clone());
-			Owner::<T>::insert(
-				&name,
-				DidNameOwnershipOf::<T> { owner, registered_at: block_number },
-			);
+			Self::schedule_release(&name, &ownership)?;
+
+			Names::<T>::insert(&ownership.owner, name.clone());
+			Owner::<T>::insert(&name, ownership);
+
+			Ok(())
 		}
 
 		/// Verify that the releasing preconditions for an owner are verified.
 		/// Specifically:
 		/// - The owner has a previously claimed name
+		/// - No sub-names are left under the name
 		fn check_releasing_preconditions(
 			owner: &DidNameOwnerOf<T>,
 		) -> Result<DidNameOf<T>, DispatchError> {
 			let name = Names::<T>::get(owner).ok_or(Error::<T>::OwnerNotFound)?;
+			ensure!(SubNameCount::<T>::get(&name) == 0, Error::<T>::SubNamesExist);
 
 			Ok(name)
 		}
@@ -295,6 +697,10 @@ pub mod pallet {
 		fn unregister_name(name: &DidNameOf<T>) -> DidNameOwnershipOf<T> {
 			let name_ownership = Owner::<T>::take(name).unwrap();
 			Names::<T>::remove(&name_ownership.owner);
+			PendingTransfers::<T>::remove(name);
+			SubNameDelegates::<T>::remove(name);
+			Self::remove_projection(name, &name_ownership.owner);
+			Self::unschedule_release(name, &name_ownership);
 
 			name_ownership
 		}
@@ -315,7 +721,8 @@ pub mod pallet {
 
 			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::AlreadyBanned);
 
-			let is_claimed = Owner::<T>::contains_key(&name);
+			let is_claimed =
+				Owner::<T>::contains_key(&name) || SubNameOwner::<T>::contains_key(&name);
 
 			Ok((name, is_claimed))
 		}
@@ -348,5 +755,374 @@ pub mod pallet {
 		fn unban_name(name: &DidNameOf<T>) {
 			Banned::<T>::remove(name);
 		}
+
+		/// Return the block at which the registration period of the
+		/// provided ownership ends, or `None` if names do not expire.
+		pub fn expires_at(ownership: &DidNameOwnershipOf<T>) -> Option<BlockNumberFor<T>> {
+			let period = T::RegistrationPeriod::get();
+			if period.is_zero() {
+				None
+			} else {
+				Some(ownership.registered_at.saturating_add(period))
+			}
+		}
+
+		/// Check whether the registration period of the provided ownership
+		/// has elapsed. Names in their grace period are considered expired.
+		pub fn is_expired(ownership: &DidNameOwnershipOf<T>) -> bool {
+			Self::expires_at(ownership)
+				.map_or(false, |expires_at| frame_system::Pallet::<T>::block_number() >= expires_at)
+		}
+
+		/// Return the block at which the provided ownership is released if
+		/// it is not renewed.
+		fn release_at(ownership: &DidNameOwnershipOf<T>) -> Option<BlockNumberFor<T>> {
+			Self::expires_at(ownership)
+				.map(|expires_at| expires_at.saturating_add(T::GracePeriod::get()))
+		}
+
+		/// Add the name to the release schedule, if names expire.
+		pub(crate) fn schedule_release(
+			name: &DidNameOf<T>,
+			ownership: &DidNameOwnershipOf<T>,
+		) -> Result<(), DispatchError> {
+			if let Some(release_at) = Self::release_at(ownership) {
+				NamesExpiresOn::<T>::try_mutate(release_at, |names| {
+					names
+						.try_push(name.clone())
+						.map_err(|_| Error::<T>::MaxNameExpiriesExceededForTheBlock)
+				})?;
+			}
+
+			Ok(())
+		}
+
+		/// Remove the name from the release schedule, if present.
+		fn unschedule_release(name: &DidNameOf<T>, ownership: &DidNameOwnershipOf<T>) {
+			if let Some(release_at) = Self::release_at(ownership) {
+				NamesExpiresOn::<T>::mutate_exists(release_at, |maybe_names| {
+					if let Some(names) = maybe_names {
+						if let Some(index) = names.iter().position(|n| n == name) {
+							names.swap_remove(index);
+						}
+						if names.is_empty() {
+							*maybe_names = None;
+						}
+					}
+				});
+			}
+		}
+
+		/// Verify that the renewing preconditions for an owner are verified.
+		/// Specifically:
+		/// - Names are configured to expire
+		/// - The owner has a previously claimed name
+		fn check_renewing_preconditions(
+			owner: &DidNameOwnerOf<T>,
+		) -> Result<(DidNameOf<T>, DidNameOwnershipOf<T>), DispatchError> {
+			ensure!(!T::RegistrationPeriod::get().is_zero(), Error::<T>::RenewalNotEnabled);
+
+			let name = Names::<T>::get(owner).ok_or(Error::<T>::OwnerNotFound)?;
+			let ownership = Owner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+
+			Ok((name, ownership))
+		}
+
+		/// Restart the registration period of the provided name from the
+		/// current block and move it in the release schedule. This function
+		/// must be called after `check_renewing_preconditions` as it does
+		/// not verify all the preconditions again.
+		fn renew_name(
+			name: &DidNameOf<T>,
+			ownership: DidNameOwnershipOf<T>,
+		) -> Result<(), DispatchError> {
+			let renewed = DidNameOwnershipOf::<T> {
+				owner: ownership.owner.clone(),
+				registered_at: frame_system::Pallet::<T>::block_number(),
+			};
+
+			Self::unschedule_release(name, &ownership);
+			Self::schedule_release(name, &renewed)?;
+			Owner::<T>::insert(name, renewed);
+
+			Ok(())
+		}
+
+		/// Verify that the transferring preconditions are verified.
+		/// Specifically:
+		/// - The owner has a previously claimed name
+		/// - The name has not expired
+		/// - The recipient is not the owner
+		/// - There is no pending transfer for the name
+		fn check_transferring_preconditions(
+			owner: &DidNameOwnerOf<T>,
+			recipient: &DidNameOwnerOf<T>,
+		) -> Result<DidNameOf<T>, DispatchError> {
+			let name = Names::<T>::get(owner).ok_or(Error::<T>::OwnerNotFound)?;
+			let ownership = Owner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+
+			ensure!(!Self::is_expired(&ownership), Error::<T>::NameExpired);
+			ensure!(owner != recipient, Error::<T>::TransferToSelf);
+			ensure!(
+				!PendingTransfers::<T>::contains_key(&name),
+				Error::<T>::TransferAlreadyRequested
+			);
+
+			Ok(name)
+		}
+
+		/// Verify that the accepting preconditions are verified.
+		/// Specifically:
+		/// - The name input data can be decoded as a valid name
+		/// - The recipient is the one the name has been offered to
+		/// - The recipient does not already own a name
+		/// - The name has not expired
+		fn check_accepting_preconditions(
+			name_input: DidNameInput<T>,
+			recipient: &DidNameOwnerOf<T>,
+		) -> Result<DidNameOf<T>, DispatchError> {
+			let name =
+				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+
+			let pending_recipient =
+				PendingTransfers::<T>::get(&name).ok_or(Error::<T>::TransferNotFound)?;
+			ensure!(&pending_recipient == recipient, Error::<T>::NotAuthorized);
+			ensure!(!Names::<T>::contains_key(recipient), Error::<T>::OwnerAlreadyExists);
+
+			let ownership = Owner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+			ensure!(!Self::is_expired(&ownership), Error::<T>::NameExpired);
+
+			Ok(name)
+		}
+
+		/// Move the ownership of the provided name to the recipient and
+		/// return the previous owner. This function must be called after
+		/// `check_accepting_preconditions` as it does not verify all the
+		/// preconditions again.
+		fn transfer_name(name: &DidNameOf<T>, recipient: DidNameOwnerOf<T>) -> DidNameOwnerOf<T> {
+			PendingTransfers::<T>::remove(name);
+
+			let ownership = Owner::<T>::take(name).unwrap();
+			Names::<T>::remove(&ownership.owner);
+			SubNameDelegates::<T>::remove(name);
+			Self::remove_projection(name, &ownership.owner);
+
+			Names::<T>::insert(&recipient, name.clone());
+			Owner::<T>::insert(
+				name,
+				DidNameOwnershipOf::<T> {
+					owner: recipient,
+					registered_at: ownership.registered_at,
+				},
+			);
+
+			ownership.owner
+		}
+
+		/// Release all the names whose grace period ends at the given block.
+		pub(crate) fn expire_names(block_number: BlockNumberFor<T>) -> Weight {
+			let mut total_weight: Weight = T::DbWeight::get().reads_writes(1, 1);
+
+			for name in NamesExpiresOn::<T>::take(block_number) {
+				if let Some(ownership) = Owner::<T>::take(&name) {
+					Names::<T>::remove(&ownership.owner);
+					PendingTransfers::<T>::remove(&name);
+					SubNameDelegates::<T>::remove(&name);
+					Self::remove_projection(&name, &ownership.owner);
+					Self::deposit_event(Event::<T>::DidNameExpired {
+						owner: ownership.owner,
+						name,
+					});
+				}
+				total_weight = total_weight.saturating_add(T::WeightInfo::expire());
+			}
+
+			total_weight
+		}
+
+		/// Return the current owner of the provided name, if the name
+		/// resolves. A top-level name resolves while it is registered and its
+		/// registration period has not elapsed. A sub-name resolves while it
+		/// is issued and every name above it resolves.
+		pub fn resolve_owner(name: &DidNameOf<T>) -> Option<DidNameOwnerOf<T>> {
+			match name.parent() {
+				None => Owner::<T>::get(name)
+					.filter(|ownership| !Self::is_expired(ownership))
+					.map(|ownership| ownership.owner),
+				Some(parent) => {
+					let ownership = SubNameOwner::<T>::get(name)?;
+					Self::resolve_owner(&parent)?;
+					Some(ownership.owner)
+				},
+			}
+		}
+
+		/// Return the owner of the provided name, regardless of whether the
+		/// names above it resolve.
+		fn owner_of(name: &DidNameOf<T>) -> Option<DidNameOwnerOf<T>> {
+			match name.parent() {
+				None => Owner::<T>::get(name),
+				Some(_) => SubNameOwner::<T>::get(name),
+			}
+			.map(|ownership| ownership.owner)
+		}
+
+		/// Check whether the provided name or any name above it has been
+		/// released, leaving the sub-names below it orphaned. Expired names
+		/// are not released until the end of their grace period.
+		fn is_released(name: &DidNameOf<T>) -> bool {
+			let mut current = Some(name.clone());
+			while let Some(name) = current {
+				if Self::owner_of(&name).is_none() {
+					return true;
+				}
+				current = name.parent();
+			}
+
+			false
+		}
+
+		/// Verify that the provided DID can manage sub-names below the
+		/// provided name, i.e., it owns or is a sub-name delegate of the name
+		/// or of any name above it.
+		fn ensure_sub_name_manager(
+			name: &DidNameOf<T>,
+			who: &DidNameOwnerOf<T>,
+		) -> Result<(), DispatchError> {
+			let mut current = Some(name.clone());
+			while let Some(name) = current {
+				if Self::owner_of(&name).as_ref() == Some(who) ||
+					SubNameDelegates::<T>::get(&name).contains(who)
+				{
+					return Ok(());
+				}
+				current = name.parent();
+			}
+
+			Err(Error::<T>::NotAuthorized.into())
+		}
+
+		/// Verify that the issuing preconditions are verified.
+		/// Specifically:
+		/// - The name input data can be decoded as a valid sub-name
+		/// - The sub-name does not already exist
+		/// - The sub-name has not been banned
+		/// - The name above the sub-name resolves
+		/// - The issuer can manage sub-names below the name above
+		///
+		/// If the preconditions are verified, return a tuple containing the
+		/// parsed sub-name and the name above it.
+		fn check_issuing_preconditions(
+			name_input: DidNameInput<T>,
+			issuer: &DidNameOwnerOf<T>,
+		) -> Result<(DidNameOf<T>, DidNameOf<T>), DispatchError> {
+			let name =
+				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+			let parent = name.parent().ok_or(Error::<T>::NotASubName)?;
+
+			ensure!(!SubNameOwner::<T>::contains_key(&name), Error::<T>::AlreadyExists);
+			ensure!(!Banned::<T>::contains_key(&name), Error::<T>::Banned);
+			ensure!(Self::resolve_owner(&parent).is_some(), Error::<T>::ParentNotFound);
+			Self::ensure_sub_name_manager(&parent, issuer)?;
+
+			Ok((name, parent))
+		}
+
+		/// Assign a sub-name to the provided owner. This function must be
+		/// called after `check_issuing_preconditions` as it does not verify
+		/// all the preconditions again.
+		fn issue_name(name: DidNameOf<T>, parent: &DidNameOf<T>, owner: DidNameOwnerOf<T>) {
+			let block_number = frame_system::Pallet::<T>::block_number();
+
+			SubNameCount::<T>::mutate(parent, |count| *count = count.saturating_add(1));
+			SubNameOwner::<T>::insert(
+				&name,
+				DidNameOwnershipOf::<T> { owner, registered_at: block_number },
+			);
+		}
+
+		/// Verify that the revoking preconditions are verified.
+		/// Specifically:
+		/// - The name input data can be decoded as a valid sub-name
+		/// - The sub-name has been issued
+		/// - No sub-names are left under the sub-name
+		/// - The revoker owns the sub-name, can manage sub-names below the name above it, or the
+		///   name above it or a name further up has been released
+		///
+		/// If the preconditions are verified, return a tuple containing the
+		/// parsed sub-name and the name above it.
+		fn check_revoking_preconditions(
+			name_input: DidNameInput<T>,
+			revoker: &DidNameOwnerOf<T>,
+		) -> Result<(DidNameOf<T>, DidNameOf<T>), DispatchError> {
+			let name =
+				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+			let parent = name.parent().ok_or(Error::<T>::NotASubName)?;
+			let ownership = SubNameOwner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+
+			ensure!(SubNameCount::<T>::get(&name) == 0, Error::<T>::SubNamesExist);
+			if &ownership.owner != revoker && !Self::is_released(&parent) {
+				Self::ensure_sub_name_manager(&parent, revoker)?;
+			}
+
+			Ok((name, parent))
+		}
+
+		/// Remove the provided sub-name. This function must be called after
+		/// `check_revoking_preconditions` as it does not verify all the
+		/// preconditions again.
+		fn revoke_name(name: &DidNameOf<T>, parent: &DidNameOf<T>) {
+			SubNameOwner::<T>::remove(name);
+			SubNameDelegates::<T>::remove(name);
+			SubNameCount::<T>::mutate_exists(parent, |maybe_count| {
+				*maybe_count = maybe_count.map(|count| count.saturating_sub(1)).filter(|c| *c > 0);
+			});
+		}
+
+		/// Verify that the delegating preconditions are verified.
+		/// Specifically:
+		/// - The name input data can be decoded as a valid name
+		/// - The owner owns the name
+		fn check_delegating_preconditions(
+			name_input: DidNameInput<T>,
+			owner: &DidNameOwnerOf<T>,
+		) -> Result<DidNameOf<T>, DispatchError> {
+			let name =
+				DidNameOf::<T>::try_from(name_input.into_inner()).map_err(DispatchError::from)?;
+
+			let name_owner = Self::owner_of(&name).ok_or(Error::<T>::NotFound)?;
+			ensure!(&name_owner == owner, Error::<T>::NotAuthorized);
+
+			Ok(name)
+		}
+
+		/// Verify that the projecting preconditions for an owner are
+		/// verified. Specifically:
+		/// - The owner has a previously claimed name
+		/// - The name has not expired
+		/// - The name is not already projected, or its projection has since been removed from the
+		///   username registry
+		fn check_projecting_preconditions(
+			owner: &DidNameOwnerOf<T>,
+		) -> Result<DidNameOf<T>, DispatchError> {
+			let name = Names::<T>::get(owner).ok_or(Error::<T>::OwnerNotFound)?;
+			let ownership = Owner::<T>::get(&name).ok_or(Error::<T>::NotFound)?;
+
+			ensure!(!Self::is_expired(&ownership), Error::<T>::NameExpired);
+			let username: Vec<u8> = name.clone().into();
+			let projected = ProjectedNames::<T>::contains_key(&name) &&
+				T::UsernameRegistry::account_of_username(&username).as_ref() == Some(owner);
+			ensure!(!projected, Error::<T>::AlreadyProjected);
+
+			Ok(name)
+		}
+
+		/// Remove the username projected from the provided name, if any.
+		fn remove_projection(name: &DidNameOf<T>, owner: &DidNameOwnerOf<T>) {
+			if ProjectedNames::<T>::take(name).is_some() {
+				let username: Vec<u8> = name.clone().into();
+				T::UsernameRegistry::remove_username(owner, &username);
+			}
+		}
 	}
 }
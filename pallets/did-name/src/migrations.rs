@@ -0,0 +1,72 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) 2019-2023 BOTLabs GmbH.
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Adapted to meet the requirements of the CORD project.
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the DID name pallet.
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_runtime::traits::Zero;
+use sp_std::marker::PhantomData;
+
+use crate::{Config, DidNameOwnershipOf, Owner, Pallet};
+
+/// Schedule the release of the names registered before names were
+/// configured to expire.
+///
+/// The registration period of every existing name restarts at the upgrade
+/// block, so that no name is hidden right after the upgrade without having
+/// been released. The names are spread over the following blocks so that no
+/// block releases more than `MaxNameExpiriesPerBlock` names.
+pub struct ScheduleExistingNames<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for ScheduleExistingNames<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut count: u32 = 0;
+		if !T::RegistrationPeriod::get().is_zero() {
+			let now = frame_system::Pallet::<T>::block_number();
+			let per_block = T::MaxNameExpiriesPerBlock::get().max(1);
+
+			Owner::<T>::translate::<DidNameOwnershipOf<T>, _>(|name, ownership| {
+				let ownership = DidNameOwnershipOf::<T> {
+					owner: ownership.owner,
+					registered_at: now.saturating_add((count / per_block).into()),
+				};
+				if Pallet::<T>::schedule_release(&name, &ownership).is_err() {
+					frame_support::defensive!(
+						"Release blocks are filled up to the max number of names"
+					);
+				}
+				count = count.saturating_add(1);
+				Some(ownership)
+			});
+		}
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		let count = u64::from(count);
+		T::DbWeight::get().reads_writes(2 * count + 1, 2 * count + 1)
+	}
+}
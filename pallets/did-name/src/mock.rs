@@ -21,11 +21,12 @@ use self::did_name::AsciiDidName;
 use super::*;
 use crate as pallet_did_name;
 use crate::Config;
+use cord_primitives::UsernameRegistry;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, ensure, parameter_types};
 use sp_runtime::{
 	traits::{IdentifyAccount, IdentityLookup, Verify},
-	BuildStorage, MultiSignature,
+	BuildStorage, DispatchError, DispatchResult, MultiSignature,
 };
 
 use frame_system::EnsureRoot;
@@ -69,6 +70,61 @@ parameter_types! {
 	pub const MaxNameLength: u32 = 64;
 	pub const MinNameLength: u32 = 3;
 	pub const MaxPrefixLength: u32 = 54;
+	pub const RegistrationPeriod: u64 = 100;
+	pub const GracePeriod: u64 = 10;
+	pub const MaxNameExpiriesPerBlock: u32 = 2;
+	pub const MaxSubNameDepth: u32 = 3;
+	pub const MaxSubNameLabelLength: u32 = 16;
+	pub const MaxSubNameDelegates: u32 = 2;
+	pub static Usernames: Vec<(TestDidNameOwner, Vec<u8>)> = vec![];
+	pub static UsernameAuthorities: Vec<(TestDidNameOwner, Vec<u8>, u32)> = vec![];
+}
+
+pub struct TestUsernameRegistry;
+impl UsernameRegistry<TestDidNameOwner> for TestUsernameRegistry {
+	fn set_primary_username(who: &TestDidNameOwner, username: Vec<u8>) -> DispatchResult {
+		ensure!(
+			!Usernames::get().iter().any(|(holder, name)| name == &username && holder != who),
+			DispatchError::Other("UsernameTaken")
+		);
+		Usernames::mutate(|usernames| {
+			usernames.retain(|(_, name)| name != &username);
+			usernames.push((who.clone(), username));
+		});
+		Ok(())
+	}
+
+	fn remove_username(who: &TestDidNameOwner, username: &[u8]) {
+		Usernames::mutate(|usernames| {
+			usernames.retain(|(holder, name)| !(holder == who && name == username))
+		});
+	}
+
+	fn use_authority_allocation(authority: &TestDidNameOwner) -> Result<Vec<u8>, DispatchError> {
+		UsernameAuthorities::mutate(|authorities| {
+			let (_, suffix, allocation) = authorities
+				.iter_mut()
+				.find(|(who, ..)| who == authority)
+				.ok_or(DispatchError::Other("NotUsernameAuthority"))?;
+			ensure!(*allocation > 0, DispatchError::Other("NoAllocation"));
+			*allocation -= 1;
+			Ok(suffix.clone())
+		})
+	}
+
+	fn account_of_username(username: &[u8]) -> Option<TestDidNameOwner> {
+		Usernames::get()
+			.into_iter()
+			.find(|(_, name)| name == username)
+			.map(|(holder, _)| holder)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_authority(authority: &TestDidNameOwner, suffix: Vec<u8>, allocation: u32) {
+		UsernameAuthorities::mutate(|authorities| {
+			authorities.push((authority.clone(), suffix, allocation))
+		});
+	}
 }
 
 impl Config for Test {
@@ -79,8 +135,15 @@ impl Config for Test {
 	type MaxNameLength = MaxNameLength;
 	type MinNameLength = MinNameLength;
 	type MaxPrefixLength = MaxPrefixLength;
+	type RegistrationPeriod = RegistrationPeriod;
+	type GracePeriod = GracePeriod;
+	type MaxNameExpiriesPerBlock = MaxNameExpiriesPerBlock;
+	type MaxSubNameDepth = MaxSubNameDepth;
+	type MaxSubNameLabelLength = MaxSubNameLabelLength;
+	type MaxSubNameDelegates = MaxSubNameDelegates;
 	type DidName = TestDidName;
 	type DidNameOwner = TestDidNameOwner;
+	type UsernameRegistry = TestUsernameRegistry;
 	type WeightInfo = ();
 }
 
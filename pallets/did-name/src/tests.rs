@@ -20,7 +20,11 @@
 
 use super::*;
 use crate::{did_name::AsciiDidName, mock::*};
-use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{GetStorageVersion, Hooks, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
 // use crate::{did_name::AsciiDidName, Banned, DidNameOwnershipOf, Error, Names,
 // Owner, Pallet};
 use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
@@ -269,3 +273,643 @@ fn unbanning_unauthorized_origin() {
 		);
 	})
 }
+
+// #############################################################################
+// Name renewal and expiry
+
+#[test]
+fn renewing_successful() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_eq!(NamesExpiresOn::<Test>::get(111).into_inner(), vec![did_name_00.clone()]);
+
+		// Renew within the grace period
+		System::set_block_number(105);
+		let ownership = Owner::<Test>::get(&did_name_00).expect("Owner should be stored.");
+		assert!(Pallet::<Test>::is_expired(&ownership));
+		assert_ok!(Pallet::<Test>::renew(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+
+		let ownership = Owner::<Test>::get(&did_name_00).expect("Owner should be stored.");
+		assert_eq!(ownership.registered_at, 105);
+		assert!(!Pallet::<Test>::is_expired(&ownership));
+		assert!(NamesExpiresOn::<Test>::get(111).is_empty());
+		assert_eq!(NamesExpiresOn::<Test>::get(215).into_inner(), vec![did_name_00.clone()]);
+
+		// The previous release block does not release the renewed name
+		Pallet::<Test>::on_initialize(111);
+		assert!(Owner::<Test>::get(&did_name_00).is_some());
+	})
+}
+
+#[test]
+fn renewing_not_found() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::renew(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::OwnerNotFound
+		);
+	})
+}
+
+#[test]
+fn expiring_after_grace_period() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+
+		Pallet::<Test>::on_initialize(110);
+		assert!(Owner::<Test>::get(&did_name_00).is_some());
+
+		Pallet::<Test>::on_initialize(111);
+		assert!(Names::<Test>::get(&DID_00).is_none());
+		assert!(Owner::<Test>::get(&did_name_00).is_none());
+		assert!(NamesExpiresOn::<Test>::get(111).is_empty());
+
+		// The name is available again
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_00.clone().0,
+		));
+	})
+}
+
+#[test]
+fn registering_expiry_schedule_full() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			get_did_name(b"did.name.a@cord").0,
+		));
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			get_did_name(b"did.name.b@cord").0,
+		));
+		assert_noop!(
+			Pallet::<Test>::register(
+				DoubleOrigin(ACCOUNT_00, SubjectId(AccountId32::new([3u8; 32]))).into(),
+				get_did_name(b"did.name.c@cord").0,
+			),
+			Error::<Test>::MaxNameExpiriesExceededForTheBlock
+		);
+	})
+}
+
+#[test]
+fn migrating_schedules_existing_names() {
+	new_test_ext().execute_with(|| {
+		// Names registered before names were configured to expire
+		for (index, input) in [b"did.name.a@cord", b"did.name.b@cord", b"did.name.c@cord"]
+			.into_iter()
+			.enumerate()
+		{
+			let owner = SubjectId(AccountId32::new([index as u8 + 1; 32]));
+			Names::<Test>::insert(&owner, get_did_name(input));
+			Owner::<Test>::insert(
+				get_did_name(input),
+				DidNameOwnershipOf::<Test> { owner, registered_at: 0 },
+			);
+		}
+		StorageVersion::new(1).put::<Pallet<Test>>();
+
+		System::set_block_number(500);
+		migrations::ScheduleExistingNames::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Pallet::<Test>::on_chain_storage_version(), 2);
+		assert_eq!(NamesExpiresOn::<Test>::get(610).len(), 2);
+		assert_eq!(NamesExpiresOn::<Test>::get(611).len(), 1);
+		let ownership =
+			Owner::<Test>::get(get_did_name(b"did.name.a@cord")).expect("Owner should be stored.");
+		assert!(!Pallet::<Test>::is_expired(&ownership));
+
+		// Running it again does not schedule the names twice
+		migrations::ScheduleExistingNames::<Test>::on_runtime_upgrade();
+		assert_eq!(NamesExpiresOn::<Test>::get(610).len(), 2);
+	})
+}
+
+// #############################################################################
+// Name transfer
+
+#[test]
+fn transferring_successful() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		assert_eq!(PendingTransfers::<Test>::get(&did_name_00), Some(DID_01));
+
+		// Ownership does not change until the recipient accepts.
+		assert_eq!(Names::<Test>::get(&DID_00), Some(did_name_00.clone()));
+
+		// Only the recipient can accept.
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				did_name_00.clone().0
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(Pallet::<Test>::accept_transfer(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_00.clone().0
+		));
+		assert!(Names::<Test>::get(&DID_00).is_none());
+		assert_eq!(Names::<Test>::get(&DID_01), Some(did_name_00.clone()));
+		assert_eq!(
+			Owner::<Test>::get(&did_name_00),
+			Some(DidNameOwnershipOf::<Test> { owner: DID_01, registered_at: 1 })
+		);
+		assert!(PendingTransfers::<Test>::get(&did_name_00).is_none());
+	})
+}
+
+#[test]
+fn transferring_recipient_already_owner() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	let did_name_01 = get_did_name(DID_NAME_01_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_01.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				did_name_00.clone().0
+			),
+			Error::<Test>::OwnerAlreadyExists
+		);
+	})
+}
+
+#[test]
+fn transferring_invalid() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01),
+			Error::<Test>::OwnerNotFound
+		);
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_noop!(
+			Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_00),
+			Error::<Test>::TransferToSelf
+		);
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		assert_noop!(
+			Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01),
+			Error::<Test>::TransferAlreadyRequested
+		);
+
+		// Expired names cannot change hands.
+		System::set_block_number(101);
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				did_name_00.clone().0
+			),
+			Error::<Test>::NameExpired
+		);
+	})
+}
+
+#[test]
+fn cancelling_transfer_successful() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_noop!(
+			Pallet::<Test>::cancel_transfer(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::TransferNotFound
+		);
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		assert_ok!(Pallet::<Test>::cancel_transfer(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_noop!(
+			Pallet::<Test>::accept_transfer(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				did_name_00.clone().0
+			),
+			Error::<Test>::TransferNotFound
+		);
+	})
+}
+
+// #############################################################################
+// Sub-names
+
+pub(crate) const ACCOUNT_02: TestDidNamePayer = AccountId32::new([3u8; 32]);
+pub(crate) const DID_02: TestDidNameOwner = SubjectId(ACCOUNT_02);
+pub(crate) const ROOT_NAME_INPUT: &[u8; 9] = b"acme@cord";
+pub(crate) const SUB_NAME_INPUT: &[u8; 12] = b"hr@acme.cord";
+pub(crate) const SUB_SUB_NAME_INPUT: &[u8; 18] = b"alice.hr@acme.cord";
+
+#[test]
+fn issuing_sub_names_successful() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	let sub_sub_name = get_did_name(SUB_SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(DoubleOrigin(ACCOUNT_00, DID_00).into(), root.0));
+
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01
+		));
+		// The owner of `hr@acme.cord` can issue below it.
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			sub_sub_name.clone().0,
+			DID_02
+		));
+
+		assert_eq!(Pallet::<Test>::resolve_owner(&sub_name), Some(DID_01));
+		assert_eq!(Pallet::<Test>::resolve_owner(&sub_sub_name), Some(DID_02));
+		assert_eq!(SubNameCount::<Test>::get(&sub_name), 1);
+
+		// Sub-names do not take the place of the owner's own name.
+		assert!(Names::<Test>::get(&DID_01).is_none());
+
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0,
+				DID_02
+			),
+			Error::<Test>::AlreadyExists
+		);
+	})
+}
+
+#[test]
+fn issuing_sub_names_invalid() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	let sub_sub_name = get_did_name(SUB_SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		// Top-level names cannot be issued and sub-names cannot be registered.
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				root.clone().0,
+				DID_01
+			),
+			Error::<Test>::NotASubName
+		);
+		assert_noop!(
+			Pallet::<Test>::register(DoubleOrigin(ACCOUNT_00, DID_00).into(), sub_name.clone().0),
+			Error::<Test>::NotATopLevelName
+		);
+
+		// The parent must exist.
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0,
+				DID_01
+			),
+			Error::<Test>::ParentNotFound
+		);
+
+		assert_ok!(Pallet::<Test>::register(DoubleOrigin(ACCOUNT_00, DID_00).into(), root.0));
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				sub_name.clone().0,
+				DID_01
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_sub_name.0,
+				DID_01
+			),
+			Error::<Test>::ParentNotFound
+		);
+	})
+}
+
+#[test]
+fn sub_name_delegates_successful() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			root.clone().0
+		));
+		assert_noop!(
+			Pallet::<Test>::add_sub_name_delegate(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				root.clone().0,
+				DID_01
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_ok!(Pallet::<Test>::add_sub_name_delegate(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			root.clone().0,
+			DID_01
+		));
+
+		// The delegate can issue and revoke without owning the name.
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			sub_name.clone().0,
+			DID_02
+		));
+		assert_ok!(Pallet::<Test>::revoke_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			sub_name.clone().0
+		));
+
+		assert_ok!(Pallet::<Test>::remove_sub_name_delegate(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			root.clone().0,
+			DID_01
+		));
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				sub_name.0,
+				DID_02
+			),
+			Error::<Test>::NotAuthorized
+		);
+		assert_noop!(
+			Pallet::<Test>::remove_sub_name_delegate(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				root.0,
+				DID_01
+			),
+			Error::<Test>::DelegateNotFound
+		);
+	})
+}
+
+#[test]
+fn transferring_removes_sub_name_delegates() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			root.clone().0
+		));
+		assert_ok!(Pallet::<Test>::add_sub_name_delegate(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			root.clone().0,
+			DID_01
+		));
+
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_02));
+		assert_ok!(Pallet::<Test>::accept_transfer(
+			DoubleOrigin(ACCOUNT_02, DID_02).into(),
+			root.clone().0
+		));
+		assert!(SubNameDelegates::<Test>::get(&root).is_empty());
+
+		// The delegates of the previous owner cannot issue under the name.
+		assert_noop!(
+			Pallet::<Test>::issue_sub_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				sub_name.0,
+				DID_01
+			),
+			Error::<Test>::NotAuthorized
+		);
+	})
+}
+
+#[test]
+fn revoking_sub_names_successful() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	let sub_sub_name = get_did_name(SUB_SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(DoubleOrigin(ACCOUNT_00, DID_00).into(), root.0));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			sub_sub_name.clone().0,
+			DID_02
+		));
+
+		// Sub-names must be revoked bottom-up.
+		assert_noop!(
+			Pallet::<Test>::revoke_sub_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				sub_name.clone().0
+			),
+			Error::<Test>::SubNamesExist
+		);
+		// The root owner can revoke anywhere below the root.
+		assert_ok!(Pallet::<Test>::revoke_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_sub_name.clone().0
+		));
+		// A sub-name owner can give up their sub-name.
+		assert_ok!(Pallet::<Test>::revoke_sub_name(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			sub_name.clone().0
+		));
+
+		assert!(SubNameOwner::<Test>::get(&sub_name).is_none());
+		assert!(SubNameOwner::<Test>::get(&sub_sub_name).is_none());
+		assert_eq!(SubNameCount::<Test>::get(&get_did_name(ROOT_NAME_INPUT)), 0);
+
+		// The root can now be released.
+		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+	})
+}
+
+#[test]
+fn revoking_sub_names_of_root_in_grace_period_not_authorized() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(DoubleOrigin(ACCOUNT_00, DID_00).into(), root.0));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01
+		));
+
+		// The root no longer resolves, but its owner can still renew it.
+		System::set_block_number(105);
+		assert!(Pallet::<Test>::resolve_owner(&sub_name).is_none());
+		assert_noop!(
+			Pallet::<Test>::revoke_sub_name(
+				DoubleOrigin(ACCOUNT_02, DID_02).into(),
+				sub_name.clone().0
+			),
+			Error::<Test>::NotAuthorized
+		);
+
+		assert_ok!(Pallet::<Test>::revoke_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.0
+		));
+	})
+}
+
+#[test]
+fn sub_names_of_banned_root() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let sub_name = get_did_name(SUB_NAME_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			root.clone().0
+		));
+		assert_ok!(Pallet::<Test>::issue_sub_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			sub_name.clone().0,
+			DID_01
+		));
+		assert_noop!(
+			Pallet::<Test>::release(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::SubNamesExist
+		);
+
+		assert_ok!(Pallet::<Test>::ban(RawOrigin::Root.into(), root.clone().0));
+		assert_ok!(Pallet::<Test>::unban(RawOrigin::Root.into(), root.clone().0));
+
+		// The sub-name no longer resolves, and blocks the root from being
+		// registered again until it is revoked.
+		assert!(Pallet::<Test>::resolve_owner(&sub_name).is_none());
+		assert_noop!(
+			Pallet::<Test>::register(DoubleOrigin(ACCOUNT_01, DID_01).into(), root.clone().0),
+			Error::<Test>::SubNamesExist
+		);
+		// Anyone can revoke a sub-name that no longer resolves.
+		assert_ok!(Pallet::<Test>::revoke_sub_name(
+			DoubleOrigin(ACCOUNT_02, DID_02).into(),
+			sub_name.0
+		));
+		assert_ok!(Pallet::<Test>::register(DoubleOrigin(ACCOUNT_01, DID_01).into(), root.0));
+	})
+}
+
+// #############################################################################
+// Identity usernames
+
+#[test]
+fn projecting_username_successful() {
+	let did_name_00 = get_did_name(DID_NAME_00_INPUT);
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Pallet::<Test>::project_username(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::OwnerNotFound
+		);
+
+		assert_ok!(Pallet::<Test>::register(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			did_name_00.clone().0,
+		));
+		assert_ok!(Pallet::<Test>::project_username(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert!(ProjectedNames::<Test>::contains_key(&did_name_00));
+		assert_eq!(Usernames::get(), vec![(DID_00, DID_NAME_00_INPUT.to_vec())]);
+		assert_noop!(
+			Pallet::<Test>::project_username(DoubleOrigin(ACCOUNT_00, DID_00).into()),
+			Error::<Test>::AlreadyProjected
+		);
+
+		// A username removed from the registry can be projected again.
+		Usernames::set(vec![]);
+		assert_ok!(Pallet::<Test>::project_username(DoubleOrigin(ACCOUNT_00, DID_00).into()));
+		assert_eq!(Usernames::get(), vec![(DID_00, DID_NAME_00_INPUT.to_vec())]);
+
+		// Transferring the name removes the username of the previous owner.
+		assert_ok!(Pallet::<Test>::transfer(DoubleOrigin(ACCOUNT_00, DID_00).into(), DID_01));
+		assert_ok!(Pallet::<Test>::accept_transfer(
+			DoubleOrigin(ACCOUNT_01, DID_01).into(),
+			did_name_00.clone().0,
+		));
+		assert!(!ProjectedNames::<Test>::contains_key(&did_name_00));
+		assert!(Usernames::get().is_empty());
+
+		// Releasing the name removes the username of the owner.
+		assert_ok!(Pallet::<Test>::project_username(DoubleOrigin(ACCOUNT_01, DID_01).into()));
+		assert_eq!(Usernames::get(), vec![(DID_01, DID_NAME_00_INPUT.to_vec())]);
+		assert_ok!(Pallet::<Test>::release(DoubleOrigin(ACCOUNT_01, DID_01).into()));
+		assert!(!ProjectedNames::<Test>::contains_key(&did_name_00));
+		assert!(Usernames::get().is_empty());
+	})
+}
+
+#[test]
+fn issuing_authority_names_successful() {
+	let root = get_did_name(ROOT_NAME_INPUT);
+	let authority_name = get_did_name(b"bob@acme.cord");
+	new_test_ext().execute_with(|| {
+		assert_ok!(Pallet::<Test>::register(DoubleOrigin(ACCOUNT_00, DID_00).into(), root.0));
+
+		assert_noop!(
+			Pallet::<Test>::issue_authority_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				BoundedVec::truncate_from(b"bob".to_vec()),
+				DID_01
+			),
+			DispatchError::Other("NotUsernameAuthority")
+		);
+
+		UsernameAuthorities::set(vec![(DID_00, b"acme".to_vec(), 1)]);
+		assert_ok!(Pallet::<Test>::issue_authority_name(
+			DoubleOrigin(ACCOUNT_00, DID_00).into(),
+			BoundedVec::truncate_from(b"bob".to_vec()),
+			DID_01
+		));
+		assert_eq!(Pallet::<Test>::resolve_owner(&authority_name), Some(DID_01));
+		assert_eq!(SubNameCount::<Test>::get(&get_did_name(ROOT_NAME_INPUT)), 1);
+
+		// Each name consumes one allocation of the authority.
+		assert_noop!(
+			Pallet::<Test>::issue_authority_name(
+				DoubleOrigin(ACCOUNT_00, DID_00).into(),
+				BoundedVec::truncate_from(b"carol".to_vec()),
+				DID_02
+			),
+			DispatchError::Other("NoAllocation")
+		);
+
+		// The authority must manage the name of its suffix.
+		UsernameAuthorities::set(vec![(DID_01, b"acme".to_vec(), 1)]);
+		assert_noop!(
+			Pallet::<Test>::issue_authority_name(
+				DoubleOrigin(ACCOUNT_01, DID_01).into(),
+				BoundedVec::truncate_from(b"carol".to_vec()),
+				DID_02
+			),
+			Error::<Test>::NotAuthorized
+		);
+	})
+}
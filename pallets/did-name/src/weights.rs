@@ -53,6 +53,17 @@ pub trait WeightInfo {
 	fn release() -> Weight;
 	fn ban(n: u32, ) -> Weight;
 	fn unban(n: u32, ) -> Weight;
+	fn renew() -> Weight;
+	fn transfer() -> Weight;
+	fn cancel_transfer() -> Weight;
+	fn accept_transfer(n: u32, ) -> Weight;
+	fn expire() -> Weight;
+	fn issue_sub_name(n: u32, ) -> Weight;
+	fn revoke_sub_name(n: u32, ) -> Weight;
+	fn add_sub_name_delegate(n: u32, ) -> Weight;
+	fn remove_sub_name_delegate(n: u32, ) -> Weight;
+	fn project_username() -> Weight;
+	fn issue_authority_name(n: u32, ) -> Weight;
 }
 
 /// Weights for `pallet_did_name` using the CORD node and recommended hardware.
@@ -121,6 +132,201 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:1)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::NamesExpiresOn` (r:2 w:2)
+	/// Proof: `DidName::NamesExpiresOn` (`max_values`: None, `max_size`: Some(65048), added: 67523, mode: `MaxEncodedLen`)
+	fn renew() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1147`
+		//  Estimated: `68513`
+		// Minimum execution time: 24_310_000 picoseconds.
+		Weight::from_parts(25_120_000, 68513)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::PendingTransfers` (r:1 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `3583`
+		// Minimum execution time: 16_020_000 picoseconds.
+		Weight::from_parts(16_730_000, 3583)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::PendingTransfers` (r:1 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn cancel_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `307`
+		//  Estimated: `3579`
+		// Minimum execution time: 13_440_000 picoseconds.
+		Weight::from_parts(14_010_000, 3579)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::PendingTransfers` (r:1 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Names` (r:1 w:2)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:1)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn accept_transfer(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339 + n * (1 ±0)`
+		//  Estimated: `3583`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_504_120, 3583)
+			// Standard Error: 812
+			.saturating_add(Weight::from_parts(14_388, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `DidName::Owner` (r:1 w:1)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Names` (r:0 w:1)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::PendingTransfers` (r:0 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn expire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `3583`
+		// Minimum execution time: 12_950_000 picoseconds.
+		Weight::from_parts(13_480_000, 3583)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:4 w:1)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Banned` (r:1 w:0)
+	/// Proof: `DidName::Banned` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:4 w:0)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameCount` (r:1 w:1)
+	/// Proof: `DidName::SubNameCount` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn issue_sub_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + n * (4 ±0)`
+		//  Estimated: `12502`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_807_516, 12502)
+			// Standard Error: 1_931
+			.saturating_add(Weight::from_parts(61_204, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:4 w:1)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameCount` (r:2 w:1)
+	/// Proof: `DidName::SubNameCount` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:3 w:1)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn revoke_sub_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `934 + n * (4 ±0)`
+		//  Estimated: `12502`
+		// Minimum execution time: 39_870_000 picoseconds.
+		Weight::from_parts(41_115_340, 12502)
+			// Standard Error: 1_874
+			.saturating_add(Weight::from_parts(58_772, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:1 w:0)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:1 w:1)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn add_sub_name_delegate(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `441 + n * (1 ±0)`
+		//  Estimated: `3868`
+		// Minimum execution time: 17_660_000 picoseconds.
+		Weight::from_parts(18_290_412, 3868)
+			// Standard Error: 903
+			.saturating_add(Weight::from_parts(16_935, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:1 w:0)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:1 w:1)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn remove_sub_name_delegate(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `473 + n * (1 ±0)`
+		//  Estimated: `3868`
+		// Minimum execution time: 17_940_000 picoseconds.
+		Weight::from_parts(18_601_907, 3868)
+			// Standard Error: 911
+			.saturating_add(Weight::from_parts(17_112, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::ProjectedNames` (r:1 w:1)
+	/// Proof: `DidName::ProjectedNames` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingUsernames` (r:1 w:0)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfUsername` (r:1 w:1)
+	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	fn project_username() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `5731`
+		// Minimum execution time: 31_870_000 picoseconds.
+		Weight::from_parts(32_944_000, 5731)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Identity::UsernameAuthorities` (r:1 w:1)
+	/// Proof: `Identity::UsernameAuthorities` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameOwner` (r:1 w:1)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Banned` (r:1 w:0)
+	/// Proof: `DidName::Banned` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:2 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:1 w:0)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameCount` (r:1 w:1)
+	/// Proof: `DidName::SubNameCount` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn issue_authority_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `604 + n * (2 ±0)`
+		//  Estimated: `6176`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_517_283, 6176)
+			// Standard Error: 1_702
+			.saturating_add(Weight::from_parts(48_391, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -188,4 +394,199 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:1)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::NamesExpiresOn` (r:2 w:2)
+	/// Proof: `DidName::NamesExpiresOn` (`max_values`: None, `max_size`: Some(65048), added: 67523, mode: `MaxEncodedLen`)
+	fn renew() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1147`
+		//  Estimated: `68513`
+		// Minimum execution time: 24_310_000 picoseconds.
+		Weight::from_parts(25_120_000, 68513)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::PendingTransfers` (r:1 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `3583`
+		// Minimum execution time: 16_020_000 picoseconds.
+		Weight::from_parts(16_730_000, 3583)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::PendingTransfers` (r:1 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn cancel_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `307`
+		//  Estimated: `3579`
+		// Minimum execution time: 13_440_000 picoseconds.
+		Weight::from_parts(14_010_000, 3579)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::PendingTransfers` (r:1 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Names` (r:1 w:2)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:1)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn accept_transfer(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `339 + n * (1 ±0)`
+		//  Estimated: `3583`
+		// Minimum execution time: 21_870_000 picoseconds.
+		Weight::from_parts(22_504_120, 3583)
+			// Standard Error: 812
+			.saturating_add(Weight::from_parts(14_388, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `DidName::Owner` (r:1 w:1)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Names` (r:0 w:1)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::PendingTransfers` (r:0 w:1)
+	/// Proof: `DidName::PendingTransfers` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	fn expire() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `275`
+		//  Estimated: `3583`
+		// Minimum execution time: 12_950_000 picoseconds.
+		Weight::from_parts(13_480_000, 3583)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:4 w:1)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Banned` (r:1 w:0)
+	/// Proof: `DidName::Banned` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:4 w:0)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameCount` (r:1 w:1)
+	/// Proof: `DidName::SubNameCount` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn issue_sub_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `812 + n * (4 ±0)`
+		//  Estimated: `12502`
+		// Minimum execution time: 41_230_000 picoseconds.
+		Weight::from_parts(42_807_516, 12502)
+			// Standard Error: 1_931
+			.saturating_add(Weight::from_parts(61_204, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:4 w:1)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameCount` (r:2 w:1)
+	/// Proof: `DidName::SubNameCount` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:3 w:1)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn revoke_sub_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `934 + n * (4 ±0)`
+		//  Estimated: `12502`
+		// Minimum execution time: 39_870_000 picoseconds.
+		Weight::from_parts(41_115_340, 12502)
+			// Standard Error: 1_874
+			.saturating_add(Weight::from_parts(58_772, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:1 w:0)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:1 w:1)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn add_sub_name_delegate(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `441 + n * (1 ±0)`
+		//  Estimated: `3868`
+		// Minimum execution time: 17_660_000 picoseconds.
+		Weight::from_parts(18_290_412, 3868)
+			// Standard Error: 903
+			.saturating_add(Weight::from_parts(16_935, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::SubNameOwner` (r:1 w:0)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:1 w:1)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[13, 64]`.
+	fn remove_sub_name_delegate(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `473 + n * (1 ±0)`
+		//  Estimated: `3868`
+		// Minimum execution time: 17_940_000 picoseconds.
+		Weight::from_parts(18_601_907, 3868)
+			// Standard Error: 911
+			.saturating_add(Weight::from_parts(17_112, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DidName::Names` (r:1 w:0)
+	/// Proof: `DidName::Names` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:1 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::ProjectedNames` (r:1 w:1)
+	/// Proof: `DidName::ProjectedNames` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::PendingUsernames` (r:1 w:0)
+	/// Proof: `Identity::PendingUsernames` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::AccountOfUsername` (r:1 w:1)
+	/// Proof: `Identity::AccountOfUsername` (`max_values`: None, `max_size`: Some(81), added: 2556, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	fn project_username() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `5731`
+		// Minimum execution time: 31_870_000 picoseconds.
+		Weight::from_parts(32_944_000, 5731)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `Identity::UsernameAuthorities` (r:1 w:1)
+	/// Proof: `Identity::UsernameAuthorities` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameOwner` (r:1 w:1)
+	/// Proof: `DidName::SubNameOwner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Banned` (r:1 w:0)
+	/// Proof: `DidName::Banned` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::Owner` (r:2 w:0)
+	/// Proof: `DidName::Owner` (`max_values`: None, `max_size`: Some(118), added: 2593, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameDelegates` (r:1 w:0)
+	/// Proof: `DidName::SubNameDelegates` (`max_values`: None, `max_size`: Some(403), added: 2878, mode: `MaxEncodedLen`)
+	/// Storage: `DidName::SubNameCount` (r:1 w:1)
+	/// Proof: `DidName::SubNameCount` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	fn issue_authority_name(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `604 + n * (2 ±0)`
+		//  Estimated: `6176`
+		// Minimum execution time: 38_412_000 picoseconds.
+		Weight::from_parts(39_517_283, 6176)
+			// Standard Error: 1_702
+			.saturating_add(Weight::from_parts(48_391, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
 }
@@ -34,6 +34,7 @@ use crate::{
 };
 // use frame_system::pallet_prelude::BlockNumberFor;
 use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
 use sp_core::{ecdsa, ed25519, sr25519};
 use sp_runtime::{
 	traits::{IdentifyAccount, Verify},
@@ -43,7 +44,18 @@ use sp_std::{convert::TryInto, vec::Vec};
 
 /// Public verification key that a DID can control.
 #[derive(
-	Clone, Decode, RuntimeDebug, Encode, Eq, Ord, PartialEq, PartialOrd, TypeInfo, MaxEncodedLen,
+	Clone,
+	Decode,
+	RuntimeDebug,
+	Encode,
+	Eq,
+	Ord,
+	PartialEq,
+	PartialOrd,
+	TypeInfo,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
 )]
 pub enum DidVerificationKey<AccountId> {
 	/// An Ed25519 public key.
@@ -122,7 +122,7 @@ use sp_runtime::{
 	traits::{Dispatchable, Saturating, Zero},
 	SaturatedConversion,
 };
-use sp_std::{boxed::Box, fmt::Debug, prelude::Clone};
+use sp_std::{boxed::Box, fmt::Debug, prelude::Clone, vec::Vec};
 
 #[cfg(feature = "runtime-benchmarks")]
 use frame_system::RawOrigin;
@@ -450,6 +450,28 @@ pub mod pallet {
 		}
 	}
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// DIDs created at genesis, with their identifier and authentication
+		/// key.
+		pub dids: Vec<(AccountIdOf<T>, DidVerificationKey<AccountIdOf<T>>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (account, authentication_key) in &self.dids {
+				let did_identifier: DidIdentifierOf<T> = account.clone().into();
+				assert!(!Did::<T>::contains_key(&did_identifier), "Genesis DID already exists");
+
+				let did_entry = DidDetails::new(authentication_key.clone(), Zero::zero())
+					.expect("Genesis DID exceeds the maximum number of public keys");
+				Did::<T>::insert(&did_identifier, did_entry);
+			}
+		}
+	}
+
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
 		#[cfg(feature = "try-runtime")]
@@ -21,7 +21,7 @@
 use crate as pallet_did;
 use codec::{Decode, Encode};
 use cord_utilities::mock::*;
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
 use frame_system::EnsureRoot;
 use pallet_chain_space::IsPermissioned;
 use scale_info::TypeInfo;
@@ -148,8 +148,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxTierNameLength = ConstU32<32>;
+	type MaxSpacesPerBatch = ConstU32<10>;
+	type MaxCapacityResetsPerBlock = ConstU32<10>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type NetworkParameters = ();
+	type IdentityVerifier = ();
+	type CreatorAccount = ();
 	type WeightInfo = ();
 }
 
@@ -23,7 +23,7 @@ use frame_support::{assert_noop, assert_ok};
 use frame_system::pallet_prelude::BlockNumberFor;
 
 use sp_core::{ed25519, Pair};
-use sp_runtime::{traits::BadOrigin, SaturatedConversion};
+use sp_runtime::{traits::BadOrigin, BuildStorage, SaturatedConversion};
 use sp_std::{
 	collections::btree_set::BTreeSet,
 	convert::{TryFrom, TryInto},
@@ -121,6 +121,28 @@ fn check_successful_simple_ecdsa_creation() {
 	});
 }
 
+#[test]
+fn check_successful_genesis_creation() {
+	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
+	let alice_did = get_did_identifier_from_sr25519_key(auth_key.public());
+	let auth_did_key = DidVerificationKey::from(auth_key.public());
+
+	let t = RuntimeGenesisConfig {
+		did: did::GenesisConfig { dids: vec![(alice_did.clone(), auth_did_key.clone())] },
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let stored_did =
+			did::Did::<Test>::get(&alice_did).expect("ALICE_DID should be present on chain.");
+		assert_eq!(stored_did.authentication_key, generate_key_id(&auth_did_key.into()));
+		assert_eq!(stored_did.public_keys.len(), 1);
+		assert_eq!(stored_did.last_tx_counter, 0u64);
+	});
+}
+
 #[test]
 fn check_successful_complete_creation() {
 	let auth_key = get_sr25519_authentication_key(&AUTH_SEED_0);
@@ -76,6 +76,13 @@ fn add_registrars<T: Config>(r: u32) -> Result<(), &'static str> {
 	Ok(())
 }
 
+// The smallest fee a registrar can charge in the benchmark fee asset.
+fn benchmark_fee<T: Config>() -> RegistrarFeeOf<T> {
+	let asset = T::BenchmarkFeeAsset::get();
+	let minimum = <T::FeeAssets as fungibles::Inspect<_>>::minimum_balance(asset.clone());
+	RegistrarFee { asset, amount: minimum.max(1u32.into()) }
+}
+
 // Create `s` sub-accounts for the identity of `who` and return them.
 // Each will have 32 bytes of raw data added to it.
 fn create_sub_accounts<T: Config>(
@@ -694,5 +701,62 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn set_fee(r: Linear<1, { T::MaxRegistrars::get() - 1 }>) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_lookup = T::Lookup::unlookup(caller.clone());
+
+		add_registrars::<T>(r)?;
+
+		let registrar_origin = T::RegistrarOrigin::try_successful_origin()
+			.expect("RegistrarOrigin has no successful origin required for the benchmark");
+		Identity::<T>::add_registrar(registrar_origin, caller_lookup)?;
+		let fee = benchmark_fee::<T>();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller.clone()), Some(fee.clone()));
+
+		assert_last_event::<T>(
+			Event::<T>::RegistrarFeeSet { registrar: caller, fee: Some(fee) }.into(),
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn expire_judgement_request() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let caller_origin =
+			<T as frame_system::Config>::RuntimeOrigin::from(RawOrigin::Signed(caller.clone()));
+
+		add_registrars::<T>(1)?;
+		let registrar: T::AccountId = account("registrar", 0, SEED);
+		let fee = benchmark_fee::<T>();
+		Identity::<T>::set_fee(RawOrigin::Signed(registrar.clone()).into(), Some(fee.clone()))?;
+		<T::FeeAssets as fungibles::Mutate<_>>::mint_into(
+			fee.asset.clone(),
+			&caller,
+			fee.amount.saturating_mul(10u32.into()),
+		)?;
+
+		let info = T::IdentityInformation::create_identity_info();
+		Identity::<T>::set_identity(caller_origin.clone(), Box::new(info))?;
+		Identity::<T>::request_judgement(caller_origin, registrar.clone())?;
+		let deadline = JudgementRequests::<T>::get(&registrar, &caller)
+			.and_then(|request| request.deadline)
+			.ok_or(BenchmarkError::Weightless)?;
+
+		#[block]
+		{
+			Identity::<T>::expire_judgement_requests(deadline);
+		}
+
+		assert_last_event::<T>(
+			Event::<T>::JudgementRequestExpired { who: caller, registrar }.into(),
+		);
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(Identity, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -44,6 +44,13 @@
 //! The number of registrars should be limited, and the deposit made sufficiently large, to ensure
 //! no state-bloat attack is viable.
 //!
+//! ### Registrar fees
+//!
+//! Registrars can charge a fee for a judgement, in the native token or any other asset of
+//! `FeeAssets`. The fee is held when the judgement is requested and paid to the registrar with the
+//! judgement. Requests which are not judged within `JudgementPeriod` blocks are cancelled and
+//! their fee refunded. The pallet keeps performance statistics for each registrar.
+//!
 //! ### Usernames
 //!
 //! The pallet provides functionality for username authorities to issue usernames. When an account
@@ -69,6 +76,7 @@
 //!
 //! #### For registrars
 //! * `provide_judgement` - Provide a judgement to an identity.
+//! * `set_fee` - Set the fee charged for a judgement.
 //!
 //! #### For super-users
 //! * `add_registrar` - Add a new registrar to the system.
@@ -93,20 +101,33 @@ use codec::Encode;
 use frame_support::{
 	ensure,
 	pallet_prelude::{DispatchError, DispatchResult},
-	traits::{Get, StorageVersion},
-	BoundedVec,
+	traits::{fungibles, tokens::Preservation, Get, StorageVersion},
+	weights::Weight,
+	BoundedVec, PalletId,
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 pub use pallet::*;
 use sp_runtime::traits::{
-	AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify,
+	AccountIdConversion, AppendZerosInput, Hash, IdentifyAccount, Saturating, StaticLookup, Verify,
+	Zero,
 };
 use sp_std::prelude::*;
 pub use types::{
-	Data, IdentityInformationProvider, Judgement, RegistrarIndex, RegistrarInfo, Registration,
+	Data, IdentityInformationProvider, Judgement, JudgementRequest, RegistrarFee, RegistrarIndex,
+	RegistrarInfo, RegistrarStats, Registration,
 };
 pub use weights::WeightInfo;
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+type FeeAssetOf<T> = <<T as Config>::FeeAssets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::AssetId;
+type FeeBalanceOf<T> = <<T as Config>::FeeAssets as fungibles::Inspect<
+	<T as frame_system::Config>::AccountId,
+>>::Balance;
+pub type RegistrarFeeOf<T> = RegistrarFee<FeeAssetOf<T>, FeeBalanceOf<T>>;
+pub type JudgementRequestOf<T> = JudgementRequest<RegistrarFeeOf<T>, BlockNumberFor<T>>;
+pub type RegistrarStatsOf<T> = RegistrarStats<BlockNumberFor<T>>;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -158,6 +179,27 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxUsernameLength: Get<u32>;
 
+		/// The assets registrars can charge their fees in, including the native token.
+		type FeeAssets: fungibles::Mutate<Self::AccountId>;
+
+		/// The pallet id, used to derive the account holding the fees of pending judgement
+		/// requests.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The number of blocks a registrar has to provide a requested judgement. Requests without
+		/// a judgement by then are cancelled and their fee refunded. Zero disables the deadline.
+		#[pallet::constant]
+		type JudgementPeriod: Get<BlockNumberFor<Self>>;
+
+		/// The maximum number of judgement requests reaching their deadline in a single block.
+		#[pallet::constant]
+		type MaxJudgementDeadlinesPerBlock: Get<u32>;
+
+		/// The asset used for registrar fees in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkFeeAsset: Get<FeeAssetOf<Self>>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -243,6 +285,38 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// The fee each registrar charges for a judgement. Registrars without a fee judge for free.
+	#[pallet::storage]
+	pub type RegistrarFees<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RegistrarFeeOf<T>, OptionQuery>;
+
+	/// Judgement requests awaiting a judgement, keyed by the registrar and then the requester.
+	#[pallet::storage]
+	pub type JudgementRequests<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		JudgementRequestOf<T>,
+		OptionQuery,
+	>;
+
+	/// The judgement requests, as `(requester, registrar)`, reaching their deadline at a block.
+	#[pallet::storage]
+	pub type JudgementDeadlines<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<(T::AccountId, T::AccountId), T::MaxJudgementDeadlinesPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Performance statistics of each registrar.
+	#[pallet::storage]
+	pub type RegistrarStatistics<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, RegistrarStatsOf<T>, ValueQuery>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Too many subs-accounts.
@@ -301,6 +375,10 @@ pub mod pallet {
 		NoUsername,
 		/// The username cannot be forcefully removed because it can still be accepted.
 		NotExpired,
+		/// The fee is zero or below the minimum balance of its asset.
+		FeeBelowMinimum,
+		/// Too many judgement requests reach their deadline in the same block.
+		TooManyJudgementDeadlines,
 	}
 
 	#[pallet::event]
@@ -344,6 +422,19 @@ pub mod pallet {
 		/// A dangling username (as in, a username corresponding to an account that has removed its
 		/// identity) has been removed.
 		DanglingUsernameRemoved { who: T::AccountId, username: Username<T> },
+		/// A username was removed from `who`.
+		UsernameRemoved { who: T::AccountId, username: Username<T> },
+		/// The fee of a registrar was set or removed.
+		RegistrarFeeSet { registrar: T::AccountId, fee: Option<RegistrarFeeOf<T>> },
+		/// A judgement request reached its deadline and its fee was refunded.
+		JudgementRequestExpired { who: T::AccountId, registrar: T::AccountId },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			Self::expire_judgement_requests(n)
+		}
 	}
 
 	#[pallet::call]
@@ -495,6 +586,7 @@ pub mod pallet {
 			let sub_ids = <SubsOf<T>>::take(&sender);
 			let (id, maybe_username) =
 				<IdentityOf<T>>::take(&sender).ok_or(Error::<T>::NoIdentity)?;
+			Self::cancel_judgement_requests(&sender);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
@@ -514,6 +606,9 @@ pub mod pallet {
 
 		/// Request a judgement from a registrar.
 		///
+		/// Payment: The fee of the registrar, if any, is held until the judgement is provided or
+		/// the request is cancelled.
+		///
 		/// The dispatch origin for this call must be _Signed_ and the sender
 		/// must have a registered identity.
 		///
@@ -552,6 +647,8 @@ pub mod pallet {
 					id.judgements.try_insert(i, item).map_err(|_| Error::<T>::TooManyRegistrars)?,
 			}
 
+			Self::open_judgement_request(&sender, &registrar)?;
+
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&sender, (id, username));
 
@@ -562,7 +659,7 @@ pub mod pallet {
 
 		/// Cancel a previous request.
 		///
-		/// Payment: A previously reserved deposit is returned on success.
+		/// Payment: The fee held for the request is returned on success.
 		///
 		/// The dispatch origin for this call must be _Signed_ and the sender
 		/// must have a registered identity.
@@ -591,6 +688,8 @@ pub mod pallet {
 				return Err(Error::<T>::JudgementGiven.into());
 			}
 
+			Self::cancel_judgement_request(&sender, &registrar);
+
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&sender, (id, username));
 
@@ -601,6 +700,8 @@ pub mod pallet {
 
 		/// Change the account associated with a registrar.
 		///
+		/// Pending judgement requests to the registrar are moved to the new account.
+		///
 		/// The dispatch origin for this call must be _Signed_ and the sender
 		/// must be the account of the registrar whose index is `index`.
 		///
@@ -633,6 +734,9 @@ pub mod pallet {
 
 					Ok(registrars.len())
 				})?;
+			RegistrarFees::<T>::swap(&who, &new);
+			RegistrarStatistics::<T>::swap(&who, &new);
+			Self::move_judgement_requests(&who, &new);
 
 			Ok(Some(T::WeightInfo::set_account_id(registrars as u32)).into())
 		}
@@ -676,6 +780,8 @@ pub mod pallet {
 
 		/// Provide a judgement for an account's identity.
 		///
+		/// Payment: The fee held for a requested judgement is paid to the registrar.
+		///
 		/// The dispatch origin for this call must be _Signed_ and the sender
 		/// must be the account of the registrar whose index is `reg_index`.
 		///
@@ -728,6 +834,8 @@ pub mod pallet {
 					.map_err(|_| Error::<T>::TooManyRegistrars)?,
 			}
 
+			Self::close_judgement_request(&target, &registrar);
+
 			let judgements = id.judgements.len();
 			<IdentityOf<T>>::insert(&target, (id, username));
 			Self::deposit_event(Event::JudgementGiven { target, registrar });
@@ -759,6 +867,7 @@ pub mod pallet {
 			let sub_ids = <SubsOf<T>>::take(&target);
 			let (id, maybe_username) =
 				<IdentityOf<T>>::take(&target).ok_or(Error::<T>::NoIdentity)?;
+			Self::withdraw_judgement_requests(&target);
 			for sub in sub_ids.iter() {
 				<SuperOf<T>>::remove(sub);
 			}
@@ -934,16 +1043,7 @@ pub mod pallet {
 			// Ensure origin is a Username Authority and has an allocation. Decrement their
 			// allocation by one.
 			let sender = ensure_signed(origin)?;
-			let suffix = UsernameAuthorities::<T>::try_mutate(
-				&sender,
-				|maybe_authority| -> Result<Suffix<T>, DispatchError> {
-					let properties =
-						maybe_authority.as_mut().ok_or(Error::<T>::NotUsernameAuthority)?;
-					ensure!(properties.allocation > 0, Error::<T>::NoAllocation);
-					properties.allocation.saturating_dec();
-					Ok(properties.suffix.clone())
-				},
-			)?;
+			let suffix = Self::use_allocation(&sender)?;
 
 			// Ensure that the username only contains allowed characters. We already know the suffix
 			// does.
@@ -1050,6 +1150,8 @@ pub mod pallet {
 		}
 		/// Remove a registrar from the system.
 		///
+		/// Pending judgement requests to the registrar are withdrawn and their fees refunded.
+		///
 		/// The dispatch origin for this call must be `T::RegistrarOrigin`.
 		///
 		/// - `account`: the account of the registrar.
@@ -1080,10 +1182,55 @@ pub mod pallet {
 					Ok(registrars.len())
 				})?;
 
+			RegistrarFees::<T>::remove(&account_id);
+			Self::withdraw_registrar_requests(&account_id);
 			Self::deposit_event(Event::RegistrarRemoved { registrar: account_id });
 
 			Ok(Some(T::WeightInfo::remove_registrar(registrar_count as u32)).into())
 		}
+
+		/// Set the fee charged by a registrar for a judgement.
+		///
+		/// The dispatch origin for this call must be _Signed_ and the sender
+		/// must be the account of a registrar.
+		///
+		/// - `fee`: the fee and the asset it is paid in, or `None` to provide judgements for free.
+		///   The fee must be at least the minimum balance of its asset.
+		///
+		/// Emits `RegistrarFeeSet` if successful.
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::set_fee(T::MaxRegistrars::get()))]
+		pub fn set_fee(
+			origin: OriginFor<T>,
+			fee: Option<RegistrarFeeOf<T>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			let registrars = Registrars::<T>::get();
+			let registrar_found =
+				registrars.iter().any(|registrar_option| match registrar_option {
+					Some(r) => r.account == who,
+					None => false,
+				});
+			ensure!(registrar_found, Error::<T>::RegistrarNotFound);
+
+			match &fee {
+				Some(fee) => {
+					let minimum =
+						<T::FeeAssets as fungibles::Inspect<_>>::minimum_balance(fee.asset.clone());
+					ensure!(
+						!fee.amount.is_zero() && fee.amount >= minimum,
+						Error::<T>::FeeBelowMinimum
+					);
+					RegistrarFees::<T>::insert(&who, fee);
+				},
+				None => RegistrarFees::<T>::remove(&who),
+			}
+
+			Self::deposit_event(Event::RegistrarFeeSet { registrar: who, fee });
+
+			Ok(Some(T::WeightInfo::set_fee(registrars.len() as u32)).into())
+		}
 	}
 }
 
@@ -1105,6 +1252,13 @@ impl<T: Config> Pallet<T> {
 		IdentityOf::<T>::get(who)
 			.map_or(false, |(registration, _username)| (registration.info.has_identity(fields)))
 	}
+	/// Check if the identity of the account holds a positive judgement from
+	/// any registrar.
+	pub fn has_positive_judgement(who: &T::AccountId) -> bool {
+		IdentityOf::<T>::get(who).map_or(false, |(registration, _username)| {
+			registration.judgements.iter().any(|(_, judgement)| judgement.is_positive())
+		})
+	}
 	/// Validate that a username conforms to allowed characters/format.
 	///
 	/// The function will validate the characters in `username` and that `length` (if `Some`)
@@ -1147,6 +1301,21 @@ impl<T: Config> Pallet<T> {
 		is_valid
 	}
 
+	/// Validate that a username projected by another pallet has the `name@domain` format, where
+	/// both `name` and `domain` conform to the characters allowed in usernames (e.g. `alice@cord`
+	/// or `hr@acme.cord`).
+	fn validate_projected_username(username: &[u8]) -> DispatchResult {
+		let mut split = username.splitn(2, |c| *c == b'@');
+		match (split.next(), split.next()) {
+			(Some(name), Some(domain)) => ensure!(
+				Self::is_valid_username(name) && Self::is_valid_username(domain),
+				Error::<T>::InvalidUsername
+			),
+			_ => Err(Error::<T>::InvalidUsername)?,
+		}
+		Ok(())
+	}
+
 	/// Validate that a suffix conforms to allowed characters/format.
 	fn validate_suffix(suffix: &Vec<u8>) -> DispatchResult {
 		// Suffix name cannot be empty.
@@ -1180,6 +1349,21 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Ensure `authority` is a username authority with an allocation left and decrement the
+	/// allocation by one. Returns the suffix of the authority.
+	fn use_allocation(authority: &T::AccountId) -> Result<Suffix<T>, DispatchError> {
+		UsernameAuthorities::<T>::try_mutate(
+			authority,
+			|maybe_authority| -> Result<Suffix<T>, DispatchError> {
+				let properties =
+					maybe_authority.as_mut().ok_or(Error::<T>::NotUsernameAuthority)?;
+				ensure!(properties.allocation > 0, Error::<T>::NoAllocation);
+				properties.allocation.saturating_dec();
+				Ok(properties.suffix.clone())
+			},
+		)
+	}
+
 	/// A username has met all conditions. Insert the relevant storage items.
 	pub fn insert_username(who: &T::AccountId, username: Username<T>) {
 		// Check if they already have a primary. If so, leave it. If not, set it.
@@ -1236,6 +1420,7 @@ impl<T: Config> Pallet<T> {
 		// `take` any storage items keyed by `target`
 		// identity
 		let (id, _maybe_username) = <IdentityOf<T>>::take(&who).ok_or(Error::<T>::NoIdentity)?;
+		Self::withdraw_judgement_requests(who);
 		let registrars = id.judgements.len() as u32;
 		let encoded_byte_size = id.info.encoded_size() as u32;
 
@@ -1249,6 +1434,195 @@ impl<T: Config> Pallet<T> {
 		Ok((registrars, encoded_byte_size, actual_subs))
 	}
 
+	/// The account holding the fees of pending judgement requests.
+	pub fn fee_account() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// The performance statistics of the registrar at `index`.
+	pub fn registrar_stats(index: RegistrarIndex) -> Option<RegistrarStatsOf<T>> {
+		let registrar = Registrars::<T>::get().get(index as usize).cloned().flatten()?;
+		Some(RegistrarStatistics::<T>::get(registrar.account))
+	}
+
+	/// Record a judgement request of `who` to `registrar`. The fee of the registrar, if any, is
+	/// moved to the fee account and the deadline of the request is scheduled.
+	fn open_judgement_request(who: &T::AccountId, registrar: &T::AccountId) -> DispatchResult {
+		let fee = RegistrarFees::<T>::get(registrar);
+		if let Some(fee) = &fee {
+			T::FeeAssets::transfer(
+				fee.asset.clone(),
+				who,
+				&Self::fee_account(),
+				fee.amount,
+				Preservation::Preserve,
+			)
+			.map_err(|_| Error::<T>::JudgementPaymentFailed)?;
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		let period = T::JudgementPeriod::get();
+		let deadline = (!period.is_zero()).then(|| now.saturating_add(period));
+		if let Some(deadline) = deadline {
+			JudgementDeadlines::<T>::try_mutate(deadline, |requests| {
+				requests
+					.try_push((who.clone(), registrar.clone()))
+					.map_err(|_| Error::<T>::TooManyJudgementDeadlines)
+			})?;
+		}
+
+		JudgementRequests::<T>::insert(
+			registrar,
+			who,
+			JudgementRequest { fee, requested_at: now, deadline },
+		);
+		RegistrarStatistics::<T>::mutate(registrar, |stats| stats.requested.saturating_inc());
+
+		Ok(())
+	}
+
+	/// Remove the judgement request of `who` to `registrar`, if any, and pay its fee to `payee`.
+	fn take_judgement_request(
+		who: &T::AccountId,
+		registrar: &T::AccountId,
+		payee: &T::AccountId,
+	) -> Option<JudgementRequestOf<T>> {
+		let request = JudgementRequests::<T>::take(registrar, who)?;
+
+		if let Some(deadline) = request.deadline {
+			JudgementDeadlines::<T>::mutate_exists(deadline, |maybe_requests| {
+				if let Some(requests) = maybe_requests {
+					requests.retain(|(w, r)| !(w == who && r == registrar));
+					if requests.is_empty() {
+						*maybe_requests = None;
+					}
+				}
+			});
+		}
+		if let Some(fee) = &request.fee {
+			// The fee was moved to the fee account when the request was opened, so this cannot
+			// fail unless the fee account was drained by other means.
+			if let Err(err) = T::FeeAssets::transfer(
+				fee.asset.clone(),
+				&Self::fee_account(),
+				payee,
+				fee.amount,
+				Preservation::Expendable,
+			) {
+				frame_support::defensive!(err, "The judgement fee is held by the fee account");
+			}
+		}
+
+		Some(request)
+	}
+
+	/// Close the judgement request of `who` to `registrar` once the registrar provided a
+	/// judgement, paying the fee to the registrar.
+	fn close_judgement_request(who: &T::AccountId, registrar: &T::AccountId) {
+		let now = frame_system::Pallet::<T>::block_number();
+		let request = Self::take_judgement_request(who, registrar, registrar);
+		RegistrarStatistics::<T>::mutate(registrar, |stats| {
+			stats.provided.saturating_inc();
+			if let Some(request) = request {
+				stats.fulfilled.saturating_inc();
+				stats.total_response_time = stats
+					.total_response_time
+					.saturating_add(now.saturating_sub(request.requested_at));
+			}
+		});
+	}
+
+	/// Cancel the judgement request of `who` to `registrar`, refunding the fee to `who`.
+	fn cancel_judgement_request(who: &T::AccountId, registrar: &T::AccountId) {
+		if Self::take_judgement_request(who, registrar, who).is_some() {
+			RegistrarStatistics::<T>::mutate(registrar, |stats| stats.cancelled.saturating_inc());
+		}
+	}
+
+	/// Cancel all the judgement requests of `who`, refunding their fees to `who`.
+	fn cancel_judgement_requests(who: &T::AccountId) {
+		for registrar in Registrars::<T>::get().into_iter().flatten() {
+			Self::cancel_judgement_request(who, &registrar.account);
+		}
+	}
+
+	/// Withdraw all the judgement requests of `who` whose identity was forcibly removed, refunding
+	/// their fees to `who`. Unlike cancellations, withdrawals are not counted in the registrar
+	/// statistics.
+	fn withdraw_judgement_requests(who: &T::AccountId) {
+		for registrar in Registrars::<T>::get().into_iter().flatten() {
+			Self::take_judgement_request(who, &registrar.account, who);
+		}
+	}
+
+	/// Withdraw all the judgement requests to a removed `registrar`, refunding their fees to the
+	/// requesters.
+	fn withdraw_registrar_requests(registrar: &T::AccountId) {
+		let requesters: Vec<T::AccountId> =
+			JudgementRequests::<T>::iter_key_prefix(registrar).collect();
+		for who in &requesters {
+			Self::take_judgement_request(who, registrar, who);
+			IdentityOf::<T>::mutate(who, |identity| {
+				if let Some((id, _)) = identity {
+					id.judgements.retain(|(r, j)| !(r == registrar && j.has_requested()));
+				}
+			});
+		}
+	}
+
+	/// Move the judgement requests to the registrar account `old` to the account `new`, along with
+	/// their deadlines and the requested judgements of the requesters.
+	fn move_judgement_requests(old: &T::AccountId, new: &T::AccountId) {
+		if old == new {
+			return;
+		}
+		let requests: Vec<_> = JudgementRequests::<T>::drain_prefix(old).collect();
+		for (who, request) in &requests {
+			if let Some(deadline) = request.deadline {
+				JudgementDeadlines::<T>::mutate(deadline, |deadlines| {
+					for (w, r) in deadlines.iter_mut() {
+						if w == who && r == old {
+							*r = new.clone();
+						}
+					}
+				});
+			}
+			IdentityOf::<T>::mutate(who, |identity| {
+				if let Some((id, _)) = identity {
+					if id.judgements.binary_search_by_key(new, |x| x.0.clone()).is_err() {
+						if let Some(entry) = id.judgements.iter_mut().find(|(r, _)| r == old) {
+							entry.0 = new.clone();
+							id.judgements.sort_by(|a, b| a.0.cmp(&b.0));
+						}
+					}
+				}
+			});
+			JudgementRequests::<T>::insert(new, who, request);
+		}
+	}
+
+	/// Cancel the judgement requests reaching their deadline at block `n`, refunding their fees.
+	pub(crate) fn expire_judgement_requests(n: BlockNumberFor<T>) -> Weight {
+		let mut total_weight = T::DbWeight::get().reads_writes(1, 1);
+
+		for (who, registrar) in JudgementDeadlines::<T>::take(n) {
+			if Self::take_judgement_request(&who, &registrar, &who).is_some() {
+				IdentityOf::<T>::mutate(&who, |identity| {
+					if let Some((id, _)) = identity {
+						id.judgements.retain(|(r, j)| !(r == &registrar && j.has_requested()));
+					}
+				});
+				RegistrarStatistics::<T>::mutate(&registrar, |stats| {
+					stats.expired.saturating_inc()
+				});
+				Self::deposit_event(Event::JudgementRequestExpired { who, registrar });
+			}
+			total_weight = total_weight.saturating_add(T::WeightInfo::expire_judgement_request());
+		}
+
+		total_weight
+	}
+
 	/// Set an identity with zero deposit. Used for benchmarking and XCM emulator tests that involve
 	/// `rejig_deposit`.
 	#[cfg(any(feature = "runtime-benchmarks", feature = "std"))]
@@ -1287,3 +1661,77 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 }
+
+impl<T: Config> cord_primitives::IdentityVerifier<T::AccountId> for Pallet<T> {
+	fn has_verified_identity(who: &T::AccountId) -> bool {
+		Self::has_positive_judgement(who)
+	}
+
+	fn has_judgement_from(who: &T::AccountId, registrar: &T::AccountId) -> bool {
+		IdentityOf::<T>::get(who).map_or(false, |(registration, _username)| {
+			registration
+				.judgements
+				.iter()
+				.any(|(judge, judgement)| judge == registrar && judgement.is_positive())
+		})
+	}
+}
+
+impl<T: Config> cord_primitives::UsernameRegistry<T::AccountId> for Pallet<T> {
+	fn set_primary_username(who: &T::AccountId, username: Vec<u8>) -> DispatchResult {
+		Self::validate_projected_username(&username)?;
+		let username =
+			Username::<T>::try_from(username).map_err(|_| Error::<T>::InvalidUsername)?;
+		ensure!(!PendingUsernames::<T>::contains_key(&username), Error::<T>::UsernameTaken);
+		match AccountOfUsername::<T>::get(&username) {
+			Some(holder) => ensure!(holder == *who, Error::<T>::UsernameTaken),
+			None => Self::insert_username(who, username.clone()),
+		}
+
+		let changed = IdentityOf::<T>::mutate(who, |identity| match identity {
+			Some((_, primary)) if primary.as_ref() != Some(&username) => {
+				*primary = Some(username.clone());
+				true
+			},
+			_ => false,
+		});
+		if changed {
+			Self::deposit_event(Event::PrimaryUsernameSet { who: who.clone(), username });
+		}
+		Ok(())
+	}
+
+	fn remove_username(who: &T::AccountId, username: &[u8]) {
+		let Ok(username) = Username::<T>::try_from(username.to_vec()) else { return };
+		if AccountOfUsername::<T>::get(&username).as_ref() != Some(who) {
+			return;
+		}
+		AccountOfUsername::<T>::remove(&username);
+		IdentityOf::<T>::mutate(who, |identity| {
+			if let Some((_, primary)) = identity {
+				if primary.as_ref() == Some(&username) {
+					*primary = None;
+				}
+			}
+		});
+		Self::deposit_event(Event::UsernameRemoved { who: who.clone(), username });
+	}
+
+	fn use_authority_allocation(authority: &T::AccountId) -> Result<Vec<u8>, DispatchError> {
+		Self::use_allocation(authority).map(|suffix| suffix.into_inner())
+	}
+
+	fn account_of_username(username: &[u8]) -> Option<T::AccountId> {
+		let username = Username::<T>::try_from(username.to_vec()).ok()?;
+		AccountOfUsername::<T>::get(&username)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_authority(authority: &T::AccountId, suffix: Vec<u8>, allocation: u32) {
+		let suffix = Suffix::<T>::try_from(suffix).expect("benchmark suffix fits");
+		UsernameAuthorities::<T>::insert(
+			authority,
+			AuthorityPropertiesOf::<T> { suffix, allocation },
+		);
+	}
+}
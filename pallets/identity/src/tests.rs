@@ -27,9 +27,14 @@ use crate::{
 };
 
 use codec::{Decode, Encode};
+use cord_primitives::{IdentityVerifier, UsernameRegistry};
 use frame_support::{
 	assert_noop, assert_ok, derive_impl, parameter_types,
-	traits::{ConstU32, ConstU64, Get, OnFinalize, OnInitialize},
+	traits::{
+		fungible::{ItemOf, Mutate},
+		ConstU32, ConstU64, Get, OnFinalize, OnInitialize,
+	},
+	PalletId,
 };
 use frame_system::EnsureRoot;
 use sp_core::H256;
@@ -50,6 +55,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		Identity: pallet_identity,
 	}
 );
@@ -59,12 +65,19 @@ impl frame_system::Config for Test {
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
 	type Block = Block;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
 }
 
 parameter_types! {
 	pub const MaxAdditionalFields: u32 = 2;
 	pub const MaxRegistrars: u32 = 20;
+	pub const NativeAsset: u32 = 0;
+	pub const IdentityPalletId: PalletId = PalletId(*b"py/ident");
 }
 
 impl pallet_identity::Config for Test {
@@ -79,6 +92,12 @@ impl pallet_identity::Config for Test {
 	type PendingUsernameExpiration = ConstU64<100>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type FeeAssets = ItemOf<Balances, NativeAsset, AccountId>;
+	type PalletId = IdentityPalletId;
+	type JudgementPeriod = ConstU64<10>;
+	type MaxJudgementDeadlinesPerBlock = ConstU32<2>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkFeeAsset = NativeAsset;
 	type WeightInfo = ();
 }
 
@@ -435,6 +454,38 @@ fn uninvited_judgement_should_work() {
 	});
 }
 
+#[test]
+fn positive_judgement_should_verify_identity() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert!(!Identity::has_positive_judgement(&ten));
+
+		let identity_hash = BlakeTwo256::hash_of(&infoof_ten());
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			ten.clone(),
+			Judgement::LowQuality,
+			identity_hash
+		));
+		assert!(!Identity::has_positive_judgement(&ten));
+
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			ten.clone(),
+			Judgement::KnownGood,
+			identity_hash
+		));
+		assert!(Identity::has_positive_judgement(&ten));
+		assert!(<Identity as IdentityVerifier<_>>::has_judgement_from(&ten, &three));
+		assert!(!<Identity as IdentityVerifier<_>>::has_judgement_from(&ten, &ten));
+	});
+}
+
 #[test]
 fn clearing_identity_and_judgement_should_work() {
 	new_test_ext().execute_with(|| {
@@ -1366,3 +1417,250 @@ fn removing_dangling_usernames_should_work() {
 		assert!(AccountOfUsername::<Test>::get::<&Username<Test>>(&username_two_to_sign).is_none());
 	});
 }
+
+#[test]
+fn username_registry_should_work() {
+	new_test_ext().execute_with(|| {
+		let [authority, _] = unfunded_accounts();
+		let [pi, e, _, _, _, _, _, _] = accounts();
+		assert_ok!(Identity::add_username_authority(
+			RuntimeOrigin::root(),
+			authority.clone(),
+			b"test".to_vec(),
+			1
+		));
+
+		// Allocations are consumed like `set_username_for` does.
+		assert_eq!(
+			<Identity as UsernameRegistry<_>>::use_authority_allocation(&authority),
+			Ok(b"test".to_vec())
+		);
+		assert_noop!(
+			<Identity as UsernameRegistry<_>>::use_authority_allocation(&authority),
+			Error::<Test>::NoAllocation
+		);
+
+		let alice: Username<Test> = b"alice@cord".to_vec().try_into().unwrap();
+		let bob: Username<Test> = b"bob@cord".to_vec().try_into().unwrap();
+		assert_ok!(<Identity as UsernameRegistry<_>>::set_primary_username(&pi, alice.to_vec()));
+		assert_eq!(IdentityOf::<Test>::get(&pi).unwrap().1, Some(alice.clone()));
+		assert_eq!(
+			<Identity as UsernameRegistry<_>>::account_of_username(&alice),
+			Some(pi.clone())
+		);
+		assert_noop!(
+			<Identity as UsernameRegistry<_>>::set_primary_username(&e, alice.to_vec()),
+			Error::<Test>::UsernameTaken
+		);
+
+		// Only usernames of the `name@domain` form are accepted.
+		for invalid in
+			[&b"alice"[..], b"Alice@cord", b"alice@", b"alice@cord@cord", b"al..ice@cord"]
+		{
+			assert_noop!(
+				<Identity as UsernameRegistry<_>>::set_primary_username(&e, invalid.to_vec()),
+				Error::<Test>::InvalidUsername
+			);
+		}
+
+		// A new username replaces the primary.
+		assert_ok!(<Identity as UsernameRegistry<_>>::set_primary_username(&pi, bob.to_vec()));
+		assert_eq!(IdentityOf::<Test>::get(&pi).unwrap().1, Some(bob.clone()));
+
+		// Only the holder can have the username removed.
+		<Identity as UsernameRegistry<_>>::remove_username(&e, &bob);
+		assert_eq!(AccountOfUsername::<Test>::get(&bob), Some(pi.clone()));
+		<Identity as UsernameRegistry<_>>::remove_username(&pi, &bob);
+		assert!(AccountOfUsername::<Test>::get(&bob).is_none());
+		assert_eq!(IdentityOf::<Test>::get(&pi).unwrap().1, None);
+		assert_eq!(AccountOfUsername::<Test>::get(&alice), Some(pi));
+	});
+}
+
+fn fee(amount: u64) -> RegistrarFeeOf<Test> {
+	RegistrarFee { asset: NativeAsset::get(), amount }
+}
+
+#[test]
+fn registrar_fee_should_be_paid_with_judgement() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, _, _, _] = accounts();
+		Balances::set_balance(&ten, 100);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+
+		assert_noop!(
+			Identity::set_fee(RuntimeOrigin::signed(ten.clone()), Some(fee(10))),
+			Error::<Test>::RegistrarNotFound
+		);
+		assert_noop!(
+			Identity::set_fee(RuntimeOrigin::signed(three.clone()), Some(fee(0))),
+			Error::<Test>::FeeBelowMinimum
+		);
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), Some(fee(10))));
+		assert_eq!(RegistrarFees::<Test>::get(&three), Some(fee(10)));
+
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), three.clone()));
+		assert_eq!(Balances::free_balance(&ten), 90);
+		assert_eq!(Balances::free_balance(Identity::fee_account()), 10);
+
+		System::set_block_number(4);
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(three.clone()),
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&infoof_ten())
+		));
+		assert_eq!(Balances::free_balance(&three), 10);
+		assert_eq!(Balances::free_balance(Identity::fee_account()), 0);
+		assert!(JudgementRequests::<Test>::get(&three, &ten).is_none());
+		assert!(JudgementDeadlines::<Test>::get(11).is_empty());
+		assert_eq!(
+			Identity::registrar_stats(0),
+			Some(RegistrarStats {
+				requested: 1,
+				provided: 1,
+				fulfilled: 1,
+				cancelled: 0,
+				expired: 0,
+				total_response_time: 3,
+			})
+		);
+
+		// Requesters must be able to pay the fee.
+		let [unfunded, _] = unfunded_accounts();
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(unfunded.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_noop!(
+			Identity::request_judgement(RuntimeOrigin::signed(unfunded), three),
+			Error::<Test>::JudgementPaymentFailed
+		);
+	});
+}
+
+#[test]
+fn cancelled_and_expired_requests_should_refund_fee() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		Balances::set_balance(&ten, 100);
+		Balances::set_balance(&twenty, 100);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), Some(fee(10))));
+		for who in [&ten, &twenty] {
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(who.clone()),
+				Box::new(infoof_ten())
+			));
+			assert_ok!(Identity::request_judgement(
+				RuntimeOrigin::signed(who.clone()),
+				three.clone()
+			));
+		}
+		assert_eq!(JudgementDeadlines::<Test>::get(11).len(), 2);
+
+		assert_ok!(Identity::cancel_request(RuntimeOrigin::signed(ten.clone()), three.clone()));
+		assert_eq!(Balances::free_balance(&ten), 100);
+		assert_eq!(
+			JudgementDeadlines::<Test>::get(11).into_inner(),
+			vec![(twenty.clone(), three.clone())]
+		);
+
+		run_to_block(11);
+		assert_eq!(Balances::free_balance(&twenty), 100);
+		assert!(JudgementRequests::<Test>::get(&three, &twenty).is_none());
+		assert!(IdentityOf::<Test>::get(&twenty).unwrap().0.judgements.is_empty());
+		System::assert_has_event(
+			Event::JudgementRequestExpired { who: twenty, registrar: three.clone() }.into(),
+		);
+		assert_eq!(
+			Identity::registrar_stats(0),
+			Some(RegistrarStats {
+				requested: 2,
+				provided: 0,
+				fulfilled: 0,
+				cancelled: 1,
+				expired: 1,
+				total_response_time: 0,
+			})
+		);
+		assert_eq!(Identity::registrar_stats(1), None);
+	});
+}
+
+#[test]
+fn registrar_account_change_should_move_requests() {
+	new_test_ext().execute_with(|| {
+		let [one, _, three, _, ten, _, _, _] = accounts();
+		Balances::set_balance(&ten, 100);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), Some(fee(10))));
+		assert_ok!(Identity::set_identity(
+			RuntimeOrigin::signed(ten.clone()),
+			Box::new(infoof_ten())
+		));
+		assert_ok!(Identity::request_judgement(RuntimeOrigin::signed(ten.clone()), three.clone()));
+
+		assert_ok!(Identity::set_account_id(RuntimeOrigin::signed(three.clone()), one.clone()));
+		assert!(JudgementRequests::<Test>::get(&three, &ten).is_none());
+		assert!(JudgementRequests::<Test>::get(&one, &ten).is_some());
+		assert_eq!(
+			JudgementDeadlines::<Test>::get(11).into_inner(),
+			vec![(ten.clone(), one.clone())]
+		);
+		assert_eq!(
+			IdentityOf::<Test>::get(&ten).unwrap().0.judgements.into_inner(),
+			vec![(one.clone(), Judgement::Requested)]
+		);
+
+		// The registrar provides the judgement from its new account and is paid the fee.
+		assert_ok!(Identity::provide_judgement(
+			RuntimeOrigin::signed(one.clone()),
+			ten.clone(),
+			Judgement::Reasonable,
+			BlakeTwo256::hash_of(&infoof_ten())
+		));
+		assert!(JudgementRequests::<Test>::get(&one, &ten).is_none());
+		assert!(JudgementDeadlines::<Test>::get(11).is_empty());
+		assert_eq!(Balances::free_balance(Identity::fee_account()), 0);
+		assert_eq!(Identity::registrar_stats(0).unwrap().fulfilled, 1);
+	});
+}
+
+#[test]
+fn forced_removals_should_withdraw_requests() {
+	new_test_ext().execute_with(|| {
+		let [_, _, three, _, ten, twenty, _, _] = accounts();
+		Balances::set_balance(&ten, 100);
+		Balances::set_balance(&twenty, 100);
+		assert_ok!(Identity::add_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_ok!(Identity::set_fee(RuntimeOrigin::signed(three.clone()), Some(fee(10))));
+		for who in [&ten, &twenty] {
+			assert_ok!(Identity::set_identity(
+				RuntimeOrigin::signed(who.clone()),
+				Box::new(infoof_ten())
+			));
+			assert_ok!(Identity::request_judgement(
+				RuntimeOrigin::signed(who.clone()),
+				three.clone()
+			));
+		}
+
+		// Killed identities are refunded without counting as cancelled.
+		assert_ok!(Identity::kill_identity(RuntimeOrigin::root(), ten.clone()));
+		assert_eq!(Balances::free_balance(&ten), 100);
+		assert!(JudgementRequests::<Test>::get(&three, &ten).is_none());
+		assert_eq!(Identity::registrar_stats(0).unwrap().cancelled, 0);
+
+		// Removing the registrar refunds its pending requests.
+		assert_ok!(Identity::remove_registrar(RuntimeOrigin::root(), three.clone()));
+		assert_eq!(Balances::free_balance(&twenty), 100);
+		assert!(JudgementRequests::<Test>::get(&three, &twenty).is_none());
+		assert!(JudgementDeadlines::<Test>::get(11).is_empty());
+		assert!(IdentityOf::<Test>::get(&twenty).unwrap().0.judgements.is_empty());
+	});
+}
@@ -227,6 +227,12 @@ impl Judgement {
 	pub(crate) fn is_sticky(&self) -> bool {
 		matches!(self, Judgement::Requested | Judgement::Erroneous)
 	}
+
+	/// Returns `true` if this judgement attests to the accuracy of the
+	/// identity.
+	pub fn is_positive(&self) -> bool {
+		matches!(self, Judgement::Reasonable | Judgement::KnownGood)
+	}
 }
 
 /// Information concerning the identity of the controller of an account.
@@ -298,6 +304,43 @@ pub struct RegistrarInfo<
 	pub fields: IdField,
 }
 
+/// The fee a registrar charges for providing a judgement.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RegistrarFee<AssetKind, Balance> {
+	/// The asset the fee is paid in, either the native token or another asset.
+	pub asset: AssetKind,
+	/// The amount of the fee.
+	pub amount: Balance,
+}
+
+/// A judgement requested from a registrar which has not been provided yet.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct JudgementRequest<Fee, BlockNumber> {
+	/// The fee paid for the judgement and held until the request is closed.
+	pub fee: Option<Fee>,
+	/// The block at which the judgement was requested.
+	pub requested_at: BlockNumber,
+	/// The block at which the request is cancelled and the fee refunded, if requests expire.
+	pub deadline: Option<BlockNumber>,
+}
+
+/// Performance statistics of a registrar.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct RegistrarStats<BlockNumber> {
+	/// The number of judgements requested from the registrar.
+	pub requested: u32,
+	/// The number of judgements provided by the registrar, requested or not.
+	pub provided: u32,
+	/// The number of requested judgements provided before their deadline.
+	pub fulfilled: u32,
+	/// The number of requests cancelled by the requester.
+	pub cancelled: u32,
+	/// The number of requests which reached their deadline without a judgement.
+	pub expired: u32,
+	/// The total number of blocks taken to fulfil requests.
+	pub total_response_time: BlockNumber,
+}
+
 /// Authority properties for a given pallet configuration.
 pub type AuthorityPropertiesOf<T> = AuthorityProperties<Suffix<T>>;
 
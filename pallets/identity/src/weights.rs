@@ -72,6 +72,8 @@ pub trait WeightInfo {
 	fn remove_expired_approval() -> Weight;
 	fn set_primary_username() -> Weight;
 	fn remove_dangling_username() -> Weight;
+	fn set_fee(r: u32, ) -> Weight;
+	fn expire_judgement_request() -> Weight;
 }
 
 /// Weights for `pallet_identity` using the CORD node and recommended hardware.
@@ -435,6 +437,41 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(821), added: 1316, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarFees` (r:0 w:1)
+	/// Proof: `Identity::RegistrarFees` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_fee(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `73 + r * (41 ±0)`
+		//  Estimated: `2306`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_803_914, 2306)
+			// Standard Error: 812
+			.saturating_add(Weight::from_parts(44_187, 0).saturating_mul(r.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::JudgementDeadlines` (r:1 w:1)
+	/// Proof: `Identity::JudgementDeadlines` (`max_values`: None, `max_size`: Some(6414), added: 8889, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStatistics` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStatistics` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn expire_judgement_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `9879`
+		// Minimum execution time: 61_208_000 picoseconds.
+		Weight::from_parts(62_877_000, 9879)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -797,4 +834,39 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Identity::Registrars` (r:1 w:0)
+	/// Proof: `Identity::Registrars` (`max_values`: Some(1), `max_size`: Some(821), added: 1316, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarFees` (r:0 w:1)
+	/// Proof: `Identity::RegistrarFees` (`max_values`: None, `max_size`: Some(61), added: 2536, mode: `MaxEncodedLen`)
+	/// The range of component `r` is `[1, 19]`.
+	fn set_fee(r: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `73 + r * (41 ±0)`
+		//  Estimated: `2306`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_803_914, 2306)
+			// Standard Error: 812
+			.saturating_add(Weight::from_parts(44_187, 0).saturating_mul(r.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Identity::JudgementDeadlines` (r:1 w:1)
+	/// Proof: `Identity::JudgementDeadlines` (`max_values`: None, `max_size`: Some(6414), added: 8889, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::JudgementRequests` (r:1 w:1)
+	/// Proof: `Identity::JudgementRequests` (`max_values`: None, `max_size`: Some(111), added: 2586, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:1)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::RegistrarStatistics` (r:1 w:1)
+	/// Proof: `Identity::RegistrarStatistics` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	fn expire_judgement_request() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1021`
+		//  Estimated: `9879`
+		// Minimum execution time: 61_208_000 picoseconds.
+		Weight::from_parts(62_877_000, 9879)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
 }
@@ -19,7 +19,10 @@
 use super::*;
 use crate as pallet_membership;
 
-use frame_support::{derive_impl, ord_parameter_types, parameter_types, traits::ConstU32};
+use frame_support::{
+	derive_impl, ord_parameter_types, parameter_types,
+	traits::{ConstU32, Nothing},
+};
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot, EnsureSignedBy};
 use sp_runtime::{bounded_vec, BuildStorage};
 
@@ -29,6 +32,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		Membership: pallet_membership,
 		NetworkMembership: pallet_network_membership,
 	}
@@ -42,6 +46,7 @@ parameter_types! {
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
 impl frame_system::Config for Test {
 	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
 }
 ord_parameter_types! {
 	pub const One: u64 = 1;
@@ -78,6 +83,12 @@ impl InitializeMembers<u64> for TestChangeMembers {
 		MEMBERS.with(|m| *m.borrow_mut() = members.to_vec());
 	}
 }
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
 parameter_types! {
 	pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 	pub const MaxMembersPerBlock: u32 = 5;
@@ -88,7 +99,12 @@ impl pallet_network_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxMembersPerBatch = ConstU32<10>;
+	type ExpirySpread = ConstU32<1>;
 	type WeightInfo = ();
+	type NetworkParameters = ();
+	type Currency = Balances;
+	type WrapperCalls = Nothing;
 }
 
 pub struct TestIsNetworkMember;
@@ -101,6 +101,7 @@ use identifier::{
 	EventEntryOf,
 };
 use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_std::vec::Vec;
 
 /// Type of a NameSpace Hash
 pub type NameSpaceHashOf<T> = <T as frame_system::Config>::Hash;
@@ -270,6 +271,23 @@ pub mod pallet {
 		NameSpaceRegistryListLimitExceeded,
 	}
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Namespaces anchored at genesis, with their digest and creator.
+		pub namespaces: Vec<(NameSpaceHashOf<T>, NameSpaceCreatorOf<T>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (digest, creator) in &self.namespaces {
+				Pallet::<T>::anchor_namespace(*digest, creator.clone())
+					.expect("Genesis namespace is already anchored");
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Adds a delegate with the ability to assert new entries to a namespace.
@@ -518,67 +536,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let creator = ensure_signed(origin)?;
 
-			// Id Digest = concat (H(<scale_encoded_registry_input>,
-			// <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier = Ss58Identifier::create_identifier(
-				&id_digest.encode()[..],
-				IdentifierType::NameSpace,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(
-				!<NameSpaces<T>>::contains_key(&identifier),
-				Error::<T>::NameSpaceAlreadyAnchored
-			);
-
-			// Construct the authorization_id from the provided parameters.
-			// Id Digest = concat (H(<scale_encoded_space_identifier>,
-			// <scale_encoded_creator_identifier> ))
-			let auth_id_digest = T::Hashing::hash(
-				&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let authorization_id = Ss58Identifier::create_identifier(
-				&auth_id_digest.encode(),
-				IdentifierType::NameSpaceAuthorization,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			let mut delegates: BoundedVec<NameSpaceCreatorOf<T>, T::MaxNameSpaceDelegates> =
-				BoundedVec::default();
-			delegates
-				.try_push(creator.clone())
-				.map_err(|_| Error::<T>::NameSpaceDelegatesLimitExceeded)?;
-
-			Delegates::<T>::insert(&identifier, delegates);
-
-			Authorizations::<T>::insert(
-				&authorization_id,
-				NameSpaceAuthorizationOf::<T> {
-					namespace_id: identifier.clone(),
-					delegate: creator.clone(),
-					permissions: Permissions::all(),
-					delegator: creator.clone(),
-				},
-			);
-
-			<NameSpaces<T>>::insert(
-				&identifier,
-				NameSpaceDetailsOf::<T> {
-					digest,
-					creator: creator.clone(),
-					archive: false,
-					registry_ids: Some(BoundedVec::default()),
-				},
-			);
-
-			Self::update_activity(&identifier, IdentifierTypeOf::NameSpace, CallTypeOf::Genesis)
-				.map_err(Error::<T>::from)?;
+			let (identifier, authorization_id) = Self::anchor_namespace(digest, creator.clone())?;
 
 			Self::deposit_event(Event::Create {
 				namespace: identifier,
@@ -713,6 +671,80 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Computes the identifier of the namespace anchored by `creator` with
+	/// the given digest.
+	pub fn namespace_id(
+		digest: &NameSpaceHashOf<T>,
+		creator: &NameSpaceCreatorOf<T>,
+	) -> Result<NameSpaceIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_registry_input>,
+		// <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::NameSpace)
+			.map_err(|_| Error::<T>::InvalidIdentifierLength)
+	}
+
+	/// Anchors a new namespace with the creator as its first delegate.
+	///
+	/// Used by `create` and by the genesis builds of this pallet and of the
+	/// registries pallet.
+	pub fn anchor_namespace(
+		digest: NameSpaceHashOf<T>,
+		creator: NameSpaceCreatorOf<T>,
+	) -> Result<(NameSpaceIdOf, AuthorizationIdOf), Error<T>> {
+		let identifier = Self::namespace_id(&digest, &creator)?;
+
+		ensure!(!<NameSpaces<T>>::contains_key(&identifier), Error::<T>::NameSpaceAlreadyAnchored);
+
+		// Construct the authorization_id from the provided parameters.
+		// Id Digest = concat (H(<scale_encoded_space_identifier>,
+		// <scale_encoded_creator_identifier> ))
+		let auth_id_digest = T::Hashing::hash(
+			&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let authorization_id = Ss58Identifier::create_identifier(
+			&auth_id_digest.encode(),
+			IdentifierType::NameSpaceAuthorization,
+		)
+		.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		let mut delegates: BoundedVec<NameSpaceCreatorOf<T>, T::MaxNameSpaceDelegates> =
+			BoundedVec::default();
+		delegates
+			.try_push(creator.clone())
+			.map_err(|_| Error::<T>::NameSpaceDelegatesLimitExceeded)?;
+
+		Delegates::<T>::insert(&identifier, delegates);
+
+		Authorizations::<T>::insert(
+			&authorization_id,
+			NameSpaceAuthorizationOf::<T> {
+				namespace_id: identifier.clone(),
+				delegate: creator.clone(),
+				permissions: Permissions::all(),
+				delegator: creator.clone(),
+			},
+		);
+
+		<NameSpaces<T>>::insert(
+			&identifier,
+			NameSpaceDetailsOf::<T> {
+				digest,
+				creator: creator.clone(),
+				archive: false,
+				registry_ids: Some(BoundedVec::default()),
+			},
+		);
+
+		Self::update_activity(&identifier, IdentifierTypeOf::NameSpace, CallTypeOf::Genesis)?;
+
+		Ok((identifier, authorization_id))
+	}
+
 	/// Adds a delegate to a namespace with specified permissions.
 	///
 	/// This function will add a new delegate to a namespace, given the namespace's ID,
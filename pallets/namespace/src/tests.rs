@@ -2,7 +2,7 @@ use super::*;
 use crate::mock::*;
 use codec::Encode;
 use frame_support::assert_ok;
-use sp_runtime::traits::Hash;
+use sp_runtime::{traits::Hash, BuildStorage};
 use sp_std::prelude::*;
 
 /// Generate a namespace id from a digest.
@@ -60,3 +60,49 @@ fn add_delegate_should_succeed() {
 		));
 	});
 }
+
+#[test]
+fn genesis_build_should_anchor_namespaces() {
+	let creator = ACCOUNT_00;
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(b"genesis-namespace");
+	let id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&id_digest);
+	let auth_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_id.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let authorization_id: AuthorizationIdOf = generate_authorization_id::<Test>(&auth_id_digest);
+
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	GenesisConfig::<Test> { namespaces: vec![(namespace_digest, creator.clone())] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let namespace = NameSpaces::<Test>::get(&namespace_id).unwrap();
+		assert_eq!(namespace.creator, creator);
+		assert!(!namespace.archive);
+
+		System::set_block_number(1);
+		assert_ok!(NameSpace::add_delegate(
+			frame_system::RawOrigin::Signed(creator).into(),
+			namespace_id,
+			ACCOUNT_01,
+			authorization_id,
+		));
+	});
+}
+
+#[test]
+#[should_panic = "Genesis namespace is already anchored"]
+fn genesis_build_should_reject_duplicate_namespaces() {
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(b"genesis-namespace");
+
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	GenesisConfig::<Test> {
+		namespaces: vec![(namespace_digest, ACCOUNT_00), (namespace_digest, ACCOUNT_00)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+}
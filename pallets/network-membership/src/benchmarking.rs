@@ -21,7 +21,9 @@
 use super::*;
 use crate::Pallet;
 use frame_benchmarking::{account, benchmarks};
+use frame_support::BoundedVec;
 use frame_system::RawOrigin;
+use sp_std::vec;
 
 const SEED: u32 = 0;
 
@@ -29,6 +31,11 @@ fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// A pallet or function name of the max length.
+fn hybrid_call_name(byte: u8) -> CallNameOf {
+	CallNameOf::try_from(vec![byte; MAX_CALL_NAME_LENGTH as usize]).expect("Should fit the bound")
+}
+
 benchmarks! {
 	where_clause { where <T::RuntimeOrigin as frame_support::traits::OriginTrait>::PalletsOrigin: Clone }
 	nominate {
@@ -60,6 +67,83 @@ benchmarks! {
 		assert_last_event::<T>(Event::MembershipRevoked { member: authority }.into());
 	}
 
+	set_hybrid_mode {
+	}: _(RawOrigin::Root, true)
+	verify {
+		assert_last_event::<T>(Event::HybridModeSet { enabled: true }.into());
+	}
+
+	set_hybrid_call_policy {
+		let pallet = hybrid_call_name(b'p');
+		let function = hybrid_call_name(b'f');
+		let policy = CallPolicyOf::<T> {
+			fee: 1u32.into(),
+			rate_limit: Some(RateLimit { max_calls: 1, period: 1u32.into() }),
+		};
+	}: _(RawOrigin::Root, pallet.clone(), function.clone(), policy.clone())
+	verify {
+		assert_last_event::<T>(Event::HybridCallPolicySet { pallet, function, policy }.into());
+	}
+
+	remove_hybrid_call_policy {
+		let pallet = hybrid_call_name(b'p');
+		let function = hybrid_call_name(b'f');
+		let policy = CallPolicyOf::<T> {
+			fee: 1u32.into(),
+			rate_limit: Some(RateLimit { max_calls: 1, period: 1u32.into() }),
+		};
+		HybridCalls::<T>::insert(&pallet, &function, policy);
+	}: _(RawOrigin::Root, pallet.clone(), function.clone())
+	verify {
+		assert_last_event::<T>(Event::HybridCallPolicyRemoved { pallet, function }.into());
+	}
+
+	nominate_batch {
+		let m in 1 .. T::MaxMembersPerBatch::get();
+
+		let members = (0..m)
+			.map(|i| account("member", i, SEED))
+			.collect::<Vec<T::AccountId>>();
+		let last = members.last().cloned().expect("Batch is not empty");
+		let members = BoundedVec::try_from(members).expect("Should fit the bound");
+	}: _(RawOrigin::Root, members, true)
+	verify {
+		assert_last_event::<T>(Event::MembershipAcquired { member: last }.into());
+	}
+
+	set_member_tier {
+		let member: T::AccountId = account("member", 0, SEED);
+
+		Pallet::<T>::nominate(RawOrigin::Root.into(), member.clone(), true).expect("Should add member");
+	}: _(RawOrigin::Root, member.clone(), Some(MembershipTier::Issuer))
+	verify {
+		assert_last_event::<T>(Event::MemberTierSet { member, tier: Some(MembershipTier::Issuer) }.into());
+	}
+
+	add_tier_call {
+		let pallet = hybrid_call_name(b'p');
+		let function = hybrid_call_name(b'f');
+	}: _(RawOrigin::Root, MembershipTier::Issuer, pallet.clone(), function.clone())
+	verify {
+		assert_last_event::<T>(Event::TierCallAdded { tier: MembershipTier::Issuer, pallet, function }.into());
+	}
+
+	remove_tier_call {
+		let pallet = hybrid_call_name(b'p');
+		let function = hybrid_call_name(b'f');
+		TierCalls::<T>::insert(MembershipTier::Issuer, (&pallet, &function), ());
+	}: _(RawOrigin::Root, MembershipTier::Issuer, pallet.clone(), function.clone())
+	verify {
+		assert_last_event::<T>(Event::TierCallRemoved { tier: MembershipTier::Issuer, pallet, function }.into());
+	}
+
+	set_tier_quota {
+		let quota = Some(RateLimit { max_calls: 1, period: 1u32.into() });
+	}: _(RawOrigin::Root, MembershipTier::Issuer, quota.clone())
+	verify {
+		assert_last_event::<T>(Event::TierQuotaSet { tier: MembershipTier::Issuer, quota }.into());
+	}
+
 	impl_benchmark_test_suite! (
 		Pallet,
 		crate::mock::new_test_ext(),
@@ -19,6 +19,7 @@
 #![warn(unused_extern_crates)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod migrations;
 pub mod weights;
 use codec::{Decode, Encode};
 use frame_support::dispatch::DispatchInfo;
@@ -33,19 +34,24 @@ pub mod benchmarking;
 #[cfg(test)]
 pub mod tests;
 
-use frame_support::{dispatch::GetDispatchInfo, traits::Get};
+use frame_support::{
+	dispatch::GetDispatchInfo,
+	traits::{Contains, Currency, ExistenceRequirement, Get, GetCallMetadata, WithdrawReasons},
+};
 use sp_runtime::{
-	traits::{DispatchInfoOf, Dispatchable, SignedExtension, Zero},
+	traits::{DispatchInfoOf, Dispatchable, One, SignedExtension, Zero},
 	transaction_validity::{
 		InvalidTransaction, TransactionLongevity, TransactionValidity, TransactionValidityError,
 		ValidTransaction,
 	},
+	Saturating,
 };
 use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, prelude::*};
 
 pub use weights::WeightInfo;
 pub mod types;
 pub use crate::types::{MemberData, *};
+pub use cord_primitives::NetworkParameters;
 use frame_support::pallet_prelude::Weight;
 use frame_system::pallet_prelude::BlockNumberFor;
 
@@ -56,9 +62,12 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 
 	/// The current storage version.
-	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 
 	pub(crate) type CordAccountOf<T> = <T as frame_system::Config>::AccountId;
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type CallPolicyOf<T> = CallPolicy<BalanceOf<T>, BlockNumberFor<T>>;
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
@@ -70,6 +79,22 @@ pub mod pallet {
 		type MembershipPeriod: Get<BlockNumberFor<Self>>;
 		#[pallet::constant]
 		type MaxMembersPerBlock: Get<u32>;
+		/// Max number of members nominated in a batch.
+		#[pallet::constant]
+		type MaxMembersPerBatch: Get<u32>;
+		/// Number of blocks over which the expiries are spread once the
+		/// expiry block of a membership is full.
+		#[pallet::constant]
+		type ExpirySpread: Get<u32>;
+		/// Network parameters which can be changed by governance. An on-chain
+		/// membership period takes precedence over `MembershipPeriod`.
+		type NetworkParameters: NetworkParameters<BlockNumberFor<Self>>;
+		/// The currency used to charge the fees of the hybrid mode calls.
+		type Currency: Currency<Self::AccountId>;
+		/// The calls which dispatch other calls, such as batches or DID
+		/// calls. Non-members can never submit them in hybrid mode, nor the
+		/// members of a tier, as the allow-lists only check the outer call.
+		type WrapperCalls: Contains<Self::RuntimeCall>;
 		type WeightInfo: WeightInfo;
 	}
 
@@ -107,6 +132,69 @@ pub mod pallet {
 	pub(crate) type MembershipBlacklist<T: Config> =
 		StorageMap<_, Blake2_128Concat, CordAccountOf<T>, ()>;
 
+	/// Whether non-members can submit the calls in the hybrid allow-list.
+	#[pallet::storage]
+	pub type HybridMode<T> = StorageValue<_, bool, ValueQuery>;
+
+	/// The calls non-members can submit in hybrid mode.
+	/// It maps from a pallet name and a function name to the call policy. An
+	/// empty function name allows all the calls of the pallet.
+	#[pallet::storage]
+	pub type HybridCalls<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CallNameOf,
+		Blake2_128Concat,
+		CallNameOf,
+		CallPolicyOf<T>,
+		OptionQuery,
+	>;
+
+	/// Usage of the rate limited hybrid mode calls.
+	/// It maps from a non-member and an allow-list entry to the usage within
+	/// the current period.
+	#[pallet::storage]
+	pub type HybridCallUsage<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		CordAccountOf<T>,
+		Blake2_128Concat,
+		(CallNameOf, CallNameOf),
+		CallUsage<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
+	/// The calls the members of a tier can submit.
+	/// It maps from a tier and a pallet name and function name to a unit
+	/// tuple. An empty function name allows all the calls of the pallet. The
+	/// `WrapperCalls` are never allowed.
+	#[pallet::storage]
+	pub type TierCalls<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		MembershipTier,
+		Blake2_128Concat,
+		(CallNameOf, CallNameOf),
+		(),
+		OptionQuery,
+	>;
+
+	/// The transaction quota of the members of a tier. Tiers without a quota
+	/// are not limited.
+	#[pallet::storage]
+	pub type TierQuotas<T: Config> =
+		StorageMap<_, Blake2_128Concat, MembershipTier, RateLimit<BlockNumberFor<T>>, OptionQuery>;
+
+	/// Usage of the tier quota by a member within the current period.
+	#[pallet::storage]
+	pub type TierUsage<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		CordAccountOf<T>,
+		CallUsage<BlockNumberFor<T>>,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -120,6 +208,24 @@ pub mod pallet {
 		MembershipRevoked { member: CordAccountOf<T> },
 		/// A membership renew request
 		MembershipRenewalRequested { member: CordAccountOf<T> },
+		/// A membership could not be renewed as the expiry schedule is full,
+		/// and expired
+		MembershipRenewalFailed { member: CordAccountOf<T> },
+		/// The hybrid mode has been enabled or disabled
+		HybridModeSet { enabled: bool },
+		/// A call has been added to the hybrid allow-list or its policy has
+		/// been updated
+		HybridCallPolicySet { pallet: CallNameOf, function: CallNameOf, policy: CallPolicyOf<T> },
+		/// A call has been removed from the hybrid allow-list
+		HybridCallPolicyRemoved { pallet: CallNameOf, function: CallNameOf },
+		/// The tier of a member has been set or cleared
+		MemberTierSet { member: CordAccountOf<T>, tier: Option<MembershipTier> },
+		/// A call has been added to the allow-list of a tier
+		TierCallAdded { tier: MembershipTier, pallet: CallNameOf, function: CallNameOf },
+		/// A call has been removed from the allow-list of a tier
+		TierCallRemoved { tier: MembershipTier, pallet: CallNameOf, function: CallNameOf },
+		/// The transaction quota of a tier has been set or cleared
+		TierQuotaSet { tier: MembershipTier, quota: Option<RateLimit<BlockNumberFor<T>>> },
 	}
 
 	#[pallet::error]
@@ -138,6 +244,12 @@ pub mod pallet {
 		MembershipExpired,
 		/// Max members limit exceeded
 		MaxMembersExceededForTheBlock,
+		/// The call is not in the hybrid allow-list
+		HybridCallNotFound,
+		/// The same member is nominated more than once in a batch
+		DuplicateMember,
+		/// The call is not in the allow-list of the tier
+		TierCallNotFound,
 	}
 
 	#[pallet::hooks]
@@ -162,6 +274,7 @@ pub mod pallet {
 		fn build(&self) {
 			for (member, expires) in &self.members {
 				Pallet::<T>::add_member_and_schedule_expiry(member, *expires)
+					.expect("Expiry schedule of the genesis members is full");
 			}
 		}
 	}
@@ -184,7 +297,7 @@ pub mod pallet {
 			// 'MembershipAlreadyAcquired'
 			ensure!(!<Members<T>>::contains_key(&member), Error::<T>::MembershipAlreadyAcquired);
 
-			Self::add_member_and_schedule_expiry(&member, expires);
+			Self::add_member_and_schedule_expiry(&member, expires)?;
 
 			Self::deposit_event(Event::MembershipAcquired { member });
 
@@ -225,6 +338,7 @@ pub mod pallet {
 
 			// Remove the member from the Members storage.
 			<Members<T>>::remove(&member);
+			TierUsage::<T>::remove(&member);
 
 			// Remove the member from the BoundedVec stored in MembershipsExpiresOn.
 			MembershipsExpiresOn::<T>::try_mutate(member_details.expire_on, |members| {
@@ -241,41 +355,236 @@ pub mod pallet {
 			Self::deposit_event(Event::MembershipRevoked { member });
 			Ok(())
 		}
+
+		/// Enable or disable the hybrid mode, in which non-members can submit
+		/// the calls in the hybrid allow-list. Only root or council origin
+		/// can perform this action.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_hybrid_mode())]
+		pub fn set_hybrid_mode(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			HybridMode::<T>::put(enabled);
+
+			Self::deposit_event(Event::HybridModeSet { enabled });
+
+			Ok(())
+		}
+
+		/// Add a call to the hybrid allow-list, or update its policy. Only
+		/// root or council origin can perform this action.
+		///
+		/// The names are the ones of the call metadata, e.g. `Did` and
+		/// `create`. An empty function name allows all the calls of the
+		/// pallet.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_hybrid_call_policy())]
+		pub fn set_hybrid_call_policy(
+			origin: OriginFor<T>,
+			pallet: CallNameOf,
+			function: CallNameOf,
+			policy: CallPolicyOf<T>,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			HybridCalls::<T>::insert(&pallet, &function, &policy);
+
+			Self::deposit_event(Event::HybridCallPolicySet { pallet, function, policy });
+
+			Ok(())
+		}
+
+		/// Remove a call from the hybrid allow-list. Only root or council
+		/// origin can perform this action.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_hybrid_call_policy())]
+		pub fn remove_hybrid_call_policy(
+			origin: OriginFor<T>,
+			pallet: CallNameOf,
+			function: CallNameOf,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				HybridCalls::<T>::take(&pallet, &function).is_some(),
+				Error::<T>::HybridCallNotFound
+			);
+
+			Self::deposit_event(Event::HybridCallPolicyRemoved { pallet, function });
+
+			Ok(())
+		}
+
+		/// Add a batch of members. Only root or council origin can perform
+		/// this action.
+		///
+		/// Expiring memberships are scheduled to expire one membership period
+		/// from now. Once that block is full, the expiries are spread over
+		/// the next blocks, up to `ExpirySpread` blocks.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::nominate_batch(members.len() as u32))]
+		pub fn nominate_batch(
+			origin: OriginFor<T>,
+			members: BoundedVec<CordAccountOf<T>, T::MaxMembersPerBatch>,
+			expires: bool,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			for (index, member) in members.iter().enumerate() {
+				ensure!(!members[..index].contains(member), Error::<T>::DuplicateMember);
+				ensure!(!<Members<T>>::contains_key(member), Error::<T>::MembershipAlreadyAcquired);
+			}
+
+			for member in members {
+				Self::add_member_and_schedule_expiry(&member, expires)?;
+				Self::deposit_event(Event::MembershipAcquired { member });
+			}
+
+			Ok(())
+		}
+
+		/// Set the tier of a member, or clear it to give the member full
+		/// access. Only root or council origin can perform this action.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_member_tier())]
+		pub fn set_member_tier(
+			origin: OriginFor<T>,
+			member: CordAccountOf<T>,
+			tier: Option<MembershipTier>,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			Members::<T>::try_mutate(&member, |data| {
+				let data = data.as_mut().ok_or(Error::<T>::MembershipNotFound)?;
+				data.tier = tier;
+				Ok::<_, Error<T>>(())
+			})?;
+			TierUsage::<T>::remove(&member);
+
+			Self::deposit_event(Event::MemberTierSet { member, tier });
+
+			Ok(())
+		}
+
+		/// Add a call to the allow-list of a tier. Only root or council
+		/// origin can perform this action.
+		///
+		/// The names are the ones of the call metadata, e.g. `Did` and
+		/// `create`. An empty function name allows all the calls of the
+		/// pallet.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::add_tier_call())]
+		pub fn add_tier_call(
+			origin: OriginFor<T>,
+			tier: MembershipTier,
+			pallet: CallNameOf,
+			function: CallNameOf,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			TierCalls::<T>::insert(tier, (&pallet, &function), ());
+
+			Self::deposit_event(Event::TierCallAdded { tier, pallet, function });
+
+			Ok(())
+		}
+
+		/// Remove a call from the allow-list of a tier. Only root or council
+		/// origin can perform this action.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::remove_tier_call())]
+		pub fn remove_tier_call(
+			origin: OriginFor<T>,
+			tier: MembershipTier,
+			pallet: CallNameOf,
+			function: CallNameOf,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				TierCalls::<T>::take(tier, (&pallet, &function)).is_some(),
+				Error::<T>::TierCallNotFound
+			);
+
+			Self::deposit_event(Event::TierCallRemoved { tier, pallet, function });
+
+			Ok(())
+		}
+
+		/// Set the transaction quota of a tier, or clear it to lift the
+		/// limit. Only root or council origin can perform this action.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as pallet::Config>::WeightInfo::set_tier_quota())]
+		pub fn set_tier_quota(
+			origin: OriginFor<T>,
+			tier: MembershipTier,
+			quota: Option<RateLimit<BlockNumberFor<T>>>,
+		) -> DispatchResult {
+			T::NetworkMembershipOrigin::ensure_origin(origin)?;
+
+			TierQuotas::<T>::set(tier, quota.clone());
+
+			Self::deposit_event(Event::TierQuotaSet { tier, quota });
+
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
-	fn add_member_and_schedule_expiry(member: &CordAccountOf<T>, expires: bool) {
-		if expires {
-			let block_number = frame_system::pallet::Pallet::<T>::block_number();
-			let expire_on = block_number + T::MembershipPeriod::get();
-			Members::<T>::insert(member, MemberData { expire_on });
+	/// life span of an expiring membership, preferring the on-chain network
+	/// parameter over the compile-time default
+	fn membership_period() -> BlockNumberFor<T> {
+		T::NetworkParameters::membership_period().unwrap_or_else(T::MembershipPeriod::get)
+	}
 
-			// the member has just been created, increment its provider
-			let _ = frame_system::Pallet::<T>::inc_providers(member);
+	/// schedule the expiry of a membership at the first block with a free
+	/// slot, starting from the given block and within `ExpirySpread` blocks
+	fn schedule_expiry(
+		member: &CordAccountOf<T>,
+		expire_on: BlockNumberFor<T>,
+	) -> Result<BlockNumberFor<T>, Error<T>> {
+		for offset in 0..=T::ExpirySpread::get() {
+			let block_number = expire_on + offset.into();
+			if MembershipsExpiresOn::<T>::try_mutate(block_number, |members| {
+				members.try_push(member.clone())
+			})
+			.is_ok()
+			{
+				return Ok(block_number);
+			}
+		}
 
-			let _ = MembershipsExpiresOn::<T>::try_mutate(expire_on, |members| {
-				members
-					.try_push(member.clone())
-					.map_err(|_| Error::<T>::MaxMembersExceededForTheBlock)
-			});
+		Err(Error::<T>::MaxMembersExceededForTheBlock)
+	}
+
+	fn add_member_and_schedule_expiry(member: &CordAccountOf<T>, expires: bool) -> DispatchResult {
+		let expire_on = if expires {
+			let block_number = frame_system::pallet::Pallet::<T>::block_number();
+			Self::schedule_expiry(member, block_number + Self::membership_period())?
 		} else {
-			let expire_on = BlockNumberFor::<T>::zero();
-			Members::<T>::insert(member, MemberData { expire_on });
-			// the member has just been created, increment its provider
-			let _ = frame_system::Pallet::<T>::inc_providers(member);
-		}
+			BlockNumberFor::<T>::zero()
+		};
+
+		Members::<T>::insert(member, MemberData { expire_on, tier: None });
+		// the member has just been created, increment its provider
+		let _ = frame_system::Pallet::<T>::inc_providers(member);
+
+		Ok(())
 	}
 
 	fn renew_membership_and_schedule_expiry(
 		member: CordAccountOf<T>,
 		expire_on: BlockNumberFor<T>,
-	) {
-		let schedule_expiry = expire_on + T::MembershipPeriod::get();
-		Members::<T>::insert(&member, MemberData { expire_on: schedule_expiry });
-		let _ = MembershipsExpiresOn::<T>::try_mutate(schedule_expiry, |members| {
-			members.try_push(member).map_err(|_| Error::<T>::MaxMembersExceededForTheBlock)
+	) -> DispatchResult {
+		let schedule_expiry =
+			Self::schedule_expiry(&member, expire_on + Self::membership_period())?;
+		Members::<T>::mutate(&member, |data| {
+			if let Some(data) = data {
+				data.expire_on = schedule_expiry;
+			}
 		});
+		Ok(())
 	}
 
 	/// perform membership renewal or expiration
@@ -286,15 +595,19 @@ impl<T: Config> Pallet<T> {
 		let mut call_weight: Weight = Weight::zero();
 
 		if MembershipsRenewsOn::<T>::take(&member).is_some() {
-			Self::renew_membership_and_schedule_expiry(member.clone(), expire_on);
-			Self::deposit_event(Event::MembershipRenewed { member });
 			call_weight += T::WeightInfo::renew();
-		} else {
-			Members::<T>::remove(&member);
-			Self::deposit_event(Event::MembershipExpired { member });
-			call_weight += T::WeightInfo::revoke();
+			if Self::renew_membership_and_schedule_expiry(member.clone(), expire_on).is_ok() {
+				Self::deposit_event(Event::MembershipRenewed { member });
+				return call_weight;
+			}
+			Self::deposit_event(Event::MembershipRenewalFailed { member: member.clone() });
 		}
 
+		Members::<T>::remove(&member);
+		TierUsage::<T>::remove(&member);
+		Self::deposit_event(Event::MembershipExpired { member });
+		call_weight += T::WeightInfo::revoke();
+
 		call_weight
 	}
 
@@ -308,11 +621,165 @@ impl<T: Config> Pallet<T> {
 
 		total_weight
 	}
+	/// the memberships expiring within the given number of blocks, in order
+	/// of expiry. Only the expiry schedule of the blocks in the range is read,
+	/// up to the latest block a membership can be scheduled to expire at.
+	pub fn expiring_memberships(
+		within: BlockNumberFor<T>,
+	) -> Vec<ExpiringMembership<CordAccountOf<T>, BlockNumberFor<T>>> {
+		let now = frame_system::Pallet::<T>::block_number();
+		let longest = Self::membership_period().saturating_add(T::ExpirySpread::get().into());
+		let until = now.saturating_add(within.min(longest));
+
+		let mut expiring = Vec::new();
+		let mut expire_on = now;
+		while expire_on < until {
+			expire_on = expire_on.saturating_add(One::one());
+			expiring.extend(MembershipsExpiresOn::<T>::get(expire_on).into_iter().map(|member| {
+				ExpiringMembership {
+					renewal_requested: MembershipsRenewsOn::<T>::contains_key(&member),
+					member,
+					expire_on,
+				}
+			}));
+		}
+
+		expiring
+	}
+
 	/// check if identity is member
 	pub fn is_member(member: &CordAccountOf<T>) -> bool {
 		Members::<T>::contains_key(member)
 	}
 
+	/// find the hybrid allow-list entry of a call, preferring an entry for
+	/// the function over an entry for the whole pallet
+	pub fn hybrid_call_policy(
+		call: &T::RuntimeCall,
+	) -> Option<((CallNameOf, CallNameOf), CallPolicyOf<T>)>
+	where
+		T::RuntimeCall: GetCallMetadata,
+	{
+		let metadata = call.get_call_metadata();
+		let pallet = CallNameOf::try_from(metadata.pallet_name.as_bytes().to_vec()).ok()?;
+		let function =
+			CallNameOf::try_from(metadata.function_name.as_bytes().to_vec()).unwrap_or_default();
+
+		[function, CallNameOf::default()].into_iter().find_map(|function| {
+			HybridCalls::<T>::get(&pallet, &function)
+				.map(|policy| ((pallet.clone(), function), policy))
+		})
+	}
+
+	/// check that a non-member can submit the call in hybrid mode, and
+	/// return the allow-list entry with the updated usage of the sender
+	fn validate_hybrid_call(
+		who: &CordAccountOf<T>,
+		call: &T::RuntimeCall,
+	) -> Result<HybridCallOf<T>, TransactionValidityError>
+	where
+		T::RuntimeCall: GetCallMetadata,
+	{
+		if !HybridMode::<T>::get() || T::WrapperCalls::contains(call) {
+			return Err(InvalidTransaction::Call.into());
+		}
+
+		let (key, policy) = Self::hybrid_call_policy(call).ok_or(InvalidTransaction::Call)?;
+
+		if !policy.fee.is_zero() && T::Currency::free_balance(who) < policy.fee {
+			return Err(InvalidTransaction::Payment.into());
+		}
+
+		let usage = match &policy.rate_limit {
+			Some(limit) => {
+				let now = frame_system::Pallet::<T>::block_number();
+				let usage = HybridCallUsage::<T>::get(who, &key)
+					.filter(|usage| now < usage.period_start.saturating_add(limit.period))
+					.unwrap_or(CallUsage { period_start: now, calls: 0 });
+				if usage.calls >= limit.max_calls {
+					return Err(InvalidTransaction::Custom(HYBRID_CALL_RATE_LIMITED).into());
+				}
+				Some(CallUsage { calls: usage.calls.saturating_add(1), ..usage })
+			},
+			None => None,
+		};
+
+		Ok((key, policy.fee, usage))
+	}
+
+	/// check that a member of a tier can submit the call, and return the
+	/// updated usage of the tier quota
+	fn validate_tier_call(
+		who: &CordAccountOf<T>,
+		tier: MembershipTier,
+		call: &T::RuntimeCall,
+	) -> Result<Option<CallUsage<BlockNumberFor<T>>>, TransactionValidityError>
+	where
+		T::RuntimeCall: GetCallMetadata,
+	{
+		// The allow-list only checks the outer call.
+		if T::WrapperCalls::contains(call) {
+			return Err(InvalidTransaction::Call.into());
+		}
+
+		let metadata = call.get_call_metadata();
+		let pallet = CallNameOf::try_from(metadata.pallet_name.as_bytes().to_vec())
+			.map_err(|_| InvalidTransaction::Call)?;
+		let function =
+			CallNameOf::try_from(metadata.function_name.as_bytes().to_vec()).unwrap_or_default();
+
+		let allowed = [function, CallNameOf::default()]
+			.into_iter()
+			.any(|function| TierCalls::<T>::contains_key(tier, (&pallet, &function)));
+		if !allowed {
+			return Err(InvalidTransaction::Call.into());
+		}
+
+		let Some(quota) = TierQuotas::<T>::get(tier) else { return Ok(None) };
+		let now = frame_system::Pallet::<T>::block_number();
+		let usage = TierUsage::<T>::get(who)
+			.filter(|usage| now < usage.period_start.saturating_add(quota.period))
+			.unwrap_or(CallUsage { period_start: now, calls: 0 });
+		if usage.calls >= quota.max_calls {
+			return Err(InvalidTransaction::Custom(TIER_QUOTA_EXCEEDED).into());
+		}
+
+		Ok(Some(CallUsage { calls: usage.calls.saturating_add(1), ..usage }))
+	}
+
+	/// check the call of a member against the allow-list and quota of its
+	/// tier, if any
+	fn validate_member_call(
+		who: &CordAccountOf<T>,
+		data: &MemberData<BlockNumberFor<T>>,
+		call: &T::RuntimeCall,
+	) -> Result<Option<CallUsage<BlockNumberFor<T>>>, TransactionValidityError>
+	where
+		T::RuntimeCall: GetCallMetadata,
+	{
+		match data.tier {
+			Some(tier) => Self::validate_tier_call(who, tier, call),
+			None => Ok(None),
+		}
+	}
+
+	/// burn the fee and record the usage of a hybrid mode call
+	fn charge_hybrid_call(
+		who: &CordAccountOf<T>,
+		(key, fee, usage): HybridCallOf<T>,
+	) -> Result<(), TransactionValidityError> {
+		if !fee.is_zero() {
+			T::Currency::withdraw(who, fee, WithdrawReasons::FEE, ExistenceRequirement::KeepAlive)
+				.map_err(|_| InvalidTransaction::Payment)?;
+		}
+
+		if let Some(usage) = usage {
+			HybridCallUsage::<T>::insert(who, key, usage);
+		}
+
+		Ok(())
+	}
+
 	// Query the data that we know about the weight of a given `call`.
 	///
 	/// All dispatchables must be annotated with weight. This function always
@@ -341,7 +808,25 @@ impl<T: Config> network_membership::MembersCount for Pallet<T> {
 	}
 }
 
+/// Custom validity error returned when a non-member exceeds the rate limit of
+/// a hybrid mode call.
+pub const HYBRID_CALL_RATE_LIMITED: u8 = 1;
+
+/// Custom validity error returned when a member exceeds the transaction quota
+/// of its tier.
+pub const TIER_QUOTA_EXCEEDED: u8 = 2;
+
+/// The allow-list entry, fee and updated usage of a hybrid mode call.
+type HybridCallOf<T> =
+	((CallNameOf, CallNameOf), BalanceOf<T>, Option<CallUsage<BlockNumberFor<T>>>);
+
 /// The `CheckNetworkMembership` struct.
+///
+/// Only members can submit transactions, whether the network is permissioned
+/// or not, except in hybrid mode, where non-members can also submit the calls
+/// in the hybrid allow-list under the fee and rate limit of the call policy.
+/// Members of a tier can only submit the calls in the allow-list of the tier,
+/// within the quota of the tier.
 #[derive(Encode, Decode, Clone, Eq, PartialEq, Default, scale_info::TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct CheckNetworkMembership<T: Config + Send + Sync>(PhantomData<T>);
@@ -369,7 +854,7 @@ impl<T: Config + Send + Sync> CheckNetworkMembership<T> {
 /// `CheckNetworkMembership` struct.
 impl<T: Config + Send + Sync> SignedExtension for CheckNetworkMembership<T>
 where
-	T::RuntimeCall: Dispatchable<Info = DispatchInfo>,
+	T::RuntimeCall: Dispatchable<Info = DispatchInfo> + GetCallMetadata,
 {
 	type AccountId = T::AccountId;
 	type Call = T::RuntimeCall;
@@ -385,28 +870,41 @@ where
 		self,
 		who: &Self::AccountId,
 		call: &Self::Call,
-		info: &DispatchInfoOf<Self::Call>,
-		len: usize,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
 	) -> Result<Self::Pre, TransactionValidityError> {
-		self.validate(who, call, info, len).map(|_| ())
+		if let Some(data) = <Members<T>>::get(who) {
+			if let Some(usage) = Pallet::<T>::validate_member_call(who, &data, call)? {
+				TierUsage::<T>::insert(who, usage);
+			}
+			return Ok(());
+		}
+
+		let hybrid_call = Pallet::<T>::validate_hybrid_call(who, call)?;
+		Pallet::<T>::charge_hybrid_call(who, hybrid_call)
 	}
 
 	fn validate(
 		&self,
 		who: &Self::AccountId,
-		_call: &Self::Call,
+		call: &Self::Call,
 		_info: &DispatchInfoOf<Self::Call>,
 		_len: usize,
 	) -> TransactionValidity {
-		if <Members<T>>::contains_key(who) {
-			Ok(ValidTransaction {
-				priority: 0,
-				longevity: TransactionLongevity::max_value(),
-				propagate: true,
-				..Default::default()
-			})
-		} else {
-			Err(InvalidTransaction::Call.into())
+		match <Members<T>>::get(who) {
+			Some(data) => {
+				Pallet::<T>::validate_member_call(who, &data, call)?;
+			},
+			None => {
+				Pallet::<T>::validate_hybrid_call(who, call)?;
+			},
 		}
+
+		Ok(ValidTransaction {
+			priority: 0,
+			longevity: TransactionLongevity::max_value(),
+			propagate: true,
+			..Default::default()
+		})
 	}
 }
@@ -0,0 +1,60 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) 2019-2023 BOTLabs GmbH.
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Adapted to meet the requirements of the CORD project.
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the network membership pallet.
+
+use codec::Decode;
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_runtime::Saturating;
+use sp_std::marker::PhantomData;
+
+use crate::{Config, MemberData, Members, Pallet};
+
+/// The member data stored before membership tiers.
+#[derive(Decode)]
+struct MemberDataV1<BlockNumber> {
+	expire_on: BlockNumber,
+}
+
+/// Add an empty tier to the data of the existing members, which keep full
+/// access.
+pub struct AddMembershipTier<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for AddMembershipTier<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 2 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut members: u64 = 0;
+		Members::<T>::translate::<MemberDataV1<BlockNumberFor<T>>, _>(|_, data| {
+			members.saturating_inc();
+			Some(MemberData { expire_on: data.expire_on, tier: None })
+		});
+
+		StorageVersion::new(2).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(members + 1, members + 1)
+	}
+}
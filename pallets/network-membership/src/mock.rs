@@ -19,7 +19,7 @@
 use crate::{self as pallet_network_membership};
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{OnFinalize, OnInitialize},
+	traits::{Contains, OnFinalize, OnInitialize},
 };
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot};
 use sp_runtime::{
@@ -37,6 +37,7 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
+		Balances: pallet_balances,
 		NetworkMembership: pallet_network_membership,
 	}
 );
@@ -50,11 +51,19 @@ impl frame_system::Config for Test {
 	type Block = Block;
 	type AccountId = AccountId;
 	type Lookup = IdentityLookup<Self::AccountId>;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
 }
 
 parameter_types! {
 	pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 	pub const MaxMembersPerBlock: u32 = 5;
+	pub const MaxMembersPerBatch: u32 = 10;
+	pub const ExpirySpread: u32 = 1;
 }
 
 impl pallet_network_membership::Config for Test {
@@ -62,7 +71,20 @@ impl pallet_network_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxMembersPerBatch = MaxMembersPerBatch;
+	type ExpirySpread = ExpirySpread;
 	type WeightInfo = ();
+	type NetworkParameters = ();
+	type Currency = Balances;
+	type WrapperCalls = WrapperCalls;
+}
+
+/// Stands in for the calls which dispatch other calls.
+pub struct WrapperCalls;
+impl Contains<RuntimeCall> for WrapperCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(frame_system::Call::kill_storage { .. }))
+	}
 }
 
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
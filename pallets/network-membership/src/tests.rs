@@ -15,11 +15,25 @@
 // You should have received a copy of the GNU General Public License
 // along with this program. If not, see <https://www.gnu.org/licenses/>.
 
-use crate::{mock::*, Error, Event, MemberData, Members};
-
-use frame_support::{assert_err, assert_ok, error::BadOrigin};
+use crate::{
+	migrations, mock::*, CallNameOf, CallPolicy, CheckNetworkMembership, Error, Event,
+	ExpiringMembership, HybridCalls, MemberData, Members, MembershipTier, MembershipsExpiresOn,
+	RateLimit, TierCalls, TierUsage, HYBRID_CALL_RATE_LIMITED, TIER_QUOTA_EXCEEDED,
+};
+
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	dispatch::DispatchInfo,
+	error::BadOrigin,
+	traits::{Currency, GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
 use frame_system::RawOrigin;
 use network_membership::MembersCount;
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
 
 #[test]
 fn test_genesis_build() {
@@ -28,7 +42,7 @@ fn test_genesis_build() {
 		// Verify state
 		assert_eq!(
 			Members::<Test>::get(AccountId::new([11u8; 32])),
-			Some(MemberData { expire_on: 5 })
+			Some(MemberData { expire_on: 5, tier: None })
 		);
 		assert_eq!(NetworkMembership::members_count(), 1);
 	});
@@ -52,7 +66,7 @@ fn test_add_member_request() {
 		// This ensures that the account was successfully added
 		assert_eq!(
 			Members::<Test>::get(AccountId::new([13u8; 32])),
-			Some(MemberData { expire_on: 1 + MembershipPeriod::get() })
+			Some(MemberData { expire_on: 1 + MembershipPeriod::get(), tier: None })
 		);
 
 		assert_eq!(NetworkMembership::members_count(), 2);
@@ -125,7 +139,7 @@ fn test_renew_membership() {
 		// This ensures that the account was successfully added
 		assert_eq!(
 			Members::<Test>::get(AccountId::new([13u8; 32])),
-			Some(MemberData { expire_on: 6 + MembershipPeriod::get() })
+			Some(MemberData { expire_on: 6 + MembershipPeriod::get(), tier: None })
 		);
 
 		assert_eq!(NetworkMembership::members_count(), 1);
@@ -271,7 +285,7 @@ fn test_renew_membership_again_should_fail() {
 		// This ensures that the account was successfully added
 		assert_eq!(
 			Members::<Test>::get(AccountId::new([13u8; 32])),
-			Some(MemberData { expire_on: 6 + MembershipPeriod::get() })
+			Some(MemberData { expire_on: 6 + MembershipPeriod::get(), tier: None })
 		);
 
 		assert_eq!(NetworkMembership::members_count(), 1);
@@ -361,3 +375,565 @@ fn test_auto_expire_non_existent_membership() {
 		assert_eq!(NetworkMembership::is_member(&AccountId::new([99u8; 32])), false);
 	});
 }
+
+fn call_name(name: &str) -> CallNameOf {
+	CallNameOf::try_from(name.as_bytes().to_vec()).unwrap()
+}
+
+fn remark_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn remark_with_event_call() -> RuntimeCall {
+	RuntimeCall::System(frame_system::Call::remark_with_event { remark: vec![] })
+}
+
+fn validate_call(who: &AccountId, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+	CheckNetworkMembership::<Test>::new()
+		.validate(who, call, &DispatchInfo::default(), 0)
+		.map(|_| ())
+}
+
+fn pre_dispatch_call(who: &AccountId, call: &RuntimeCall) -> Result<(), TransactionValidityError> {
+	CheckNetworkMembership::<Test>::new().pre_dispatch(who, call, &DispatchInfo::default(), 0)
+}
+
+fn allow_remark(fee: u64, rate_limit: Option<RateLimit<u64>>) {
+	assert_ok!(NetworkMembership::set_hybrid_call_policy(
+		RawOrigin::Root.into(),
+		call_name("System"),
+		call_name("remark"),
+		CallPolicy { fee, rate_limit },
+	));
+}
+
+#[test]
+fn test_members_pass_membership_check() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(validate_call(&AccountId::new([11u8; 32]), &remark_call()));
+		assert_eq!(
+			validate_call(&AccountId::new([12u8; 32]), &remark_call()),
+			Err(InvalidTransaction::Call.into())
+		);
+	});
+}
+
+#[test]
+fn test_hybrid_call_requires_hybrid_mode() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let non_member = AccountId::new([12u8; 32]);
+		allow_remark(0, None);
+
+		assert_eq!(
+			validate_call(&non_member, &remark_call()),
+			Err(InvalidTransaction::Call.into())
+		);
+
+		assert_ok!(NetworkMembership::set_hybrid_mode(RawOrigin::Root.into(), true));
+		System::assert_last_event(Event::HybridModeSet { enabled: true }.into());
+
+		assert_ok!(validate_call(&non_member, &remark_call()));
+		assert_ok!(pre_dispatch_call(&non_member, &remark_call()));
+		// Calls which are not in the allow-list are still rejected.
+		assert_eq!(
+			validate_call(&non_member, &remark_with_event_call()),
+			Err(InvalidTransaction::Call.into())
+		);
+	});
+}
+
+#[test]
+fn test_hybrid_call_pallet_wide_policy() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let non_member = AccountId::new([12u8; 32]);
+		assert_ok!(NetworkMembership::set_hybrid_mode(RawOrigin::Root.into(), true));
+		assert_ok!(NetworkMembership::set_hybrid_call_policy(
+			RawOrigin::Root.into(),
+			call_name("System"),
+			CallNameOf::default(),
+			CallPolicy { fee: 0, rate_limit: None },
+		));
+
+		assert_ok!(validate_call(&non_member, &remark_call()));
+		assert_ok!(validate_call(&non_member, &remark_with_event_call()));
+	});
+}
+
+#[test]
+fn test_hybrid_call_rejects_wrapper_calls() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let non_member = AccountId::new([12u8; 32]);
+		assert_ok!(NetworkMembership::set_hybrid_mode(RawOrigin::Root.into(), true));
+		assert_ok!(NetworkMembership::set_hybrid_call_policy(
+			RawOrigin::Root.into(),
+			call_name("System"),
+			CallNameOf::default(),
+			CallPolicy { fee: 0, rate_limit: None },
+		));
+
+		let wrapper_call = RuntimeCall::System(frame_system::Call::kill_storage { keys: vec![] });
+		assert_eq!(validate_call(&non_member, &wrapper_call), Err(InvalidTransaction::Call.into()));
+		assert_eq!(
+			pre_dispatch_call(&non_member, &wrapper_call),
+			Err(InvalidTransaction::Call.into())
+		);
+
+		// Members are not restricted by the hybrid allow-list.
+		assert_ok!(validate_call(&AccountId::new([11u8; 32]), &wrapper_call));
+	});
+}
+
+#[test]
+fn test_hybrid_call_fee_is_charged() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let non_member = AccountId::new([12u8; 32]);
+		assert_ok!(NetworkMembership::set_hybrid_mode(RawOrigin::Root.into(), true));
+		allow_remark(10, None);
+
+		assert_eq!(
+			validate_call(&non_member, &remark_call()),
+			Err(InvalidTransaction::Payment.into())
+		);
+
+		Balances::make_free_balance_be(&non_member, 100);
+		assert_ok!(pre_dispatch_call(&non_member, &remark_call()));
+		assert_eq!(Balances::free_balance(&non_member), 90);
+	});
+}
+
+#[test]
+fn test_hybrid_call_rate_limit() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let non_member = AccountId::new([12u8; 32]);
+		assert_ok!(NetworkMembership::set_hybrid_mode(RawOrigin::Root.into(), true));
+		allow_remark(0, Some(RateLimit { max_calls: 2, period: 5 }));
+
+		assert_ok!(pre_dispatch_call(&non_member, &remark_call()));
+		assert_ok!(pre_dispatch_call(&non_member, &remark_call()));
+		assert_eq!(
+			validate_call(&non_member, &remark_call()),
+			Err(InvalidTransaction::Custom(HYBRID_CALL_RATE_LIMITED).into())
+		);
+
+		// The limit is per sender.
+		assert_ok!(validate_call(&AccountId::new([13u8; 32]), &remark_call()));
+
+		// The limit resets with the next period.
+		run_to_block(6);
+		assert_ok!(pre_dispatch_call(&non_member, &remark_call()));
+	});
+}
+
+#[test]
+fn test_hybrid_call_policy_management() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let policy = CallPolicy { fee: 5, rate_limit: None };
+
+		assert_noop!(
+			NetworkMembership::set_hybrid_call_policy(
+				RuntimeOrigin::signed(AccountId::new([11u8; 32])),
+				call_name("System"),
+				call_name("remark"),
+				policy.clone(),
+			),
+			BadOrigin
+		);
+
+		allow_remark(5, None);
+		assert_eq!(
+			HybridCalls::<Test>::get(call_name("System"), call_name("remark")),
+			Some(policy)
+		);
+
+		assert_ok!(NetworkMembership::remove_hybrid_call_policy(
+			RawOrigin::Root.into(),
+			call_name("System"),
+			call_name("remark"),
+		));
+		System::assert_last_event(
+			Event::HybridCallPolicyRemoved {
+				pallet: call_name("System"),
+				function: call_name("remark"),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			NetworkMembership::remove_hybrid_call_policy(
+				RawOrigin::Root.into(),
+				call_name("System"),
+				call_name("remark"),
+			),
+			Error::<Test>::HybridCallNotFound
+		);
+	});
+}
+
+fn batch(ids: std::ops::Range<u8>) -> BoundedVec<AccountId, MaxMembersPerBatch> {
+	ids.map(|id| AccountId::new([id; 32])).collect::<Vec<_>>().try_into().unwrap()
+}
+
+#[test]
+fn test_nominate_batch_spreads_expiries() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			NetworkMembership::nominate_batch(
+				RuntimeOrigin::signed(AccountId::new([11u8; 32])),
+				batch(20..28),
+				true
+			),
+			BadOrigin
+		);
+		assert_ok!(NetworkMembership::nominate_batch(RawOrigin::Root.into(), batch(20..28), true));
+		System::assert_last_event(RuntimeEvent::NetworkMembership(Event::MembershipAcquired {
+			member: AccountId::new([27u8; 32]),
+		}));
+		assert_eq!(NetworkMembership::members_count(), 9);
+
+		// The first block is filled, then the next one
+		assert_eq!(MembershipsExpiresOn::<Test>::get(6).len(), 5);
+		assert_eq!(MembershipsExpiresOn::<Test>::get(7).len(), 3);
+		assert_eq!(
+			Members::<Test>::get(AccountId::new([27u8; 32])),
+			Some(MemberData { expire_on: 7, tier: None })
+		);
+
+		run_to_block(7);
+		assert!(!NetworkMembership::is_member(&AccountId::new([20u8; 32])));
+		assert!(!NetworkMembership::is_member(&AccountId::new([27u8; 32])));
+	});
+}
+
+#[test]
+fn test_nominate_batch_fails_when_schedule_is_full() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(NetworkMembership::nominate_batch(RawOrigin::Root.into(), batch(20..28), true));
+
+		// Blocks 6 and 7 only have 2 free slots left
+		assert_noop!(
+			NetworkMembership::nominate_batch(RawOrigin::Root.into(), batch(30..33), true),
+			Error::<Test>::MaxMembersExceededForTheBlock
+		);
+		assert_ok!(NetworkMembership::nominate(
+			RawOrigin::Root.into(),
+			AccountId::new([30u8; 32]),
+			true
+		));
+		assert_ok!(NetworkMembership::nominate(
+			RawOrigin::Root.into(),
+			AccountId::new([31u8; 32]),
+			true
+		));
+		assert_noop!(
+			NetworkMembership::nominate(RawOrigin::Root.into(), AccountId::new([32u8; 32]), true),
+			Error::<Test>::MaxMembersExceededForTheBlock
+		);
+
+		// Non-expiring memberships are not scheduled
+		assert_ok!(NetworkMembership::nominate_batch(RawOrigin::Root.into(), batch(32..35), false));
+	});
+}
+
+#[test]
+fn test_nominate_batch_rejects_duplicates() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let mut members = batch(20..22);
+		members.try_push(AccountId::new([20u8; 32])).unwrap();
+		assert_noop!(
+			NetworkMembership::nominate_batch(RawOrigin::Root.into(), members, true),
+			Error::<Test>::DuplicateMember
+		);
+		assert_noop!(
+			NetworkMembership::nominate_batch(RawOrigin::Root.into(), batch(10..12), true),
+			Error::<Test>::MembershipAlreadyAcquired
+		);
+	});
+}
+
+#[test]
+fn test_renewal_fails_when_schedule_is_full() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		let member = AccountId::new([11u8; 32]);
+		assert_ok!(NetworkMembership::renew(RawOrigin::Root.into(), member.clone()));
+		MembershipsExpiresOn::<Test>::insert(10, batch(20..25).into_inner());
+		MembershipsExpiresOn::<Test>::insert(11, batch(30..35).into_inner());
+
+		run_to_block(5);
+		System::assert_has_event(RuntimeEvent::NetworkMembership(Event::MembershipRenewalFailed {
+			member: member.clone(),
+		}));
+		System::assert_last_event(RuntimeEvent::NetworkMembership(Event::MembershipExpired {
+			member: member.clone(),
+		}));
+		assert!(!NetworkMembership::is_member(&member));
+	});
+}
+
+#[test]
+fn test_expiring_memberships() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(NetworkMembership::nominate(
+			RawOrigin::Root.into(),
+			AccountId::new([13u8; 32]),
+			true
+		));
+		assert_ok!(NetworkMembership::renew(RawOrigin::Root.into(), AccountId::new([13u8; 32])));
+
+		assert_eq!(
+			NetworkMembership::expiring_memberships(4),
+			vec![ExpiringMembership {
+				member: AccountId::new([11u8; 32]),
+				expire_on: 5,
+				renewal_requested: false,
+			}]
+		);
+		assert_eq!(
+			NetworkMembership::expiring_memberships(5)
+				.into_iter()
+				.map(|m| (m.expire_on, m.renewal_requested))
+				.collect::<Vec<_>>(),
+			vec![(5, false), (6, true)]
+		);
+		// A huge range stops at the latest block a membership can expire at.
+		assert_eq!(
+			NetworkMembership::expiring_memberships(u64::MAX),
+			NetworkMembership::expiring_memberships(MembershipPeriod::get() + 10)
+		);
+		assert_eq!(NetworkMembership::expiring_memberships(u64::MAX).len(), 2);
+	});
+}
+
+fn issuer() -> AccountId {
+	let member = AccountId::new([11u8; 32]);
+	assert_ok!(NetworkMembership::set_member_tier(
+		RawOrigin::Root.into(),
+		member.clone(),
+		Some(MembershipTier::Issuer),
+	));
+	member
+}
+
+#[test]
+fn test_set_member_tier() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let member = issuer();
+		System::assert_last_event(
+			Event::MemberTierSet { member: member.clone(), tier: Some(MembershipTier::Issuer) }
+				.into(),
+		);
+		assert_eq!(Members::<Test>::get(&member).unwrap().tier, Some(MembershipTier::Issuer));
+
+		assert_noop!(
+			NetworkMembership::set_member_tier(
+				RawOrigin::Root.into(),
+				AccountId::new([12u8; 32]),
+				Some(MembershipTier::Reader),
+			),
+			Error::<Test>::MembershipNotFound
+		);
+
+		// The tier is kept when the membership is renewed.
+		assert_ok!(NetworkMembership::renew(RawOrigin::Root.into(), member.clone()));
+		run_to_block(5);
+		assert_eq!(
+			Members::<Test>::get(&member),
+			Some(MemberData {
+				expire_on: 5 + MembershipPeriod::get(),
+				tier: Some(MembershipTier::Issuer),
+			})
+		);
+	});
+}
+
+#[test]
+fn test_tier_allow_list() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let member = issuer();
+
+		assert_eq!(validate_call(&member, &remark_call()), Err(InvalidTransaction::Call.into()));
+
+		assert_ok!(NetworkMembership::add_tier_call(
+			RawOrigin::Root.into(),
+			MembershipTier::Issuer,
+			call_name("System"),
+			call_name("remark"),
+		));
+		assert_ok!(validate_call(&member, &remark_call()));
+		assert_eq!(
+			validate_call(&member, &remark_with_event_call()),
+			Err(InvalidTransaction::Call.into())
+		);
+		// The allow-list of another tier does not apply.
+		assert_ok!(NetworkMembership::add_tier_call(
+			RawOrigin::Root.into(),
+			MembershipTier::Operator,
+			call_name("System"),
+			CallNameOf::default(),
+		));
+		assert_eq!(
+			validate_call(&member, &remark_with_event_call()),
+			Err(InvalidTransaction::Call.into())
+		);
+
+		// Clearing the tier gives full access back.
+		assert_ok!(NetworkMembership::set_member_tier(
+			RawOrigin::Root.into(),
+			member.clone(),
+			None
+		));
+		assert_ok!(validate_call(&member, &remark_with_event_call()));
+	});
+}
+
+#[test]
+fn test_tier_allow_list_rejects_wrapper_calls() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let member = issuer();
+		assert_ok!(NetworkMembership::add_tier_call(
+			RawOrigin::Root.into(),
+			MembershipTier::Issuer,
+			call_name("System"),
+			CallNameOf::default(),
+		));
+
+		// Stands in for a disallowed call wrapped in a DID call or a batch.
+		let wrapper_call = RuntimeCall::System(frame_system::Call::kill_storage { keys: vec![] });
+		assert_ok!(validate_call(&member, &remark_call()));
+		assert_eq!(validate_call(&member, &wrapper_call), Err(InvalidTransaction::Call.into()));
+		assert_eq!(pre_dispatch_call(&member, &wrapper_call), Err(InvalidTransaction::Call.into()));
+
+		// Members without a tier are not restricted.
+		assert_ok!(NetworkMembership::set_member_tier(
+			RawOrigin::Root.into(),
+			member.clone(),
+			None
+		));
+		assert_ok!(validate_call(&member, &wrapper_call));
+	});
+}
+
+#[test]
+fn test_tier_quota() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+		let member = issuer();
+		assert_ok!(NetworkMembership::add_tier_call(
+			RawOrigin::Root.into(),
+			MembershipTier::Issuer,
+			call_name("System"),
+			CallNameOf::default(),
+		));
+		assert_ok!(NetworkMembership::set_tier_quota(
+			RawOrigin::Root.into(),
+			MembershipTier::Issuer,
+			Some(RateLimit { max_calls: 2, period: 3 }),
+		));
+
+		assert_ok!(pre_dispatch_call(&member, &remark_call()));
+		assert_ok!(pre_dispatch_call(&member, &remark_with_event_call()));
+		assert_eq!(
+			validate_call(&member, &remark_call()),
+			Err(InvalidTransaction::Custom(TIER_QUOTA_EXCEEDED).into())
+		);
+
+		// The quota resets with the next period.
+		run_to_block(4);
+		assert_ok!(pre_dispatch_call(&member, &remark_call()));
+
+		// The usage is dropped with the membership.
+		assert_ok!(NetworkMembership::revoke(RawOrigin::Root.into(), member.clone()));
+		assert_eq!(TierUsage::<Test>::get(&member), None);
+	});
+}
+
+#[test]
+fn test_tier_call_management() {
+	new_test_ext().execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			NetworkMembership::add_tier_call(
+				RuntimeOrigin::signed(AccountId::new([11u8; 32])),
+				MembershipTier::Reader,
+				call_name("System"),
+				call_name("remark"),
+			),
+			BadOrigin
+		);
+
+		assert_ok!(NetworkMembership::add_tier_call(
+			RawOrigin::Root.into(),
+			MembershipTier::Reader,
+			call_name("System"),
+			call_name("remark"),
+		));
+		assert!(TierCalls::<Test>::contains_key(
+			MembershipTier::Reader,
+			(call_name("System"), call_name("remark"))
+		));
+
+		assert_ok!(NetworkMembership::remove_tier_call(
+			RawOrigin::Root.into(),
+			MembershipTier::Reader,
+			call_name("System"),
+			call_name("remark"),
+		));
+		System::assert_last_event(
+			Event::TierCallRemoved {
+				tier: MembershipTier::Reader,
+				pallet: call_name("System"),
+				function: call_name("remark"),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			NetworkMembership::remove_tier_call(
+				RawOrigin::Root.into(),
+				MembershipTier::Reader,
+				call_name("System"),
+				call_name("remark"),
+			),
+			Error::<Test>::TierCallNotFound
+		);
+	});
+}
+
+#[test]
+fn test_migration_adds_empty_tier() {
+	new_test_ext().execute_with(|| {
+		let member = AccountId::new([11u8; 32]);
+		let count = NetworkMembership::members_count();
+
+		// Member data stored before membership tiers.
+		frame_support::storage::unhashed::put(&Members::<Test>::hashed_key_for(&member), &7u64);
+		StorageVersion::new(1).put::<NetworkMembership>();
+
+		migrations::AddMembershipTier::<Test>::on_runtime_upgrade();
+
+		assert_eq!(Members::<Test>::get(&member), Some(MemberData { expire_on: 7, tier: None }));
+		assert_eq!(NetworkMembership::members_count(), count);
+		assert_eq!(NetworkMembership::on_chain_storage_version(), 2);
+	});
+}
@@ -18,7 +18,7 @@
 
 use crate::*;
 use codec::{Decode, Encode, MaxEncodedLen};
-use frame_support::dispatch::DispatchClass;
+use frame_support::{dispatch::DispatchClass, traits::ConstU32, BoundedVec};
 use scale_info::TypeInfo;
 
 #[cfg(feature = "std")]
@@ -42,4 +42,66 @@ pub struct RuntimeDispatchWeightInfo<Weight = frame_support::weights::Weight> {
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct MemberData<BlockNumber: Decode + Encode + TypeInfo> {
 	pub expire_on: BlockNumber,
+	/// Tier restricting the calls of the member. Members without a tier
+	/// have full access.
+	pub tier: Option<MembershipTier>,
+}
+
+/// Tier of a restricted membership. Each tier has its own allow-list of
+/// calls and transaction quota.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum MembershipTier {
+	/// Read-mostly access, e.g. for auditors.
+	Reader,
+	/// Access to issue records, e.g. for partner organisations.
+	Issuer,
+	/// Access to operate the network infrastructure.
+	Operator,
+}
+
+/// A membership expiring soon, as returned by the runtime API.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct ExpiringMembership<AccountId, BlockNumber> {
+	pub member: AccountId,
+	pub expire_on: BlockNumber,
+	/// Whether a renewal has been requested for the membership.
+	pub renewal_requested: bool,
+}
+
+/// Max length of the pallet and function names in the hybrid allow-list.
+pub const MAX_CALL_NAME_LENGTH: u32 = 64;
+
+/// Name of a pallet or of a function, as found in the call metadata.
+pub type CallNameOf = BoundedVec<u8, ConstU32<MAX_CALL_NAME_LENGTH>>;
+
+/// Max number of calls a non-member can submit within a period.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct RateLimit<BlockNumber> {
+	/// Max number of calls within the period.
+	pub max_calls: u32,
+	/// Length of the period, in blocks.
+	pub period: BlockNumber,
+}
+
+/// Conditions under which a non-member can submit an allowed call in hybrid
+/// mode.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallPolicy<Balance, BlockNumber> {
+	/// Fee burned from the sender on top of the transaction fee.
+	pub fee: Balance,
+	/// Optional rate limit, applied per sender.
+	pub rate_limit: Option<RateLimit<BlockNumber>>,
+}
+
+/// Usage of a rate limited call by a non-member.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CallUsage<BlockNumber> {
+	/// First block of the current period.
+	pub period_start: BlockNumber,
+	/// Number of calls submitted within the current period.
+	pub calls: u32,
 }
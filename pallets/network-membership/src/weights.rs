@@ -52,6 +52,14 @@ pub trait WeightInfo {
 	fn nominate() -> Weight;
 	fn renew() -> Weight;
 	fn revoke() -> Weight;
+	fn set_hybrid_mode() -> Weight;
+	fn set_hybrid_call_policy() -> Weight;
+	fn remove_hybrid_call_policy() -> Weight;
+	fn nominate_batch(m: u32, ) -> Weight;
+	fn set_member_tier() -> Weight;
+	fn add_tier_call() -> Weight;
+	fn remove_tier_call() -> Weight;
+	fn set_tier_quota() -> Weight;
 }
 
 /// Weights for `pallet_network_membership` using the CORD node and recommended hardware.
@@ -102,6 +110,104 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `NetworkMembership::HybridMode` (r:0 w:1)
+	/// Proof: `NetworkMembership::HybridMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_hybrid_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_120_000 picoseconds.
+		Weight::from_parts(5_381_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::HybridCalls` (r:0 w:1)
+	/// Proof: `NetworkMembership::HybridCalls` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn set_hybrid_call_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_030_000 picoseconds.
+		Weight::from_parts(7_412_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::HybridCalls` (r:1 w:1)
+	/// Proof: `NetworkMembership::HybridCalls` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn remove_hybrid_call_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3664`
+		// Minimum execution time: 11_942_000 picoseconds.
+		Weight::from_parts(12_371_000, 3664)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::Members` (r:100 w:100)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::CounterForMembers` (r:1 w:1)
+	/// Proof: `NetworkMembership::CounterForMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::MembershipsExpiresOn` (r:1 w:1)
+	/// Proof: `NetworkMembership::MembershipsExpiresOn` (`max_values`: None, `max_size`: Some(32022), added: 34497, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 100]`.
+	fn nominate_batch(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `35487`
+		// Minimum execution time: 24_120_000 picoseconds.
+		Weight::from_parts(18_402_000, 35487)
+			// Standard Error: 2_114
+			.saturating_add(Weight::from_parts(15_310_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(m.into()))
+	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:1)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::TierUsage` (r:0 w:1)
+	/// Proof: `NetworkMembership::TierUsage` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_member_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `3519`
+		// Minimum execution time: 11_240_000 picoseconds.
+		Weight::from_parts(11_710_000, 3519)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NetworkMembership::TierCalls` (r:0 w:1)
+	/// Proof: `NetworkMembership::TierCalls` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	fn add_tier_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_780_000 picoseconds.
+		Weight::from_parts(7_090_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::TierCalls` (r:1 w:1)
+	/// Proof: `NetworkMembership::TierCalls` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	fn remove_tier_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `3630`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_260_000, 3630)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::TierQuotas` (r:0 w:1)
+	/// Proof: `NetworkMembership::TierQuotas` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_tier_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_610_000 picoseconds.
+		Weight::from_parts(5_930_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -151,4 +257,102 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `NetworkMembership::HybridMode` (r:0 w:1)
+	/// Proof: `NetworkMembership::HybridMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_hybrid_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_120_000 picoseconds.
+		Weight::from_parts(5_381_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::HybridCalls` (r:0 w:1)
+	/// Proof: `NetworkMembership::HybridCalls` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn set_hybrid_call_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_030_000 picoseconds.
+		Weight::from_parts(7_412_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::HybridCalls` (r:1 w:1)
+	/// Proof: `NetworkMembership::HybridCalls` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
+	fn remove_hybrid_call_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `142`
+		//  Estimated: `3664`
+		// Minimum execution time: 11_942_000 picoseconds.
+		Weight::from_parts(12_371_000, 3664)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::Members` (r:100 w:100)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::CounterForMembers` (r:1 w:1)
+	/// Proof: `NetworkMembership::CounterForMembers` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::MembershipsExpiresOn` (r:1 w:1)
+	/// Proof: `NetworkMembership::MembershipsExpiresOn` (`max_values`: None, `max_size`: Some(32022), added: 34497, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:100 w:100)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[1, 100]`.
+	fn nominate_batch(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `224`
+		//  Estimated: `35487`
+		// Minimum execution time: 24_120_000 picoseconds.
+		Weight::from_parts(18_402_000, 35487)
+			// Standard Error: 2_114
+			.saturating_add(Weight::from_parts(15_310_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2603).saturating_mul(m.into()))
+	}
+	/// Storage: `NetworkMembership::Members` (r:1 w:1)
+	/// Proof: `NetworkMembership::Members` (`max_values`: None, `max_size`: Some(54), added: 2529, mode: `MaxEncodedLen`)
+	/// Storage: `NetworkMembership::TierUsage` (r:0 w:1)
+	/// Proof: `NetworkMembership::TierUsage` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn set_member_tier() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `187`
+		//  Estimated: `3519`
+		// Minimum execution time: 11_240_000 picoseconds.
+		Weight::from_parts(11_710_000, 3519)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `NetworkMembership::TierCalls` (r:0 w:1)
+	/// Proof: `NetworkMembership::TierCalls` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	fn add_tier_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_780_000 picoseconds.
+		Weight::from_parts(7_090_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::TierCalls` (r:1 w:1)
+	/// Proof: `NetworkMembership::TierCalls` (`max_values`: None, `max_size`: Some(165), added: 2640, mode: `MaxEncodedLen`)
+	fn remove_tier_call() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `145`
+		//  Estimated: `3630`
+		// Minimum execution time: 9_870_000 picoseconds.
+		Weight::from_parts(10_260_000, 3630)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `NetworkMembership::TierQuotas` (r:0 w:1)
+	/// Proof: `NetworkMembership::TierQuotas` (`max_values`: None, `max_size`: Some(25), added: 2500, mode: `MaxEncodedLen`)
+	fn set_tier_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_610_000 picoseconds.
+		Weight::from_parts(5_930_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -71,6 +75,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// The range of component `l` is `[1, 15360]`.
 	fn register_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -80,8 +85,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(53_034_543, 4144)
 			// Standard Error: 6
 			.saturating_add(Weight::from_parts(9, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -97,6 +102,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// The range of component `l` is `[1, 15360]`.
 	fn revoke_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -106,8 +112,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(69_315_996, 7298)
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(21, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -123,6 +129,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// The range of component `l` is `[1, 15360]`.
 	fn revise_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -132,8 +139,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(70_972_982, 7298)
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(37, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(9_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
 }
 
@@ -153,6 +160,7 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// The range of component `l` is `[1, 15360]`.
 	fn register_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -162,8 +170,8 @@ impl WeightInfo for () {
 		Weight::from_parts(53_034_543, 4144)
 			// Standard Error: 6
 			.saturating_add(Weight::from_parts(9, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -179,6 +187,7 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// The range of component `l` is `[1, 15360]`.
 	fn revoke_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -188,8 +197,8 @@ impl WeightInfo for () {
 		Weight::from_parts(69_315_996, 7298)
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(21, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -205,6 +214,7 @@ impl WeightInfo for () {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// The range of component `l` is `[1, 15360]`.
 	fn revise_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -214,7 +224,7 @@ impl WeightInfo for () {
 		Weight::from_parts(70_972_982, 7298)
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(37, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(9_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
 }
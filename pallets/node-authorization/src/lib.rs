@@ -31,7 +31,15 @@
 //! A node must have an owner. The owner can additionally change the connections
 //! for the node. Only one user is allowed to claim a specific node. To
 //! eliminate false claim, the maintainer of the node should claim it before
-//! even starting the node. This pallet uses offchain worker to set reserved
+//! even starting the node.
+//!
+//! A network member can claim a node by proving the control of its ed25519
+//! network key, with a signature by the key of the SCALE encoded domain tag,
+//! genesis hash and account (`ownership_proof_payload`). The owner of a well
+//! known node proves the control of the new node the same way when swapping it.
+//! Claimed nodes become well known nodes on governance approval, or
+//! immediately while the member owns no more nodes than the claim quota set by
+//! governance. This pallet uses offchain worker to set reserved
 //! nodes, if the node is not an authority, make sure to enable offchain worker
 //! with the right CLI flag. The node can be lagged with the latest block, in
 //! this case you need to disable offchain worker and manually set reserved
@@ -47,17 +55,27 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
+pub mod migrations;
 pub mod types;
 pub mod weights;
 
 pub use crate::{pallet::*, types::*, weights::WeightInfo};
+use codec::Encode;
 use cord_primitives::NodeId;
-use sp_core::OpaquePeerId as PeerId;
-use sp_runtime::traits::StaticLookup;
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_core::{ed25519, OpaquePeerId as PeerId};
+use sp_runtime::traits::{IsMember, StaticLookup, Zero};
 use sp_std::{collections::btree_set::BTreeSet, iter::FromIterator, prelude::*};
 
 type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
 
+/// Prefix of the PeerId of an ed25519 network key, which is the identity
+/// multihash of the protobuf encoded public key.
+const ED25519_PEER_ID_PREFIX: [u8; 6] = [0x00, 0x24, 0x08, 0x01, 0x12, 0x20];
+
+/// Domain tag of the payload signed to prove the ownership of a node.
+pub const OWNERSHIP_PROOF_TAG: &[u8] = b"cord-node-claim";
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -70,7 +88,10 @@ pub mod pallet {
 
 	pub type NodeInfoOf<T> = NodeInfo<NodeIdOf<T>, AccountIdOf<T>>;
 
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
@@ -95,6 +116,9 @@ pub mod pallet {
 		/// The origin which can add a well known node.
 		type NodeAuthorizationOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// The network members, which can claim nodes.
+		type IsMember: IsMember<Self::AccountId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -112,6 +136,15 @@ pub mod pallet {
 	pub type AdditionalConnections<T> =
 		StorageMap<_, Blake2_128Concat, PeerId, BTreeSet<PeerId>, ValueQuery>;
 
+	/// The number of nodes owned by each account.
+	#[pallet::storage]
+	pub type OwnedNodes<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+	/// The number of nodes a member can own for its claims to be approved
+	/// without governance.
+	#[pallet::storage]
+	pub type ClaimQuota<T> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -147,6 +180,8 @@ pub mod pallet {
 		ConnectionsAdded { node_id: NodeId, connection: NodeId },
 		/// The allowed connections were removed from a node.
 		ConnectionsRemoved { node_id: NodeId, connection: NodeId },
+		/// The claim quota was set.
+		ClaimQuotaSet { quota: u32 },
 	}
 
 	#[pallet::error]
@@ -174,6 +209,12 @@ pub mod pallet {
 		InvalidNodeIdentifier,
 		/// The node is already connected.
 		AlreadyConnected,
+		/// The account is not a network member.
+		NotNetworkMember,
+		/// The node identifier is not derived from an ed25519 network key.
+		InvalidNodeKey,
+		/// The signature does not prove the control of the network key.
+		BadOwnershipProof,
 	}
 
 	#[pallet::hooks]
@@ -238,6 +279,7 @@ pub mod pallet {
 
 			WellKnownNodes::<T>::put(&nodes);
 			<Owners<T>>::insert(&node, NodeInfoOf::<T> { id: node_id_bytes, owner: owner.clone() });
+			OwnedNodes::<T>::mutate(&owner, |count| *count = count.saturating_add(1));
 
 			Self::deposit_event(Event::NodeAdded { node_id, who: owner });
 			Ok(())
@@ -264,7 +306,9 @@ pub mod pallet {
 			nodes.remove(&node);
 
 			WellKnownNodes::<T>::put(&nodes);
-			<Owners<T>>::remove(&node);
+			if let Some(node_info) = <Owners<T>>::take(&node) {
+				Self::release_ownership(&node_info.owner);
+			}
 			AdditionalConnections::<T>::remove(&node);
 
 			Self::deposit_event(Event::NodeRemoved { node_id });
@@ -272,16 +316,20 @@ pub mod pallet {
 		}
 
 		/// Swap a well known node to another. Both the ownership and additional
-		/// connections stay untouched.
+		/// connections stay untouched. The owner proves the control of the
+		/// network key of the new node, as with `claim_node`.
 		///
 		/// - `remove`: the node which will be moved out from the list.
 		/// - `add`: the node which will be put in the list.
+		/// - `signature`: signature of the `ownership_proof_payload` of the sender, by the ed25519
+		///   network key of the `add` node.
 		#[pallet::call_index(2)]
 		#[pallet::weight((T::WeightInfo::swap_well_known_node(), DispatchClass::Operational))]
 		pub fn swap_well_known_node(
 			origin: OriginFor<T>,
 			remove_id: NodeId,
 			add_id: NodeId,
+			signature: ed25519::Signature,
 		) -> DispatchResult {
 			let sender = ensure_signed(origin)?;
 
@@ -307,6 +355,8 @@ pub mod pallet {
 			let mut nodes = WellKnownNodes::<T>::get();
 			ensure!(nodes.contains(&remove), Error::<T>::NotExist);
 			ensure!(!nodes.contains(&add), Error::<T>::AlreadyJoined);
+			ensure!(!Owners::<T>::contains_key(&add), Error::<T>::AlreadyClaimed);
+			Self::ensure_ownership_proof(&add, &sender, &signature)?;
 
 			nodes.remove(&remove);
 			nodes.insert(add.clone());
@@ -352,6 +402,8 @@ pub mod pallet {
 				BoundedVec::try_from(node_id.clone()).map_err(|_| Error::<T>::NodeIdTooLong)?;
 
 			<Owners<T>>::insert(&node, NodeInfoOf::<T> { id: node_id_bytes, owner: owner.clone() });
+			Self::release_ownership(&sender);
+			OwnedNodes::<T>::mutate(&owner, |count| *count = count.saturating_add(1));
 
 			Self::deposit_event(Event::NodeTransferred { node_id, target: owner });
 			Ok(())
@@ -429,6 +481,124 @@ pub mod pallet {
 			Self::deposit_event(Event::ConnectionsRemoved { node_id, connection: connection_id });
 			Ok(())
 		}
+
+		/// Claim a node by proving the control of its network key.
+		///
+		/// The node becomes a well known node if the member owns no more nodes
+		/// than the claim quota, and stays pending for governance approval
+		/// otherwise. The owner can manage the additional connections of the
+		/// node in both cases.
+		///
+		/// - `node`: identifier of the node.
+		/// - `signature`: signature of the `ownership_proof_payload` of the sender, by the ed25519
+		///   network key of the node.
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::claim_node())]
+		pub fn claim_node(
+			origin: OriginFor<T>,
+			node_id: NodeId,
+			signature: ed25519::Signature,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			ensure!(T::IsMember::is_member(&sender), Error::<T>::NotNetworkMember);
+
+			let node_id_bytes: BoundedVec<u8, T::MaxNodeIdLength> =
+				BoundedVec::try_from(node_id.clone()).map_err(|_| Error::<T>::NodeIdTooLong)?;
+			let node = Self::generate_peer_id(&node_id)?;
+			ensure!(node.0.len() < T::MaxPeerIdLength::get() as usize, Error::<T>::PeerIdTooLong);
+			ensure!(!Owners::<T>::contains_key(&node), Error::<T>::AlreadyClaimed);
+
+			Self::ensure_ownership_proof(&node, &sender, &signature)?;
+
+			let owned = OwnedNodes::<T>::mutate(&sender, |count| {
+				*count = count.saturating_add(1);
+				*count
+			});
+			<Owners<T>>::insert(
+				&node,
+				NodeInfoOf::<T> { id: node_id_bytes, owner: sender.clone() },
+			);
+
+			Self::deposit_event(Event::NodeClaimed { peer_id: node.clone(), who: sender.clone() });
+
+			let mut nodes = WellKnownNodes::<T>::get();
+			if owned <= ClaimQuota::<T>::get() &&
+				nodes.len() < T::MaxWellKnownNodes::get() as usize &&
+				nodes.insert(node)
+			{
+				WellKnownNodes::<T>::put(&nodes);
+				Self::deposit_event(Event::NodeAdded { node_id, who: sender });
+			}
+			Ok(())
+		}
+
+		/// Remove a pending claim. Well known nodes are removed through
+		/// `remove_well_known_node`.
+		///
+		/// - `node`: identifier of the node.
+		#[pallet::call_index(7)]
+		#[pallet::weight(T::WeightInfo::remove_claim())]
+		pub fn remove_claim(origin: OriginFor<T>, node_id: NodeId) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+
+			ensure!(node_id.len() < T::MaxNodeIdLength::get() as usize, Error::<T>::NodeIdTooLong);
+			let node = Self::generate_peer_id(&node_id)?;
+			ensure!(node.0.len() < T::MaxPeerIdLength::get() as usize, Error::<T>::PeerIdTooLong);
+
+			let node_info = Owners::<T>::get(&node).ok_or(Error::<T>::NotExist)?;
+			ensure!(node_info.owner == sender, Error::<T>::NotOwner);
+			ensure!(!WellKnownNodes::<T>::get().contains(&node), Error::<T>::PermissionDenied);
+
+			<Owners<T>>::remove(&node);
+			AdditionalConnections::<T>::remove(&node);
+			Self::release_ownership(&sender);
+
+			Self::deposit_event(Event::ClaimRemoved { peer_id: node, who: sender });
+			Ok(())
+		}
+
+		/// Approve a pending claim, adding the node to the set of well known
+		/// nodes.
+		///
+		/// May only be called from `T::NodeAuthorizationOrigin`.
+		///
+		/// - `node`: identifier of the node.
+		#[pallet::call_index(8)]
+		#[pallet::weight((T::WeightInfo::approve_claim(), DispatchClass::Operational))]
+		pub fn approve_claim(origin: OriginFor<T>, node_id: NodeId) -> DispatchResult {
+			T::NodeAuthorizationOrigin::ensure_origin(origin)?;
+
+			ensure!(node_id.len() < T::MaxNodeIdLength::get() as usize, Error::<T>::NodeIdTooLong);
+			let node = Self::generate_peer_id(&node_id)?;
+			ensure!(node.0.len() < T::MaxPeerIdLength::get() as usize, Error::<T>::PeerIdTooLong);
+
+			let node_info = Owners::<T>::get(&node).ok_or(Error::<T>::NotExist)?;
+
+			let mut nodes = WellKnownNodes::<T>::get();
+			ensure!(!nodes.contains(&node), Error::<T>::AlreadyJoined);
+			ensure!(nodes.len() < T::MaxWellKnownNodes::get() as usize, Error::<T>::TooManyNodes);
+			nodes.insert(node);
+			WellKnownNodes::<T>::put(&nodes);
+
+			Self::deposit_event(Event::NodeAdded { node_id, who: node_info.owner });
+			Ok(())
+		}
+
+		/// Set the number of nodes a member can own for its claims to be
+		/// approved without governance. A quota of zero requires governance
+		/// approval of every claim.
+		///
+		/// May only be called from `T::NodeAuthorizationOrigin`.
+		#[pallet::call_index(9)]
+		#[pallet::weight((T::WeightInfo::set_claim_quota(), DispatchClass::Operational))]
+		pub fn set_claim_quota(origin: OriginFor<T>, quota: u32) -> DispatchResult {
+			T::NodeAuthorizationOrigin::ensure_origin(origin)?;
+
+			ClaimQuota::<T>::put(quota);
+
+			Self::deposit_event(Event::ClaimQuotaSet { quota });
+			Ok(())
+		}
 	}
 }
 
@@ -457,6 +627,7 @@ impl<T: Config> Pallet<T> {
 							peer_id,
 							NodeInfoOf::<T> { id: node_id_bytes, owner: who.clone() },
 						);
+						OwnedNodes::<T>::mutate(who, |count| *count = count.saturating_add(1));
 					}
 				} else {
 					log::error!("Node ownership update failed!");
@@ -479,6 +650,40 @@ impl<T: Config> Pallet<T> {
 		Vec::from_iter(nodes)
 	}
 
+	fn release_ownership(owner: &T::AccountId) {
+		OwnedNodes::<T>::mutate_exists(owner, |count| {
+			*count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+		});
+	}
+
+	fn node_public_key(node: &PeerId) -> Option<ed25519::Public> {
+		let key = node.0.strip_prefix(&ED25519_PEER_ID_PREFIX[..])?;
+		ed25519::Public::try_from(key).ok()
+	}
+
+	/// Check that the signature by the network key of the node proves that
+	/// the account owns the node.
+	fn ensure_ownership_proof(
+		node: &PeerId,
+		who: &T::AccountId,
+		signature: &ed25519::Signature,
+	) -> DispatchResult {
+		let public = Self::node_public_key(node).ok_or(Error::<T>::InvalidNodeKey)?;
+		ensure!(
+			sp_io::crypto::ed25519_verify(signature, &Self::ownership_proof_payload(who), &public),
+			Error::<T>::BadOwnershipProof
+		);
+		Ok(())
+	}
+
+	/// The payload the network key of a node signs to prove that the account
+	/// owns the node. The domain tag and the genesis hash keep the proof
+	/// from being replayed in another context or on another chain.
+	pub fn ownership_proof_payload(who: &T::AccountId) -> Vec<u8> {
+		let genesis_hash = frame_system::Pallet::<T>::block_hash(BlockNumberFor::<T>::zero());
+		(OWNERSHIP_PROOF_TAG, genesis_hash, who).encode()
+	}
+
 	fn generate_peer_id(node_identity: &NodeId) -> Result<PeerId, Error<T>> {
 		let encoded = sp_std::str::from_utf8(node_identity).map_err(|_| Error::<T>::InvalidUtf8)?;
 		let decoded = bs58::decode(encoded)
@@ -490,3 +695,15 @@ impl<T: Config> Pallet<T> {
 		Ok(node)
 	}
 }
+
+impl<T: Config> cord_primitives::NodeOwnership<T::AccountId> for Pallet<T> {
+	fn node_owner(node_id: &NodeId) -> Option<T::AccountId> {
+		let node = Self::generate_peer_id(node_id).ok()?;
+		Owners::<T>::get(node).map(|info| info.owner)
+	}
+
+	fn is_well_known(node_id: &NodeId) -> bool {
+		Self::generate_peer_id(node_id)
+			.map_or(false, |node| WellKnownNodes::<T>::get().contains(&node))
+	}
+}
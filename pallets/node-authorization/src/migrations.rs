@@ -0,0 +1,58 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) 2019-2023 BOTLabs GmbH.
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+// Adapted to meet the requirements of the CORD project.
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Storage migrations of the node authorization pallet.
+
+use frame_support::{
+	pallet_prelude::*,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData};
+
+use crate::{Config, OwnedNodes, Owners, Pallet};
+
+/// Count the nodes owned by each account, for the nodes claimed before the
+/// number of owned nodes was tracked.
+pub struct CountOwnedNodes<T>(PhantomData<T>);
+
+impl<T: Config> OnRuntimeUpgrade for CountOwnedNodes<T> {
+	fn on_runtime_upgrade() -> Weight {
+		if Pallet::<T>::on_chain_storage_version() >= 1 {
+			return T::DbWeight::get().reads(1);
+		}
+
+		let mut owned = BTreeMap::<T::AccountId, u32>::new();
+		let mut nodes: u64 = 0;
+		for (_, info) in Owners::<T>::iter() {
+			let count = owned.entry(info.owner).or_default();
+			*count = count.saturating_add(1);
+			nodes = nodes.saturating_add(1);
+		}
+
+		let owners = owned.len() as u64;
+		for (owner, count) in owned {
+			OwnedNodes::<T>::insert(owner, count);
+		}
+
+		StorageVersion::new(1).put::<Pallet<T>>();
+
+		T::DbWeight::get().reads_writes(nodes + 1, owners + 1)
+	}
+}
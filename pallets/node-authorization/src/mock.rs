@@ -87,9 +87,17 @@ impl Config for Test {
 	type MaxNodeIdLength = ConstU32<54>;
 	type MaxPeerIdLength = ConstU32<128>;
 	type NodeAuthorizationOrigin = EnsureSignedBy<One, u64>;
+	type IsMember = TestIsNetworkMember;
 	type WeightInfo = ();
 }
 
+pub struct TestIsNetworkMember;
+impl IsMember<u64> for TestIsNetworkMember {
+	fn is_member(who: &u64) -> bool {
+		*who < 100
+	}
+}
+
 // Constants for test parameters
 pub const TEST_NODE_1: &str = "12D3KooWBmAwcd4PJNJvfV89HwE48nwkRmAgo8Vy3uQEyNNHBox2";
 pub const TEST_NODE_2: &str = "12D3KooWQYV9dGMFoRzNStwpXztXaBUjtPqi6aU76ZgUriHhKust";
@@ -109,6 +117,19 @@ pub fn generate_peer(input: &str) -> PeerId {
 	Pallet::<Test>::generate_peer_id(&node_id).unwrap()
 }
 
+/// The identifier and the ownership proof of a node whose network key is
+/// derived from the given seed.
+pub fn claimed_node(seed: u8, owner: u64) -> (NodeId, ed25519::Signature) {
+	use sp_core::Pair;
+
+	let pair = ed25519::Pair::from_seed(&[seed; 32]);
+	let mut peer_id = ED25519_PEER_ID_PREFIX.to_vec();
+	peer_id.extend_from_slice(pair.public().as_ref());
+	let node_id = bs58::encode(peer_id).into_string().into_bytes();
+
+	(node_id, pair.sign(&NodeAuthorization::ownership_proof_payload(&owner)))
+}
+
 pub fn genesis_node(id: u8) -> PeerId {
 	PeerId(vec![id])
 }
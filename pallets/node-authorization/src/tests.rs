@@ -22,7 +22,11 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_err, assert_noop, assert_ok};
+use frame_support::{
+	assert_err, assert_noop, assert_ok,
+	traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion},
+};
+use sp_core::H256;
 use sp_runtime::traits::BadOrigin;
 
 #[test]
@@ -44,6 +48,20 @@ fn check_genesis_well_known_nodes() {
 	});
 }
 
+#[test]
+fn node_ownership_works() {
+	use cord_primitives::NodeOwnership;
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(NodeAuthorization::node_owner(&test_node(TEST_NODE_1)), Some(10));
+		assert!(NodeAuthorization::is_well_known(&test_node(TEST_NODE_1)));
+
+		assert_eq!(NodeAuthorization::node_owner(&test_node(TEST_NODE_4)), None);
+		assert!(!NodeAuthorization::is_well_known(&test_node(TEST_NODE_4)));
+		assert!(!NodeAuthorization::is_well_known(&test_node(TEST_NODE_7)));
+	});
+}
+
 #[test]
 fn add_well_known_node_works() {
 	new_test_ext().execute_with(|| {
@@ -175,11 +193,13 @@ fn remove_well_known_node_works() {
 #[test]
 fn swap_well_known_node_works() {
 	new_test_ext().execute_with(|| {
+		let no_proof = ed25519::Signature::from_raw([0; 64]);
 		assert_noop!(
 			NodeAuthorization::swap_well_known_node(
 				RuntimeOrigin::signed(1),
 				test_node(TEST_NODE_1),
-				test_node(TEST_NODE_5)
+				test_node(TEST_NODE_5),
+				no_proof.clone()
 			),
 			Error::<Test>::NotOwner
 		);
@@ -187,7 +207,8 @@ fn swap_well_known_node_works() {
 			NodeAuthorization::swap_well_known_node(
 				RuntimeOrigin::signed(1),
 				test_node(TEST_NODE_LEN),
-				test_node(TEST_NODE_5)
+				test_node(TEST_NODE_5),
+				no_proof.clone()
 			),
 			Error::<Test>::NodeIdTooLong
 		);
@@ -195,7 +216,8 @@ fn swap_well_known_node_works() {
 			NodeAuthorization::swap_well_known_node(
 				RuntimeOrigin::signed(1),
 				test_node(TEST_NODE_7),
-				test_node(TEST_NODE_6)
+				test_node(TEST_NODE_6),
+				no_proof.clone()
 			),
 			Error::<Test>::InvalidNodeIdentifier
 		);
@@ -203,22 +225,23 @@ fn swap_well_known_node_works() {
 			NodeAuthorization::swap_well_known_node(
 				RuntimeOrigin::signed(3),
 				test_node(TEST_NODE_1),
-				test_node(TEST_NODE_LEN)
+				test_node(TEST_NODE_LEN),
+				no_proof.clone()
 			),
 			Error::<Test>::NodeIdTooLong
 		);
 
+		let (node_a, proof_a) = claimed_node(1, 10);
 		assert_ok!(NodeAuthorization::swap_well_known_node(
 			RuntimeOrigin::signed(10),
 			test_node(TEST_NODE_1),
-			test_node(TEST_NODE_4)
+			node_a.clone(),
+			proof_a
 		));
 
-		let expected_nodes = vec![
-			generate_peer(TEST_NODE_4),
-			generate_peer(TEST_NODE_2),
-			generate_peer(TEST_NODE_3),
-		];
+		let peer_a = Pallet::<Test>::generate_peer_id(&node_a).unwrap();
+		let expected_nodes =
+			vec![peer_a.clone(), generate_peer(TEST_NODE_2), generate_peer(TEST_NODE_3)];
 		let expected_set: BTreeSet<PeerId> = expected_nodes.into_iter().collect();
 
 		assert_eq!(WellKnownNodes::<Test>::get(), expected_set);
@@ -227,7 +250,8 @@ fn swap_well_known_node_works() {
 			NodeAuthorization::swap_well_known_node(
 				RuntimeOrigin::signed(3),
 				test_node(TEST_NODE_5),
-				test_node(TEST_NODE_1)
+				test_node(TEST_NODE_1),
+				no_proof.clone()
 			),
 			Error::<Test>::NotExist
 		);
@@ -235,7 +259,8 @@ fn swap_well_known_node_works() {
 			NodeAuthorization::swap_well_known_node(
 				RuntimeOrigin::signed(20),
 				test_node(TEST_NODE_2),
-				test_node(TEST_NODE_3)
+				test_node(TEST_NODE_3),
+				no_proof
 			),
 			Error::<Test>::AlreadyJoined
 		);
@@ -245,17 +270,16 @@ fn swap_well_known_node_works() {
 			BTreeSet::from_iter(vec![generate_peer(TEST_NODE_4)]),
 		);
 
+		let (node_b, proof_b) = claimed_node(2, 20);
 		assert_ok!(NodeAuthorization::swap_well_known_node(
 			RuntimeOrigin::signed(20),
 			test_node(TEST_NODE_2),
-			test_node(TEST_NODE_5)
+			node_b.clone(),
+			proof_b
 		));
 
-		let expected_nodes = vec![
-			generate_peer(TEST_NODE_4),
-			generate_peer(TEST_NODE_5),
-			generate_peer(TEST_NODE_3),
-		];
+		let peer_b = Pallet::<Test>::generate_peer_id(&node_b).unwrap();
+		let expected_nodes = vec![peer_a, peer_b.clone(), generate_peer(TEST_NODE_3)];
 		let expected_set: BTreeSet<PeerId> = expected_nodes.into_iter().collect();
 
 		assert_eq!(WellKnownNodes::<Test>::get(), expected_set);
@@ -264,16 +288,65 @@ fn swap_well_known_node_works() {
 		assert!(!AdditionalConnections::<Test>::contains_key(generate_peer(TEST_NODE_2)));
 
 		assert_eq!(
-			Owners::<Test>::get(generate_peer(TEST_NODE_5)),
-			Some(NodeInfo { id: test_node_id(TEST_NODE_5), owner: 20 })
+			Owners::<Test>::get(&peer_b),
+			Some(NodeInfo { id: BoundedVec::try_from(node_b).unwrap(), owner: 20 })
 		);
+		assert!(AdditionalConnections::<Test>::contains_key(&peer_b));
+	});
+}
 
-		assert!(Owners::<Test>::contains_key(generate_peer(TEST_NODE_5)));
-		assert!(AdditionalConnections::<Test>::contains_key(generate_peer(TEST_NODE_5)));
+#[test]
+fn swap_well_known_node_requires_ownership_proof() {
+	new_test_ext().execute_with(|| {
+		let (node_id, signature) = claimed_node(1, 10);
 
-		AdditionalConnections::<Test>::insert(
-			generate_peer(TEST_NODE_5),
-			BTreeSet::from_iter(vec![generate_peer(TEST_NODE_4)]),
+		// The proof of another account does not prove the ownership.
+		assert_noop!(
+			NodeAuthorization::swap_well_known_node(
+				RuntimeOrigin::signed(20),
+				test_node(TEST_NODE_2),
+				node_id.clone(),
+				signature.clone()
+			),
+			Error::<Test>::BadOwnershipProof
+		);
+		assert_noop!(
+			NodeAuthorization::swap_well_known_node(
+				RuntimeOrigin::signed(10),
+				test_node(TEST_NODE_1),
+				b"QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N".to_vec(),
+				signature
+			),
+			Error::<Test>::InvalidNodeKey
+		);
+	});
+}
+
+#[test]
+fn swap_well_known_node_rejects_claimed_nodes() {
+	new_test_ext().execute_with(|| {
+		// A pending claim of another member.
+		let (node_id, signature) = claimed_node(1, 40);
+		assert_ok!(NodeAuthorization::claim_node(
+			RuntimeOrigin::signed(40),
+			node_id.clone(),
+			signature
+		));
+
+		let (_, proof) = claimed_node(1, 10);
+		assert_noop!(
+			NodeAuthorization::swap_well_known_node(
+				RuntimeOrigin::signed(10),
+				test_node(TEST_NODE_1),
+				node_id.clone(),
+				proof
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_eq!(
+			Owners::<Test>::get(Pallet::<Test>::generate_peer_id(&node_id).unwrap())
+				.map(|info| info.owner),
+			Some(40)
 		);
 	});
 }
@@ -459,3 +532,170 @@ fn test_generate_peer_id_invalid_utf8() {
 	let invalid_node_id: NodeId = vec![0xFF, 0xFE, 0xFD];
 	assert_err!(NodeAuthorization::generate_peer_id(&invalid_node_id), Error::<Test>::InvalidUtf8);
 }
+
+#[test]
+fn claim_node_pending_approval_works() {
+	new_test_ext().execute_with(|| {
+		let (node_id, signature) = claimed_node(1, 40);
+		let node = NodeAuthorization::generate_peer_id(&node_id).unwrap();
+
+		assert_ok!(NodeAuthorization::claim_node(
+			RuntimeOrigin::signed(40),
+			node_id.clone(),
+			signature
+		));
+		assert_eq!(Owners::<Test>::get(&node).map(|info| info.owner), Some(40));
+		assert_eq!(OwnedNodes::<Test>::get(40), 1);
+		assert!(!WellKnownNodes::<Test>::get().contains(&node));
+
+		// The proven owner manages the connections of a pending node.
+		assert_ok!(NodeAuthorization::add_connection(
+			RuntimeOrigin::signed(40),
+			node_id.clone(),
+			test_node(TEST_NODE_1)
+		));
+
+		assert_noop!(
+			NodeAuthorization::approve_claim(RuntimeOrigin::signed(40), node_id.clone()),
+			BadOrigin
+		);
+		assert_ok!(NodeAuthorization::approve_claim(RuntimeOrigin::signed(1), node_id.clone()));
+		assert!(WellKnownNodes::<Test>::get().contains(&node));
+		assert_noop!(
+			NodeAuthorization::approve_claim(RuntimeOrigin::signed(1), node_id),
+			Error::<Test>::AlreadyJoined
+		);
+	});
+}
+
+#[test]
+fn claim_node_within_quota_is_approved() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(NodeAuthorization::set_claim_quota(RuntimeOrigin::signed(40), 1), BadOrigin);
+		assert_ok!(NodeAuthorization::set_claim_quota(RuntimeOrigin::signed(1), 1));
+
+		let (first, signature) = claimed_node(1, 40);
+		assert_ok!(NodeAuthorization::claim_node(
+			RuntimeOrigin::signed(40),
+			first.clone(),
+			signature
+		));
+		assert!(WellKnownNodes::<Test>::get()
+			.contains(&NodeAuthorization::generate_peer_id(&first).unwrap()));
+
+		// Above the quota, the claim waits for governance.
+		let (second, signature) = claimed_node(2, 40);
+		assert_ok!(NodeAuthorization::claim_node(
+			RuntimeOrigin::signed(40),
+			second.clone(),
+			signature
+		));
+		assert!(!WellKnownNodes::<Test>::get()
+			.contains(&NodeAuthorization::generate_peer_id(&second).unwrap()));
+		assert_eq!(OwnedNodes::<Test>::get(40), 2);
+	});
+}
+
+#[test]
+fn claim_node_requires_ownership_proof() {
+	new_test_ext().execute_with(|| {
+		let (node_id, signature) = claimed_node(1, 40);
+
+		assert_noop!(
+			NodeAuthorization::claim_node(
+				RuntimeOrigin::signed(100),
+				node_id.clone(),
+				signature.clone()
+			),
+			Error::<Test>::NotNetworkMember
+		);
+		assert_noop!(
+			NodeAuthorization::claim_node(
+				RuntimeOrigin::signed(41),
+				node_id.clone(),
+				signature.clone()
+			),
+			Error::<Test>::BadOwnershipProof
+		);
+		assert_noop!(
+			NodeAuthorization::claim_node(
+				RuntimeOrigin::signed(40),
+				b"QmYyQSo1c1Ym7orWxLYvCrM2EmxFTANf8wXmmE7DWjhx5N".to_vec(),
+				signature.clone()
+			),
+			Error::<Test>::InvalidNodeKey
+		);
+		assert_noop!(
+			NodeAuthorization::claim_node(
+				RuntimeOrigin::signed(40),
+				test_node(TEST_NODE_1),
+				signature
+			),
+			Error::<Test>::AlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn claim_node_rejects_replayed_proof() {
+	use sp_core::Pair;
+
+	new_test_ext().execute_with(|| {
+		let pair = ed25519::Pair::from_seed(&[1; 32]);
+		let (node_id, _) = claimed_node(1, 40);
+
+		// A signature of the bare account, or for another chain, is not a proof.
+		let bare = pair.sign(&40u64.encode());
+		assert_noop!(
+			NodeAuthorization::claim_node(RuntimeOrigin::signed(40), node_id.clone(), bare),
+			Error::<Test>::BadOwnershipProof
+		);
+		let other_chain = pair.sign(&(OWNERSHIP_PROOF_TAG, H256::repeat_byte(1), 40u64).encode());
+		assert_noop!(
+			NodeAuthorization::claim_node(RuntimeOrigin::signed(40), node_id, other_chain),
+			Error::<Test>::BadOwnershipProof
+		);
+	});
+}
+
+#[test]
+fn migration_counts_owned_nodes() {
+	new_test_ext().execute_with(|| {
+		OwnedNodes::<Test>::remove(10);
+		OwnedNodes::<Test>::remove(20);
+		StorageVersion::new(0).put::<NodeAuthorization>();
+
+		migrations::CountOwnedNodes::<Test>::on_runtime_upgrade();
+
+		assert_eq!(OwnedNodes::<Test>::get(10), 1);
+		assert_eq!(OwnedNodes::<Test>::get(20), 1);
+		assert_eq!(NodeAuthorization::on_chain_storage_version(), 1);
+	});
+}
+
+#[test]
+fn remove_claim_works() {
+	new_test_ext().execute_with(|| {
+		let (node_id, signature) = claimed_node(1, 40);
+		assert_ok!(NodeAuthorization::claim_node(
+			RuntimeOrigin::signed(40),
+			node_id.clone(),
+			signature
+		));
+
+		assert_noop!(
+			NodeAuthorization::remove_claim(RuntimeOrigin::signed(41), node_id.clone()),
+			Error::<Test>::NotOwner
+		);
+		assert_noop!(
+			NodeAuthorization::remove_claim(RuntimeOrigin::signed(10), test_node(TEST_NODE_1)),
+			Error::<Test>::PermissionDenied
+		);
+
+		assert_ok!(NodeAuthorization::remove_claim(RuntimeOrigin::signed(40), node_id.clone()));
+		assert!(
+			Owners::<Test>::get(NodeAuthorization::generate_peer_id(&node_id).unwrap()).is_none()
+		);
+		assert_eq!(OwnedNodes::<Test>::get(40), 0);
+	});
+}
@@ -31,6 +31,10 @@ pub trait WeightInfo {
 	fn transfer_node() -> Weight;
 	fn add_connection() -> Weight;
 	fn remove_connection() -> Weight;
+	fn claim_node() -> Weight;
+	fn remove_claim() -> Weight;
+	fn approve_claim() -> Weight;
+	fn set_claim_quota() -> Weight;
 }
 
 impl WeightInfo for () {
@@ -40,4 +44,8 @@ impl WeightInfo for () {
 	fn transfer_node() -> Weight { Weight::from_parts(50_000_000, 0) }
 	fn add_connection() -> Weight { Weight::from_parts(50_000_000, 0) }
 	fn remove_connection() -> Weight { Weight::from_parts(50_000_000, 0) }
+	fn claim_node() -> Weight { Weight::from_parts(50_000_000, 0) }
+	fn remove_claim() -> Weight { Weight::from_parts(50_000_000, 0) }
+	fn approve_claim() -> Weight { Weight::from_parts(50_000_000, 0) }
+	fn set_claim_quota() -> Weight { Weight::from_parts(50_000_000, 0) }
 }
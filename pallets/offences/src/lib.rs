@@ -18,15 +18,16 @@
 
 //! This is a fork of the Substrate `offences` pallet that is modified to agree
 //! with the offence rules based on the `cord-authority-membershiper` pallet and
-//! not in the Substrate `staking` pallet. Cord provides a basic way to process
-//! offences:
+//! not in the Substrate `staking` pallet.
+//!
+//! The offences triage is realized in the `offences` pallet. The new offenders
+//! are passed with the offence kind to the `OnOffenceHandler`, which selects a
+//! `SlashStrategy` from its offence policy and executes it. Without a policy
+//! for the kind, `SlashStrategy::default_for` applies:
 //!
 //! - On offences from `im-online` pallet, the offender disconnection is required.
 //! - On other offences, the offender disconnection is required and the offender is required to be
 //!   blacklisted and only an authorized origin can remove the offender from the blacklist.
-//!
-//! The offences triage is realized in the `offences` pallet and the slashing
-//! execution is done in the `cord-authority-membership` pallet.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -38,10 +39,14 @@ mod tests;
 
 use core::marker::PhantomData;
 
-use codec::Encode;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::weights::Weight;
-use sp_runtime::traits::Hash;
-use sp_staking::offence::{Kind, Offence, OffenceDetails, OffenceError, ReportOffence};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Hash, RuntimeDebug};
+use sp_staking::{
+	offence::{Kind, Offence, OffenceDetails, OffenceError, ReportOffence},
+	SessionIndex,
+};
 use sp_std::prelude::*;
 
 pub use pallet::*;
@@ -55,11 +60,43 @@ type OpaqueTimeSlot = Vec<u8>;
 /// A type alias for a report identifier.
 type ReportIdOf<T> = <T as frame_system::Config>::Hash;
 
+/// An offence report along with the kind and the encoded time slot of the
+/// offence.
+pub type ReportOf<T> = (
+	Kind,
+	OpaqueTimeSlot,
+	OffenceDetails<<T as frame_system::Config>::AccountId, <T as Config>::IdentificationTuple>,
+);
+
+/// The offence kind reported by the `im-online` pallet.
+pub const IM_ONLINE_OFFLINE: Kind = *b"im-online:offlin";
+
+/// The penalty applied to an offender.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 pub enum SlashStrategy {
+	/// Record the offence without further penalty.
+	Warn,
+	/// Disconnect the offender, which can go online again.
 	Disconnect,
+	/// Disconnect the offender for the given number of sessions, after which
+	/// it is reinstated automatically.
+	Suspend(SessionIndex),
+	/// Disconnect and blacklist the offender, until an authorized origin
+	/// removes it from the blacklist.
 	BlackList,
 }
 
+impl SlashStrategy {
+	/// The strategy applied to an offence kind without an offence policy.
+	pub fn default_for(kind: &Kind) -> Self {
+		if *kind == IM_ONLINE_OFFLINE {
+			SlashStrategy::Disconnect
+		} else {
+			SlashStrategy::BlackList
+		}
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -128,24 +165,15 @@ where
 
 		// Go through all offenders in the offence report and find all offenders that
 		// were spotted in unique reports.
-		let TriageOutcome { concurrent_offenders } =
+		let TriageOutcome { new_offenders } =
 			match Self::triage_offence_report::<O>(reporters, &time_slot, offenders) {
 				Some(triage) => triage,
 				None => return Err(OffenceError::DuplicateReport),
 			};
 
-		// Define the slash strategy.
-		let slash_strategy = if O::ID == *b"im-online:offlin" {
-			SlashStrategy::Disconnect
-		} else {
-			SlashStrategy::BlackList
-		};
-
-		T::OnOffenceHandler::on_offence(
-			&concurrent_offenders,
-			slash_strategy,
-			offence.session_index(),
-		);
+		// The slash strategy is selected by the handler, based on the offence kind
+		// and the offence history of each offender.
+		T::OnOffenceHandler::on_offence(&new_offenders, O::ID, offence.session_index());
 
 		Self::deposit_event(Event::Offence { kind: O::ID, timeslot: time_slot.encode() });
 
@@ -163,6 +191,21 @@ where
 }
 
 impl<T: Config> Pallet<T> {
+	/// All the offence reports, along with the kind and the encoded time slot
+	/// of the offence.
+	///
+	/// This iterates over the whole report index and is meant for off-chain
+	/// queries only.
+	pub fn reports() -> Vec<ReportOf<T>> {
+		<ConcurrentReportsIndex<T>>::iter()
+			.flat_map(|(kind, time_slot, report_ids)| {
+				report_ids.into_iter().filter_map(move |report_id| {
+					<Reports<T>>::get(report_id).map(|details| (kind, time_slot.clone(), details))
+				})
+			})
+			.collect()
+	}
+
 	/// Compute the ID for the given report properties.
 	///
 	/// The report id depends on the offence kind, time slot and the id of
@@ -175,8 +218,7 @@ impl<T: Config> Pallet<T> {
 	}
 
 	/// Triages the offence report and returns the set of offenders that was
-	/// involved in unique reports along with the list of the concurrent
-	/// offences.
+	/// involved in unique reports.
 	fn triage_offence_report<O: Offence<T::IdentificationTuple>>(
 		reporters: Vec<T::AccountId>,
 		time_slot: &O::TimeSlot,
@@ -184,41 +226,32 @@ impl<T: Config> Pallet<T> {
 	) -> Option<TriageOutcome<T>> {
 		let mut storage = ReportIndexStorage::<T, O>::load(time_slot);
 
-		let mut any_new = false;
+		let mut new_offenders = Vec::new();
 		for offender in offenders {
 			let report_id = Self::report_id::<O>(time_slot, &offender);
 
 			if !<Reports<T>>::contains_key(report_id) {
-				any_new = true;
-				<Reports<T>>::insert(
-					report_id,
-					OffenceDetails { offender, reporters: reporters.clone() },
-				);
+				let details = OffenceDetails { offender, reporters: reporters.clone() };
+				<Reports<T>>::insert(report_id, &details);
+				new_offenders.push(details);
 
 				storage.insert(report_id);
 			}
 		}
 
-		if any_new {
-			// Load report details for the all reports happened at the same time.
-			let concurrent_offenders = storage
-				.concurrent_reports
-				.iter()
-				.filter_map(<Reports<T>>::get)
-				.collect::<Vec<_>>();
-
+		if new_offenders.is_empty() {
+			None
+		} else {
 			storage.save();
 
-			Some(TriageOutcome { concurrent_offenders })
-		} else {
-			None
+			Some(TriageOutcome { new_offenders })
 		}
 	}
 }
 
 struct TriageOutcome<T: Config> {
-	/// Other reports for the same report kinds.
-	concurrent_offenders: Vec<OffenceDetails<T::AccountId, T::IdentificationTuple>>,
+	/// The offenders which were not reported yet for the time slot.
+	new_offenders: Vec<OffenceDetails<T::AccountId, T::IdentificationTuple>>,
 }
 
 /// An auxiliary struct for working with storage of indexes localized for a
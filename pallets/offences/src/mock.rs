@@ -18,7 +18,7 @@
 
 #![cfg(test)]
 
-use crate::{self as pallet_offences, Config, SlashStrategy};
+use crate::{self as pallet_offences, Config};
 use codec::Encode;
 use frame_support::{derive_impl, parameter_types, weights::Weight};
 use sp_runtime::{BuildStorage, Perbill};
@@ -39,7 +39,7 @@ impl<Reporter, Offender> pallet_offences::OnOffenceHandler<Reporter, Offender, W
 {
 	fn on_offence(
 		_offenders: &[OffenceDetails<Reporter, Offender>],
-		_strategy: SlashStrategy,
+		_kind: Kind,
 		_offence_session: SessionIndex,
 	) -> Weight {
 		OffenceWeight::get()
@@ -234,3 +234,23 @@ fn should_properly_count_offences() {
 		);
 	});
 }
+
+#[test]
+fn should_list_all_reports() {
+	new_test_ext().execute_with(|| {
+		let offence = Offence { validator_set_count: 5, time_slot: 42, offenders: vec![5] };
+		Offences::report_offence(vec![1], offence).unwrap();
+		let offence = Offence { validator_set_count: 5, time_slot: 43, offenders: vec![5] };
+		Offences::report_offence(vec![2], offence).unwrap();
+
+		let mut reports = Offences::reports();
+		reports.sort_by(|a, b| a.1.cmp(&b.1));
+		assert_eq!(
+			reports,
+			vec![
+				(KIND, 42u128.encode(), OffenceDetails { offender: 5, reporters: vec![1] }),
+				(KIND, 43u128.encode(), OffenceDetails { offender: 5, reporters: vec![2] }),
+			]
+		);
+	});
+}
@@ -16,15 +16,18 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
-use sp_staking::{offence::OffenceDetails, SessionIndex};
-
-use crate::SlashStrategy;
+use sp_staking::{
+	offence::{Kind, OffenceDetails},
+	SessionIndex,
+};
 
+/// A handler of the reported offences, which selects and applies the
+/// `SlashStrategy` of each offender.
 pub trait OnOffenceHandler<Reporter, Offender, Res> {
 	// Required method
 	fn on_offence(
 		offenders: &[OffenceDetails<Reporter, Offender>],
-		slash_strategy: SlashStrategy,
+		kind: Kind,
 		session: SessionIndex,
 	) -> Res;
 }
@@ -106,7 +106,11 @@ use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_runtime::{
+	traits::{Hash, UniqueSaturatedInto},
+	DispatchError,
+};
+use sp_std::vec::Vec;
 
 /// Registry Authorization Identifier
 pub type RegistryAuthorizationIdOf = Ss58Identifier;
@@ -120,6 +124,8 @@ pub type RegistryIdOf = Ss58Identifier;
 pub type RegistryHashOf<T> = <T as frame_system::Config>::Hash;
 /// Type of the Registry Creator
 pub type RegistryCreatorOf<T> = <T as frame_system::Config>::AccountId;
+/// Type of the Namespace Digest
+pub type NameSpaceHashOf<T> = pallet_namespace::NameSpaceHashOf<T>;
 /// Type of the Registry Template Id
 pub type TemplateIdOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedInputLength>;
 /// Type of the Schema Id
@@ -278,6 +284,34 @@ pub mod pallet {
 		RegistryArchived,
 	}
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Registries anchored at genesis, with their digest, the digest of
+		/// their namespace and their creator. The namespace must be part of
+		/// the namespace genesis, with the same creator.
+		pub registries: Vec<(RegistryHashOf<T>, NameSpaceHashOf<T>, RegistryCreatorOf<T>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (digest, namespace_digest, creator) in &self.registries {
+				let namespace_id =
+					pallet_namespace::Pallet::<T>::namespace_id(namespace_digest, creator)
+						.expect("Invalid namespace identifier of a genesis registry");
+				// The namespace pallet builds before this one and anchors the
+				// namespaces of the genesis registries.
+				assert!(
+					pallet_namespace::NameSpaces::<T>::contains_key(&namespace_id),
+					"Genesis registry references an unknown namespace"
+				);
+				Pallet::<T>::anchor_registry(*digest, namespace_id, creator.clone(), None)
+					.expect("Genesis registry is already anchored");
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Adds a delegate with permission to assert new entries to a registry.
@@ -615,76 +649,8 @@ pub mod pallet {
 			)
 			.map_err(<pallet_namespace::Error<T>>::from)?;
 
-			// Id Digest = concat (H(<scale_encoded_registry_input_digest>,
-			// <scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
-			);
-
-			let identifier = Ss58Identifier::create_identifier(
-				&id_digest.encode()[..],
-				IdentifierType::Registries,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(
-				!<RegistryInfo<T>>::contains_key(&identifier),
-				Error::<T>::RegistryAlreadyAnchored
-			);
-
-			// Construct the authorization_id from the provided parameters.
-			// Id Digest = concat (H(<scale_encoded_registry_identifier>,
-			// <scale_encoded_creator_identifier> ))
-			let auth_id_digest = T::Hashing::hash(
-				&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let authorization_id = Ss58Identifier::create_identifier(
-				&auth_id_digest.encode(),
-				IdentifierType::RegistryAuthorization,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			let mut delegates: BoundedVec<RegistryCreatorOf<T>, T::MaxRegistryDelegates> =
-				BoundedVec::default();
-			delegates
-				.try_push(creator.clone())
-				.map_err(|_| Error::<T>::RegistryDelegatesLimitExceeded)?;
-
-			Delegates::<T>::insert(&identifier, delegates);
-
-			Authorizations::<T>::insert(
-				&authorization_id,
-				RegistryAuthorizationOf::<T> {
-					registry_id: identifier.clone(),
-					delegate: creator.clone(),
-					permissions: Permissions::all(),
-					delegator: creator.clone(),
-				},
-			);
-
-			<RegistryInfo<T>>::insert(
-				&identifier,
-				RegistryDetailsOf::<T> {
-					creator: creator.clone(),
-					revoked: false,
-					archived: false,
-					digest,
-					namespace_id: namespace_id.clone(),
-					schema_id,
-				},
-			);
-
-			// Update the namespace with the newly added registry.
-			pallet_namespace::Pallet::<T>::add_registry_id_to_namespace_details(
-				&namespace_id,
-				&identifier,
-			)
-			.map_err(<pallet_namespace::Error<T>>::from)?;
-
-			Self::update_activity(&identifier, IdentifierTypeOf::Registries, CallTypeOf::Genesis)
-				.map_err(Error::<T>::from)?;
+			let (identifier, authorization_id) =
+				Self::anchor_registry(digest, namespace_id, creator.clone(), schema_id)?;
 
 			Self::deposit_event(Event::Create {
 				registry_id: identifier,
@@ -1077,6 +1043,84 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Anchors a new registry in a namespace, with the creator as its first
+	/// delegate.
+	///
+	/// Used by `create`, once the namespace authorization of the creator has
+	/// been checked, and by the genesis build.
+	pub(crate) fn anchor_registry(
+		digest: RegistryHashOf<T>,
+		namespace_id: NameSpaceIdOf,
+		creator: RegistryCreatorOf<T>,
+		schema_id: Option<SchemaIdOf>,
+	) -> Result<(RegistryIdOf, RegistryAuthorizationIdOf), DispatchError> {
+		// Id Digest = concat (H(<scale_encoded_registry_input_digest>,
+		// <scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&digest.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&id_digest.encode()[..], IdentifierType::Registries)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<RegistryInfo<T>>::contains_key(&identifier), Error::<T>::RegistryAlreadyAnchored);
+
+		// Construct the authorization_id from the provided parameters.
+		// Id Digest = concat (H(<scale_encoded_registry_identifier>,
+		// <scale_encoded_creator_identifier> ))
+		let auth_id_digest = T::Hashing::hash(
+			&[&identifier.encode()[..], &creator.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let authorization_id = Ss58Identifier::create_identifier(
+			&auth_id_digest.encode(),
+			IdentifierType::RegistryAuthorization,
+		)
+		.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		let mut delegates: BoundedVec<RegistryCreatorOf<T>, T::MaxRegistryDelegates> =
+			BoundedVec::default();
+		delegates
+			.try_push(creator.clone())
+			.map_err(|_| Error::<T>::RegistryDelegatesLimitExceeded)?;
+
+		Delegates::<T>::insert(&identifier, delegates);
+
+		Authorizations::<T>::insert(
+			&authorization_id,
+			RegistryAuthorizationOf::<T> {
+				registry_id: identifier.clone(),
+				delegate: creator.clone(),
+				permissions: Permissions::all(),
+				delegator: creator.clone(),
+			},
+		);
+
+		<RegistryInfo<T>>::insert(
+			&identifier,
+			RegistryDetailsOf::<T> {
+				creator: creator.clone(),
+				revoked: false,
+				archived: false,
+				digest,
+				namespace_id: namespace_id.clone(),
+				schema_id,
+			},
+		);
+
+		// Update the namespace with the newly added registry.
+		pallet_namespace::Pallet::<T>::add_registry_id_to_namespace_details(
+			&namespace_id,
+			&identifier,
+		)
+		.map_err(<pallet_namespace::Error<T>>::from)?;
+
+		Self::update_activity(&identifier, IdentifierTypeOf::Registries, CallTypeOf::Genesis)?;
+
+		Ok((identifier, authorization_id))
+	}
+
 	/// Adds a delegate to a registry with specified permissions.
 	///
 	/// This function will add a new delegate to a registry, given the registry's ID,
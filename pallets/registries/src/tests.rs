@@ -4,7 +4,7 @@ use codec::Encode;
 use frame_support::{assert_err, assert_ok};
 use pallet_namespace::{NameSpaceCodeOf, NameSpaceIdOf};
 use pallet_schema_accounts::{InputSchemaOf, SchemaHashOf};
-use sp_runtime::traits::Hash;
+use sp_runtime::{traits::Hash, BuildStorage};
 use sp_std::prelude::*;
 
 pub fn generate_registry_id<T: Config>(digest: &RegistryHashOf<T>) -> RegistryIdOf {
@@ -2991,3 +2991,57 @@ fn ensure_authorization_admin_remove_origin_should_fail_if_authorization_is_not_
 		);
 	});
 }
+
+#[test]
+fn genesis_build_should_anchor_registries_in_genesis_namespaces() {
+	let creator = ACCOUNT_00;
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(b"genesis-namespace");
+	let namespace_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&namespace_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let namespace_id: NameSpaceIdOf = generate_namespace_id::<Test>(&namespace_id_digest);
+
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(b"genesis-registry");
+	let registry_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&registry_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let registry_id: RegistryIdOf = generate_registry_id::<Test>(&registry_id_digest);
+
+	let t = RuntimeGenesisConfig {
+		name_space: pallet_namespace::GenesisConfig {
+			namespaces: vec![(namespace_digest, creator.clone())],
+		},
+		registries: GenesisConfig {
+			registries: vec![(registry_digest, namespace_digest, creator.clone())],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let registry = RegistryInfo::<Test>::get(&registry_id).unwrap();
+		assert_eq!(registry.creator, creator);
+		assert_eq!(registry.namespace_id, namespace_id);
+		assert_eq!(registry.schema_id, None);
+
+		let namespace = pallet_namespace::NameSpaces::<Test>::get(&namespace_id).unwrap();
+		assert_eq!(namespace.registry_ids.unwrap().into_inner(), vec![registry_id]);
+	});
+}
+
+#[test]
+#[should_panic = "Genesis registry references an unknown namespace"]
+fn genesis_build_should_reject_registries_of_unknown_namespaces() {
+	let namespace_digest = <Test as frame_system::Config>::Hashing::hash(b"genesis-namespace");
+	let registry_digest = <Test as frame_system::Config>::Hashing::hash(b"genesis-registry");
+
+	RuntimeGenesisConfig {
+		registries: GenesisConfig {
+			registries: vec![(registry_digest, namespace_digest, ACCOUNT_00)],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+}
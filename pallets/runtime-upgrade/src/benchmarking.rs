@@ -0,0 +1,68 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use crate::Pallet;
+use frame_benchmarking::{account, benchmarks, BenchmarkError};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use sp_runtime::traits::Hash;
+use sp_std::vec;
+
+const SEED: u32 = 0;
+/// Max size of the runtime code.
+const MAX_CODE_SIZE: u32 = 3 * 1024 * 1024;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
+	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
+}
+
+benchmarks! {
+	authorize_upgrade {
+		let origin = T::SetCodeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let code_hash = T::Hashing::hash(&[1u8]);
+	}: _<T::RuntimeOrigin>(origin, code_hash)
+	verify {
+		assert_last_event::<T>(Event::UpgradeAuthorized { code_hash }.into());
+	}
+
+	upload_code {
+		let l in 0 .. MAX_CODE_SIZE;
+
+		let caller: T::AccountId = account("caller", 0, SEED);
+		let code = vec![1u8; l as usize];
+		let code_hash = T::Hashing::hash(&code);
+		AuthorizedUpgrade::<T>::put(code_hash);
+	}: _(RawOrigin::Signed(caller), code)
+	verify {
+		assert!(ScheduledUpgrade::<T>::exists());
+	}
+
+	cancel_upgrade {
+		let origin = T::SetCodeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let code = vec![1u8; MAX_CODE_SIZE as usize];
+		let code_hash = T::Hashing::hash(&code);
+		PendingCode::<T>::put(code);
+		ScheduledUpgrade::<T>::put(UpgradeSchedule { code_hash, apply_at: 1u32.into() });
+	}: _<T::RuntimeOrigin>(origin)
+	verify {
+		assert_last_event::<T>(Event::UpgradeCancelled { code_hash }.into());
+	}
+}
@@ -20,25 +20,115 @@
 //!
 //! A wrapper pallet for frame_system set_code action. This call upgrades the
 //! runtime.
+//!
+//! Besides the direct `set_code` call, the pallet supports a staged upgrade
+//! flow:
+//! - The `SetCodeOrigin` authorizes the hash of the new code with `authorize_upgrade`.
+//! - Anyone uploads the code matching the authorized hash with `upload_code`. The code must
+//!   decompress into a runtime with the same spec name and a higher spec version than the current
+//!   one.
+//! - The upgrade is applied `UpgradeDelay` blocks after the upload, at the start of the block.
+//!
+//! A pending upgrade can be cancelled by the `SetCodeOrigin` with
+//! `cancel_upgrade` until it is applied.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod weights;
+
+#[cfg(any(feature = "mock", test))]
+pub mod mock;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarking;
+
+#[cfg(test)]
+pub mod tests;
+
+pub mod types;
+
+pub use crate::types::*;
 pub use pallet::*;
+pub use weights::WeightInfo;
 
 #[frame_support::pallet]
 pub mod pallet {
-	use frame_support::pallet_prelude::*;
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::SetCode};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::{
+		traits::{Hash, One},
+		Saturating,
+	};
 	use sp_std::vec::Vec;
 
+	pub type UpgradeScheduleOf<T> =
+		UpgradeSchedule<<T as frame_system::Config>::Hash, BlockNumberFor<T>>;
+
 	#[pallet::pallet]
 	#[pallet::without_storage_info]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 		/// Origin for runtime upgrades
 		type SetCodeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		/// Number of blocks between the upload of an authorized code and its
+		/// application.
+		#[pallet::constant]
+		type UpgradeDelay: Get<BlockNumberFor<Self>>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The code hash authorized for the next upload.
+	#[pallet::storage]
+	pub type AuthorizedUpgrade<T: Config> = StorageValue<_, T::Hash, OptionQuery>;
+
+	/// The uploaded upgrade waiting to be applied.
+	#[pallet::storage]
+	pub type ScheduledUpgrade<T: Config> = StorageValue<_, UpgradeScheduleOf<T>, OptionQuery>;
+
+	/// The code of the scheduled upgrade.
+	#[pallet::storage]
+	pub type PendingCode<T: Config> = StorageValue<_, Vec<u8>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A code hash has been authorized for upload.
+		UpgradeAuthorized { code_hash: T::Hash },
+		/// The authorized code has been uploaded and scheduled.
+		UpgradeScheduled { code_hash: T::Hash, apply_at: BlockNumberFor<T> },
+		/// The pending upgrade has been cancelled.
+		UpgradeCancelled { code_hash: T::Hash },
+		/// The scheduled upgrade has been applied.
+		UpgradeApplied { code_hash: T::Hash },
+		/// The scheduled upgrade could not be applied and has been dropped.
+		UpgradeFailed { code_hash: T::Hash, error: DispatchError },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// No code hash has been authorized.
+		NothingAuthorized,
+		/// The code does not match the authorized code hash.
+		Unauthorized,
+		/// An upgrade is already scheduled.
+		UpgradeAlreadyScheduled,
+		/// There is no pending upgrade to cancel.
+		NothingToCancel,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+			match ScheduledUpgrade::<T>::get() {
+				Some(schedule) if schedule.apply_at <= n => Self::apply_upgrade(schedule),
+				_ => T::DbWeight::get().reads(1),
+			}
+		}
 	}
 
 	#[pallet::call]
@@ -51,5 +141,92 @@ pub mod pallet {
 			frame_system::Pallet::<T>::set_code(frame_system::RawOrigin::Root.into(), code)?;
 			Ok(Pays::No.into())
 		}
+
+		/// Authorize the hash of the code for the next runtime upgrade.
+		///
+		/// Replaces any previous authorization which has not been uploaded
+		/// yet. Fails if an upgrade is already scheduled.
+		#[pallet::call_index(1)]
+		#[pallet::weight((<T as Config>::WeightInfo::authorize_upgrade(), DispatchClass::Operational))]
+		pub fn authorize_upgrade(origin: OriginFor<T>, code_hash: T::Hash) -> DispatchResult {
+			T::SetCodeOrigin::ensure_origin(origin)?;
+			ensure!(!ScheduledUpgrade::<T>::exists(), Error::<T>::UpgradeAlreadyScheduled);
+
+			AuthorizedUpgrade::<T>::put(code_hash);
+
+			Self::deposit_event(Event::UpgradeAuthorized { code_hash });
+
+			Ok(())
+		}
+
+		/// Upload the code matching the authorized hash and schedule it to be
+		/// applied after `UpgradeDelay` blocks.
+		///
+		/// The code must decompress into a runtime with the same spec name
+		/// and a higher spec version than the current one. The call is free
+		/// when successful.
+		#[pallet::call_index(2)]
+		#[pallet::weight((<T as Config>::WeightInfo::upload_code(code.len() as u32), DispatchClass::Operational))]
+		pub fn upload_code(origin: OriginFor<T>, code: Vec<u8>) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let authorized = AuthorizedUpgrade::<T>::get().ok_or(Error::<T>::NothingAuthorized)?;
+			let code_hash = T::Hashing::hash(&code);
+			ensure!(code_hash == authorized, Error::<T>::Unauthorized);
+			ensure!(!ScheduledUpgrade::<T>::exists(), Error::<T>::UpgradeAlreadyScheduled);
+
+			frame_system::Pallet::<T>::can_set_code(&code)?;
+
+			let apply_at = frame_system::Pallet::<T>::block_number()
+				.saturating_add(T::UpgradeDelay::get().max(One::one()));
+
+			AuthorizedUpgrade::<T>::kill();
+			PendingCode::<T>::put(code);
+			ScheduledUpgrade::<T>::put(UpgradeSchedule { code_hash, apply_at });
+
+			Self::deposit_event(Event::UpgradeScheduled { code_hash, apply_at });
+
+			Ok(Pays::No.into())
+		}
+
+		/// Cancel the authorized or scheduled upgrade.
+		#[pallet::call_index(3)]
+		#[pallet::weight((<T as Config>::WeightInfo::cancel_upgrade(), DispatchClass::Operational))]
+		pub fn cancel_upgrade(origin: OriginFor<T>) -> DispatchResult {
+			T::SetCodeOrigin::ensure_origin(origin)?;
+
+			let code_hash = ScheduledUpgrade::<T>::take()
+				.map(|schedule| schedule.code_hash)
+				.or_else(AuthorizedUpgrade::<T>::get)
+				.ok_or(Error::<T>::NothingToCancel)?;
+
+			AuthorizedUpgrade::<T>::kill();
+			PendingCode::<T>::kill();
+
+			Self::deposit_event(Event::UpgradeCancelled { code_hash });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Apply the scheduled upgrade. The code is checked again, as the
+		/// runtime may have been upgraded since the upload.
+		pub(crate) fn apply_upgrade(schedule: UpgradeScheduleOf<T>) -> Weight {
+			ScheduledUpgrade::<T>::kill();
+			let code = PendingCode::<T>::take().unwrap_or_default();
+
+			let result = frame_system::Pallet::<T>::can_set_code(&code)
+				.and_then(|_| <T as frame_system::Config>::OnSetCode::set_code(code));
+
+			let code_hash = schedule.code_hash;
+			match result {
+				Ok(()) => Self::deposit_event(Event::UpgradeApplied { code_hash }),
+				Err(error) => Self::deposit_event(Event::UpgradeFailed { code_hash, error }),
+			}
+
+			// Same as the set_code() extrinsic from frame_system.
+			T::BlockWeights::get().max_block
+		}
 	}
 }
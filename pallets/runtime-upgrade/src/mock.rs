@@ -0,0 +1,106 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{self as pallet_runtime_upgrade};
+use codec::Encode;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{OnFinalize, OnInitialize},
+};
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot};
+use sp_core::traits::{Externalities, ReadRuntimeVersion, ReadRuntimeVersionExt};
+use sp_runtime::{
+	traits::{IdentifyAccount, IdentityLookup, Verify},
+	BuildStorage, MultiSignature,
+};
+use sp_version::RuntimeVersion;
+
+type Signature = MultiSignature;
+type AccountPublic = <Signature as Verify>::Signer;
+pub type AccountId = <AccountPublic as IdentifyAccount>::AccountId;
+pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		RuntimeUpgrade: pallet_runtime_upgrade,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+}
+
+parameter_types! {
+	pub const UpgradeDelay: BlockNumberFor<Test> = 5;
+}
+
+impl pallet_runtime_upgrade::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type SetCodeOrigin = EnsureRoot<AccountId>;
+	type UpgradeDelay = UpgradeDelay;
+	type WeightInfo = ();
+}
+
+/// Code which does not decompress into a runtime.
+pub(crate) const INVALID_CODE: &[u8] = b"invalid";
+/// Code of a runtime with the same spec version as the current one.
+pub(crate) const OLD_CODE: &[u8] = b"old";
+/// Code of a runtime with a higher spec version than the current one.
+pub(crate) const NEW_CODE: &[u8] = b"new";
+
+/// Reads the runtime version from the mock codes above.
+struct MockReadRuntimeVersion;
+
+impl ReadRuntimeVersion for MockReadRuntimeVersion {
+	fn read_runtime_version(
+		&self,
+		wasm_code: &[u8],
+		_ext: &mut dyn Externalities,
+	) -> Result<Vec<u8>, String> {
+		let spec_version = match wasm_code {
+			OLD_CODE => 0,
+			NEW_CODE => 1,
+			_ => return Err("failed to decompress the code".into()),
+		};
+		Ok(RuntimeVersion { spec_version, ..Default::default() }.encode())
+	}
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.register_extension(ReadRuntimeVersionExt::new(MockReadRuntimeVersion));
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+pub(crate) fn run_to_block(n: BlockNumberFor<Test>) {
+	while System::block_number() < n {
+		let block_number = System::block_number();
+		RuntimeUpgrade::on_finalize(block_number);
+		System::on_finalize(block_number);
+		System::set_block_number(block_number + 1);
+		System::on_initialize(block_number + 1);
+		RuntimeUpgrade::on_initialize(block_number + 1);
+	}
+}
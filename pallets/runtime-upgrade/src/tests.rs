@@ -0,0 +1,160 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use crate::{
+	mock::*, AuthorizedUpgrade, Error, Event, PendingCode, ScheduledUpgrade, UpgradeSchedule,
+};
+use frame_support::{assert_noop, assert_ok, error::BadOrigin};
+use sp_core::storage::well_known_keys;
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+fn alice() -> AccountId {
+	AccountId::new([1u8; 32])
+}
+
+fn authorize(code: &[u8]) -> <Test as frame_system::Config>::Hash {
+	let code_hash = BlakeTwo256::hash(code);
+	assert_ok!(RuntimeUpgrade::authorize_upgrade(RuntimeOrigin::root(), code_hash));
+	code_hash
+}
+
+#[test]
+fn authorize_upgrade_works() {
+	new_test_ext().execute_with(|| {
+		let code_hash = authorize(NEW_CODE);
+
+		assert_eq!(AuthorizedUpgrade::<Test>::get(), Some(code_hash));
+		System::assert_last_event(Event::UpgradeAuthorized { code_hash }.into());
+	});
+}
+
+#[test]
+fn authorize_upgrade_requires_set_code_origin() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::authorize_upgrade(
+				RuntimeOrigin::signed(alice()),
+				BlakeTwo256::hash(NEW_CODE)
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn upload_code_schedules_upgrade() {
+	new_test_ext().execute_with(|| {
+		let code_hash = authorize(NEW_CODE);
+
+		assert_ok!(RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), NEW_CODE.to_vec()));
+
+		assert_eq!(AuthorizedUpgrade::<Test>::get(), None);
+		assert_eq!(PendingCode::<Test>::get(), Some(NEW_CODE.to_vec()));
+		assert_eq!(
+			ScheduledUpgrade::<Test>::get(),
+			Some(UpgradeSchedule { code_hash, apply_at: 6 })
+		);
+		System::assert_last_event(Event::UpgradeScheduled { code_hash, apply_at: 6 }.into());
+	});
+}
+
+#[test]
+fn upload_code_checks_authorization() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), NEW_CODE.to_vec()),
+			Error::<Test>::NothingAuthorized
+		);
+
+		authorize(NEW_CODE);
+		assert_noop!(
+			RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), OLD_CODE.to_vec()),
+			Error::<Test>::Unauthorized
+		);
+	});
+}
+
+#[test]
+fn upload_code_checks_runtime_version() {
+	new_test_ext().execute_with(|| {
+		authorize(OLD_CODE);
+		assert_noop!(
+			RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), OLD_CODE.to_vec()),
+			frame_system::Error::<Test>::SpecVersionNeedsToIncrease
+		);
+
+		authorize(INVALID_CODE);
+		assert_noop!(
+			RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), INVALID_CODE.to_vec()),
+			frame_system::Error::<Test>::FailedToExtractRuntimeVersion
+		);
+	});
+}
+
+#[test]
+fn authorize_upgrade_fails_when_upgrade_is_scheduled() {
+	new_test_ext().execute_with(|| {
+		authorize(NEW_CODE);
+		assert_ok!(RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), NEW_CODE.to_vec()));
+
+		assert_noop!(
+			RuntimeUpgrade::authorize_upgrade(RuntimeOrigin::root(), BlakeTwo256::hash(OLD_CODE)),
+			Error::<Test>::UpgradeAlreadyScheduled
+		);
+	});
+}
+
+#[test]
+fn scheduled_upgrade_is_applied() {
+	new_test_ext().execute_with(|| {
+		let code_hash = authorize(NEW_CODE);
+		assert_ok!(RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), NEW_CODE.to_vec()));
+
+		run_to_block(5);
+		assert!(sp_io::storage::get(well_known_keys::CODE).is_none());
+
+		run_to_block(6);
+		assert_eq!(
+			sp_io::storage::get(well_known_keys::CODE).map(|c| c.to_vec()),
+			Some(NEW_CODE.to_vec())
+		);
+		assert_eq!(ScheduledUpgrade::<Test>::get(), None);
+		assert_eq!(PendingCode::<Test>::get(), None);
+		System::assert_has_event(Event::UpgradeApplied { code_hash }.into());
+	});
+}
+
+#[test]
+fn cancel_upgrade_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			RuntimeUpgrade::cancel_upgrade(RuntimeOrigin::root()),
+			Error::<Test>::NothingToCancel
+		);
+
+		let code_hash = authorize(NEW_CODE);
+		assert_ok!(RuntimeUpgrade::upload_code(RuntimeOrigin::signed(alice()), NEW_CODE.to_vec()));
+
+		assert_noop!(RuntimeUpgrade::cancel_upgrade(RuntimeOrigin::signed(alice())), BadOrigin);
+		assert_ok!(RuntimeUpgrade::cancel_upgrade(RuntimeOrigin::root()));
+		System::assert_last_event(Event::UpgradeCancelled { code_hash }.into());
+
+		run_to_block(6);
+		assert!(sp_io::storage::get(well_known_keys::CODE).is_none());
+	});
+}
@@ -0,0 +1,160 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `pallet_runtime_upgrade`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
+//! DATE: 2024-05-17, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/production/cord
+// benchmark
+// pallet
+// --chain=dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_runtime_upgrade
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=./pallets/runtime-upgrade/src/weights.rs
+// --header=./HEADER-GPL3
+// --template=./.maintain/frame-weight-template.hbs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_runtime_upgrade`.
+pub trait WeightInfo {
+	fn authorize_upgrade() -> Weight;
+	fn upload_code(l: u32, ) -> Weight;
+	fn cancel_upgrade() -> Weight;
+}
+
+/// Weights for `pallet_runtime_upgrade` using the CORD node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `RuntimeUpgrade::ScheduledUpgrade` (r:1 w:0)
+	/// Proof: `RuntimeUpgrade::ScheduledUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::AuthorizedUpgrade` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::AuthorizedUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn authorize_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 6_212_000 picoseconds.
+		Weight::from_parts(6_492_000, 1485)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RuntimeUpgrade::AuthorizedUpgrade` (r:1 w:1)
+	/// Proof: `RuntimeUpgrade::AuthorizedUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::ScheduledUpgrade` (r:1 w:1)
+	/// Proof: `RuntimeUpgrade::ScheduledUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RuntimeUpgrade::PendingCode` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::PendingCode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `l` is `[0, 3145728]`.
+	fn upload_code(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 13_381_000 picoseconds.
+		Weight::from_parts(13_790_000, 1527)
+			// Standard Error: 2
+			.saturating_add(Weight::from_parts(1_361, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `RuntimeUpgrade::ScheduledUpgrade` (r:1 w:1)
+	/// Proof: `RuntimeUpgrade::ScheduledUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::AuthorizedUpgrade` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::AuthorizedUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::PendingCode` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::PendingCode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1561`
+		// Minimum execution time: 9_058_000 picoseconds.
+		Weight::from_parts(9_441_000, 1561)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	/// Storage: `RuntimeUpgrade::ScheduledUpgrade` (r:1 w:0)
+	/// Proof: `RuntimeUpgrade::ScheduledUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::AuthorizedUpgrade` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::AuthorizedUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn authorize_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1485`
+		// Minimum execution time: 6_212_000 picoseconds.
+		Weight::from_parts(6_492_000, 1485)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `RuntimeUpgrade::AuthorizedUpgrade` (r:1 w:1)
+	/// Proof: `RuntimeUpgrade::AuthorizedUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::ScheduledUpgrade` (r:1 w:1)
+	/// Proof: `RuntimeUpgrade::ScheduledUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `System::Number` (r:1 w:0)
+	/// Proof: `System::Number` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `RuntimeUpgrade::PendingCode` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::PendingCode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// The range of component `l` is `[0, 3145728]`.
+	fn upload_code(l: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `1527`
+		// Minimum execution time: 13_381_000 picoseconds.
+		Weight::from_parts(13_790_000, 1527)
+			// Standard Error: 2
+			.saturating_add(Weight::from_parts(1_361, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	/// Storage: `RuntimeUpgrade::ScheduledUpgrade` (r:1 w:1)
+	/// Proof: `RuntimeUpgrade::ScheduledUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::AuthorizedUpgrade` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::AuthorizedUpgrade` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `RuntimeUpgrade::PendingCode` (r:0 w:1)
+	/// Proof: `RuntimeUpgrade::PendingCode` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn cancel_upgrade() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `76`
+		//  Estimated: `1561`
+		// Minimum execution time: 9_058_000 picoseconds.
+		Weight::from_parts(9_441_000, 1561)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+}
@@ -52,11 +52,13 @@ pub mod benchmarking;
 #[cfg(test)]
 pub mod tests;
 
+use codec::Encode;
 use identifier::{
 	types::{CallTypeOf, IdentifierTypeOf, Timepoint},
 	EventEntryOf,
 };
-use sp_runtime::traits::UniqueSaturatedInto;
+use sp_runtime::traits::{Hash, UniqueSaturatedInto};
+use sp_std::vec::Vec;
 
 /// Extra Types for Schema
 pub mod types;
@@ -71,7 +73,7 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	pub use identifier::{IdentifierCreator, IdentifierTimeline, IdentifierType, Ss58Identifier};
-	use sp_runtime::{traits::Hash, SaturatedConversion};
+	use sp_runtime::SaturatedConversion;
 
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
@@ -88,6 +90,8 @@ pub mod pallet {
 	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 	/// Type of a Schema creator.
 	pub type SchemaCreatorOf<T> = pallet_chain_space::SpaceCreatorOf<T>;
+	/// Code of the space of a schema.
+	pub type SpaceCodeOf<T> = pallet_chain_space::SpaceCodeOf<T>;
 	/// Type for an input schema
 	pub type InputSchemaOf<T> = BoundedVec<u8, <T as Config>::MaxEncodedSchemaLength>;
 	/// Type for a schema entry
@@ -149,6 +153,31 @@ pub mod pallet {
 		EmptyTransaction,
 	}
 
+	#[pallet::genesis_config]
+	#[derive(frame_support::DefaultNoBound)]
+	pub struct GenesisConfig<T: Config> {
+		/// Schemas anchored at genesis, as the code and creator of the space
+		/// they belong to along with the schema.
+		pub schemas: Vec<(SpaceCodeOf<T>, SchemaCreatorOf<T>, Vec<u8>)>,
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+		fn build(&self) {
+			for (space_code, creator, schema) in &self.schemas {
+				let space_id = pallet_chain_space::Pallet::<T>::space_id(space_code, creator)
+					.expect("Invalid space identifier of a genesis schema");
+				let schema: InputSchemaOf<T> = schema
+					.clone()
+					.try_into()
+					.expect("Genesis schema exceeds the maximum encoded length");
+				assert!(!schema.is_empty(), "Genesis schema is empty");
+				Pallet::<T>::anchor_schema(schema, space_id, creator.clone())
+					.expect("Genesis schema is already anchored");
+			}
+		}
+	}
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create a new schema and associates with its identifier.
@@ -183,45 +212,7 @@ pub mod pallet {
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
-			// Id Digest = concat (H(<scale_encoded_schema_input>,
-			// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
-			let id_digest = <T as frame_system::Config>::Hashing::hash(
-				&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()
-					[..],
-			);
-
-			let identifier = Ss58Identifier::create_identifier(
-				&(id_digest).encode()[..],
-				IdentifierType::Schema,
-			)
-			.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
-
-			ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
-
-			let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
-			let block_number = frame_system::Pallet::<T>::block_number();
-
-			log::debug!(
-				"Schema created with identifier: {:?}, schema: {:?} digest: {:?}, creator:
-			{:?}, block_number: {:?}",
-				identifier,
-				tx_schema,
-				digest,
-				creator,
-				block_number
-			);
-
-			<Schemas<T>>::insert(
-				&identifier,
-				SchemaEntryOf::<T> {
-					schema: tx_schema,
-					digest,
-					creator: creator.clone(),
-					space: space_id,
-				},
-			);
-
-			Self::update_activity(&identifier, CallTypeOf::Genesis).map_err(<Error<T>>::from)?;
+			let identifier = Self::anchor_schema(tx_schema, space_id, creator.clone())?;
 
 			Self::deposit_event(Event::Created { identifier, creator });
 
@@ -231,6 +222,55 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Anchors a schema in a space.
+	///
+	/// Used by `create`, once the authorization of the creator has been
+	/// checked, and by the genesis build.
+	pub(crate) fn anchor_schema(
+		tx_schema: InputSchemaOf<T>,
+		space_id: SpaceIdOf,
+		creator: SchemaCreatorOf<T>,
+	) -> Result<SchemaIdOf, Error<T>> {
+		// Id Digest = concat (H(<scale_encoded_schema_input>,
+		// <<scale_encoded_space_identifier>, scale_encoded_creator_identifier>))
+		let id_digest = <T as frame_system::Config>::Hashing::hash(
+			&[&tx_schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+		);
+
+		let identifier =
+			Ss58Identifier::create_identifier(&(id_digest).encode()[..], IdentifierType::Schema)
+				.map_err(|_| Error::<T>::InvalidIdentifierLength)?;
+
+		ensure!(!<Schemas<T>>::contains_key(&identifier), Error::<T>::SchemaAlreadyAnchored);
+
+		let digest = <T as frame_system::Config>::Hashing::hash(&tx_schema[..]);
+		let block_number = frame_system::Pallet::<T>::block_number();
+
+		log::debug!(
+			"Schema created with identifier: {:?}, schema: {:?} digest: {:?}, creator:
+			{:?}, block_number: {:?}",
+			identifier,
+			tx_schema,
+			digest,
+			creator,
+			block_number
+		);
+
+		<Schemas<T>>::insert(
+			&identifier,
+			SchemaEntryOf::<T> {
+				schema: tx_schema,
+				digest,
+				creator: creator.clone(),
+				space: space_id,
+			},
+		);
+
+		Self::update_activity(&identifier, CallTypeOf::Genesis)?;
+
+		Ok(identifier)
+	}
+
 	/// `ensure!` is a macro that takes a boolean expression and an error type.
 	/// If the expression is false, it returns the error
 	///
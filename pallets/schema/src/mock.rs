@@ -19,7 +19,7 @@
 use crate as pallet_schema;
 use cord_primitives::IsPermissioned;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
 use frame_system::EnsureRoot;
 use sp_runtime::{
 	traits::{IdentifyAccount, IdentityLookup, Verify},
@@ -93,8 +93,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxTierNameLength = ConstU32<32>;
+	type MaxSpacesPerBatch = ConstU32<10>;
+	type MaxCapacityResetsPerBlock = ConstU32<10>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type NetworkParameters = ();
+	type IdentityVerifier = ();
+	type CreatorAccount = ();
 	type WeightInfo = ();
 }
 
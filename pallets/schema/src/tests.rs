@@ -23,7 +23,7 @@ use cord_utilities::mock::{mock_origin::DoubleOrigin, SubjectId};
 use frame_support::{assert_err, assert_noop, assert_ok, BoundedVec};
 use frame_system::RawOrigin;
 use sp_core::H256;
-use sp_runtime::{traits::Hash, AccountId32};
+use sp_runtime::{traits::Hash, AccountId32, BuildStorage};
 use sp_std::prelude::*;
 const DEFAULT_SCHEMA_HASH_SEED: u64 = 1u64;
 const ALTERNATIVE_SCHEMA_HASH_SEED: u64 = 2u64;
@@ -346,3 +346,42 @@ fn check_schema_not_found() {
 		assert_err!(Schema::is_valid(&schema_id), Error::<Test>::SchemaNotFound);
 	});
 }
+
+// This test verifies that the schemas of the genesis configuration are
+// anchored in the spaces anchored at genesis.
+#[test]
+fn check_genesis_schema_creation() {
+	let creator = DID_00;
+	let raw_space = [2u8; 256].to_vec();
+	let space_digest = <Test as frame_system::Config>::Hashing::hash(&raw_space.encode()[..]);
+	let space_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&space_digest.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let space_id: SpaceIdOf = generate_space_id::<Test>(&space_id_digest);
+
+	let raw_schema = [3u8; 256].to_vec();
+	let schema: InputSchemaOf<Test> = BoundedVec::try_from(raw_schema.clone())
+		.expect("Test Schema should fit into the expected input length of for the test runtime.");
+	let schema_id_digest = <Test as frame_system::Config>::Hashing::hash(
+		&[&schema.encode()[..], &space_id.encode()[..], &creator.encode()[..]].concat()[..],
+	);
+	let schema_id: SchemaIdOf = generate_schema_id::<Test>(&schema_id_digest);
+
+	let t = RuntimeGenesisConfig {
+		schema: GenesisConfig { schemas: vec![(space_digest, creator.clone(), raw_schema)] },
+		space: pallet_chain_space::GenesisConfig {
+			spaces: vec![(space_digest, creator.clone(), 5)],
+		},
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(t).execute_with(|| {
+		let stored_schema = Schemas::<Test>::get(&schema_id)
+			.expect("Schema Identifier should be present on chain.");
+		assert_eq!(stored_schema.creator, creator);
+		assert_eq!(stored_schema.space, space_id);
+		assert!(pallet_chain_space::Spaces::<Test>::get(&space_id).unwrap().approved);
+	});
+}
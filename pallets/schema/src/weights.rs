@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -63,6 +67,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// The range of component `l` is `[1, 15360]`.
 	fn create(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -72,8 +77,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(37_960_558, 19007)
 			// Standard Error: 10
 			.saturating_add(Weight::from_parts(3_232, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 }
 
@@ -87,6 +92,7 @@ impl WeightInfo for () {
 	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// The range of component `l` is `[1, 15360]`.
 	fn create(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -96,7 +102,7 @@ impl WeightInfo for () {
 		Weight::from_parts(37_960_558, 19007)
 			// Standard Error: 10
 			.saturating_add(Weight::from_parts(3_232, 0).saturating_mul(l.into()))
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 }
@@ -80,7 +80,7 @@ pub mod mock;
 #[cfg(test)]
 pub mod tests;
 
-use cord_primitives::StatusOf;
+use cord_primitives::{NetworkParameters, StatusOf};
 use frame_support::{ensure, storage::types::StorageMap};
 use sp_runtime::traits::UniqueSaturatedInto;
 use sp_std::{prelude::Clone, str};
@@ -144,7 +144,8 @@ pub mod pallet {
 			Success = <Self as Config>::OriginSuccess,
 		>;
 		type OriginSuccess: CallSources<AccountIdOf<Self>, StatementCreatorOf<Self>>;
-		/// Maximum entires supported per batch call
+		/// Maximum entires supported per batch call, unless overridden by the
+		/// network parameters.
 		#[pallet::constant]
 		type MaxDigestsPerBatch: Get<u16>;
 		/// Maximum removals per call
@@ -900,8 +901,11 @@ pub mod pallet {
 			)
 			.map_err(<pallet_chain_space::Error<T>>::from)?;
 
+			let max_digests =
+				<T as pallet_chain_space::Config>::NetworkParameters::max_digests_per_batch()
+					.unwrap_or_else(T::MaxDigestsPerBatch::get);
 			ensure!(
-				digests.len() <= T::MaxDigestsPerBatch::get() as usize,
+				digests.len() <= max_digests as usize,
 				Error::<T>::MaxDigestLimitExceeded
 			);
 
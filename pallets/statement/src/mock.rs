@@ -19,7 +19,7 @@
 use super::*;
 use crate as pallet_statement;
 use cord_utilities::mock::{mock_origin, SubjectId};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{derive_impl, parameter_types, traits::ConstU32};
 use pallet_chain_space::IsPermissioned;
 
 use frame_system::EnsureRoot;
@@ -98,8 +98,14 @@ impl pallet_chain_space::Config for Test {
 	type OriginSuccess = mock_origin::DoubleOrigin<AccountId, SubjectId>;
 	type SpaceCreatorId = SubjectId;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxTierNameLength = ConstU32<32>;
+	type MaxSpacesPerBatch = ConstU32<10>;
+	type MaxCapacityResetsPerBlock = ConstU32<10>;
 	type ChainSpaceOrigin = EnsureRoot<AccountId>;
 	type NetworkPermission = NetworkPermission;
+	type NetworkParameters = ();
+	type IdentityVerifier = ();
+	type CreatorAccount = ();
 	type WeightInfo = ();
 }
 
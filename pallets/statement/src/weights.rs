@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `cord-benchmark-16gb`, CPU: `AMD EPYC 7B13`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: `1024`
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -70,6 +74,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:1)
@@ -80,8 +85,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3671`
 		// Minimum execution time: 41_811_000 picoseconds.
 		Weight::from_parts(42_809_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -95,6 +100,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
@@ -103,8 +109,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3671`
 		// Minimum execution time: 50_250_000 picoseconds.
 		Weight::from_parts(53_920_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -116,14 +122,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1031`
 		//  Estimated: `3671`
 		// Minimum execution time: 40_320_000 picoseconds.
 		Weight::from_parts(41_710_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -135,14 +142,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1199`
 		//  Estimated: `3671`
 		// Minimum execution time: 41_480_000 picoseconds.
 		Weight::from_parts(42_729_000, 3671)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -154,6 +162,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 5120]`.
@@ -163,8 +172,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `6216`
 		// Minimum execution time: 65_530_000 picoseconds.
 		Weight::from_parts(68_143_442, 6216)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -174,6 +183,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:3 w:3)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:3 w:3)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:3)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:3)
@@ -185,8 +195,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `9012`
 		// Minimum execution time: 75_360_000 picoseconds.
 		Weight::from_parts(78_226_686, 9012)
-			.saturating_add(T::DbWeight::get().reads(8_u64))
-			.saturating_add(T::DbWeight::get().writes(13_u64))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(16_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -200,6 +210,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn add_presentation() -> Weight {
@@ -208,8 +219,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3686`
 		// Minimum execution time: 44_760_000 picoseconds.
 		Weight::from_parts(45_750_000, 3686)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -219,6 +230,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn remove_presentation() -> Weight {
@@ -227,8 +239,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		//  Estimated: `3686`
 		// Minimum execution time: 42_580_000 picoseconds.
 		Weight::from_parts(43_370_000, 3686)
-			.saturating_add(T::DbWeight::get().reads(4_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
 	}
 }
 
@@ -242,6 +254,7 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:1)
@@ -252,8 +265,8 @@ impl WeightInfo for () {
 		//  Estimated: `3671`
 		// Minimum execution time: 41_811_000 picoseconds.
 		Weight::from_parts(42_809_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -267,6 +280,7 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
@@ -275,8 +289,8 @@ impl WeightInfo for () {
 		//  Estimated: `3671`
 		// Minimum execution time: 50_250_000 picoseconds.
 		Weight::from_parts(53_920_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -288,14 +302,15 @@ impl WeightInfo for () {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1031`
 		//  Estimated: `3671`
 		// Minimum execution time: 40_320_000 picoseconds.
 		Weight::from_parts(41_710_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -307,14 +322,15 @@ impl WeightInfo for () {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1199`
 		//  Estimated: `3671`
 		// Minimum execution time: 41_480_000 picoseconds.
 		Weight::from_parts(42_729_000, 3671)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -326,6 +342,7 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 5120]`.
@@ -335,8 +352,8 @@ impl WeightInfo for () {
 		//  Estimated: `6216`
 		// Minimum execution time: 65_530_000 picoseconds.
 		Weight::from_parts(68_143_442, 6216)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -346,6 +363,7 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:3 w:3)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:3 w:3)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:3)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:3)
@@ -357,8 +375,8 @@ impl WeightInfo for () {
 		//  Estimated: `9012`
 		// Minimum execution time: 75_360_000 picoseconds.
 		Weight::from_parts(78_226_686, 9012)
-			.saturating_add(RocksDbWeight::get().reads(8_u64))
-			.saturating_add(RocksDbWeight::get().writes(13_u64))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(16_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -372,6 +390,7 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn add_presentation() -> Weight {
@@ -380,8 +399,8 @@ impl WeightInfo for () {
 		//  Estimated: `3686`
 		// Minimum execution time: 44_760_000 picoseconds.
 		Weight::from_parts(45_750_000, 3686)
-			.saturating_add(RocksDbWeight::get().reads(6_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -391,6 +410,7 @@ impl WeightInfo for () {
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn remove_presentation() -> Weight {
@@ -399,7 +419,7 @@ impl WeightInfo for () {
 		//  Estimated: `3686`
 		// Minimum execution time: 42_580_000 picoseconds.
 		Weight::from_parts(43_370_000, 3686)
-			.saturating_add(RocksDbWeight::get().reads(4_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
 	}
 }
@@ -22,7 +22,7 @@
 use sp_runtime::{
 	generic,
 	traits::{BlakeTwo256, IdentifyAccount, Verify},
-	MultiSignature, OpaqueExtrinsic,
+	DispatchError, DispatchResult, MultiSignature, OpaqueExtrinsic,
 };
 use sp_std::vec::Vec;
 
@@ -101,3 +101,85 @@ pub const AUTHORSHIP_PERIOD: u32 = 20;
 pub trait IsPermissioned {
 	fn is_permissioned() -> bool;
 }
+
+/// Trait definition for network parameters which can be changed at runtime.
+///
+/// A `None` value means that the parameter has not been set on chain, and the
+/// caller falls back to its compile-time default.
+pub trait NetworkParameters<BlockNumber> {
+	/// Transaction capacity assigned to chain spaces approved on creation.
+	fn default_space_capacity() -> Option<u64>;
+	/// Max number of digests accepted in a statement batch.
+	fn max_digests_per_batch() -> Option<u16>;
+	/// Life span of an expiring network membership, in blocks.
+	fn membership_period() -> Option<BlockNumber>;
+}
+
+impl<BlockNumber> NetworkParameters<BlockNumber> for () {
+	fn default_space_capacity() -> Option<u64> {
+		None
+	}
+	fn max_digests_per_batch() -> Option<u16> {
+		None
+	}
+	fn membership_period() -> Option<BlockNumber> {
+		None
+	}
+}
+
+/// Trait definition for identity verification by registrars.
+pub trait IdentityVerifier<AccountId> {
+	/// Whether the identity of the account holds a positive judgement.
+	fn has_verified_identity(who: &AccountId) -> bool;
+	/// Whether the identity of the account holds a positive judgement from
+	/// the given registrar.
+	fn has_judgement_from(who: &AccountId, registrar: &AccountId) -> bool;
+}
+
+impl<AccountId> IdentityVerifier<AccountId> for () {
+	fn has_verified_identity(_who: &AccountId) -> bool {
+		false
+	}
+	fn has_judgement_from(_who: &AccountId, _registrar: &AccountId) -> bool {
+		false
+	}
+}
+
+/// Trait definition for the usernames held by identities.
+pub trait UsernameRegistry<AccountId> {
+	/// Register the username for the account, if needed, and set it as the
+	/// primary username of the account. The username must have the
+	/// `name@domain` form, e.g. `alice@cord` or `hr@acme.cord`.
+	fn set_primary_username(who: &AccountId, username: Vec<u8>) -> DispatchResult;
+	/// Remove the username from the account, if the account holds it.
+	fn remove_username(who: &AccountId, username: &[u8]);
+	/// Consume one allocation of the username authority and return its
+	/// suffix.
+	fn use_authority_allocation(authority: &AccountId) -> Result<Vec<u8>, DispatchError>;
+	/// The account holding the username, if any.
+	fn account_of_username(username: &[u8]) -> Option<AccountId>;
+	/// Register the account as a username authority for the suffix.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add_authority(_authority: &AccountId, _suffix: Vec<u8>, _allocation: u32) {}
+}
+
+impl<AccountId> UsernameRegistry<AccountId> for () {
+	fn set_primary_username(_who: &AccountId, _username: Vec<u8>) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+	fn remove_username(_who: &AccountId, _username: &[u8]) {}
+	fn use_authority_allocation(_authority: &AccountId) -> Result<Vec<u8>, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+	fn account_of_username(_username: &[u8]) -> Option<AccountId> {
+		None
+	}
+}
+
+/// Trait definition for the ownership of network nodes.
+pub trait NodeOwnership<AccountId> {
+	/// The owner of the node, if any.
+	fn node_owner(node_id: &NodeId) -> Option<AccountId>;
+	/// Whether the node is a well known node.
+	fn is_well_known(node_id: &NodeId) -> bool;
+}
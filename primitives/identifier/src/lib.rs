@@ -26,7 +26,8 @@ pub use crate::curi::{
 	CordIdentifierType, IdentifierCreator, IdentifierError, IdentifierTimeline, IdentifierType,
 	Ss58Identifier,
 };
-use sp_runtime::BoundedVec;
+use codec::Encode;
+use sp_runtime::{BoundedVec, Digest, DigestItem};
 use sp_std::{prelude::Clone, str};
 pub mod types;
 pub use crate::types::*;
@@ -74,7 +75,26 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Taking the entries of the block in `on_finalize`.
+			T::DbWeight::get().reads_writes(1, 1)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let entries = BlockEntries::<T>::take();
+			if !entries.is_empty() {
+				// Only written by the nodes running with offchain indexing enabled.
+				sp_io::offchain_index::set(
+					&timeline_block_key(
+						&frame_system::Pallet::<T>::parent_hash(),
+						&frame_system::Pallet::<T>::digest(),
+					),
+					&entries.encode(),
+				);
+			}
+		}
+	}
 
 	#[pallet::storage]
 	pub type Identifiers<T: Config> = StorageDoubleMap<
@@ -87,6 +107,32 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Number of entries ever added to a timeline. The on-chain history only
+	/// keeps the latest ones, the complete history is written to the offchain
+	/// index under the position of each entry.
+	#[pallet::storage]
+	pub type TimelineLength<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		IdentifierOf,
+		Twox64Concat,
+		IdentifierTypeOf,
+		u64,
+		OptionQuery,
+	>;
+
+	/// Timeline entries added in the current block, along with their
+	/// timelines and positions. Written to the offchain index and cleared at
+	/// the end of the block, never kept in the state.
+	#[pallet::storage]
+	#[pallet::unbounded]
+	#[pallet::whitelist_storage]
+	pub(super) type BlockEntries<T: Config> = StorageValue<
+		_,
+		sp_std::vec::Vec<(IdentifierOf, IdentifierTypeOf, u64, EventEntryOf)>,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		// Max exvents history exceeded
@@ -94,7 +140,50 @@ pub mod pallet {
 	}
 }
 
+/// Offchain index prefix of the timeline entries added by a block.
+const TIMELINE_BLOCK_PREFIX: &[u8] = b"cord-timeline/block/";
+/// Offchain index prefix of the timeline entries.
+const TIMELINE_ENTRY_PREFIX: &[u8] = b"cord-timeline/entry/";
+/// Offchain index prefix of the timeline lengths.
+const TIMELINE_LENGTH_PREFIX: &[u8] = b"cord-timeline/length/";
+
+/// Offchain index key of the timeline entries added by a block, from the hash
+/// of its parent and its pre-runtime digests.
+///
+/// The hash of the block is not known while it is built. The blocks of
+/// different forks are told apart by their parent, and sibling blocks by the
+/// slot and author found in their pre-runtime digests, so the entries of a
+/// fork never overwrite those of another one. The node moves the entries of
+/// the finalized blocks to the keys of [`timeline_entry_key`] and
+/// [`timeline_length_key`].
+pub fn timeline_block_key<H: Encode>(parent_hash: &H, digest: &Digest) -> sp_std::vec::Vec<u8> {
+	let pre_runtime = digest
+		.logs
+		.iter()
+		.filter(|item| matches!(item, DigestItem::PreRuntime(..)))
+		.collect::<sp_std::vec::Vec<_>>();
+	[TIMELINE_BLOCK_PREFIX, &(parent_hash, pre_runtime).encode()].concat()
+}
+
+/// Offchain index key of the entry at `position` in a timeline, once the
+/// block adding it is finalized.
+pub fn timeline_entry_key(
+	id: &IdentifierOf,
+	id_type: IdentifierTypeOf,
+	position: u64,
+) -> sp_std::vec::Vec<u8> {
+	[TIMELINE_ENTRY_PREFIX, &(id, id_type, position).encode()].concat()
+}
+
+/// Offchain index key of the number of entries of a timeline, as of the last
+/// finalized block.
+pub fn timeline_length_key(id: &IdentifierOf, id_type: IdentifierTypeOf) -> sp_std::vec::Vec<u8> {
+	[TIMELINE_LENGTH_PREFIX, &(id, id_type).encode()].concat()
+}
+
 pub trait IdentifierUpdate<I, IT, EE, E> {
+	/// Adds an entry to a timeline. Reads and writes its `Identifiers` and
+	/// `TimelineLength` entries, the weights of the callers account for both.
 	fn update_timeline(id: &I, id_type: IT, entry: EE) -> Result<(), E>;
 }
 
@@ -108,12 +197,21 @@ impl<T: Config> IdentifierUpdate<IdentifierOf, IdentifierTypeOf, EventEntryOf, I
 	) -> Result<(), IdentifierError> {
 		Identifiers::<T>::try_mutate(id, id_type, |timeline| {
 			let events = timeline.get_or_insert_with(BoundedVec::default);
+			// Timelines started before their length was tracked continue from
+			// the entries kept on chain.
+			let position = TimelineLength::<T>::get(id, id_type).unwrap_or(events.len() as u64);
 
 			if events.len() == T::MaxEventsHistory::get() as usize {
 				events.remove(1);
 			}
 
-			events.try_push(entry).map_err(|_| IdentifierError::MaxEventsHistoryExceeded)
+			events
+				.try_push(entry.clone())
+				.map_err(|_| IdentifierError::MaxEventsHistoryExceeded)?;
+
+			TimelineLength::<T>::insert(id, id_type, position + 1);
+			BlockEntries::<T>::append((id.clone(), id_type, position, entry));
+			Ok(())
 		})
 		.map_err(|_| IdentifierError::MaxEventsHistoryExceeded) // Map DispatchError to your custom Error
 	}
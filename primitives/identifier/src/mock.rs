@@ -16,6 +16,7 @@
 // You should have received a copy of the GNU General Public License
 // along with CORD. If not, see <https://www.gnu.org/licenses/>.
 
+use crate as identifier;
 use frame_support::{derive_impl, parameter_types};
 
 use sp_runtime::{
@@ -30,12 +31,14 @@ pub(crate) type Block = frame_system::mocking::MockBlock<Test>;
 
 frame_support::construct_runtime!(
 	pub enum Test {
-		System: frame_system
+		System: frame_system,
+		Identifier: identifier,
 	}
 );
 
 parameter_types! {
 	pub const SS58Prefix: u8 = 29;
+	pub const MaxEventsHistory: u32 = 3;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -43,6 +46,10 @@ impl frame_system::Config for Test {
 	type Block = Block;
 }
 
+impl identifier::Config for Test {
+	type MaxEventsHistory = MaxEventsHistory;
+}
+
 #[allow(dead_code)]
 pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
 	let t: sp_runtime::Storage =
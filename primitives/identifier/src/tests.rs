@@ -1,7 +1,9 @@
 use super::*;
 use crate::mock::*;
 use codec::Encode;
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, traits::Hooks};
+use sp_core::H256;
+use sp_runtime::{Digest, DigestItem};
 
 #[test]
 fn creating_a_invalid_identifier_length_should_fail() {
@@ -24,3 +26,48 @@ fn creating_a_invalid_identifier_length_should_fail() {
 		));
 	});
 }
+
+#[test]
+fn timeline_history_beyond_max_events_should_be_indexed_offchain() {
+	let id = Ss58Identifier::create_identifier(&[2u8; 30].encode()[..], IdentifierType::Statement)
+		.unwrap();
+	let entry = |height| EventEntry {
+		action: CallTypeOf::Update,
+		location: Timepoint { height, index: 0 },
+	};
+	let block_key = |slot: u8| {
+		let digest = Digest { logs: vec![DigestItem::PreRuntime(*b"BABE", vec![slot])] };
+		timeline_block_key(&H256::repeat_byte(1), &digest)
+	};
+
+	let mut ext = new_test_ext();
+	ext.execute_with(|| {
+		System::initialize(
+			&2,
+			&H256::repeat_byte(1),
+			&Digest { logs: vec![DigestItem::PreRuntime(*b"BABE", vec![1])] },
+		);
+		for height in 0..5 {
+			assert_ok!(IdentifierTimeline::update_timeline::<Test>(
+				&id,
+				IdentifierTypeOf::Statement,
+				entry(height)
+			));
+		}
+		let on_chain = Identifiers::<Test>::get(&id, IdentifierTypeOf::Statement).unwrap();
+		assert_eq!(on_chain.into_inner(), vec![entry(0), entry(3), entry(4)]);
+		assert_eq!(TimelineLength::<Test>::get(&id, IdentifierTypeOf::Statement), Some(5));
+
+		Identifier::on_finalize(2);
+		assert!(BlockEntries::<Test>::get().is_empty());
+	});
+
+	ext.persist_offchain_overlay();
+	let offchain = ext.offchain_db();
+	let indexed = (0..5)
+		.map(|height| (id.clone(), IdentifierTypeOf::Statement, height as u64, entry(height)))
+		.collect::<Vec<_>>();
+	assert_eq!(offchain.get(&block_key(1)), Some(indexed.encode()));
+	// A sibling block, with the same parent but another slot, has its own key.
+	assert_eq!(offchain.get(&block_key(2)), None);
+}
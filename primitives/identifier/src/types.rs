@@ -19,6 +19,8 @@
 use crate::*;
 use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
 use sp_runtime::RuntimeDebug;
 
 /// A global index, formed as the extrinsic index within a block, together with
@@ -26,6 +28,7 @@ use sp_runtime::RuntimeDebug;
 #[derive(
 	Copy, Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo, MaxEncodedLen,
 )]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct Timepoint {
 	/// The height of the chain at the point in time.
 	pub height: u32,
@@ -35,6 +38,7 @@ pub struct Timepoint {
 
 /// Identifier Event Entries
 #[derive(Encode, Decode, Clone, MaxEncodedLen, RuntimeDebug, PartialEq, Eq, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub struct EventEntry<CallTypeOf> {
 	/// Identifier Type.
 	pub action: CallTypeOf,
@@ -44,6 +48,7 @@ pub struct EventEntry<CallTypeOf> {
 
 /// Defining the possible actions that can be performed on a identifier.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[repr(u8)]
 pub enum CallTypeOf {
 	Archive,
@@ -71,6 +76,7 @@ pub enum CallTypeOf {
 }
 /// Defining the identifier target types.
 #[derive(Clone, Copy, RuntimeDebug, Decode, Encode, PartialEq, Eq, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 pub enum IdentifierTypeOf {
 	Asset,
 	Auth,
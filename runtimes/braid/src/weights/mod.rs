@@ -22,6 +22,7 @@ pub mod frame_system;
 pub mod pallet_babe;
 pub mod pallet_balances;
 pub mod pallet_chain_space;
+pub mod pallet_config;
 pub mod pallet_did;
 pub mod pallet_did_name;
 pub mod pallet_identity;
@@ -31,6 +32,7 @@ pub mod pallet_multisig;
 pub mod pallet_network_membership;
 pub mod pallet_network_score;
 pub mod pallet_remark;
+pub mod pallet_runtime_upgrade;
 pub mod pallet_scheduler;
 pub mod pallet_schema;
 pub mod pallet_session;
@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -57,6 +61,7 @@ impl<T: frame_system::Config> pallet_schema::WeightInfo for WeightInfo<T> {
 	/// Proof: `Schema::Schemas` (`max_values`: None, `max_size`: Some(15542), added: 18017, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// The range of component `l` is `[1, 15360]`.
 	fn create(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -67,7 +72,7 @@ impl<T: frame_system::Config> pallet_schema::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 19007))
 			// Standard Error: 8
 			.saturating_add(Weight::from_parts(3_234, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 }
@@ -0,0 +1,45 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the health of the authorities.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use authority_membership::{AuthorityHealth, OffenceRecord, OffenceReport};
+use codec::Codec;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait AuthorityMembershipApi<ValidatorId>
+	where
+		ValidatorId: Codec,
+	{
+		/// Returns the membership state, the pending changes, the blacklist
+		/// status and the recent offences of an authority.
+		fn authority(authority: ValidatorId) -> Option<AuthorityHealth<ValidatorId>>;
+
+		/// Returns the health of the members, the current validators and the
+		/// blacklisted authorities.
+		fn authorities() -> Vec<AuthorityHealth<ValidatorId>>;
+
+		/// Returns the offence reports against an authority kept by the
+		/// offences pallet, including the reporters and the time slots.
+		fn offence_reports(authority: ValidatorId) -> Vec<OffenceReport<ValidatorId>>;
+	}
+}
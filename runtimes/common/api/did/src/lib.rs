@@ -56,7 +56,7 @@ pub type RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber> =
 	DidLinkedInfo<DidIdentifier, AccountId, Vec<u8>, Vec<u8>, Vec<u8>, Vec<u8>, Key, BlockNumber>;
 
 sp_api::decl_runtime_apis! {
-	#[api_version(1)]
+	#[api_version(2)]
 	pub trait DidApi<DidIdentifier, AccountId, Key: Ord, BlockNumber: MaxEncodedLen> where
 		DidIdentifier: Codec,
 		AccountId: Codec,
@@ -68,6 +68,9 @@ sp_api::decl_runtime_apis! {
 	/// * public keys stored for the did
 	/// * the didName (optional)
 	/// * service endpoints
+	///
+	/// Sub-names (`label@prefix.cord`) are resolved only while every name
+	/// above them resolves.
 	fn query_by_name(name: Vec<u8>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber>>;
 
 	/// Given a did this returns:
@@ -75,5 +78,13 @@ sp_api::decl_runtime_apis! {
 	/// * public keys stored for the did
 	/// * service endpoints
 	fn query(did: DidIdentifier) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber>>;
+
+	/// Given a didname or an identity username this returns the same
+	/// information as `query_by_name`.
+	///
+	/// Didnames take precedence. A username resolves to the DID of the
+	/// account holding it.
+	#[api_version(2)]
+	fn resolve(name: Vec<u8>) -> Option<RawDidLinkedInfo<DidIdentifier, AccountId, Key, BlockNumber>>;
 	}
 }
@@ -0,0 +1,36 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+//! Runtime API definition for the identity registrars.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+pub use pallet_identity::{RegistrarIndex, RegistrarStats};
+
+sp_api::decl_runtime_apis! {
+	#[api_version(1)]
+	pub trait IdentityApi<BlockNumber>
+	where
+		BlockNumber: Codec,
+	{
+		/// Returns the performance statistics of the registrar at `index`, or
+		/// `None` if there is no registrar at `index`.
+		fn registrar_stats(index: RegistrarIndex) -> Option<RegistrarStats<BlockNumber>>;
+	}
+}
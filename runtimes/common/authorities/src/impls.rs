@@ -20,8 +20,10 @@
 //! Implementation of the offence handling logic.
 //!
 //! Offences are sorted in the `offences` pallet.
-//! The offences are executed here based. The offenders are disconnected and
-//! can be added to a blacklist to avoid futur connection.
+//! The offences are executed here based on the offence policy of the offence
+//! kind and the offence history of the offender. The offenders can be warned,
+//! disconnected, suspended for a number of sessions or added to a blacklist
+//! to avoid futur connection.
 
 #![allow(clippy::type_complexity)]
 
@@ -29,7 +31,10 @@ use super::pallet::*;
 use frame_support::{pallet_prelude::Weight, traits::Get};
 use pallet_offences::{traits::OnOffenceHandler, SlashStrategy};
 use sp_runtime::traits::Convert;
-use sp_staking::{offence::OffenceDetails, SessionIndex};
+use sp_staking::{
+	offence::{Kind, OffenceDetails},
+	SessionIndex,
+};
 
 impl<T: Config>
 	OnOffenceHandler<T::AccountId, pallet_session::historical::IdentificationTuple<T>, Weight>
@@ -46,36 +51,43 @@ where
 			T::AccountId,
 			pallet_session::historical::IdentificationTuple<T>,
 		>],
-		strategy: SlashStrategy,
-		_slash_session: SessionIndex,
+		kind: Kind,
+		slash_session: SessionIndex,
 	) -> Weight {
 		let mut consumed_weight = Weight::from_parts(0, 0);
 		let mut add_db_reads_writes = |reads, writes| {
 			consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
 		};
 
-		match strategy {
-			SlashStrategy::BlackList =>
-				for offender in offenders {
-					BlackList::<T>::mutate(|blacklist| {
-						if let Some(member) = T::ValidatorIdOf::convert(offender.offender.0.clone())
-						{
-							if !blacklist.contains(&member) {
-								blacklist.push(member.clone());
-								add_db_reads_writes(0, 1);
-							}
-							Self::mark_for_blacklist_and_removal(member);
-							add_db_reads_writes(2, 1);
-						}
-					})
+		for offender in offenders {
+			let Some(member) = T::ValidatorIdOf::convert(offender.offender.0.clone()) else {
+				continue;
+			};
+
+			let count = Self::record_offence(&member, kind, slash_session);
+			add_db_reads_writes(3, 2);
+
+			match Self::offence_strategy(&kind, count) {
+				SlashStrategy::Warn => {
+					Self::deposit_event(Event::MemberWarned { member, kind });
+				},
+				SlashStrategy::Disconnect => {
+					Self::mark_for_disconnect(member);
+					add_db_reads_writes(1, 1);
+				},
+				SlashStrategy::Suspend(sessions) => {
+					// The member leaves the set of authorities in 2 sessions.
+					let until = pallet_session::Pallet::<T>::current_index()
+						.saturating_add(2)
+						.saturating_add(sessions);
+					Self::mark_for_suspension(member, until);
+					add_db_reads_writes(3, 4);
 				},
-			SlashStrategy::Disconnect =>
-				for offender in offenders {
-					if let Some(member) = T::ValidatorIdOf::convert(offender.offender.0.clone()) {
-						Self::mark_for_disconnect(member);
-						add_db_reads_writes(1, 1);
-					}
+				SlashStrategy::BlackList => {
+					Self::mark_for_blacklist_and_removal(member);
+					add_db_reads_writes(2, 2);
 				},
+			}
 		}
 		consumed_weight
 	}
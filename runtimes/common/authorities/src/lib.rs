@@ -22,11 +22,21 @@
 
 pub mod impls;
 
-use frame_support::{dispatch::DispatchResult, ensure, pallet_prelude::*, traits::EnsureOrigin};
+use cord_primitives::{IdentityVerifier, NodeOwnership};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	pallet_prelude::*,
+	traits::{Currency, EnsureOrigin, ReservableCurrency},
+};
 pub use pallet::*;
-use sp_staking::SessionIndex;
+pub use pallet_offences::SlashStrategy;
+use sp_staking::{offence::Kind, SessionIndex};
 use sp_std::{vec, vec::Vec};
 
+pub mod types;
+pub use crate::types::*;
+
 #[cfg(any(feature = "mock", test))]
 pub mod mock;
 
@@ -41,6 +51,13 @@ pub mod pallet {
 	use frame_system::pallet_prelude::*;
 	use sp_runtime::traits::{Convert, IsMember};
 
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NodeIdOf<T> = BoundedVec<u8, <T as Config>::MaxNodeIdLength>;
+
+	pub type ApplicationOf<T> = CandidateApplication<NodeIdOf<T>, BalanceOf<T>, BlockNumberFor<T>>;
+
 	/// The current storage version.
 	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
@@ -61,6 +78,27 @@ pub mod pallet {
 		type MinAuthorities: Get<u32>;
 		/// Privileged origin that can add or remove validators.
 		type AuthorityMembershipOrigin: EnsureOrigin<<Self as frame_system::Config>::RuntimeOrigin>;
+		/// Max number of steps of an offence policy.
+		#[pallet::constant]
+		type MaxOffencePolicySteps: Get<u32>;
+		/// Max number of offences kept in the history of an authority.
+		#[pallet::constant]
+		type MaxOffenceRecords: Get<u32>;
+		/// Currency in which the application bonds are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Bond reserved from a candidate while its application is pending.
+		#[pallet::constant]
+		type ApplicationBond: Get<BalanceOf<Self>>;
+		/// Max number of pending applications.
+		#[pallet::constant]
+		type MaxPendingApplications: Get<u32>;
+		/// The maximum length in bytes of a node identifier.
+		#[pallet::constant]
+		type MaxNodeIdLength: Get<u32>;
+		/// Identity judgements of the candidates.
+		type IdentityVerifier: IdentityVerifier<Self::AccountId>;
+		/// Ownership of the nodes linked by the candidates.
+		type NodeOwnership: NodeOwnership<Self::AccountId>;
 	}
 
 	#[pallet::event]
@@ -87,6 +125,29 @@ pub mod pallet {
 		/// A member is added to the blacklist and is scheduled for removal in 2 sessions due to
 		/// non-availability.
 		MemberBlacklistedRemoved(T::ValidatorId),
+		/// A member has been warned for an offence.
+		MemberWarned { member: T::ValidatorId, kind: Kind },
+		/// A member is scheduled for removal in 2 sessions and will be
+		/// reinstated at the given session.
+		MemberSuspended { member: T::ValidatorId, until: SessionIndex },
+		/// A suspended member has been reinstated and will enter the set of
+		/// authorities in 2 sessions.
+		MemberReinstated(T::ValidatorId),
+		/// The offence policy of an offence kind has been set.
+		OffencePolicySet { kind: Kind, steps: Vec<SlashStrategy> },
+		/// The offence policy of an offence kind has been cleared.
+		OffencePolicyCleared { kind: Kind },
+		/// The offence history of a member has been cleared.
+		OffenceHistoryCleared(T::AccountId),
+		/// A candidate has applied to join the set of authorities.
+		ApplicationSubmitted { candidate: T::AccountId, node_id: NodeIdOf<T> },
+		/// A candidate has withdrawn its application.
+		ApplicationWithdrawn(T::AccountId),
+		/// The application of a candidate has been approved.
+		ApplicationApproved(T::AccountId),
+		/// The application of a candidate has been rejected and its bond
+		/// slashed.
+		ApplicationRejected(T::AccountId),
 	}
 
 	#[pallet::error]
@@ -109,6 +170,24 @@ pub mod pallet {
 		NetworkMembershipNotFound,
 		/// Authority count below threshold
 		TooLowAuthorityCount,
+		/// Member is suspended
+		MemberSuspended,
+		/// An offence policy needs at least one step
+		EmptyOffencePolicy,
+		/// There is no offence policy for the offence kind
+		OffencePolicyNotFound,
+		/// The candidate already has a pending application
+		ApplicationAlreadyExists,
+		/// There is no pending application of the candidate
+		ApplicationNotFound,
+		/// Too many pending applications
+		TooManyApplications,
+		/// The identity of the candidate has no positive judgement
+		IdentityNotVerified,
+		/// The node is not owned by the candidate
+		NodeNotOwned,
+		/// The node is not a well known node
+		NodeNotWellKnown,
 	}
 
 	/// list incoming authorities
@@ -127,6 +206,49 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type BlackList<T: Config> = StorageValue<_, Vec<T::ValidatorId>, ValueQuery>;
 
+	/// Offence policies.
+	/// It maps from an offence kind to the strategy applied on each repeated
+	/// offence. The last step applies to all further offences.
+	#[pallet::storage]
+	pub type OffencePolicies<T: Config> =
+		StorageMap<_, Twox64Concat, Kind, BoundedVec<SlashStrategy, T::MaxOffencePolicySteps>>;
+
+	/// Number of offences of each kind committed by a member.
+	#[pallet::storage]
+	pub type OffenceCounts<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, T::ValidatorId, Twox64Concat, Kind, u32, ValueQuery>;
+
+	/// The most recent offences committed by a member.
+	#[pallet::storage]
+	pub type OffenceHistory<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::ValidatorId,
+		BoundedVec<OffenceRecord, T::MaxOffenceRecords>,
+		ValueQuery,
+	>;
+
+	/// Suspended members.
+	/// It maps from a member to the session at which it is reinstated.
+	#[pallet::storage]
+	pub type Suspensions<T: Config> =
+		StorageMap<_, Twox64Concat, T::ValidatorId, SessionIndex, OptionQuery>;
+
+	/// Suspended members by the session at which they are reinstated.
+	#[pallet::storage]
+	pub type SuspensionsEndingAt<T: Config> =
+		StorageMap<_, Twox64Concat, SessionIndex, Vec<T::ValidatorId>, ValueQuery>;
+
+	/// Pending applications of the candidates.
+	#[pallet::storage]
+	pub type Applications<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ApplicationOf<T>, OptionQuery>;
+
+	/// Candidates with a pending application, in order of submission.
+	#[pallet::storage]
+	pub type ApplicationQueue<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxPendingApplications>, ValueQuery>;
+
 	#[pallet::genesis_config]
 	#[derive(frame_support::DefaultNoBound)]
 	pub struct GenesisConfig<T: Config> {
@@ -253,6 +375,7 @@ pub mod pallet {
 			if Self::is_blacklisted(&member) {
 				return Err(Error::<T>::MemberBlackListed.into());
 			}
+			ensure!(!Suspensions::<T>::contains_key(&member), Error::<T>::MemberSuspended);
 
 			ensure!(<Members<T>>::get().contains(&member), Error::<T>::MemberNotFound);
 
@@ -261,6 +384,171 @@ pub mod pallet {
 			Self::deposit_event(Event::MemberGoOnline(who));
 			Ok(())
 		}
+
+		/// Set the offence policy of an offence kind.
+		/// The n-th step is applied on the n-th offence of the kind by the
+		/// same member, and the last step on all further offences.
+		#[pallet::call_index(5)]
+		#[pallet::weight({200_000})]
+		pub fn set_offence_policy(
+			origin: OriginFor<T>,
+			kind: Kind,
+			steps: BoundedVec<SlashStrategy, T::MaxOffencePolicySteps>,
+		) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+			ensure!(!steps.is_empty(), Error::<T>::EmptyOffencePolicy);
+
+			OffencePolicies::<T>::insert(kind, &steps);
+
+			Self::deposit_event(Event::OffencePolicySet { kind, steps: steps.into_inner() });
+			Ok(())
+		}
+
+		/// Clear the offence policy of an offence kind, which falls back to
+		/// the default strategy.
+		#[pallet::call_index(6)]
+		#[pallet::weight({200_000})]
+		pub fn clear_offence_policy(origin: OriginFor<T>, kind: Kind) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+			ensure!(OffencePolicies::<T>::take(kind).is_some(), Error::<T>::OffencePolicyNotFound);
+
+			Self::deposit_event(Event::OffencePolicyCleared { kind });
+			Ok(())
+		}
+
+		/// Clear the offence history of a member, so that its next offences
+		/// are handled as first offences.
+		#[pallet::call_index(7)]
+		#[pallet::weight({200_000})]
+		pub fn clear_offence_history(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+
+			let member = T::ValidatorIdOf::convert(candidate.clone())
+				.ok_or(pallet_session::Error::<T>::NoAssociatedValidatorId)?;
+
+			let _ = OffenceCounts::<T>::clear_prefix(&member, u32::MAX, None);
+			OffenceHistory::<T>::remove(&member);
+
+			Self::deposit_event(Event::OffenceHistoryCleared(candidate));
+			Ok(())
+		}
+
+		/// Apply to join the set of authorities.
+		/// The candidate sets its session keys and links a well known node it
+		/// owns. The application bond is reserved until governance approves
+		/// or rejects the application.
+		#[pallet::call_index(8)]
+		#[pallet::weight({200_000})]
+		pub fn apply(
+			origin: OriginFor<T>,
+			keys: T::Keys,
+			proof: Vec<u8>,
+			node_id: NodeIdOf<T>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			if !T::IsMember::is_member(&who) {
+				return Err(Error::<T>::NetworkMembershipNotFound.into());
+			}
+
+			let member = T::ValidatorIdOf::convert(who.clone())
+				.ok_or(pallet_session::Error::<T>::NoAssociatedValidatorId)?;
+
+			if Self::is_blacklisted(&member) {
+				return Err(Error::<T>::MemberBlackListed.into());
+			}
+			ensure!(!<Members<T>>::get().contains(&member), Error::<T>::MemberAlreadyExists);
+			ensure!(!Applications::<T>::contains_key(&who), Error::<T>::ApplicationAlreadyExists);
+
+			Self::ensure_eligible(&who, &node_id)?;
+
+			ApplicationQueue::<T>::try_mutate(|queue| queue.try_push(who.clone()))
+				.map_err(|_| Error::<T>::TooManyApplications)?;
+
+			let bond = T::ApplicationBond::get();
+			T::Currency::reserve(&who, bond)?;
+
+			pallet_session::Pallet::<T>::set_keys(origin, keys, proof)?;
+
+			Applications::<T>::insert(
+				&who,
+				CandidateApplication {
+					node_id: node_id.clone(),
+					bond,
+					submitted_at: frame_system::Pallet::<T>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::ApplicationSubmitted { candidate: who, node_id });
+			Ok(())
+		}
+
+		/// Withdraw a pending application and unreserve its bond.
+		#[pallet::call_index(9)]
+		#[pallet::weight({200_000})]
+		pub fn withdraw_application(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let application = Self::take_application(&who)?;
+			T::Currency::unreserve(&who, application.bond);
+
+			Self::deposit_event(Event::ApplicationWithdrawn(who));
+			Ok(())
+		}
+
+		/// Approve a pending application.
+		/// The candidate is added to the authorities, which will be active
+		/// from current session + 2, and its bond is unreserved.
+		#[pallet::call_index(10)]
+		#[pallet::weight({200_000})]
+		pub fn approve_application(
+			origin: OriginFor<T>,
+			candidate: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+
+			let application =
+				Applications::<T>::get(&candidate).ok_or(Error::<T>::ApplicationNotFound)?;
+
+			// The node and the identity may have changed since the application.
+			Self::ensure_eligible(&candidate, &application.node_id)?;
+
+			let member = T::ValidatorIdOf::convert(candidate.clone())
+				.ok_or(pallet_session::Error::<T>::NoAssociatedValidatorId)?;
+
+			if Self::is_blacklisted(&member) {
+				return Err(Error::<T>::MemberBlackListed.into());
+			}
+			ensure!(
+				pallet_session::NextKeys::<T>::contains_key(&member),
+				Error::<T>::SessionKeysNotAdded
+			);
+
+			Self::add_authority_member(&member)?;
+
+			Self::take_application(&candidate)?;
+			T::Currency::unreserve(&candidate, application.bond);
+
+			Self::deposit_event(Event::ApplicationApproved(candidate.clone()));
+			Self::deposit_event(Event::MemberAdded(candidate));
+			Ok(())
+		}
+
+		/// Reject a pending application and slash its bond.
+		#[pallet::call_index(11)]
+		#[pallet::weight({200_000})]
+		pub fn reject_application(origin: OriginFor<T>, candidate: T::AccountId) -> DispatchResult {
+			T::AuthorityMembershipOrigin::ensure_origin(origin)?;
+
+			let application = Self::take_application(&candidate)?;
+			let _ = T::Currency::slash_reserved(&candidate, application.bond);
+
+			Self::deposit_event(Event::ApplicationRejected(candidate));
+			Ok(())
+		}
 	}
 }
 
@@ -302,7 +590,11 @@ impl<T: Config> Pallet<T> {
 	}
 	// Adds offline authorities to a local cache for removal and blacklist.
 	fn mark_for_blacklist_and_removal(authority: T::ValidatorId) {
-		BlackList::<T>::mutate(|v| v.push(authority.clone()));
+		BlackList::<T>::mutate(|v| {
+			if !v.contains(&authority) {
+				v.push(authority.clone())
+			}
+		});
 		OutgoingAuthorities::<T>::mutate(|v| v.push(authority.clone()));
 		Self::deposit_event(Event::MemberBlacklistedRemoved(authority));
 	}
@@ -318,10 +610,120 @@ impl<T: Config> Pallet<T> {
 	fn is_blacklisted(authority: &T::ValidatorId) -> bool {
 		BlackList::<T>::get().contains(authority)
 	}
+	/// Check that the identity of the candidate is verified, and that the
+	/// node is a well known node owned by the candidate.
+	fn ensure_eligible(candidate: &T::AccountId, node_id: &NodeIdOf<T>) -> DispatchResult {
+		ensure!(
+			T::IdentityVerifier::has_verified_identity(candidate),
+			Error::<T>::IdentityNotVerified
+		);
+
+		let node_id = node_id.to_vec();
+		ensure!(
+			T::NodeOwnership::node_owner(&node_id).as_ref() == Some(candidate),
+			Error::<T>::NodeNotOwned
+		);
+		ensure!(T::NodeOwnership::is_well_known(&node_id), Error::<T>::NodeNotWellKnown);
+		Ok(())
+	}
+	/// Remove the pending application of a candidate from the queue.
+	fn take_application(candidate: &T::AccountId) -> Result<ApplicationOf<T>, DispatchError> {
+		let application =
+			Applications::<T>::take(candidate).ok_or(Error::<T>::ApplicationNotFound)?;
+		ApplicationQueue::<T>::mutate(|queue| queue.retain(|c| c != candidate));
+		Ok(application)
+	}
+	/// The health of an authority, or `None` if it is unknown to the
+	/// pallet.
+	pub fn authority_health(authority: T::ValidatorId) -> Option<AuthorityHealth<T::ValidatorId>> {
+		let health = AuthorityHealth {
+			is_member: <Members<T>>::get().contains(&authority),
+			is_active: Session::<T>::validators().contains(&authority),
+			pending_addition: Self::is_incoming(&authority),
+			pending_removal: Self::is_outgoing(&authority),
+			is_blacklisted: Self::is_blacklisted(&authority),
+			suspended_until: Suspensions::<T>::get(&authority),
+			offences: OffenceHistory::<T>::get(&authority).into_inner(),
+			authority,
+		};
+
+		let is_known = health.is_member ||
+			health.is_active ||
+			health.is_blacklisted ||
+			!health.offences.is_empty();
+		is_known.then_some(health)
+	}
+	/// The health of the members, the current validators and the
+	/// blacklisted authorities.
+	pub fn authorities_health() -> Vec<AuthorityHealth<T::ValidatorId>> {
+		let mut authorities = <Members<T>>::get();
+		Session::<T>::validators()
+			.into_iter()
+			.chain(BlackList::<T>::get())
+			.for_each(|v| {
+				if !authorities.contains(&v) {
+					authorities.push(v);
+				}
+			});
+
+		authorities.into_iter().filter_map(Self::authority_health).collect()
+	}
+	/// Record an offence of an authority, and return the number of offences
+	/// of the same kind committed by the authority.
+	fn record_offence(authority: &T::ValidatorId, kind: Kind, session: SessionIndex) -> u32 {
+		OffenceHistory::<T>::mutate(authority, |history| {
+			if history.is_full() {
+				history.remove(0);
+			}
+			let _ = history.try_push(OffenceRecord { kind, session });
+		});
+		OffenceCounts::<T>::mutate(authority, kind, |count| {
+			*count = count.saturating_add(1);
+			*count
+		})
+	}
+	/// The strategy applied on the `count`-th offence of the given kind.
+	pub fn offence_strategy(kind: &Kind, count: u32) -> SlashStrategy {
+		OffencePolicies::<T>::get(kind)
+			.and_then(|steps| {
+				let step = (count.saturating_sub(1) as usize).min(steps.len().saturating_sub(1));
+				steps.get(step).copied()
+			})
+			.unwrap_or_else(|| SlashStrategy::default_for(kind))
+	}
+	// Adds offline authorities to a local cache for removal, and suspends
+	// them until the given session.
+	fn mark_for_suspension(authority: T::ValidatorId, until: SessionIndex) {
+		OutgoingAuthorities::<T>::mutate(|v| v.push(authority.clone()));
+		if let Some(previous) = Suspensions::<T>::get(&authority) {
+			SuspensionsEndingAt::<T>::mutate(previous, |suspended| {
+				suspended.retain(|a| a != &authority)
+			});
+		}
+		Suspensions::<T>::insert(&authority, until);
+		SuspensionsEndingAt::<T>::append(until, &authority);
+		Self::deposit_event(Event::MemberSuspended { member: authority, until });
+	}
+	// Reinstates the suspended authorities whose suspension ends at the given
+	// session.
+	fn reinstate_suspended(session: SessionIndex) {
+		for authority in SuspensionsEndingAt::<T>::take(session) {
+			Suspensions::<T>::remove(&authority);
+			if <Members<T>>::get().contains(&authority) &&
+				!Self::is_blacklisted(&authority) &&
+				!Self::is_incoming(&authority)
+			{
+				Self::mark_for_addition(authority.clone());
+				Self::deposit_event(Event::MemberReinstated(authority));
+			}
+		}
+	}
 }
 
 impl<T: Config> pallet_session::SessionManager<T::ValidatorId> for Pallet<T> {
-	fn new_session(_new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+	fn new_session(new_index: SessionIndex) -> Option<Vec<T::ValidatorId>> {
+		Self::reinstate_suspended(new_index);
+
 		let members_to_add = IncomingAuthorities::<T>::take();
 		let members_to_del = OutgoingAuthorities::<T>::take();
 
@@ -18,12 +18,16 @@
 //
 use super::*;
 use crate::{self as cord_authority_membership};
-use frame_support::{derive_impl, parameter_types};
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU64, Nothing},
+};
 use sp_state_machine::BasicExternalities;
 use std::collections::BTreeMap;
 
+use cord_primitives::NodeId;
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot};
-use pallet_offences::{traits::OnOffenceHandler, SlashStrategy};
+use pallet_offences::traits::OnOffenceHandler;
 use pallet_session::ShouldEndSession;
 use sp_core::crypto::key_types::DUMMY;
 use sp_runtime::{
@@ -32,7 +36,7 @@ use sp_runtime::{
 	traits::{ConvertInto, IsMember, OpaqueKeys},
 	BuildStorage, KeyTypeId,
 };
-use sp_staking::offence::OffenceDetails;
+use sp_staking::offence::{Kind, OffenceDetails};
 
 type AccountId = u64;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -53,6 +57,7 @@ impl From<UintAuthorityId> for MockSessionKeys {
 frame_support::construct_runtime!(
 	pub enum Test {
 		System: frame_system,
+		Balances: pallet_balances,
 		Session: pallet_session,
 		NetworkMembership: pallet_network_membership,
 		AuthorityMembership: cord_authority_membership,
@@ -63,7 +68,7 @@ frame_support::construct_runtime!(
 impl frame_system::Config for Test {
 	type AccountId = u64;
 	type Block = Block;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 }
 
 pub struct TestSessionHandler;
@@ -102,6 +107,11 @@ impl pallet_session::Config for Test {
 	type WeightInfo = ();
 }
 
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
 parameter_types! {
 	pub const MembershipPeriod: BlockNumberFor<Test> = 5;
 	pub const MaxMembersPerBlock: u32 = 5;
@@ -112,7 +122,12 @@ impl pallet_network_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxMembersPerBatch = ConstU32<10>;
+	type ExpirySpread = ConstU32<1>;
 	type WeightInfo = ();
+	type NetworkParameters = ();
+	type Currency = Balances;
+	type WrapperCalls = Nothing;
 }
 
 pub struct FullIdentificationOfImpl;
@@ -147,14 +162,54 @@ impl cord_authority_membership::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type MinAuthorities = ConstU32<1>;
 	type IsMember = TestIsNetworkMember;
+	type MaxOffencePolicySteps = ConstU32<3>;
+	type MaxOffenceRecords = ConstU32<3>;
+	type Currency = Balances;
+	type ApplicationBond = ConstU64<10>;
+	type MaxPendingApplications = ConstU32<2>;
+	type MaxNodeIdLength = ConstU32<53>;
+	type IdentityVerifier = TestIdentityVerifier;
+	type NodeOwnership = TestNodeOwnership;
+}
+
+pub struct TestIdentityVerifier;
+impl IdentityVerifier<AccountId> for TestIdentityVerifier {
+	fn has_verified_identity(who: &AccountId) -> bool {
+		VerifiedIdentities::get().contains(who)
+	}
+
+	fn has_judgement_from(who: &AccountId, _registrar: &AccountId) -> bool {
+		Self::has_verified_identity(who)
+	}
 }
 
+pub struct TestNodeOwnership;
+impl NodeOwnership<AccountId> for TestNodeOwnership {
+	fn node_owner(node_id: &NodeId) -> Option<AccountId> {
+		NodeOwners::get()
+			.into_iter()
+			.find(|(n, _)| n == node_id)
+			.map(|(_, owner)| owner)
+	}
+
+	fn is_well_known(node_id: &NodeId) -> bool {
+		WellKnownNodes::get().contains(node_id)
+	}
+}
+
+/// An offence kind which blacklists the offender by default.
+pub const EQUIVOCATION: Kind = *b"babe:equivocatio";
+
 parameter_types! {
 	pub static Validators: Vec<u64> = vec![3,6,9];
 	pub static NextValidators: Vec<u64> = vec![3,6,9];
 	pub static Authorities: Vec<UintAuthorityId> =
 		vec![UintAuthorityId(3), UintAuthorityId(6), UintAuthorityId(9)];
 	pub static ValidatorAccounts: BTreeMap<u64, u64> = BTreeMap::new();
+	pub static VerifiedIdentities: Vec<u64> = vec![12, 15];
+	pub static NodeOwners: Vec<(NodeId, u64)> =
+		vec![(b"node-12".to_vec(), 12), (b"node-15".to_vec(), 15)];
+	pub static WellKnownNodes: Vec<NodeId> = vec![b"node-12".to_vec(), b"node-15".to_vec()];
 
 }
 
@@ -177,6 +232,9 @@ pub fn new_test_ext(authorities_len: u64) -> sp_io::TestExternalities {
 		frame_system::Pallet::<Test>::inc_providers(&12);
 		frame_system::Pallet::<Test>::inc_providers(&15);
 	});
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(12, 100), (15, 100)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
 	cord_authority_membership::GenesisConfig::<Test> {
 		initial_authorities: keys.iter().map(|x| x.0).collect::<Vec<_>>(),
 	}
@@ -208,7 +266,7 @@ pub(crate) fn on_offence(
 		AccountId,
 		pallet_session::historical::IdentificationTuple<Test>,
 	>],
-	slash_strategy: SlashStrategy,
+	kind: Kind,
 ) {
-	AuthorityMembership::on_offence(offenders, slash_strategy, 0);
+	AuthorityMembership::on_offence(offenders, kind, 0);
 }
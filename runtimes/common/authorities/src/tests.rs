@@ -183,11 +183,11 @@ fn test_offence_disconnect() {
 
 		on_offence(
 			&[OffenceDetails { offender: (9, ()), reporters: vec![] }],
-			pallet_offences::SlashStrategy::Disconnect,
+			pallet_offences::IM_ONLINE_OFFLINE,
 		);
 		on_offence(
 			&[OffenceDetails { offender: (3, ()), reporters: vec![] }],
-			pallet_offences::SlashStrategy::Disconnect,
+			pallet_offences::IM_ONLINE_OFFLINE,
 		);
 
 		// Verify state
@@ -220,7 +220,7 @@ fn test_offence_disconnect() {
 		run_to_block(35);
 		on_offence(
 			&[OffenceDetails { offender: (3, ()), reporters: vec![] }],
-			pallet_offences::SlashStrategy::Disconnect,
+			pallet_offences::IM_ONLINE_OFFLINE,
 		);
 
 		assert_eq!(IncomingAuthorities::<Test>::get(), EMPTY);
@@ -238,10 +238,7 @@ fn test_offence_black_list() {
 		// at block 0 begins session 0
 		run_to_block(1);
 
-		on_offence(
-			&[OffenceDetails { offender: (9, ()), reporters: vec![] }],
-			pallet_offences::SlashStrategy::BlackList,
-		);
+		on_offence(&[OffenceDetails { offender: (9, ()), reporters: vec![] }], EQUIVOCATION);
 
 		// Verify state
 		assert_eq!(Members::<Test>::get(), vec![3, 6, 9]);
@@ -267,10 +264,7 @@ fn test_offence_black_list_prevent_from_going_online() {
 	new_test_ext(3).execute_with(|| {
 		run_to_block(1);
 
-		on_offence(
-			&[OffenceDetails { offender: (9, ()), reporters: vec![] }],
-			pallet_offences::SlashStrategy::BlackList,
-		);
+		on_offence(&[OffenceDetails { offender: (9, ()), reporters: vec![] }], EQUIVOCATION);
 
 		// Verify state
 		assert_eq!(IncomingAuthorities::<Test>::get(), EMPTY);
@@ -305,3 +299,303 @@ fn test_offence_black_list_prevent_from_going_online() {
 		);
 	});
 }
+
+/// tests the graduated offence policy, from warning to blacklisting
+#[test]
+fn test_offence_policy_is_graduated() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::set_offence_policy(
+			RawOrigin::Root.into(),
+			EQUIVOCATION,
+			vec![SlashStrategy::Warn, SlashStrategy::Suspend(2), SlashStrategy::BlackList]
+				.try_into()
+				.unwrap(),
+		));
+
+		// First offence: warning only
+		on_offence(&[OffenceDetails { offender: (9, ()), reporters: vec![] }], EQUIVOCATION);
+		System::assert_last_event(Event::MemberWarned { member: 9, kind: EQUIVOCATION }.into());
+		assert_eq!(OutgoingAuthorities::<Test>::get(), EMPTY);
+		assert_eq!(OffenceCounts::<Test>::get(9, EQUIVOCATION), 1);
+
+		// Second offence: suspension for 2 sessions
+		on_offence(&[OffenceDetails { offender: (9, ()), reporters: vec![] }], EQUIVOCATION);
+		System::assert_last_event(Event::MemberSuspended { member: 9, until: 4 }.into());
+		assert_eq!(OutgoingAuthorities::<Test>::get(), vec![9]);
+		assert_eq!(Suspensions::<Test>::get(9), Some(4));
+		assert_eq!(SuspensionsEndingAt::<Test>::get(4), vec![9]);
+		assert_eq!(BlackList::<Test>::get(), EMPTY);
+
+		// Member 9 is out during the suspension and cannot go online
+		run_to_block(10);
+		assert_eq!(Session::current_index(), 2);
+		assert_eq!(Session::validators(), vec![3, 6]);
+		assert_err!(
+			AuthorityMembership::go_online(RuntimeOrigin::signed(9)),
+			Error::<Test>::MemberSuspended
+		);
+
+		// Member 9 is reinstated automatically
+		run_to_block(20);
+		assert_eq!(Session::current_index(), 4);
+		assert_eq!(Session::validators(), vec![3, 6, 9]);
+		assert_eq!(Suspensions::<Test>::get(9), None);
+		assert!(SuspensionsEndingAt::<Test>::get(4).is_empty());
+
+		// Third and further offences: blacklist
+		on_offence(&[OffenceDetails { offender: (9, ()), reporters: vec![] }], EQUIVOCATION);
+		assert_eq!(OutgoingAuthorities::<Test>::get(), vec![9]);
+		assert_eq!(BlackList::<Test>::get(), vec![9]);
+	});
+}
+
+/// tests the offence history is bounded and can be cleared
+#[test]
+fn test_offence_history() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::set_offence_policy(
+			RawOrigin::Root.into(),
+			EQUIVOCATION,
+			vec![SlashStrategy::Warn].try_into().unwrap(),
+		));
+
+		for _ in 0..4 {
+			on_offence(&[OffenceDetails { offender: (6, ()), reporters: vec![] }], EQUIVOCATION);
+		}
+		assert_eq!(OffenceCounts::<Test>::get(6, EQUIVOCATION), 4);
+		assert_eq!(OffenceHistory::<Test>::get(6).len(), 3);
+		assert_eq!(
+			OffenceHistory::<Test>::get(6)[0],
+			OffenceRecord { kind: EQUIVOCATION, session: 0 }
+		);
+
+		assert_err!(
+			AuthorityMembership::clear_offence_history(RuntimeOrigin::signed(6), 6),
+			BadOrigin
+		);
+		assert_ok!(AuthorityMembership::clear_offence_history(RawOrigin::Root.into(), 6));
+		System::assert_last_event(Event::OffenceHistoryCleared(6).into());
+		assert_eq!(OffenceCounts::<Test>::get(6, EQUIVOCATION), 0);
+		assert!(OffenceHistory::<Test>::get(6).is_empty());
+	});
+}
+
+/// tests the offence policy management
+#[test]
+fn test_offence_policy_management() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			AuthorityMembership::set_offence_policy(
+				RawOrigin::Root.into(),
+				EQUIVOCATION,
+				vec![].try_into().unwrap(),
+			),
+			Error::<Test>::EmptyOffencePolicy
+		);
+		assert_noop!(
+			AuthorityMembership::set_offence_policy(
+				RuntimeOrigin::signed(3),
+				EQUIVOCATION,
+				vec![SlashStrategy::Warn].try_into().unwrap(),
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			AuthorityMembership::clear_offence_policy(RawOrigin::Root.into(), EQUIVOCATION),
+			Error::<Test>::OffencePolicyNotFound
+		);
+
+		assert_ok!(AuthorityMembership::set_offence_policy(
+			RawOrigin::Root.into(),
+			EQUIVOCATION,
+			vec![SlashStrategy::Disconnect].try_into().unwrap(),
+		));
+		assert_eq!(
+			AuthorityMembership::offence_strategy(&EQUIVOCATION, 5),
+			SlashStrategy::Disconnect
+		);
+
+		assert_ok!(AuthorityMembership::clear_offence_policy(RawOrigin::Root.into(), EQUIVOCATION));
+		System::assert_last_event(Event::OffencePolicyCleared { kind: EQUIVOCATION }.into());
+		assert_eq!(
+			AuthorityMembership::offence_strategy(&EQUIVOCATION, 1),
+			SlashStrategy::BlackList
+		);
+	});
+}
+
+/// tests the health of the authorities
+#[test]
+fn test_authority_health() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_eq!(AuthorityMembership::authority_health(12), None);
+		assert_eq!(
+			AuthorityMembership::authority_health(3),
+			Some(AuthorityHealth {
+				authority: 3,
+				is_member: true,
+				is_active: true,
+				pending_addition: false,
+				pending_removal: false,
+				is_blacklisted: false,
+				suspended_until: None,
+				offences: vec![],
+			})
+		);
+
+		on_offence(&[OffenceDetails { offender: (9, ()), reporters: vec![] }], EQUIVOCATION);
+		let health = AuthorityMembership::authority_health(9).unwrap();
+		assert!(health.pending_removal);
+		assert!(health.is_blacklisted);
+		assert_eq!(health.offences, vec![OffenceRecord { kind: EQUIVOCATION, session: 0 }]);
+
+		assert_eq!(
+			AuthorityMembership::authorities_health()
+				.into_iter()
+				.map(|h| h.authority)
+				.collect::<Vec<_>>(),
+			vec![3, 6, 9]
+		);
+	});
+}
+
+fn node_id(node: &[u8]) -> NodeIdOf<Test> {
+	node.to_vec().try_into().unwrap()
+}
+
+/// tests an application is approved through the queue
+#[test]
+fn test_application_is_approved() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::apply(
+			RuntimeOrigin::signed(12),
+			UintAuthorityId(12).into(),
+			vec![],
+			node_id(b"node-12"),
+		));
+		System::assert_last_event(
+			Event::ApplicationSubmitted { candidate: 12, node_id: node_id(b"node-12") }.into(),
+		);
+		assert_eq!(ApplicationQueue::<Test>::get().into_inner(), vec![12]);
+		assert_eq!(Balances::reserved_balance(12), 10);
+		assert_noop!(
+			AuthorityMembership::apply(
+				RuntimeOrigin::signed(12),
+				UintAuthorityId(12).into(),
+				vec![],
+				node_id(b"node-12"),
+			),
+			Error::<Test>::ApplicationAlreadyExists
+		);
+
+		assert_noop!(
+			AuthorityMembership::approve_application(RuntimeOrigin::signed(3), 12),
+			BadOrigin
+		);
+		assert_ok!(AuthorityMembership::approve_application(RawOrigin::Root.into(), 12));
+		System::assert_has_event(Event::ApplicationApproved(12).into());
+		System::assert_last_event(Event::MemberAdded(12).into());
+		assert!(ApplicationQueue::<Test>::get().is_empty());
+		assert_eq!(Balances::reserved_balance(12), 0);
+		assert_eq!(IncomingAuthorities::<Test>::get(), vec![12]);
+
+		run_to_block(10);
+		assert_eq!(Session::validators(), vec![3, 6, 9, 12]);
+	});
+}
+
+/// tests an application can be withdrawn or rejected
+#[test]
+fn test_application_is_withdrawn_or_rejected() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_ok!(AuthorityMembership::apply(
+			RuntimeOrigin::signed(12),
+			UintAuthorityId(12).into(),
+			vec![],
+			node_id(b"node-12"),
+		));
+		assert_ok!(AuthorityMembership::withdraw_application(RuntimeOrigin::signed(12)));
+		System::assert_last_event(Event::ApplicationWithdrawn(12).into());
+		assert_eq!(Balances::reserved_balance(12), 0);
+		assert_noop!(
+			AuthorityMembership::withdraw_application(RuntimeOrigin::signed(12)),
+			Error::<Test>::ApplicationNotFound
+		);
+
+		assert_ok!(AuthorityMembership::apply(
+			RuntimeOrigin::signed(15),
+			UintAuthorityId(15).into(),
+			vec![],
+			node_id(b"node-15"),
+		));
+		assert_ok!(AuthorityMembership::reject_application(RawOrigin::Root.into(), 15));
+		System::assert_last_event(Event::ApplicationRejected(15).into());
+		assert_eq!(Balances::reserved_balance(15), 0);
+		assert_eq!(Balances::free_balance(15), 90);
+		assert!(Applications::<Test>::get(15).is_none());
+		assert_eq!(Members::<Test>::get(), vec![3, 6, 9]);
+	});
+}
+
+/// tests the eligibility of the candidates
+#[test]
+fn test_application_eligibility() {
+	new_test_ext(3).execute_with(|| {
+		run_to_block(1);
+
+		assert_noop!(
+			AuthorityMembership::apply(
+				RuntimeOrigin::signed(12),
+				UintAuthorityId(12).into(),
+				vec![],
+				node_id(b"node-15"),
+			),
+			Error::<Test>::NodeNotOwned
+		);
+		assert_noop!(
+			AuthorityMembership::apply(
+				RuntimeOrigin::signed(18),
+				UintAuthorityId(18).into(),
+				vec![],
+				node_id(b"node-12"),
+			),
+			Error::<Test>::IdentityNotVerified
+		);
+
+		WellKnownNodes::set(vec![b"node-15".to_vec()]);
+		assert_noop!(
+			AuthorityMembership::apply(
+				RuntimeOrigin::signed(12),
+				UintAuthorityId(12).into(),
+				vec![],
+				node_id(b"node-12"),
+			),
+			Error::<Test>::NodeNotWellKnown
+		);
+
+		// The node is checked again on approval
+		assert_ok!(AuthorityMembership::apply(
+			RuntimeOrigin::signed(15),
+			UintAuthorityId(15).into(),
+			vec![],
+			node_id(b"node-15"),
+		));
+		WellKnownNodes::set(vec![]);
+		assert_noop!(
+			AuthorityMembership::approve_application(RawOrigin::Root.into(), 15),
+			Error::<Test>::NodeNotWellKnown
+		);
+	});
+}
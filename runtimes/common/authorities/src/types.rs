@@ -0,0 +1,83 @@
+// This file is part of CORD – https://cord.network
+
+// Copyright (C) Dhiway Networks Pvt. Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+// CORD is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// CORD is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with CORD. If not, see <https://www.gnu.org/licenses/>.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+use sp_staking::{offence::Kind, SessionIndex};
+use sp_std::vec::Vec;
+
+/// An offence committed by an authority.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OffenceRecord {
+	/// The kind of the offence.
+	pub kind: Kind,
+	/// The session in which the offence happened.
+	pub session: SessionIndex,
+}
+
+/// An offence report against an authority, as kept by the offences pallet.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct OffenceReport<AccountId> {
+	/// The kind of the offence.
+	pub kind: Kind,
+	/// The SCALE encoded time slot of the offence, which depends on the kind.
+	pub time_slot: Vec<u8>,
+	/// The accounts which reported the offence.
+	pub reporters: Vec<AccountId>,
+}
+
+/// Health of an authority, as returned by the runtime API.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub struct AuthorityHealth<ValidatorId> {
+	/// The authority.
+	pub authority: ValidatorId,
+	/// Whether the authority is a member.
+	pub is_member: bool,
+	/// Whether the authority is in the current set of validators.
+	pub is_active: bool,
+	/// Whether the authority is scheduled for addition.
+	pub pending_addition: bool,
+	/// Whether the authority is scheduled for removal.
+	pub pending_removal: bool,
+	/// Whether the authority is blacklisted.
+	pub is_blacklisted: bool,
+	/// The session at which a suspended authority is reinstated.
+	pub suspended_until: Option<SessionIndex>,
+	/// The most recent offences of the authority, oldest first.
+	pub offences: Vec<OffenceRecord>,
+}
+
+/// A pending application of a candidate to the set of authorities.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct CandidateApplication<NodeId, Balance, BlockNumber> {
+	/// The well known node linked by the candidate.
+	pub node_id: NodeId,
+	/// The bond reserved from the candidate.
+	pub bond: Balance,
+	/// The block at which the application was submitted.
+	pub submitted_at: BlockNumber,
+}
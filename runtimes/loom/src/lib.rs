@@ -487,6 +487,9 @@ parameter_types! {
 	pub const MaxSubAccounts: u32 = 100;
 	pub const MaxRegistrars: u32 = 20;
 	pub const MaxAdditionalFields: u32 = 20;
+	pub const IdentityPalletId: PalletId = PalletId(*b"py/ident");
+	pub const JudgementPeriod: BlockNumber = 7 * DAYS;
+	pub const MaxJudgementDeadlinesPerBlock: u32 = 100;
 }
 
 impl pallet_identity::Config for Runtime {
@@ -501,6 +504,12 @@ impl pallet_identity::Config for Runtime {
 	type PendingUsernameExpiration = ConstU32<{ 7 * DAYS }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
+	type FeeAssets = UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<u32>, AccountId>;
+	type PalletId = IdentityPalletId;
+	type JudgementPeriod = JudgementPeriod;
+	type MaxJudgementDeadlinesPerBlock = MaxJudgementDeadlinesPerBlock;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkFeeAsset = Native;
 	type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
 
@@ -753,11 +762,24 @@ impl pallet_multisig::Config for Runtime {
 parameter_types! {
 	pub const MaxProposalLength: u16 = 5;
 }
+
+parameter_types! {
+	pub const ApplicationBond: Balance = 1_000 * UNITS;
+}
+
 impl authority_membership::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type IsMember = NetworkMembership;
 	type MinAuthorities = ConstU32<3>;
 	type AuthorityMembershipOrigin = MoreThanHalfCouncil;
+	type MaxOffencePolicySteps = ConstU32<10>;
+	type MaxOffenceRecords = ConstU32<50>;
+	type Currency = Balances;
+	type ApplicationBond = ApplicationBond;
+	type MaxPendingApplications = ConstU32<50>;
+	type MaxNodeIdLength = MaxNodeIdLength;
+	type IdentityVerifier = Identity;
+	type NodeOwnership = NodeAuthorization;
 }
 
 parameter_types! {
@@ -772,12 +794,15 @@ impl pallet_node_authorization::Config for Runtime {
 	type MaxPeerIdLength = MaxPeerIdLength;
 	type MaxNodeIdLength = MaxNodeIdLength;
 	type NodeAuthorizationOrigin = MoreThanHalfCouncil;
+	type IsMember = NetworkMembership;
 	type WeightInfo = ();
 }
 
 parameter_types! {
 	pub const MembershipPeriod: BlockNumber = YEAR;
 	pub const MaxMembersPerBlock: u32 = 1_000;
+	pub const MaxMembersPerBatch: u32 = 100;
+	pub const ExpirySpread: u32 = 10;
 	pub const MaxEventsHistory: u32 = u32::MAX;
 }
 
@@ -786,15 +811,43 @@ impl pallet_network_membership::Config for Runtime {
 	type NetworkMembershipOrigin = MoreThanHalfCouncil;
 	type MembershipPeriod = MembershipPeriod;
 	type MaxMembersPerBlock = MaxMembersPerBlock;
+	type MaxMembersPerBatch = MaxMembersPerBatch;
+	type ExpirySpread = ExpirySpread;
 	type WeightInfo = weights::pallet_network_membership::WeightInfo<Runtime>;
+	type NetworkParameters = NetworkParameters;
+	type Currency = Balances;
+	type WrapperCalls = WrapperCalls;
+}
+
+/// Calls dispatching other calls, which neither non-members in hybrid mode nor
+/// the members of a tier can submit.
+pub struct WrapperCalls;
+impl Contains<RuntimeCall> for WrapperCalls {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Utility(..) |
+				RuntimeCall::Multisig(..) |
+				RuntimeCall::Sudo(..) |
+				RuntimeCall::Did(pallet_did::Call::submit_did_call { .. }) |
+				RuntimeCall::Did(pallet_did::Call::dispatch_as { .. })
+		)
+	}
 }
 
 impl identifier::Config for Runtime {
 	type MaxEventsHistory = MaxEventsHistory;
 }
 
+parameter_types! {
+	pub const UpgradeDelay: BlockNumber = HOURS;
+}
+
 impl pallet_runtime_upgrade::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
 	type SetCodeOrigin = EnsureRootOrCommitteeApproval;
+	type UpgradeDelay = UpgradeDelay;
+	type WeightInfo = weights::pallet_runtime_upgrade::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -845,6 +898,12 @@ parameter_types! {
 	pub const MinNameLength: u32 = 3;
 	pub const MaxNameLength: u32 = 64;
 	pub const MaxPrefixLength: u32 = 54;
+	pub const NameRegistrationPeriod: BlockNumber = YEAR;
+	pub const NameGracePeriod: BlockNumber = 30 * DAYS;
+	pub const MaxNameExpiriesPerBlock: u32 = 1_000;
+	pub const MaxSubNameDepth: u32 = 4;
+	pub const MaxSubNameLabelLength: u32 = 32;
+	pub const MaxSubNameDelegates: u32 = 10;
 }
 
 impl pallet_did_name::Config for Runtime {
@@ -855,8 +914,15 @@ impl pallet_did_name::Config for Runtime {
 	type MaxNameLength = MaxNameLength;
 	type MinNameLength = MinNameLength;
 	type MaxPrefixLength = MaxPrefixLength;
+	type RegistrationPeriod = NameRegistrationPeriod;
+	type GracePeriod = NameGracePeriod;
+	type MaxNameExpiriesPerBlock = MaxNameExpiriesPerBlock;
+	type MaxSubNameDepth = MaxSubNameDepth;
+	type MaxSubNameLabelLength = MaxSubNameLabelLength;
+	type MaxSubNameDelegates = MaxSubNameDelegates;
 	type DidName = pallet_did_name::did_name::AsciiDidName<Runtime>;
 	type DidNameOwner = DidIdentifier;
+	type UsernameRegistry = Identity;
 	type WeightInfo = weights::pallet_did_name::WeightInfo<Runtime>;
 }
 
@@ -881,6 +947,9 @@ impl pallet_schema_accounts::Config for Runtime {
 
 parameter_types! {
 	pub const MaxSpaceDelegates: u32 = 10_000;
+	pub const MaxTierNameLength: u32 = 32;
+	pub const MaxSpacesPerBatch: u32 = 50;
+	pub const MaxCapacityResetsPerBlock: u32 = 100;
 }
 
 impl pallet_chain_space::Config for Runtime {
@@ -890,7 +959,13 @@ impl pallet_chain_space::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type ChainSpaceOrigin = MoreThanHalfCouncil;
 	type NetworkPermission = NetworkParameters;
+	type NetworkParameters = NetworkParameters;
 	type MaxSpaceDelegates = MaxSpaceDelegates;
+	type MaxTierNameLength = MaxTierNameLength;
+	type MaxSpacesPerBatch = MaxSpacesPerBatch;
+	type MaxCapacityResetsPerBlock = MaxCapacityResetsPerBlock;
+	type IdentityVerifier = Identity;
+	type CreatorAccount = sp_runtime::traits::ConvertInto;
 	type WeightInfo = weights::pallet_chain_space::WeightInfo<Runtime>;
 }
 
@@ -957,7 +1032,28 @@ impl pallet_network_score::Config for Runtime {
 	type WeightInfo = weights::pallet_network_score::WeightInfo<Runtime>;
 }
 
-impl pallet_config::Config for Runtime {}
+pub struct NetworkParameterBounds;
+impl frame_support::traits::Contains<pallet_config::NetworkParameterOf<Runtime>>
+	for NetworkParameterBounds
+{
+	fn contains(parameter: &pallet_config::NetworkParameterOf<Runtime>) -> bool {
+		use pallet_config::NetworkParameter;
+		match parameter {
+			NetworkParameter::DefaultSpaceCapacity(capacity) => *capacity <= 1_000_000_000,
+			NetworkParameter::MaxDigestsPerBatch(max_digests) =>
+				(1..=MaxDigestsPerBatch::get()).contains(max_digests),
+			NetworkParameter::MembershipPeriod(period) => (DAYS..=5 * YEAR).contains(period),
+		}
+	}
+}
+
+impl pallet_config::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ConfigOrigin = MoreThanHalfCouncil;
+	type ParameterBounds = NetworkParameterBounds;
+	type WeightInfo = weights::pallet_config::WeightInfo<Runtime>;
+}
+
 impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
 impl pallet_root_testing::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
@@ -1162,6 +1258,11 @@ mod runtime {
 	#[runtime::pallet_index(60)]
 	pub type NetworkParameters = pallet_config;
 
+	// Declared before `Registries`, so that the genesis namespaces are
+	// anchored before the genesis registries.
+	#[runtime::pallet_index(64)]
+	pub type NameSpace = pallet_namespace;
+
 	#[runtime::pallet_index(61)]
 	pub type Registries = pallet_registries;
 
@@ -1171,9 +1272,6 @@ mod runtime {
 	#[runtime::pallet_index(63)]
 	pub type SchemaAccounts = pallet_schema_accounts;
 
-	#[runtime::pallet_index(64)]
-	pub type NameSpace = pallet_namespace;
-
 	#[runtime::pallet_index(254)]
 	pub type RootTesting = pallet_root_testing;
 
@@ -1308,6 +1406,13 @@ pub type UncheckedExtrinsic =
 /// Extrinsic type that has already been checked.
 pub type CheckedExtrinsic = generic::CheckedExtrinsic<AccountId, RuntimeCall, SignedExtra>;
 
+/// Migrations to apply on runtime upgrade.
+pub type Migrations = (
+	pallet_did_name::migrations::ScheduleExistingNames<Runtime>,
+	pallet_node_authorization::migrations::CountOwnedNodes<Runtime>,
+	pallet_network_membership::migrations::AddMembershipTier<Runtime>,
+);
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -1315,6 +1420,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
+	Migrations,
 >;
 /// The payload being signed in transactions.
 pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
@@ -1341,6 +1447,7 @@ mod benches {
 		[pallet_multisig, Multisig]
 		[pallet_preimage, Preimage]
 		[pallet_remark, Remark]
+		[pallet_runtime_upgrade, RuntimeUpgrade]
 		[pallet_scheduler, Scheduler]
 		[frame_system, SystemBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
@@ -1349,6 +1456,7 @@ mod benches {
 		[pallet_schema, Schema]
 		[pallet_statement, Statement]
 		[pallet_chain_space, ChainSpace]
+		[pallet_config, NetworkParameters]
 		[pallet_did, Did]
 		[pallet_did_name, DidName]
 		[pallet_network_membership, NetworkMembership]
@@ -1638,22 +1746,76 @@ sp_api::impl_runtime_apis! {
 			>
 		> {
 			let dname: pallet_did_name::did_name::AsciiDidName<Runtime> = name.try_into().ok()?;
-			pallet_did_name::Owner::<Runtime>::get(&dname)
-				.and_then(|owner_info| {
-					pallet_did::Did::<Runtime>::get(&owner_info.owner).map(|details| (owner_info, details))
+			DidName::resolve_owner(&dname)
+				.and_then(|owner| {
+					pallet_did::Did::<Runtime>::get(&owner).map(|details| (owner, details))
 				})
-				.map(|(owner_info, details)| {
-					let service_endpoints = pallet_did::ServiceEndpoints::<Runtime>::iter_prefix(&owner_info.owner).map(|e| From::from(e.1)).collect();
+				.map(|(owner, details)| {
+					let service_endpoints = pallet_did::ServiceEndpoints::<Runtime>::iter_prefix(&owner).map(|e| From::from(e.1)).collect();
 
 					pallet_did_runtime_api::RawDidLinkedInfo{
-						identifier: owner_info.owner.clone(),
-						account: owner_info.owner,
+						identifier: owner.clone(),
+						account: owner,
 						name: Some(dname.into()),
 						service_endpoints,
 						details: details.into(),
 					}
 			})
 		}
+		fn resolve(name: Vec<u8>) -> Option<pallet_did_runtime_api::RawDidLinkedInfo<
+				DidIdentifier,
+				AccountId,
+				Hash,
+				BlockNumber
+			>
+		> {
+			Self::query_by_name(name.clone()).or_else(|| {
+				<Identity as cord_primitives::UsernameRegistry<AccountId>>::account_of_username(&name)
+					.and_then(Self::query)
+			})
+		}
+	}
+
+	impl authority_membership_runtime_api::AuthorityMembershipApi<Block, AccountId> for Runtime {
+		fn authority(
+			authority: AccountId,
+		) -> Option<authority_membership_runtime_api::AuthorityHealth<AccountId>> {
+			AuthorityMembership::authority_health(authority)
+		}
+
+		fn authorities() -> Vec<authority_membership_runtime_api::AuthorityHealth<AccountId>> {
+			AuthorityMembership::authorities_health()
+		}
+
+		fn offence_reports(
+			authority: AccountId,
+		) -> Vec<authority_membership_runtime_api::OffenceReport<AccountId>> {
+			Offences::reports()
+				.into_iter()
+				.filter(|(_, _, details)| details.offender.0 == authority)
+				.map(|(kind, time_slot, details)| authority_membership_runtime_api::OffenceReport {
+					kind,
+					time_slot,
+					reporters: details.reporters,
+				})
+				.collect()
+		}
+	}
+
+	impl pallet_network_membership_runtime_api::NetworkMembershipApi<Block, AccountId, BlockNumber> for Runtime {
+		fn expiring_memberships(
+			within: BlockNumber,
+		) -> Vec<pallet_network_membership_runtime_api::ExpiringMembership<AccountId, BlockNumber>> {
+			NetworkMembership::expiring_memberships(within)
+		}
+	}
+
+	impl pallet_identity_runtime_api::IdentityApi<Block, BlockNumber> for Runtime {
+		fn registrar_stats(
+			index: pallet_identity_runtime_api::RegistrarIndex,
+		) -> Option<pallet_identity_runtime_api::RegistrarStats<BlockNumber>> {
+			Identity::registrar_stats(index)
+		}
 	}
 
 	impl pallet_transaction_weight_runtime_api::TransactionWeightApi<Block> for Runtime {
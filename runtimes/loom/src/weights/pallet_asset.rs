@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -57,6 +61,7 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
 	/// Proof: `Asset::AssetLookup` (`max_values`: None, `max_size`: Some(98), added: 2573, mode: `MaxEncodedLen`)
 	fn create() -> Weight {
@@ -66,8 +71,8 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 36_120_000 picoseconds.
 		Weight::from_parts(37_030_000, 0)
 			.saturating_add(Weight::from_parts(0, 6659))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -79,6 +84,7 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Distribution` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// Storage: `Asset::Issuance` (r:0 w:1)
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Asset::AssetLookup` (r:0 w:1)
@@ -90,8 +96,8 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 50_500_000 picoseconds.
 		Weight::from_parts(51_470_000, 0)
 			.saturating_add(Weight::from_parts(0, 6659))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(7))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(9))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
@@ -99,6 +105,7 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn transfer() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `852`
@@ -106,8 +113,8 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 29_210_000 picoseconds.
 		Weight::from_parts(30_010_000, 0)
 			.saturating_add(Weight::from_parts(0, 6795))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Asset::Assets` (r:1 w:0)
 	/// Proof: `Asset::Assets` (`max_values`: None, `max_size`: Some(3194), added: 5669, mode: `MaxEncodedLen`)
@@ -115,6 +122,7 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 	/// Proof: `Asset::Issuance` (`max_values`: None, `max_size`: Some(3330), added: 5805, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn status_change() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `852`
@@ -122,7 +130,7 @@ impl<T: frame_system::Config> pallet_asset::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 28_660_000 picoseconds.
 		Weight::from_parts(29_540_000, 0)
 			.saturating_add(Weight::from_parts(0, 6795))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 }
@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -57,6 +61,7 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
@@ -64,8 +69,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_040_000 picoseconds.
 		Weight::from_parts(40_450_000, 0)
 			.saturating_add(Weight::from_parts(0, 323533))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -75,6 +80,7 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_admin_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
@@ -82,8 +88,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_800_000 picoseconds.
 		Weight::from_parts(40_390_000, 0)
 			.saturating_add(Weight::from_parts(0, 323533))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -93,6 +99,7 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn add_delegator() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `804`
@@ -100,8 +107,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 38_960_000 picoseconds.
 		Weight::from_parts(40_140_000, 0)
 			.saturating_add(Weight::from_parts(0, 323533))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:2 w:1)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -111,6 +118,7 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn remove_delegate() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1061`
@@ -118,13 +126,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_500_000 picoseconds.
 		Weight::from_parts(40_580_000, 0)
 			.saturating_add(Weight::from_parts(0, 323533))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -136,13 +145,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 26_050_000 picoseconds.
 		Weight::from_parts(26_470_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approve() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `494`
@@ -150,8 +160,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_520_000 picoseconds.
 		Weight::from_parts(21_950_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -159,6 +169,7 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn archive() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `715`
@@ -166,8 +177,8 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 29_260_000 picoseconds.
 		Weight::from_parts(30_260_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -175,6 +186,7 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `724`
@@ -182,13 +194,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 29_390_000 picoseconds.
 		Weight::from_parts(30_249_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn update_transaction_capacity() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
@@ -196,13 +209,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_580_000 picoseconds.
 		Weight::from_parts(22_350_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn reset_transaction_count() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
@@ -210,13 +224,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_709_000 picoseconds.
 		Weight::from_parts(22_130_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approval_revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `503`
@@ -224,13 +239,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_130_000 picoseconds.
 		Weight::from_parts(21_980_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn approval_restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `512`
@@ -238,13 +254,14 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 21_360_000 picoseconds.
 		Weight::from_parts(21_761_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `ChainSpace::Spaces` (r:2 w:2)
 	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `ChainSpace::Delegates` (r:0 w:1)
 	/// Proof: `ChainSpace::Delegates` (`max_values`: None, `max_size`: Some(320068), added: 322543, mode: `MaxEncodedLen`)
 	/// Storage: `ChainSpace::Authorizations` (r:0 w:1)
@@ -256,7 +273,93 @@ impl<T: frame_system::Config> pallet_chain_space::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 34_460_000 picoseconds.
 		Weight::from_parts(35_520_000, 0)
 			.saturating_add(Weight::from_parts(0, 6352))
-			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(6))
+	}
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:0 w:1)
+	/// Proof: `ChainSpace::SpaceIdentityPolicy` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	fn set_identity_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_450_000 picoseconds.
+		Weight::from_parts(6_820_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:1 w:0)
+	/// Proof: `ChainSpace::SpaceIdentityPolicy` (`max_values`: Some(1), `max_size`: Some(34), added: 529, mode: `MaxEncodedLen`)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Proof: `ChainSpace::Spaces` (`max_values`: None, `max_size`: Some(206), added: 2681, mode: `MaxEncodedLen`)
+	/// Storage: `Identity::IdentityOf` (r:1 w:0)
+	/// Proof: `Identity::IdentityOf` (`max_values`: None, `max_size`: Some(2266), added: 4741, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	fn suspend_unverified() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `912`
+		//  Estimated: `5731`
+		// Minimum execution time: 29_870_000 picoseconds.
+		Weight::from_parts(30_940_000, 0)
+			.saturating_add(Weight::from_parts(0, 5731))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:1)
+	fn set_capacity_tier() -> Weight {
+		Weight::from_parts(10_340_000, 0)
+			.saturating_add(Weight::from_parts(0, 3526))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:50 w:50)
+	/// Storage: `ChainSpace::SpaceIdentityPolicy` (r:1 w:0)
+	/// Storage: `ChainSpace::SpaceTiers` (r:50 w:50)
+	/// Storage: `ChainSpace::CapacityResets` (r:50 w:50)
+	/// Storage: `Identifier::Identifiers` (r:50 w:50)
+	/// Storage: `Identifier::TimelineLength` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn bulk_approve(n: u32, ) -> Weight {
+		Weight::from_parts(23_518_402, 0)
+			.saturating_add(Weight::from_parts(0, 8486))
+			.saturating_add(Weight::from_parts(19_794_221, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().reads((5).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(n.into()))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:50 w:50)
+	/// Storage: `ChainSpace::SpaceTiers` (r:50 w:50)
+	/// Storage: `ChainSpace::CapacityResets` (r:50 w:50)
+	/// Storage: `Identifier::Identifiers` (r:50 w:50)
+	/// Storage: `Identifier::TimelineLength` (r:50 w:50)
+	/// The range of component `n` is `[1, 50]`.
+	fn assign_capacity_tier(n: u32, ) -> Weight {
+		Weight::from_parts(21_904_117, 0)
+			.saturating_add(Weight::from_parts(0, 8486))
+			.saturating_add(Weight::from_parts(18_632_905, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((5).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((5).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2681).saturating_mul(n.into()))
+	}
+	/// Not benchmarked yet, estimated from the storage accesses.
+	/// Storage: `ChainSpace::SpaceTiers` (r:1 w:1)
+	/// Storage: `ChainSpace::CapacityTiers` (r:1 w:0)
+	/// Storage: `ChainSpace::Spaces` (r:1 w:1)
+	/// Storage: `ChainSpace::CapacityResets` (r:1 w:1)
+	/// Storage: `Identifier::Identifiers` (r:1 w:1)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
+	fn reset_capacity_tier() -> Weight {
+		Weight::from_parts(28_150_000, 0)
+			.saturating_add(Weight::from_parts(0, 8486))
+			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(5))
 	}
 }
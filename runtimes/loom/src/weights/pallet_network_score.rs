@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -63,6 +67,7 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// The range of component `l` is `[1, 15360]`.
 	fn register_rating(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -71,8 +76,8 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 		// Minimum execution time: 48_370_000 picoseconds.
 		Weight::from_parts(50_137_334, 0)
 			.saturating_add(Weight::from_parts(0, 4145))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -88,6 +93,7 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// The range of component `l` is `[1, 15360]`.
 	fn revoke_rating(l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -98,8 +104,8 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 			.saturating_add(Weight::from_parts(0, 7300))
 			// Standard Error: 9
 			.saturating_add(Weight::from_parts(15, 0).saturating_mul(l.into()))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -115,6 +121,7 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:2 w:2)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:2 w:2)
 	/// The range of component `l` is `[1, 15360]`.
 	fn revise_rating(_l: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -123,7 +130,7 @@ impl<T: frame_system::Config> pallet_network_score::WeightInfo for WeightInfo<T>
 		// Minimum execution time: 65_800_000 picoseconds.
 		Weight::from_parts(68_253_387, 0)
 			.saturating_add(Weight::from_parts(0, 7300))
-			.saturating_add(T::DbWeight::get().reads(9))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 }
@@ -23,6 +23,10 @@
 //! WORST CASE MAP SIZE: `1000000`
 //! HOSTNAME: `smohan-dev-host`, CPU: `AMD EPYC 7B12`
 //! WASM-EXECUTION: `Compiled`, CHAIN: `Some("dev")`, DB CACHE: 1024
+//!
+//! The `Identifier::TimelineLength` reads and writes were added to the
+//! benchmarked counts by hand. The execution times and proof sizes do not
+//! include them until the weights are benchmarked again.
 
 // Executed Command:
 // ./target/production/cord
@@ -57,6 +61,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:1)
@@ -68,8 +73,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_590_000 picoseconds.
 		Weight::from_parts(40_530_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -83,6 +88,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn update() -> Weight {
@@ -92,8 +98,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 48_350_000 picoseconds.
 		Weight::from_parts(49_530_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -105,6 +111,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn revoke() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1031`
@@ -112,8 +119,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 38_210_000 picoseconds.
 		Weight::from_parts(38_980_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -125,6 +132,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::RevocationList` (`max_values`: None, `max_size`: Some(139), added: 2614, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	fn restore() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1199`
@@ -132,8 +140,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 39_610_000 picoseconds.
 		Weight::from_parts(40_700_000, 0)
 			.saturating_add(Weight::from_parts(0, 3671))
-			.saturating_add(T::DbWeight::get().reads(5))
-			.saturating_add(T::DbWeight::get().writes(3))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -145,6 +153,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Entries` (`max_values`: None, `max_size`: Some(138), added: 2613, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// The range of component `l` is `[1, 5120]`.
@@ -155,8 +164,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 62_809_000 picoseconds.
 		Weight::from_parts(65_285_526, 0)
 			.saturating_add(Weight::from_parts(0, 6216))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(6))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -166,6 +175,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Statements` (`max_values`: None, `max_size`: Some(199), added: 2674, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:3 w:3)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:3 w:3)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:3)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	/// Storage: `Statement::Entries` (r:0 w:3)
@@ -178,8 +188,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 71_319_000 picoseconds.
 		Weight::from_parts(73_983_087, 0)
 			.saturating_add(Weight::from_parts(0, 9012))
-			.saturating_add(T::DbWeight::get().reads(8))
-			.saturating_add(T::DbWeight::get().writes(13))
+			.saturating_add(T::DbWeight::get().reads(11))
+			.saturating_add(T::DbWeight::get().writes(16))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -193,6 +203,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn add_presentation() -> Weight {
@@ -202,8 +213,8 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 43_220_000 picoseconds.
 		Weight::from_parts(44_590_000, 0)
 			.saturating_add(Weight::from_parts(0, 3686))
-			.saturating_add(T::DbWeight::get().reads(6))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 	/// Storage: `ChainSpace::Authorizations` (r:1 w:0)
 	/// Proof: `ChainSpace::Authorizations` (`max_values`: None, `max_size`: Some(184), added: 2659, mode: `MaxEncodedLen`)
@@ -213,6 +224,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 	/// Proof: `Statement::Presentations` (`max_values`: None, `max_size`: Some(221), added: 2696, mode: `MaxEncodedLen`)
 	/// Storage: `Identifier::Identifiers` (r:1 w:1)
 	/// Proof: `Identifier::Identifiers` (`max_values`: None, `max_size`: Some(4294967295), added: 2474, mode: `MaxEncodedLen`)
+	/// Storage: `Identifier::TimelineLength` (r:1 w:1)
 	/// Storage: `Statement::IdentifierLookup` (r:0 w:1)
 	/// Proof: `Statement::IdentifierLookup` (`max_values`: None, `max_size`: Some(156), added: 2631, mode: `MaxEncodedLen`)
 	fn remove_presentation() -> Weight {
@@ -222,7 +234,7 @@ impl<T: frame_system::Config> pallet_statement::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 41_970_000 picoseconds.
 		Weight::from_parts(42_910_000, 0)
 			.saturating_add(Weight::from_parts(0, 3686))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
 	}
 }